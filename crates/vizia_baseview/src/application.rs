@@ -317,27 +317,15 @@ impl ApplicationRunner {
                 baseview::MouseEvent::WheelScrolled { delta, modifiers } => {
                     update_modifiers(modifiers);
 
-                    let (lines_x, lines_y) = match delta {
-                        baseview::ScrollDelta::Lines { x, y } => (x, y),
-                        baseview::ScrollDelta::Pixels { x, y } => (
-                            if x < 0.0 {
-                                -1.0
-                            } else if x > 1.0 {
-                                1.0
-                            } else {
-                                0.0
-                            },
-                            if y < 0.0 {
-                                -1.0
-                            } else if y > 1.0 {
-                                1.0
-                            } else {
-                                0.0
-                            },
+                    let out_event = match delta {
+                        baseview::ScrollDelta::Lines { x, y } => WindowEvent::MouseScroll(x, y),
+                        baseview::ScrollDelta::Pixels { x, y } => WindowEvent::MouseScrollPixels(
+                            x * self.window_scale_factor as f32,
+                            y * self.window_scale_factor as f32,
                         ),
                     };
 
-                    cx.emit_origin(WindowEvent::MouseScroll(lines_x, lines_y));
+                    cx.emit_origin(out_event);
                 }
                 _ => {}
             },
@@ -5,7 +5,7 @@ use morphorm::GeometryChanged;
 use std::fmt::Debug;
 
 use crate::prelude::*;
-use crate::style::Transform2D;
+use crate::style::{ClipShape, Transform2D};
 use vizia_storage::SparseSet;
 use vizia_storage::SparseSetError;
 
@@ -244,6 +244,27 @@ impl BoundingBox {
         let y_hit = other.y >= self.y && other.y < self.y + self.h;
         x_hit && y_hit
     }
+
+    /// Returns the smallest bounding box which contains both bounding boxes.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> BoundingBox {
+        BoundingBox::from_min_max(
+            self.left().min(other.left()),
+            self.top().min(other.top()),
+            self.right().max(other.right()),
+            self.bottom().max(other.bottom()),
+        )
+    }
+
+    /// Returns the overlapping area of two bounding boxes, which is empty if they don't intersect.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> BoundingBox {
+        let left = self.left().max(other.left());
+        let top = self.top().max(other.top());
+        let right = self.right().min(other.right()).max(left);
+        let bottom = self.bottom().min(other.bottom()).max(top);
+        BoundingBox::from_min_max(left, top, right, bottom)
+    }
 }
 
 #[derive(Debug, Default, Clone, PartialEq)]
@@ -272,6 +293,13 @@ pub struct CachedData {
 
     //pub(crate) prev_size: SparseSet<Pos>,
     clip_region: SparseSet<BoundingBox>,
+    // Maps the clip region of an entity into window coordinates
+    clip_transform: SparseSet<Transform2D>,
+    // The shape an entity clips itself and its descendants to, if it can't be applied as a scissor
+    clip_shape: SparseSet<ClipShape>,
+
+    // The distance a sticky entity has been moved from its laid out position
+    sticky_offset: SparseSet<(f32, f32)>,
 
     // Transform
     rotate: SparseSet<f32>,
@@ -317,6 +345,7 @@ impl CachedData {
         self.z_index.insert(entity, 0)?;
 
         self.clip_region.insert(entity, Default::default())?;
+        self.clip_transform.insert(entity, Transform2D::identity())?;
         self.space.insert(entity, Default::default())?;
         self.size.insert(entity, Default::default())?;
         self.cross_stretch_sum.insert(entity, Default::default())?;
@@ -354,6 +383,9 @@ impl CachedData {
         self.z_index.remove(entity);
 
         self.clip_region.remove(entity);
+        self.clip_transform.remove(entity);
+        self.clip_shape.remove(entity);
+        self.sticky_offset.remove(entity);
         self.space.remove(entity);
         self.size.remove(entity);
         self.cross_stretch_sum.remove(entity);
@@ -485,6 +517,26 @@ impl CachedData {
         self.clip_region.get(entity).cloned().unwrap()
     }
 
+    /// Returns the transform from the coordinates of the clip region of the entity to window
+    /// coordinates.
+    ///
+    /// This is the identity unless the entity is within a transformed view which clips its
+    /// descendants, in which case the clip region is in the untransformed coordinates of that view.
+    pub fn get_clip_transform(&self, entity: Entity) -> Transform2D {
+        self.clip_transform.get(entity).cloned().unwrap()
+    }
+
+    /// Returns the shape the entity clips itself and its descendants to, if the clipping can't be
+    /// applied with its clip region alone.
+    pub(crate) fn get_clip_shape(&self, entity: Entity) -> Option<ClipShape> {
+        self.clip_shape.get(entity).copied()
+    }
+
+    /// Returns the distance a sticky entity has been moved from its laid out position.
+    pub(crate) fn get_sticky_offset(&self, entity: Entity) -> (f32, f32) {
+        self.sticky_offset.get(entity).copied().unwrap_or_default()
+    }
+
     /// Returns the Z index of the entity.
     ///
     /// Entities can specify a z-index with `entity.set_z_index(cx, value)`.
@@ -675,6 +727,32 @@ impl CachedData {
         }
     }
 
+    pub(crate) fn set_clip_transform(&mut self, entity: Entity, val: Transform2D) {
+        if let Some(clip_transform) = self.clip_transform.get_mut(entity) {
+            *clip_transform = val;
+        }
+    }
+
+    pub(crate) fn set_clip_shape(&mut self, entity: Entity, val: Option<ClipShape>) {
+        match val {
+            Some(shape) => {
+                self.clip_shape.insert(entity, shape).expect("Failed to set clip shape");
+            }
+
+            None => {
+                self.clip_shape.remove(entity);
+            }
+        }
+    }
+
+    pub(crate) fn set_sticky_offset(&mut self, entity: Entity, val: (f32, f32)) {
+        if val == (0.0, 0.0) {
+            self.sticky_offset.remove(entity);
+        } else {
+            self.sticky_offset.insert(entity, val).expect("Failed to set sticky offset");
+        }
+    }
+
     pub fn set_z_index(&mut self, entity: Entity, val: i32) {
         if let Some(z_index) = self.z_index.get_mut(entity) {
             *z_index = val;
@@ -950,4 +1028,27 @@ mod tests {
         let b = BoundingBox { x: 100f32, y: 75f32, w: 100f32, h: 150f32 };
         assert_eq!(a, b);
     }
+
+    #[test]
+    fn get_union() {
+        let rect = rect();
+        let a = rect.union(&BoundingBox { x: 150f32, y: 50f32, w: 100f32, h: 100f32 });
+        let b = BoundingBox { x: 100f32, y: 50f32, w: 150f32, h: 150f32 };
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn get_intersection() {
+        let rect = rect();
+        let a = rect.intersection(&BoundingBox { x: 150f32, y: 50f32, w: 100f32, h: 100f32 });
+        let b = BoundingBox { x: 150f32, y: 100f32, w: 50f32, h: 50f32 };
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn get_empty_intersection() {
+        let rect = rect();
+        let a = rect.intersection(&BoundingBox { x: 300f32, y: 100f32, w: 100f32, h: 100f32 });
+        assert_eq!(a.w, 0f32);
+    }
 }
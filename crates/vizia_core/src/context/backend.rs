@@ -168,6 +168,13 @@ impl<'a> BackendContext<'a> {
         !self.0.event_queue.is_empty()
     }
 
+    /// Returns the time at which a tooltip waiting to be shown is due, if any. The backend should
+    /// process visual updates again at that time even if no events arrive.
+    pub fn tooltip_deadline(&self) -> Option<instant::Instant> {
+        let tooltips = &self.0.tooltips;
+        tooltips.is_pending().then(|| tooltips.since + tooltips.delay)
+    }
+
     /// For each binding or data observer, check if its data has changed, and if so, rerun its
     /// builder/body.
     pub fn process_data_updates(&mut self) {
@@ -202,6 +209,10 @@ impl<'a> BackendContext<'a> {
         for img in self.0.resource_manager.images.values_mut() {
             if img.dirty {
                 observers.extend(img.observers.iter());
+                // Views showing the image need to be redrawn even if they aren't bindings
+                for observer in img.observers.iter() {
+                    self.0.style.redraw(*observer);
+                }
                 img.dirty = false;
             }
         }
@@ -221,6 +232,9 @@ impl<'a> BackendContext<'a> {
                 binding.update(self.0);
                 self.0.current = prev;
                 self.0.bindings.insert(observer, binding);
+
+                // The view containing the binding is redrawn, as its content may have changed
+                self.0.style.redraw(observer);
             }
         }
     }
@@ -249,6 +263,16 @@ impl<'a> BackendContext<'a> {
         // Compute any animations for this frame.
         animation_system(self.0);
 
+        // Send animation frame events to the views which requested them.
+        for entity in std::mem::take(&mut self.0.animation_frames) {
+            self.0.event_queue.push_back(
+                Event::new(WindowEvent::AnimationFrame)
+                    .target(entity)
+                    .origin(Entity::root())
+                    .propagate(Propagation::Direct),
+            );
+        }
+
         // Apply z-order inheritance.
         z_ordering_system(self.0, &tree);
 
@@ -258,12 +282,18 @@ impl<'a> BackendContext<'a> {
         // Perform layout.
         layout_system(self.0, &tree);
 
+        // Move anchored views next to their anchors.
+        anchor_system(self.0, &tree);
+
         // Apply transform inheritance.
         transform_system(self.0, &tree);
 
         // Determine hovered entity.
         hover_system(self.0);
 
+        // Show or hide tooltips.
+        tooltip_system(self.0);
+
         // Apply clipping inheritance.
         clipping_system(self.0, &tree);
 
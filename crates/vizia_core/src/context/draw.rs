@@ -2,8 +2,8 @@ use cosmic_text::{FamilyOwned, Weight};
 use std::any::{Any, TypeId};
 use std::ops::Range;
 
-use femtovg::{ImageId, Paint, Path};
-use fnv::FnvHashMap;
+use femtovg::{ImageId, Paint, Path, RenderTarget};
+use fnv::{FnvHashMap, FnvHashSet};
 use morphorm::Units;
 
 use crate::cache::{BoundingBox, CachedData};
@@ -11,7 +11,7 @@ use crate::events::ViewHandler;
 use crate::prelude::*;
 use crate::resource::ResourceManager;
 use crate::state::ModelDataStore;
-use crate::style::{LinearGradient, Style};
+use crate::style::{LinearGradient, Style, Transform2D};
 use crate::text::TextContext;
 use vizia_input::{Modifiers, MouseState};
 use vizia_storage::SparseSet;
@@ -20,19 +20,91 @@ use vizia_storage::SparseSet;
 pub struct DrawCache {
     pub shadow_image: SparseSet<(ImageId, ImageId)>,
     pub text_lines: SparseSet<Vec<(Range<usize>, femtovg::TextMetrics)>>,
+    /// The image the window is drawn into, which keeps the previous frame so that only damaged
+    /// regions need to be redrawn.
+    pub(crate) frame_image: Option<ImageId>,
+    /// The area of the window each entity covered when it was last drawn.
+    pub(crate) footprints: FnvHashMap<Entity, Footprint>,
+    /// The area uncovered by entities which have been removed since the last frame.
+    pub(crate) damage: Option<BoundingBox>,
+    /// Whether redrawn regions are highlighted on screen.
+    pub(crate) paint_flashing: bool,
+    /// The layers of cached or shape-clipped views, keyed by the root view of each layer.
+    pub(crate) layers: FnvHashMap<Entity, Layer>,
+    /// The views which are drawn into their own layer in the current frame.
+    pub(crate) layer_roots: FnvHashSet<Entity>,
+    /// The layer image currently being rendered into, if any.
+    pub(crate) layer_target: Option<ImageId>,
+    /// The points of the window to sample the colour of once drawn, with the views to send them to.
+    pub(crate) color_samples: Vec<(Entity, f32, f32)>,
 }
 
 impl DrawCache {
     pub fn new() -> Self {
-        Self { shadow_image: SparseSet::new(), text_lines: SparseSet::new() }
+        Self {
+            shadow_image: SparseSet::new(),
+            text_lines: SparseSet::new(),
+            frame_image: None,
+            footprints: FnvHashMap::default(),
+            damage: None,
+            paint_flashing: false,
+            layers: FnvHashMap::default(),
+            layer_roots: FnvHashSet::default(),
+            layer_target: None,
+            color_samples: Vec::new(),
+        }
     }
 
     pub fn remove(&mut self, entity: Entity) {
         self.shadow_image.remove(entity);
         self.text_lines.remove(entity);
+
+        if let Some(footprint) = self.footprints.remove(&entity) {
+            self.add_damage(footprint.area);
+        }
+    }
+
+    /// Adds an area of the window which needs to be redrawn.
+    pub(crate) fn add_damage(&mut self, area: BoundingBox) {
+        if area.w <= 0.0 || area.h <= 0.0 {
+            return;
+        }
+
+        self.damage = Some(match self.damage {
+            Some(damage) => damage.union(&area),
+            None => area,
+        });
+    }
+
+    /// Returns the target views should draw to, which is restored after drawing into other images.
+    pub fn render_target(&self) -> RenderTarget {
+        self.layer_target.or(self.frame_image).map_or(RenderTarget::Screen, RenderTarget::Image)
     }
 }
 
+/// The state of an entity when it was last drawn. If any of it changes the entity is redrawn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Footprint {
+    /// The area of the window covered by the entity, including its shadow and outline.
+    pub area: BoundingBox,
+    pub transform: Transform2D,
+    pub opacity: f32,
+    pub z_index: i32,
+}
+
+/// An offscreen image holding the rendered content of a view and its descendants.
+pub(crate) struct Layer {
+    pub image: ImageId,
+    /// The area covered by the layer, relative to the bounds of its root view.
+    pub area: BoundingBox,
+    /// The bounds of each entity in the layer, relative to the root view, and the region of the
+    /// layer it was clipped to, when it was rendered.
+    pub contents: Vec<(Entity, BoundingBox, BoundingBox)>,
+    pub dpi_factor: f64,
+    /// Whether something within the layer has changed since it was rendered.
+    pub dirty: bool,
+}
+
 /// A restricted context used when drawing.
 pub struct DrawContext<'a> {
     pub(crate) current: Entity,
@@ -145,6 +217,7 @@ impl<'a> DrawContext<'a> {
     style_getter_untranslated!(Color, outer_shadow_color);
     style_getter_untranslated!(Color, inner_shadow_color);
     style_getter_untranslated!(Color, selection_color);
+    style_getter_untranslated!(Color, match_color);
     style_getter_untranslated!(Color, caret_color);
     style_getter_untranslated!(LinearGradient, background_gradient);
     style_getter_untranslated!(BorderCornerShape, border_shape_top_right);
@@ -152,6 +225,13 @@ impl<'a> DrawContext<'a> {
     style_getter_untranslated!(BorderCornerShape, border_shape_bottom_right);
     style_getter_untranslated!(BorderCornerShape, border_shape_bottom_left);
     style_getter_untranslated!(String, background_image);
+    style_getter_untranslated!(BackgroundSize, background_size);
+    style_getter_untranslated!(ImagePosition, background_position);
+    style_getter_untranslated!(BackgroundRepeat, background_repeat);
+    style_getter_untranslated!(String, border_image);
+    style_getter_untranslated!(BorderImageSlice, border_image_slice);
+    style_getter_untranslated!(ObjectFit, object_fit);
+    style_getter_untranslated!(ImagePosition, object_position);
     style_getter_untranslated!(String, image);
     style_getter_untranslated!(Vec<FamilyOwned>, font_family);
     style_getter_untranslated!(Weight, font_weight);
@@ -180,6 +260,16 @@ impl<'a> DrawContext<'a> {
         origin: (f32, f32),
         justify: (f32, f32),
     ) {
+        if let Some(color) = self.match_color().copied() {
+            let mut path = Path::new();
+            for (x, y, w, h) in
+                self.text_context.layout_search_matches(self.current, origin, justify)
+            {
+                path.rect(x, y, w, h);
+            }
+            canvas.fill_path(&mut path, &Paint::color(color.into()));
+        }
+
         if let Some(color) = self.selection_color().copied() {
             let mut path = Path::new();
             for (x, y, w, h) in self.text_context.layout_selection(self.current, origin, justify) {
@@ -41,6 +41,7 @@ pub struct EventContext<'a> {
     pub modifiers: &'a Modifiers,
     pub mouse: &'a MouseState<Entity>,
     pub(crate) event_queue: &'a mut VecDeque<Event>,
+    animation_frames: &'a mut Vec<Entity>,
     cursor_icon_locked: &'a mut bool,
     window_size: &'a mut WindowSize,
     user_scale_factor: &'a mut f64,
@@ -69,6 +70,7 @@ impl<'a> EventContext<'a> {
             modifiers: &cx.modifiers,
             mouse: &cx.mouse,
             event_queue: &mut cx.event_queue,
+            animation_frames: &mut cx.animation_frames,
             cursor_icon_locked: &mut cx.cursor_icon_locked,
             window_size: &mut cx.window_size,
             user_scale_factor: &mut cx.user_scale_factor,
@@ -113,9 +115,9 @@ impl<'a> EventContext<'a> {
             pseudo_classes.set(PseudoClass::ACTIVE, active);
         }
 
-        self.style.needs_restyle = true;
-        self.style.needs_relayout = true;
-        self.style.needs_redraw = true;
+        self.style.restyle(self.current);
+        self.style.relayout(self.current);
+        self.style.redraw(self.current);
     }
 
     /// Capture mouse input for the current entity.
@@ -130,6 +132,32 @@ impl<'a> EventContext<'a> {
         }
     }
 
+    /// Requests that the current entity is sent a [`WindowEvent::AnimationFrame`] event before the
+    /// next frame is drawn.
+    ///
+    /// A view animating itself requests another frame each time it receives one, until the
+    /// animation is finished.
+    pub fn request_animation_frame(&mut self) {
+        if !self.animation_frames.contains(&self.current) {
+            self.animation_frames.push(self.current);
+        }
+    }
+
+    /// Scrolls any scroll views containing the entity so that it is in view.
+    pub fn scroll_to(&mut self, entity: Entity) {
+        self.event_queue.push_back(
+            Event::new(ScrollEvent::ScrollTo(entity))
+                .target(entity)
+                .origin(self.current)
+                .propagate(Propagation::Up),
+        );
+    }
+
+    /// Scrolls any scroll views containing the current entity so that it is in view.
+    pub fn scroll_into_view(&mut self) {
+        self.scroll_to(self.current);
+    }
+
     /// Enables or disables pseudoclasses for the focus of an entity
     fn set_focus_pseudo_classes(&mut self, focused: Entity, enabled: bool, focus_visible: bool) {
         #[cfg(debug_assertions)]
@@ -154,12 +182,14 @@ impl<'a> EventContext<'a> {
                 pseudo_classes.set(PseudoClass::FOCUS_VISIBLE, enabled);
             }
         }
+        self.style.restyle(focused);
 
         for ancestor in focused.parent_iter(&self.tree) {
             let entity = ancestor;
             if let Some(pseudo_classes) = self.style.pseudo_classes.get_mut(entity) {
                 pseudo_classes.set(PseudoClass::FOCUS_WITHIN, enabled);
             }
+            self.style.restyle(entity);
         }
     }
 
@@ -175,9 +205,9 @@ impl<'a> EventContext<'a> {
         }
         self.set_focus_pseudo_classes(new_focus, true, focus_visible);
 
-        self.style.needs_relayout = true;
-        self.style.needs_redraw = true;
-        self.style.needs_restyle = true;
+        self.style.relayout(self.current);
+        self.style.redraw(old_focus);
+        self.style.redraw(new_focus);
     }
 
     /// Sets application focus to the current entity using the previous focus visibility.
@@ -241,9 +271,9 @@ impl<'a> EventContext<'a> {
             pseudo_classes.set(PseudoClass::HOVER, flag);
         }
 
-        self.style.needs_restyle = true;
-        self.style.needs_relayout = true;
-        self.style.needs_redraw = true;
+        self.style.restyle(self.current);
+        self.style.relayout(self.current);
+        self.style.redraw(self.current);
     }
 
     /// Sets the checked flag of the current entity.
@@ -253,9 +283,9 @@ impl<'a> EventContext<'a> {
             pseudo_classes.set(PseudoClass::CHECKED, flag);
         }
 
-        self.style.needs_restyle = true;
-        self.style.needs_relayout = true;
-        self.style.needs_redraw = true;
+        self.style.restyle(self.current);
+        self.style.relayout(self.current);
+        self.style.redraw(self.current);
     }
 
     /// Sets the checked flag of the current entity.
@@ -265,9 +295,20 @@ impl<'a> EventContext<'a> {
             pseudo_classes.set(PseudoClass::SELECTED, flag);
         }
 
-        self.style.needs_restyle = true;
-        self.style.needs_relayout = true;
-        self.style.needs_redraw = true;
+        self.style.restyle(self.current);
+        self.style.relayout(self.current);
+        self.style.redraw(self.current);
+    }
+
+    /// Sets the invalid flag of the current entity.
+    pub fn set_invalid(&mut self, flag: bool) {
+        let current = self.current();
+        if let Some(pseudo_classes) = self.style.pseudo_classes.get_mut(current) {
+            pseudo_classes.set(PseudoClass::INVALID, flag);
+        }
+
+        self.style.restyle(self.current);
+        self.style.redraw(self.current);
     }
 
     /// Get the contents of the system clipboard. This may fail for a variety of backend-specific
@@ -301,9 +342,9 @@ impl<'a> EventContext<'a> {
             self.style.classes.insert(current, class_list).expect("Failed to insert class name");
         }
 
-        self.style.needs_restyle = true;
-        self.style.needs_relayout = true;
-        self.style.needs_redraw = true;
+        self.style.restyle_branch(self.current);
+        self.style.relayout(self.current);
+        self.style.redraw(self.current);
     }
 
     pub fn play_animation(&mut self, animation: Animation) {
@@ -314,13 +355,15 @@ impl<'a> EventContext<'a> {
         self.data::<Environment>().unwrap()
     }
 
+    /// Marks the current entity as needing to be redrawn.
     pub fn needs_redraw(&mut self) {
-        self.style.needs_redraw = true;
+        self.style.redraw(self.current);
     }
 
+    /// Marks the current entity as needing to be laid out, such as when its content has changed size.
     pub fn needs_relayout(&mut self) {
-        self.style.needs_relayout = true;
-        self.style.needs_redraw = true;
+        self.style.relayout(self.current);
+        self.style.redraw(self.current);
     }
 
     pub fn reload_styles(&mut self) -> Result<(), std::io::Error> {
@@ -351,7 +394,8 @@ impl<'a> EventContext<'a> {
 
         self.style.needs_restyle = true;
         self.style.needs_relayout = true;
-        self.style.needs_redraw = true;
+        // Redraw the whole window as rule values may have changed without changing which rules match
+        self.style.redraw(Entity::root());
 
         Ok(())
     }
@@ -2,6 +2,7 @@ pub mod backend;
 mod draw;
 mod event;
 mod proxy;
+mod tooltip;
 
 use instant::Instant;
 use std::any::{Any, TypeId};
@@ -23,12 +24,15 @@ use unic_langid::LanguageIdentifier;
 pub use draw::*;
 pub use event::*;
 pub use proxy::*;
+pub(crate) use tooltip::*;
 
 use crate::cache::CachedData;
 use crate::environment::Environment;
 use crate::events::ViewHandler;
 use crate::fonts;
 use crate::prelude::*;
+#[cfg(feature = "svg")]
+use crate::resource::{rasterize_svg, StoredSvg};
 use crate::resource::{ImageOrId, ImageRetentionPolicy, ResourceManager, StoredImage};
 use crate::state::{BindingHandler, ModelDataStore};
 use crate::style::Style;
@@ -60,6 +64,9 @@ pub struct Context {
     pub(crate) style: Style,
     pub(crate) cache: CachedData,
     pub(crate) draw_cache: DrawCache,
+    pub(crate) tooltips: TooltipState,
+    /// The views which have requested an animation frame event before the next frame.
+    pub(crate) animation_frames: Vec<Entity>,
 
     pub(crate) canvases: HashMap<Entity, crate::prelude::Canvas>,
     //environment: Environment,
@@ -133,6 +140,8 @@ impl Context {
             style: Style::default(),
             cache,
             draw_cache: DrawCache::new(),
+            tooltips: TooltipState::new(),
+            animation_frames: Vec::new(),
             canvases: HashMap::new(),
             // environment: Environment::new(),
             event_queue: VecDeque::new(),
@@ -225,9 +234,28 @@ impl Context {
         self.user_scale_factor
     }
 
-    /// Mark the application as needing to rerun the draw method
+    /// Mark the current entity as needing to be redrawn
     pub fn need_redraw(&mut self) {
-        self.style.needs_redraw = true;
+        self.style.redraw(self.current);
+    }
+
+    /// Highlight the regions of the window which are redrawn each frame.
+    ///
+    /// In debug builds this can also be toggled with Ctrl+Shift+Alt+P.
+    pub fn set_paint_flashing(&mut self, enabled: bool) {
+        self.draw_cache.paint_flashing = enabled;
+        self.style.redraw(Entity::root());
+    }
+
+    /// Sets the time the pointer has to rest on a view, or keyboard focus stay on it, before its
+    /// tooltip is shown. Defaults to half a second.
+    pub fn set_tooltip_delay(&mut self, delay: std::time::Duration) {
+        self.tooltips.delay = delay;
+    }
+
+    /// Sets where tooltips are placed when they are shown.
+    pub fn set_tooltip_placement(&mut self, placement: TooltipPlacement) {
+        self.tooltips.placement = placement;
     }
 
     /// Mark the application as needing to recompute view styles
@@ -269,12 +297,14 @@ impl Context {
                 pseudo_classes.set(PseudoClass::FOCUS_VISIBLE, enabled);
             }
         }
+        self.style.restyle(focused);
 
         for ancestor in focused.parent_iter(&self.tree) {
             let entity = ancestor;
             if let Some(pseudo_classes) = self.style.pseudo_classes.get_mut(entity) {
                 pseudo_classes.set(PseudoClass::FOCUS_WITHIN, enabled);
             }
+            self.style.restyle(entity);
         }
     }
 
@@ -290,9 +320,9 @@ impl Context {
         }
         self.set_focus_pseudo_classes(new_focus, true, focus_visible);
 
-        self.style.needs_relayout = true;
-        self.style.needs_redraw = true;
-        self.style.needs_restyle = true;
+        self.style.relayout(self.current);
+        self.style.redraw(old_focus);
+        self.style.redraw(new_focus);
     }
 
     /// Sets application focus to the current entity using the previous focus visibility
@@ -314,9 +344,9 @@ impl Context {
             pseudo_classes.set(PseudoClass::SELECTED, flag);
         }
 
-        self.style.needs_restyle = true;
-        self.style.needs_relayout = true;
-        self.style.needs_redraw = true;
+        self.style.restyle(self.current);
+        self.style.relayout(self.current);
+        self.style.redraw(self.current);
     }
 
     pub(crate) fn remove_children(&mut self, entity: Entity) {
@@ -329,10 +359,10 @@ impl Context {
     pub fn remove(&mut self, entity: Entity) {
         let delete_list = entity.branch_iter(&self.tree).collect::<Vec<_>>();
 
-        if !delete_list.is_empty() {
-            self.style.needs_restyle = true;
-            self.style.needs_relayout = true;
-            self.style.needs_redraw = true;
+        // The siblings of the removed entity may move into the space it leaves
+        if let Some(parent) = self.tree.get_layout_parent(entity) {
+            self.style.relayout(parent);
+            self.style.redraw(parent);
         }
 
         for entity in delete_list.iter().rev() {
@@ -362,6 +392,8 @@ impl Context {
             self.tree.remove(*entity).expect("");
             self.cache.remove(*entity);
             self.draw_cache.remove(*entity);
+            self.tooltips.remove(*entity);
+            self.animation_frames.retain(|e| e != entity);
             self.style.remove(*entity);
             self.data.remove(*entity);
             self.views.remove(entity);
@@ -499,6 +531,12 @@ impl Context {
                 );
                 occ.get_mut().dirty = true;
                 occ.get_mut().retention_policy = policy;
+
+                // Views already showing the image need to show the new one
+                for &observer in occ.get().observers.iter() {
+                    self.style.relayout(observer);
+                    self.style.redraw(observer);
+                }
             }
             Entry::Vacant(vac) => {
                 vac.insert(StoredImage {
@@ -513,8 +551,26 @@ impl Context {
                 });
             }
         }
-        self.style.needs_redraw = true;
-        self.style.needs_relayout = true;
+    }
+
+    /// Loads an SVG image from its source. The image is rasterized for the current scale factor,
+    /// and again whenever the scale factor changes, so that it stays crisp.
+    ///
+    /// The size of the image in logical pixels is the size given by the SVG document.
+    #[cfg(feature = "svg")]
+    pub fn load_svg(
+        &mut self,
+        path: String,
+        data: &[u8],
+        policy: ImageRetentionPolicy,
+    ) -> Result<(), usvg::Error> {
+        let tree = usvg::Tree::from_data(data, &usvg::Options::default())?;
+        let scale = self.style.dpi_factor;
+        let image = rasterize_svg(&tree, scale).ok_or(usvg::Error::InvalidSize)?;
+        self.resource_manager.svgs.insert(path.clone(), StoredSvg { tree, scale });
+        self.load_image(path, image, policy);
+
+        Ok(())
     }
 
     pub fn add_translation(&mut self, lang: LanguageIdentifier, ftl: String) {
@@ -542,8 +598,24 @@ pub(crate) enum InternalEvent {
         image: Mutex<Option<image::DynamicImage>>,
         policy: ImageRetentionPolicy,
     },
+    #[cfg(feature = "svg")]
+    LoadSvg {
+        path: String,
+        data: Vec<u8>,
+        policy: ImageRetentionPolicy,
+    },
+    /// Samples the colour of the window at a point once it has been drawn, which is sent to the
+    /// target as a [`SampledColor`].
+    SampleColor {
+        target: Entity,
+        x: f32,
+        y: f32,
+    },
 }
 
+/// The colour of the window at a point requested with [`InternalEvent::SampleColor`].
+pub(crate) struct SampledColor(pub Color);
+
 /// A trait for any Context-like object that lets you access stored model data.
 ///
 /// This lets e.g Lens::get be generic over any of these types.
@@ -88,6 +88,17 @@ impl ContextProxy {
         self.emit(InternalEvent::LoadImage { path, image: Mutex::new(Some(image)), policy })
     }
 
+    /// Loads an SVG image from its source. See [`Context::load_svg`].
+    #[cfg(feature = "svg")]
+    pub fn load_svg(
+        &mut self,
+        path: String,
+        data: Vec<u8>,
+        policy: ImageRetentionPolicy,
+    ) -> Result<(), ProxyEmitError> {
+        self.emit(InternalEvent::LoadSvg { path, data, policy })
+    }
+
     pub fn spawn<F>(&self, target: F)
     where
         F: 'static + Send + FnOnce(&mut ContextProxy),
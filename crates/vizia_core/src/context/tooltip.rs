@@ -0,0 +1,63 @@
+use fnv::FnvHashMap;
+use instant::{Duration, Instant};
+
+use crate::prelude::*;
+
+/// The time the pointer has to rest on a view, or keyboard focus stay on it, before its tooltip is
+/// shown.
+const DEFAULT_TOOLTIP_DELAY: Duration = Duration::from_millis(500);
+
+/// The state of the tooltips of a window.
+pub(crate) struct TooltipState {
+    /// The tooltip view of each view with a tooltip.
+    pub(crate) tooltips: FnvHashMap<Entity, Entity>,
+    pub(crate) delay: Duration,
+    pub(crate) placement: TooltipPlacement,
+    /// The view whose tooltip is waiting to be shown, or is being shown.
+    pub(crate) target: Option<Entity>,
+    /// When the current target was hovered or focused.
+    pub(crate) since: Instant,
+    pub(crate) shown: bool,
+    /// Whether the tooltip of the current target has been dismissed by a press, scroll or escape.
+    pub(crate) dismissed: bool,
+    /// A zero-sized view moved to the cursor, which tooltips placed at the cursor are anchored to.
+    pub(crate) cursor: Option<Entity>,
+}
+
+impl TooltipState {
+    pub fn new() -> Self {
+        Self {
+            tooltips: FnvHashMap::default(),
+            delay: DEFAULT_TOOLTIP_DELAY,
+            placement: TooltipPlacement::Anchored(AnchorSide::Bottom),
+            target: None,
+            since: Instant::now(),
+            shown: false,
+            dismissed: false,
+            cursor: None,
+        }
+    }
+
+    /// Returns true if a tooltip is waiting for its delay to pass before being shown.
+    pub fn is_pending(&self) -> bool {
+        self.target.is_some() && !self.shown && !self.dismissed
+    }
+
+    /// Hides the tooltip being shown, if any, until another view is hovered or focused.
+    pub fn dismiss(&mut self) {
+        self.dismissed = true;
+    }
+
+    pub fn remove(&mut self, entity: Entity) {
+        self.tooltips.remove(&entity);
+
+        if self.target == Some(entity) {
+            self.target = None;
+            self.shown = false;
+        }
+
+        if self.cursor == Some(entity) {
+            self.cursor = None;
+        }
+    }
+}
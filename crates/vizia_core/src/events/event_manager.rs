@@ -54,6 +54,16 @@ impl EventManager {
                         context.load_image(path.clone(), image, *policy);
                     }
                 }
+                #[cfg(feature = "svg")]
+                InternalEvent::LoadSvg { path, data, policy } => {
+                    if let Err(error) = context.load_svg(path.clone(), data, *policy) {
+                        println!("Failed to load SVG image {}: {}", path, error);
+                    }
+                }
+                InternalEvent::SampleColor { target, x, y } => {
+                    context.draw_cache.color_samples.push((*target, *x, *y));
+                    context.style.redraw(*target);
+                }
             });
 
             // handle state updates for window events
@@ -202,6 +212,8 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
             mutate_direct_or_up(meta, context.captured, context.hovered, false);
         }
         WindowEvent::MouseDown(button) => {
+            context.tooltips.dismiss();
+
             // do direct state-updates
             match button {
                 MouseButton::Left => {
@@ -215,6 +227,7 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                     {
                         pseudo_classes.set(PseudoClass::ACTIVE, true);
                     }
+                    context.style.restyle(context.triggered);
                     let focusable = context
                         .style
                         .abilities
@@ -310,19 +323,24 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                 {
                     pseudo_classes.set(PseudoClass::ACTIVE, false);
                 }
-                context.need_restyle();
+                context.style.restyle(context.triggered);
 
                 context.triggered = Entity::null();
             }
 
             mutate_direct_or_up(meta, context.captured, context.hovered, true);
         }
-        WindowEvent::MouseScroll(_, _) => {
+        WindowEvent::MouseScroll(_, _) | WindowEvent::MouseScrollPixels(_, _) => {
+            context.tooltips.dismiss();
             meta.target = context.hovered;
         }
         WindowEvent::KeyDown(code, _) => {
             meta.target = context.focused;
 
+            if *code == Code::Escape {
+                context.tooltips.dismiss();
+            }
+
             #[cfg(debug_assertions)]
             if *code == Code::KeyH {
                 for entity in context.tree.into_iter() {
@@ -443,11 +461,26 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                 }
             }
 
+            #[cfg(debug_assertions)]
+            if *code == Code::KeyP
+                && context.modifiers == Modifiers::CTRL | Modifiers::SHIFT | Modifiers::ALT
+            {
+                let enabled = !context.draw_cache.paint_flashing;
+                context.set_paint_flashing(enabled);
+            }
+
             if *code == Code::F5 {
                 EventContext::new(context).reload_styles().unwrap();
             }
 
-            if *code == Code::Tab {
+            let tab_input = context
+                .style
+                .abilities
+                .get(context.focused)
+                .map_or(false, |abilities| abilities.contains(Abilities::TAB_INPUT));
+
+            // Ctrl+Tab is left to views which switch between pages with it, such as tab views
+            if *code == Code::Tab && !tab_input && !context.modifiers.contains(Modifiers::CTRL) {
                 let lock_focus_to = context.tree.lock_focus_within(context.focused);
                 if context.modifiers.contains(Modifiers::SHIFT) {
                     let prev_focused = if let Some(prev_focused) =
@@ -478,7 +511,8 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                         {
                             pseudo_classes.set(PseudoClass::ACTIVE, false);
                         }
-                        context.need_restyle();
+                        context.style.restyle(context.triggered);
+                        context.style.redraw(context.triggered);
                         context.triggered = Entity::null();
                     }
                 } else {
@@ -510,14 +544,15 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                         {
                             pseudo_classes.set(PseudoClass::ACTIVE, false);
                         }
-                        context.need_restyle();
+                        context.style.restyle(context.triggered);
+                        context.style.redraw(context.triggered);
                         context.triggered = Entity::null();
                     }
                 }
 
-                context.style.needs_relayout = true;
-                context.style.needs_redraw = true;
-                context.style.needs_restyle = true;
+                context.style.relayout(context.focused);
+                context.style.redraw(context.focused);
+                context.style.restyle(context.focused);
             }
 
             if matches!(*code, Code::Enter | Code::NumpadEnter | Code::Space) {
@@ -527,6 +562,7 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                 {
                     pseudo_classes.set(PseudoClass::ACTIVE, true);
                 }
+                context.style.restyle(context.triggered);
                 context.with_current(context.focused, |cx| {
                     cx.emit(WindowEvent::PressDown { mouse: false })
                 });
@@ -545,7 +581,7 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                 {
                     pseudo_classes.set(PseudoClass::ACTIVE, false);
                 }
-                context.need_restyle();
+                context.style.restyle(context.triggered);
                 context.triggered = Entity::null();
             }
         }
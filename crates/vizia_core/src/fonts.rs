@@ -24,9 +24,60 @@ pub mod material_names {
     pub const RIGHT: &str = "\u{E315}";
     pub const UP: &str = "\u{e316}";
     pub const PENCIL: &str = "\u{e150}";
+    pub const COLORIZE: &str = "\u{e3b8}";
+    pub const CALENDAR: &str = "\u{e935}";
+    pub const CLOCK: &str = "\u{e8b5}";
 }
 
 pub mod unicode_names {
     pub const PLUS: &str = "\u{2b}";
     pub const MINUS: &str = "\u{2d}";
 }
+
+mod icons;
+
+/// Returns the character of the icon with the given name in the [`ENTYPO`] font, e.g.
+/// `"down-open"`.
+pub fn entypo_icon(name: &str) -> Option<char> {
+    find_icon(icons::ENTYPO, name)
+}
+
+/// Returns the character of the icon with the given name in the [`MATERIAL_ICONS_REGULAR`] font,
+/// e.g. `"home"`.
+pub fn material_icon(name: &str) -> Option<char> {
+    find_icon(icons::MATERIAL_ICONS, name)
+}
+
+fn find_icon(icons: &[(&str, char)], name: &str) -> Option<char> {
+    icons.binary_search_by(|(icon, _)| (*icon).cmp(name)).ok().map(|index| icons[index].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn icon_tables_are_sorted() {
+        assert!(icons::ENTYPO.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(icons::MATERIAL_ICONS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn find_icons_by_name() {
+        assert_eq!(entypo_icon("down-open").map(String::from).as_deref(), Some(icons_names::DOWN));
+        assert_eq!(
+            material_icon("keyboard_arrow_down").map(String::from).as_deref(),
+            Some(material_names::DOWN)
+        );
+        assert_eq!(
+            material_icon("calendar_today").map(String::from).as_deref(),
+            Some(material_names::CALENDAR)
+        );
+        assert_eq!(
+            material_icon("schedule").map(String::from).as_deref(),
+            Some(material_names::CLOCK)
+        );
+        assert_eq!(material_icon("home"), Some('\u{e88a}'));
+        assert_eq!(material_icon("not_an_icon"), None);
+    }
+}
@@ -0,0 +1,2433 @@
+// Generated from the cmap and ligature tables of the bundled icon fonts. Both tables are sorted
+// by name so that they can be binary searched.
+
+pub(crate) const ENTYPO: &[(&str, char)] = &[
+    ("address", '\u{e723}'),
+    ("adjust", '\u{25d1}'),
+    ("air", '\u{e753}'),
+    ("alert", '\u{1f4a5}'),
+    ("archive", '\u{e738}'),
+    ("arrow-combo", '\u{e74f}'),
+    ("arrows-ccw", '\u{1f504}'),
+    ("attach", '\u{1f4ce}'),
+    ("attention", '\u{26a0}'),
+    ("back", '\u{1f519}'),
+    ("back-in-time", '\u{e771}'),
+    ("bag", '\u{1f45c}'),
+    ("basket", '\u{e73d}'),
+    ("battery", '\u{1f50b}'),
+    ("behance", '\u{f34e}'),
+    ("bell", '\u{1f514}'),
+    ("block", '\u{1f6ab}'),
+    ("book", '\u{1f4d5}'),
+    ("book-open", '\u{1f4d6}'),
+    ("bookmark", '\u{1f516}'),
+    ("bookmarks", '\u{1f4d1}'),
+    ("box", '\u{1f4e6}'),
+    ("briefcase", '\u{1f4bc}'),
+    ("brush", '\u{e79a}'),
+    ("bucket", '\u{e756}'),
+    ("calendar", '\u{1f4c5}'),
+    ("camera", '\u{1f4f7}'),
+    ("cancel", '\u{2715}'),
+    ("cancel-circled", '\u{2716}'),
+    ("cancel-squared", '\u{274e}'),
+    ("cc", '\u{e7a5}'),
+    ("cc-by", '\u{e7a6}'),
+    ("cc-nc", '\u{e7a7}'),
+    ("cc-nc-eu", '\u{e7a8}'),
+    ("cc-nc-jp", '\u{e7a9}'),
+    ("cc-nd", '\u{e7ab}'),
+    ("cc-pd", '\u{e7ac}'),
+    ("cc-remix", '\u{e7af}'),
+    ("cc-sa", '\u{e7aa}'),
+    ("cc-share", '\u{e7ae}'),
+    ("cc-zero", '\u{e7ad}'),
+    ("ccw", '\u{27f2}'),
+    ("cd", '\u{1f4bf}'),
+    ("chart-area", '\u{1f53e}'),
+    ("chart-bar", '\u{1f4ca}'),
+    ("chart-line", '\u{1f4c8}'),
+    ("chart-pie", '\u{e751}'),
+    ("chat", '\u{e720}'),
+    ("check", '\u{2713}'),
+    ("clipboard", '\u{1f4cb}'),
+    ("clock", '\u{1f554}'),
+    ("cloud", '\u{2601}'),
+    ("cloud-thunder", '\u{26c8}'),
+    ("code", '\u{e714}'),
+    ("cog", '\u{2699}'),
+    ("comment", '\u{e718}'),
+    ("compass", '\u{e728}'),
+    ("credit-card", '\u{1f4b3}'),
+    ("cup", '\u{2615}'),
+    ("cw", '\u{27f3}'),
+    ("database", '\u{e754}'),
+    ("db-shape", '\u{f600}'),
+    ("direction", '\u{27a2}'),
+    ("doc", '\u{e730}'),
+    ("doc-landscape", '\u{e737}'),
+    ("doc-text", '\u{1f4c4}'),
+    ("doc-text-inv", '\u{e731}'),
+    ("docs", '\u{e736}'),
+    ("dot", '\u{e78b}'),
+    ("dot-2", '\u{e78c}'),
+    ("dot-3", '\u{e78d}'),
+    ("down", '\u{2b07}'),
+    ("down-bold", '\u{e4b0}'),
+    ("down-circled", '\u{e758}'),
+    ("down-dir", '\u{25be}'),
+    ("down-open", '\u{e75c}'),
+    ("down-open-big", '\u{e764}'),
+    ("down-open-mini", '\u{e760}'),
+    ("down-thin", '\u{2193}'),
+    ("download", '\u{1f4e5}'),
+    ("dribbble", '\u{f31b}'),
+    ("dribbble-circled", '\u{f31c}'),
+    ("drive", '\u{e755}'),
+    ("dropbox", '\u{f330}'),
+    ("droplet", '\u{1f4a7}'),
+    ("erase", '\u{232b}'),
+    ("evernote", '\u{f333}'),
+    ("export", '\u{e715}'),
+    ("eye", '\u{e70a}'),
+    ("facebook", '\u{f30c}'),
+    ("facebook-circled", '\u{f30d}'),
+    ("facebook-squared", '\u{f30e}'),
+    ("fast-backward", '\u{23ea}'),
+    ("fast-forward", '\u{23e9}'),
+    ("feather", '\u{2712}'),
+    ("flag", '\u{2691}'),
+    ("flash", '\u{26a1}'),
+    ("flashlight", '\u{1f526}'),
+    ("flattr", '\u{f336}'),
+    ("flickr", '\u{f303}'),
+    ("flickr-circled", '\u{f304}'),
+    ("flight", '\u{2708}'),
+    ("floppy", '\u{1f4be}'),
+    ("flow-branch", '\u{e791}'),
+    ("flow-cascade", '\u{e790}'),
+    ("flow-line", '\u{e793}'),
+    ("flow-parallel", '\u{e794}'),
+    ("flow-tree", '\u{e792}'),
+    ("folder", '\u{1f4c1}'),
+    ("forward", '\u{27a6}'),
+    ("gauge", '\u{e7a2}'),
+    ("github", '\u{f300}'),
+    ("github-circled", '\u{f301}'),
+    ("globe", '\u{1f30e}'),
+    ("google-circles", '\u{f351}'),
+    ("gplus", '\u{f30f}'),
+    ("gplus-circled", '\u{f310}'),
+    ("graduation-cap", '\u{1f393}'),
+    ("heart", '\u{2665}'),
+    ("heart-empty", '\u{2661}'),
+    ("help", '\u{2753}'),
+    ("help-circled", '\u{e704}'),
+    ("home", '\u{2302}'),
+    ("hourglass", '\u{23f3}'),
+    ("inbox", '\u{e777}'),
+    ("infinity", '\u{221e}'),
+    ("info", '\u{2139}'),
+    ("info-circled", '\u{e705}'),
+    ("instagram", '\u{f32d}'),
+    ("install", '\u{e778}'),
+    ("key", '\u{1f511}'),
+    ("keyboard", '\u{2328}'),
+    ("lamp", '\u{1f4a1}'),
+    ("language", '\u{e752}'),
+    ("lastfm", '\u{f321}'),
+    ("lastfm-circled", '\u{f322}'),
+    ("layout", '\u{268f}'),
+    ("leaf", '\u{1f342}'),
+    ("left", '\u{2b05}'),
+    ("left-bold", '\u{e4ad}'),
+    ("left-circled", '\u{e759}'),
+    ("left-dir", '\u{25c2}'),
+    ("left-open", '\u{e75d}'),
+    ("left-open-big", '\u{e765}'),
+    ("left-open-mini", '\u{e761}'),
+    ("left-thin", '\u{2190}'),
+    ("level-down", '\u{21b3}'),
+    ("level-up", '\u{21b0}'),
+    ("lifebuoy", '\u{e788}'),
+    ("light-down", '\u{1f505}'),
+    ("light-up", '\u{1f506}'),
+    ("link", '\u{1f517}'),
+    ("linkedin", '\u{f318}'),
+    ("linkedin-circled", '\u{f319}'),
+    ("list", '\u{e005}'),
+    ("list-add", '\u{e003}'),
+    ("location", '\u{e724}'),
+    ("lock", '\u{1f512}'),
+    ("lock-open", '\u{1f513}'),
+    ("login", '\u{e740}'),
+    ("logo-db", '\u{f603}'),
+    ("logout", '\u{e741}'),
+    ("loop", '\u{1f501}'),
+    ("magnet", '\u{e7a1}'),
+    ("mail", '\u{2709}'),
+    ("map", '\u{e727}'),
+    ("megaphone", '\u{1f4e3}'),
+    ("menu", '\u{2630}'),
+    ("mic", '\u{1f3a4}'),
+    ("minus", '\u{2d}'),
+    ("minus-circled", '\u{2796}'),
+    ("minus-squared", '\u{229f}'),
+    ("mixi", '\u{f34b}'),
+    ("mobile", '\u{1f4f1}'),
+    ("monitor", '\u{1f4bb}'),
+    ("moon", '\u{263d}'),
+    ("mouse", '\u{e789}'),
+    ("music", '\u{1f3b5}'),
+    ("mute", '\u{1f507}'),
+    ("network", '\u{e776}'),
+    ("newspaper", '\u{1f4f0}'),
+    ("note", '\u{266a}'),
+    ("note-beamed", '\u{266b}'),
+    ("palette", '\u{1f3a8}'),
+    ("paper-plane", '\u{e79b}'),
+    ("pause", '\u{2389}'),
+    ("paypal", '\u{f342}'),
+    ("pencil", '\u{270e}'),
+    ("phone", '\u{1f4de}'),
+    ("picasa", '\u{f345}'),
+    ("picture", '\u{1f304}'),
+    ("pinterest", '\u{f312}'),
+    ("pinterest-circled", '\u{f313}'),
+    ("play", '\u{25b6}'),
+    ("plus", '\u{2b}'),
+    ("plus-circled", '\u{2795}'),
+    ("plus-squared", '\u{229e}'),
+    ("popup", '\u{e74c}'),
+    ("print", '\u{e716}'),
+    ("progress-0", '\u{e768}'),
+    ("progress-1", '\u{e769}'),
+    ("progress-2", '\u{e76a}'),
+    ("progress-3", '\u{e76b}'),
+    ("publish", '\u{e74d}'),
+    ("qq", '\u{f32a}'),
+    ("quote", '\u{275e}'),
+    ("rdio", '\u{f324}'),
+    ("rdio-circled", '\u{f325}'),
+    ("record", '\u{26ab}'),
+    ("renren", '\u{f33c}'),
+    ("reply", '\u{e712}'),
+    ("reply-all", '\u{e713}'),
+    ("resize-full", '\u{e744}'),
+    ("resize-small", '\u{e746}'),
+    ("retweet", '\u{e717}'),
+    ("right", '\u{27a1}'),
+    ("right-bold", '\u{e4ae}'),
+    ("right-circled", '\u{e75a}'),
+    ("right-dir", '\u{25b8}'),
+    ("right-open", '\u{e75e}'),
+    ("right-open-big", '\u{e766}'),
+    ("right-open-mini", '\u{e762}'),
+    ("right-thin", '\u{2192}'),
+    ("rocket", '\u{1f680}'),
+    ("rss", '\u{e73a}'),
+    ("search", '\u{1f50d}'),
+    ("share", '\u{e73c}'),
+    ("shareable", '\u{e73e}'),
+    ("shuffle", '\u{1f500}'),
+    ("signal", '\u{1f4f6}'),
+    ("sina-weibo", '\u{f33f}'),
+    ("skype", '\u{f339}'),
+    ("skype-circled", '\u{f33a}'),
+    ("smashing", '\u{f357}'),
+    ("sound", '\u{1f50a}'),
+    ("soundcloud", '\u{f348}'),
+    ("spotify", '\u{f327}'),
+    ("spotify-circled", '\u{f328}'),
+    ("star", '\u{2605}'),
+    ("star-empty", '\u{2606}'),
+    ("stop", '\u{25a0}'),
+    ("stumbleupon", '\u{f31e}'),
+    ("stumbleupon-circled", '\u{f31f}'),
+    ("suitcase", '\u{e78e}'),
+    ("sweden", '\u{f601}'),
+    ("switch", '\u{21c6}'),
+    ("tag", '\u{e70c}'),
+    ("tape", '\u{2707}'),
+    ("target", '\u{1f3af}'),
+    ("thermometer", '\u{e757}'),
+    ("thumbs-down", '\u{1f44e}'),
+    ("thumbs-up", '\u{1f44d}'),
+    ("ticket", '\u{1f3ab}'),
+    ("to-end", '\u{23ed}'),
+    ("to-start", '\u{23ee}'),
+    ("tools", '\u{2692}'),
+    ("traffic-cone", '\u{e7a3}'),
+    ("trash", '\u{e729}'),
+    ("trophy", '\u{1f3c6}'),
+    ("tumblr", '\u{f315}'),
+    ("tumblr-circled", '\u{f316}'),
+    ("twitter", '\u{f309}'),
+    ("twitter-circled", '\u{f30a}'),
+    ("up", '\u{2b06}'),
+    ("up-bold", '\u{e4af}'),
+    ("up-circled", '\u{e75b}'),
+    ("up-dir", '\u{25b4}'),
+    ("up-open", '\u{e75f}'),
+    ("up-open-big", '\u{e767}'),
+    ("up-open-mini", '\u{e763}'),
+    ("up-thin", '\u{2191}'),
+    ("upload", '\u{1f4e4}'),
+    ("upload-cloud", '\u{e711}'),
+    ("user", '\u{1f464}'),
+    ("user-add", '\u{e700}'),
+    ("users", '\u{1f465}'),
+    ("vcard", '\u{e722}'),
+    ("video", '\u{1f3ac}'),
+    ("vimeo", '\u{f306}'),
+    ("vimeo-circled", '\u{f307}'),
+    ("vkontakte", '\u{f354}'),
+    ("volume", '\u{e742}'),
+    ("water", '\u{1f4a6}'),
+    ("window", '\u{e74e}'),
+];
+
+pub(crate) const MATERIAL_ICONS: &[(&str, char)] = &[
+    ("10k", '\u{e951}'),
+    ("10mp", '\u{e952}'),
+    ("11mp", '\u{e953}'),
+    ("123", '\u{eb8d}'),
+    ("12mp", '\u{e954}'),
+    ("13mp", '\u{e955}'),
+    ("14mp", '\u{e956}'),
+    ("15mp", '\u{e957}'),
+    ("16mp", '\u{e958}'),
+    ("17mp", '\u{e959}'),
+    ("18mp", '\u{e95a}'),
+    ("19mp", '\u{e95b}'),
+    ("1k", '\u{e95c}'),
+    ("1k_plus", '\u{e95d}'),
+    ("1x_mobiledata", '\u{efcd}'),
+    ("20mp", '\u{e95e}'),
+    ("21mp", '\u{e95f}'),
+    ("22mp", '\u{e960}'),
+    ("23mp", '\u{e961}'),
+    ("24mp", '\u{e962}'),
+    ("2k", '\u{e963}'),
+    ("2k_plus", '\u{e964}'),
+    ("2mp", '\u{e965}'),
+    ("30fps", '\u{efce}'),
+    ("30fps_select", '\u{efcf}'),
+    ("360", '\u{e577}'),
+    ("3d_rotation", '\u{e84d}'),
+    ("3g_mobiledata", '\u{efd0}'),
+    ("3k", '\u{e966}'),
+    ("3k_plus", '\u{e967}'),
+    ("3mp", '\u{e968}'),
+    ("3p", '\u{efd1}'),
+    ("4g_mobiledata", '\u{efd2}'),
+    ("4g_plus_mobiledata", '\u{efd3}'),
+    ("4k", '\u{e072}'),
+    ("4k_plus", '\u{e969}'),
+    ("4mp", '\u{e96a}'),
+    ("5g", '\u{ef38}'),
+    ("5k", '\u{e96b}'),
+    ("5k_plus", '\u{e96c}'),
+    ("5mp", '\u{e96d}'),
+    ("60fps", '\u{efd4}'),
+    ("60fps_select", '\u{efd5}'),
+    ("6_ft_apart", '\u{f21e}'),
+    ("6k", '\u{e96e}'),
+    ("6k_plus", '\u{e96f}'),
+    ("6mp", '\u{e970}'),
+    ("7k", '\u{e971}'),
+    ("7k_plus", '\u{e972}'),
+    ("7mp", '\u{e973}'),
+    ("8k", '\u{e974}'),
+    ("8k_plus", '\u{e975}'),
+    ("8mp", '\u{e976}'),
+    ("9k", '\u{e977}'),
+    ("9k_plus", '\u{e978}'),
+    ("9mp", '\u{e979}'),
+    ("abc", '\u{eb94}'),
+    ("ac_unit", '\u{eb3b}'),
+    ("access_alarm", '\u{e190}'),
+    ("access_alarms", '\u{e191}'),
+    ("access_time", '\u{e192}'),
+    ("access_time_filled", '\u{efd6}'),
+    ("accessibility", '\u{e84e}'),
+    ("accessibility_new", '\u{e92c}'),
+    ("accessible", '\u{e914}'),
+    ("accessible_forward", '\u{e934}'),
+    ("account_balance", '\u{e84f}'),
+    ("account_balance_wallet", '\u{e850}'),
+    ("account_box", '\u{e851}'),
+    ("account_circle", '\u{e853}'),
+    ("account_tree", '\u{e97a}'),
+    ("ad_units", '\u{ef39}'),
+    ("adb", '\u{e60e}'),
+    ("add", '\u{e145}'),
+    ("add_a_photo", '\u{e439}'),
+    ("add_alarm", '\u{e193}'),
+    ("add_alert", '\u{e003}'),
+    ("add_box", '\u{e146}'),
+    ("add_business", '\u{e729}'),
+    ("add_call", '\u{e0e8}'),
+    ("add_card", '\u{eb86}'),
+    ("add_chart", '\u{e97b}'),
+    ("add_circle", '\u{e147}'),
+    ("add_circle_outline", '\u{e148}'),
+    ("add_comment", '\u{e266}'),
+    ("add_ic_call", '\u{e97c}'),
+    ("add_link", '\u{e178}'),
+    ("add_location", '\u{e567}'),
+    ("add_location_alt", '\u{ef3a}'),
+    ("add_moderator", '\u{e97d}'),
+    ("add_photo_alternate", '\u{e43e}'),
+    ("add_reaction", '\u{e1d3}'),
+    ("add_road", '\u{ef3b}'),
+    ("add_shopping_cart", '\u{e854}'),
+    ("add_task", '\u{f23a}'),
+    ("add_to_drive", '\u{e65c}'),
+    ("add_to_home_screen", '\u{e1fe}'),
+    ("add_to_photos", '\u{e39d}'),
+    ("add_to_queue", '\u{e05c}'),
+    ("addchart", '\u{ef3c}'),
+    ("adf_scanner", '\u{eada}'),
+    ("adjust", '\u{e39e}'),
+    ("admin_panel_settings", '\u{ef3d}'),
+    ("adobe", '\u{ea96}'),
+    ("ads_click", '\u{e762}'),
+    ("agriculture", '\u{ea79}'),
+    ("air", '\u{efd8}'),
+    ("airline_seat_flat", '\u{e630}'),
+    ("airline_seat_flat_angled", '\u{e631}'),
+    ("airline_seat_individual_suite", '\u{e632}'),
+    ("airline_seat_legroom_extra", '\u{e633}'),
+    ("airline_seat_legroom_normal", '\u{e634}'),
+    ("airline_seat_legroom_reduced", '\u{e635}'),
+    ("airline_seat_recline_extra", '\u{e636}'),
+    ("airline_seat_recline_normal", '\u{e637}'),
+    ("airline_stops", '\u{e7d0}'),
+    ("airlines", '\u{e7ca}'),
+    ("airplane_ticket", '\u{efd9}'),
+    ("airplanemode_active", '\u{e195}'),
+    ("airplanemode_inactive", '\u{e194}'),
+    ("airplanemode_off", '\u{e194}'),
+    ("airplanemode_on", '\u{e195}'),
+    ("airplay", '\u{e055}'),
+    ("airport_shuttle", '\u{eb3c}'),
+    ("alarm", '\u{e855}'),
+    ("alarm_add", '\u{e856}'),
+    ("alarm_off", '\u{e857}'),
+    ("alarm_on", '\u{e858}'),
+    ("album", '\u{e019}'),
+    ("align_horizontal_center", '\u{e00f}'),
+    ("align_horizontal_left", '\u{e00d}'),
+    ("align_horizontal_right", '\u{e010}'),
+    ("align_vertical_bottom", '\u{e015}'),
+    ("align_vertical_center", '\u{e011}'),
+    ("align_vertical_top", '\u{e00c}'),
+    ("all_inbox", '\u{e97f}'),
+    ("all_inclusive", '\u{eb3d}'),
+    ("all_out", '\u{e90b}'),
+    ("alt_route", '\u{f184}'),
+    ("alternate_email", '\u{e0e6}'),
+    ("amp_stories", '\u{ea13}'),
+    ("analytics", '\u{ef3e}'),
+    ("anchor", '\u{f1cd}'),
+    ("android", '\u{e859}'),
+    ("animation", '\u{e71c}'),
+    ("announcement", '\u{e85a}'),
+    ("aod", '\u{efda}'),
+    ("apartment", '\u{ea40}'),
+    ("api", '\u{f1b7}'),
+    ("app_blocking", '\u{ef3f}'),
+    ("app_registration", '\u{ef40}'),
+    ("app_settings_alt", '\u{ef41}'),
+    ("app_shortcut", '\u{eae4}'),
+    ("apple", '\u{ea80}'),
+    ("approval", '\u{e982}'),
+    ("apps", '\u{e5c3}'),
+    ("apps_outage", '\u{e7cc}'),
+    ("architecture", '\u{ea3b}'),
+    ("archive", '\u{e149}'),
+    ("area_chart", '\u{e770}'),
+    ("arrow_back", '\u{e5c4}'),
+    ("arrow_back_ios", '\u{e5e0}'),
+    ("arrow_back_ios_new", '\u{e2ea}'),
+    ("arrow_circle_down", '\u{f181}'),
+    ("arrow_circle_left", '\u{eaa7}'),
+    ("arrow_circle_right", '\u{eaaa}'),
+    ("arrow_circle_up", '\u{f182}'),
+    ("arrow_downward", '\u{e5db}'),
+    ("arrow_drop_down", '\u{e5c5}'),
+    ("arrow_drop_down_circle", '\u{e5c6}'),
+    ("arrow_drop_up", '\u{e5c7}'),
+    ("arrow_forward", '\u{e5c8}'),
+    ("arrow_forward_ios", '\u{e5e1}'),
+    ("arrow_left", '\u{e5de}'),
+    ("arrow_right", '\u{e5df}'),
+    ("arrow_right_alt", '\u{e941}'),
+    ("arrow_upward", '\u{e5d8}'),
+    ("art_track", '\u{e060}'),
+    ("article", '\u{ef42}'),
+    ("aspect_ratio", '\u{e85b}'),
+    ("assessment", '\u{e85c}'),
+    ("assignment", '\u{e85d}'),
+    ("assignment_ind", '\u{e85e}'),
+    ("assignment_late", '\u{e85f}'),
+    ("assignment_return", '\u{e860}'),
+    ("assignment_returned", '\u{e861}'),
+    ("assignment_turned_in", '\u{e862}'),
+    ("assistant", '\u{e39f}'),
+    ("assistant_direction", '\u{e988}'),
+    ("assistant_navigation", '\u{e989}'),
+    ("assistant_photo", '\u{e3a0}'),
+    ("assured_workload", '\u{eb6f}'),
+    ("atm", '\u{e573}'),
+    ("attach_email", '\u{ea5e}'),
+    ("attach_file", '\u{e226}'),
+    ("attach_money", '\u{e227}'),
+    ("attachment", '\u{e2bc}'),
+    ("attractions", '\u{ea52}'),
+    ("attribution", '\u{efdb}'),
+    ("audio_file", '\u{eb82}'),
+    ("audiotrack", '\u{e3a1}'),
+    ("auto_awesome", '\u{e65f}'),
+    ("auto_awesome_mosaic", '\u{e660}'),
+    ("auto_awesome_motion", '\u{e661}'),
+    ("auto_delete", '\u{ea4c}'),
+    ("auto_fix_high", '\u{e663}'),
+    ("auto_fix_normal", '\u{e664}'),
+    ("auto_fix_off", '\u{e665}'),
+    ("auto_graph", '\u{e4fb}'),
+    ("auto_stories", '\u{e666}'),
+    ("autofps_select", '\u{efdc}'),
+    ("autorenew", '\u{e863}'),
+    ("av_timer", '\u{e01b}'),
+    ("baby_changing_station", '\u{f19b}'),
+    ("back_hand", '\u{e764}'),
+    ("backpack", '\u{f19c}'),
+    ("backspace", '\u{e14a}'),
+    ("backup", '\u{e864}'),
+    ("backup_table", '\u{ef43}'),
+    ("badge", '\u{ea67}'),
+    ("bakery_dining", '\u{ea53}'),
+    ("balance", '\u{eaf6}'),
+    ("balcony", '\u{e58f}'),
+    ("ballot", '\u{e172}'),
+    ("bar_chart", '\u{e26b}'),
+    ("batch_prediction", '\u{f0f5}'),
+    ("bathroom", '\u{efdd}'),
+    ("bathtub", '\u{ea41}'),
+    ("battery_0_bar", '\u{ebdc}'),
+    ("battery_1_bar", '\u{ebd9}'),
+    ("battery_2_bar", '\u{ebe0}'),
+    ("battery_3_bar", '\u{ebdd}'),
+    ("battery_4_bar", '\u{ebe2}'),
+    ("battery_5_bar", '\u{ebd4}'),
+    ("battery_6_bar", '\u{ebd2}'),
+    ("battery_alert", '\u{e19c}'),
+    ("battery_charging_full", '\u{e1a3}'),
+    ("battery_full", '\u{e1a4}'),
+    ("battery_saver", '\u{efde}'),
+    ("battery_std", '\u{e1a5}'),
+    ("battery_unknown", '\u{e1a6}'),
+    ("beach_access", '\u{eb3e}'),
+    ("bed", '\u{efdf}'),
+    ("bedroom_baby", '\u{efe0}'),
+    ("bedroom_child", '\u{efe1}'),
+    ("bedroom_parent", '\u{efe2}'),
+    ("bedtime", '\u{ef44}'),
+    ("bedtime_off", '\u{eb76}'),
+    ("beenhere", '\u{e52d}'),
+    ("bento", '\u{f1f4}'),
+    ("bike_scooter", '\u{ef45}'),
+    ("biotech", '\u{ea3a}'),
+    ("blender", '\u{efe3}'),
+    ("block", '\u{e14b}'),
+    ("block_flipped", '\u{ef46}'),
+    ("bloodtype", '\u{efe4}'),
+    ("bluetooth", '\u{e1a7}'),
+    ("bluetooth_audio", '\u{e60f}'),
+    ("bluetooth_connected", '\u{e1a8}'),
+    ("bluetooth_disabled", '\u{e1a9}'),
+    ("bluetooth_drive", '\u{efe5}'),
+    ("bluetooth_searching", '\u{e1aa}'),
+    ("blur_circular", '\u{e3a2}'),
+    ("blur_linear", '\u{e3a3}'),
+    ("blur_off", '\u{e3a4}'),
+    ("blur_on", '\u{e3a5}'),
+    ("bolt", '\u{ea0b}'),
+    ("book", '\u{e865}'),
+    ("book_online", '\u{f217}'),
+    ("bookmark", '\u{e866}'),
+    ("bookmark_add", '\u{e598}'),
+    ("bookmark_added", '\u{e599}'),
+    ("bookmark_border", '\u{e867}'),
+    ("bookmark_outline", '\u{e867}'),
+    ("bookmark_remove", '\u{e59a}'),
+    ("bookmarks", '\u{e98b}'),
+    ("border_all", '\u{e228}'),
+    ("border_bottom", '\u{e229}'),
+    ("border_clear", '\u{e22a}'),
+    ("border_color", '\u{e22b}'),
+    ("border_horizontal", '\u{e22c}'),
+    ("border_inner", '\u{e22d}'),
+    ("border_left", '\u{e22e}'),
+    ("border_outer", '\u{e22f}'),
+    ("border_right", '\u{e230}'),
+    ("border_style", '\u{e231}'),
+    ("border_top", '\u{e232}'),
+    ("border_vertical", '\u{e233}'),
+    ("boy", '\u{eb67}'),
+    ("branding_watermark", '\u{e06b}'),
+    ("breakfast_dining", '\u{ea54}'),
+    ("brightness_1", '\u{e3a6}'),
+    ("brightness_2", '\u{e3a7}'),
+    ("brightness_3", '\u{e3a8}'),
+    ("brightness_4", '\u{e3a9}'),
+    ("brightness_5", '\u{e3aa}'),
+    ("brightness_6", '\u{e3ab}'),
+    ("brightness_7", '\u{e3ac}'),
+    ("brightness_auto", '\u{e1ab}'),
+    ("brightness_high", '\u{e1ac}'),
+    ("brightness_low", '\u{e1ad}'),
+    ("brightness_medium", '\u{e1ae}'),
+    ("broken_image", '\u{e3ad}'),
+    ("browse_gallery", '\u{ebd1}'),
+    ("browser_not_supported", '\u{ef47}'),
+    ("browser_updated", '\u{e7cf}'),
+    ("brunch_dining", '\u{ea73}'),
+    ("brush", '\u{e3ae}'),
+    ("bubble_chart", '\u{e6dd}'),
+    ("bug_report", '\u{e868}'),
+    ("build", '\u{e869}'),
+    ("build_circle", '\u{ef48}'),
+    ("bungalow", '\u{e591}'),
+    ("burst_mode", '\u{e43c}'),
+    ("bus_alert", '\u{e98f}'),
+    ("business", '\u{e0af}'),
+    ("business_center", '\u{eb3f}'),
+    ("cabin", '\u{e589}'),
+    ("cable", '\u{efe6}'),
+    ("cached", '\u{e86a}'),
+    ("cake", '\u{e7e9}'),
+    ("calculate", '\u{ea5f}'),
+    ("calendar_month", '\u{ebcc}'),
+    ("calendar_today", '\u{e935}'),
+    ("calendar_view_day", '\u{e936}'),
+    ("calendar_view_month", '\u{efe7}'),
+    ("calendar_view_week", '\u{efe8}'),
+    ("call", '\u{e0b0}'),
+    ("call_end", '\u{e0b1}'),
+    ("call_made", '\u{e0b2}'),
+    ("call_merge", '\u{e0b3}'),
+    ("call_missed", '\u{e0b4}'),
+    ("call_missed_outgoing", '\u{e0e4}'),
+    ("call_received", '\u{e0b5}'),
+    ("call_split", '\u{e0b6}'),
+    ("call_to_action", '\u{e06c}'),
+    ("camera", '\u{e3af}'),
+    ("camera_alt", '\u{e3b0}'),
+    ("camera_enhance", '\u{e8fc}'),
+    ("camera_front", '\u{e3b1}'),
+    ("camera_indoor", '\u{efe9}'),
+    ("camera_outdoor", '\u{efea}'),
+    ("camera_rear", '\u{e3b2}'),
+    ("camera_roll", '\u{e3b3}'),
+    ("cameraswitch", '\u{efeb}'),
+    ("campaign", '\u{ef49}'),
+    ("cancel", '\u{e5c9}'),
+    ("cancel_presentation", '\u{e0e9}'),
+    ("cancel_schedule_send", '\u{ea39}'),
+    ("candlestick_chart", '\u{ead4}'),
+    ("car_crash", '\u{ebf2}'),
+    ("car_rental", '\u{ea55}'),
+    ("car_repair", '\u{ea56}'),
+    ("card_giftcard", '\u{e8f6}'),
+    ("card_membership", '\u{e8f7}'),
+    ("card_travel", '\u{e8f8}'),
+    ("carpenter", '\u{f1f8}'),
+    ("cases", '\u{e992}'),
+    ("casino", '\u{eb40}'),
+    ("cast", '\u{e307}'),
+    ("cast_connected", '\u{e308}'),
+    ("cast_for_education", '\u{efec}'),
+    ("castle", '\u{eab1}'),
+    ("catching_pokemon", '\u{e508}'),
+    ("category", '\u{e574}'),
+    ("celebration", '\u{ea65}'),
+    ("cell_tower", '\u{ebba}'),
+    ("cell_wifi", '\u{e0ec}'),
+    ("center_focus_strong", '\u{e3b4}'),
+    ("center_focus_weak", '\u{e3b5}'),
+    ("chair", '\u{efed}'),
+    ("chair_alt", '\u{efee}'),
+    ("chalet", '\u{e585}'),
+    ("change_circle", '\u{e2e7}'),
+    ("change_history", '\u{e86b}'),
+    ("charging_station", '\u{f19d}'),
+    ("chat", '\u{e0b7}'),
+    ("chat_bubble", '\u{e0ca}'),
+    ("chat_bubble_outline", '\u{e0cb}'),
+    ("check", '\u{e5ca}'),
+    ("check_box", '\u{e834}'),
+    ("check_box_outline_blank", '\u{e835}'),
+    ("check_circle", '\u{e86c}'),
+    ("check_circle_outline", '\u{e92d}'),
+    ("checklist", '\u{e6b1}'),
+    ("checklist_rtl", '\u{e6b3}'),
+    ("checkroom", '\u{f19e}'),
+    ("chevron_left", '\u{e5cb}'),
+    ("chevron_right", '\u{e5cc}'),
+    ("child_care", '\u{eb41}'),
+    ("child_friendly", '\u{eb42}'),
+    ("chrome_reader_mode", '\u{e86d}'),
+    ("church", '\u{eaae}'),
+    ("circle", '\u{ef4a}'),
+    ("circle_notifications", '\u{e994}'),
+    ("class", '\u{e86e}'),
+    ("clean_hands", '\u{f21f}'),
+    ("cleaning_services", '\u{f0ff}'),
+    ("clear", '\u{e14c}'),
+    ("clear_all", '\u{e0b8}'),
+    ("close", '\u{e5cd}'),
+    ("close_fullscreen", '\u{f1cf}'),
+    ("closed_caption", '\u{e01c}'),
+    ("closed_caption_disabled", '\u{f1dc}'),
+    ("closed_caption_off", '\u{e996}'),
+    ("cloud", '\u{e2bd}'),
+    ("cloud_circle", '\u{e2be}'),
+    ("cloud_done", '\u{e2bf}'),
+    ("cloud_download", '\u{e2c0}'),
+    ("cloud_off", '\u{e2c1}'),
+    ("cloud_queue", '\u{e2c2}'),
+    ("cloud_sync", '\u{eb5a}'),
+    ("cloud_upload", '\u{e2c3}'),
+    ("cloudy_snowing", '\u{e810}'),
+    ("co2", '\u{e7b0}'),
+    ("co_present", '\u{eaf0}'),
+    ("code", '\u{e86f}'),
+    ("code_off", '\u{e4f3}'),
+    ("coffee", '\u{efef}'),
+    ("coffee_maker", '\u{eff0}'),
+    ("collections", '\u{e3b6}'),
+    ("collections_bookmark", '\u{e431}'),
+    ("color_lens", '\u{e3b7}'),
+    ("colorize", '\u{e3b8}'),
+    ("comment", '\u{e0b9}'),
+    ("comment_bank", '\u{ea4e}'),
+    ("comments_disabled", '\u{e7a2}'),
+    ("commit", '\u{eaf5}'),
+    ("commute", '\u{e940}'),
+    ("compare", '\u{e3b9}'),
+    ("compare_arrows", '\u{e915}'),
+    ("compass_calibration", '\u{e57c}'),
+    ("compost", '\u{e761}'),
+    ("compress", '\u{e94d}'),
+    ("computer", '\u{e30a}'),
+    ("confirmation_num", '\u{e638}'),
+    ("confirmation_number", '\u{e638}'),
+    ("connect_without_contact", '\u{f223}'),
+    ("connected_tv", '\u{e998}'),
+    ("connecting_airports", '\u{e7c9}'),
+    ("construction", '\u{ea3c}'),
+    ("contact_mail", '\u{e0d0}'),
+    ("contact_page", '\u{f22e}'),
+    ("contact_phone", '\u{e0cf}'),
+    ("contact_support", '\u{e94c}'),
+    ("contactless", '\u{ea71}'),
+    ("contacts", '\u{e0ba}'),
+    ("content_copy", '\u{e14d}'),
+    ("content_cut", '\u{e14e}'),
+    ("content_paste", '\u{e14f}'),
+    ("content_paste_go", '\u{ea8e}'),
+    ("content_paste_off", '\u{e4f8}'),
+    ("content_paste_search", '\u{ea9b}'),
+    ("contrast", '\u{eb37}'),
+    ("control_camera", '\u{e074}'),
+    ("control_point", '\u{e3ba}'),
+    ("control_point_duplicate", '\u{e3bb}'),
+    ("cookie", '\u{eaac}'),
+    ("copy_all", '\u{e2ec}'),
+    ("copyright", '\u{e90c}'),
+    ("coronavirus", '\u{f221}'),
+    ("corporate_fare", '\u{f1d0}'),
+    ("cottage", '\u{e587}'),
+    ("countertops", '\u{f1f7}'),
+    ("create", '\u{e150}'),
+    ("create_new_folder", '\u{e2cc}'),
+    ("credit_card", '\u{e870}'),
+    ("credit_card_off", '\u{e4f4}'),
+    ("credit_score", '\u{eff1}'),
+    ("crib", '\u{e588}'),
+    ("crisis_alert", '\u{ebe9}'),
+    ("crop", '\u{e3be}'),
+    ("crop_16_9", '\u{e3bc}'),
+    ("crop_3_2", '\u{e3bd}'),
+    ("crop_5_4", '\u{e3bf}'),
+    ("crop_7_5", '\u{e3c0}'),
+    ("crop_din", '\u{e3c1}'),
+    ("crop_free", '\u{e3c2}'),
+    ("crop_landscape", '\u{e3c3}'),
+    ("crop_original", '\u{e3c4}'),
+    ("crop_portrait", '\u{e3c5}'),
+    ("crop_rotate", '\u{e437}'),
+    ("crop_square", '\u{e3c6}'),
+    ("cruelty_free", '\u{e799}'),
+    ("css", '\u{eb93}'),
+    ("currency_bitcoin", '\u{ebc5}'),
+    ("currency_exchange", '\u{eb70}'),
+    ("currency_franc", '\u{eafa}'),
+    ("currency_lira", '\u{eaef}'),
+    ("currency_pound", '\u{eaf1}'),
+    ("currency_ruble", '\u{eaec}'),
+    ("currency_rupee", '\u{eaf7}'),
+    ("currency_yen", '\u{eafb}'),
+    ("currency_yuan", '\u{eaf9}'),
+    ("cyclone", '\u{ebd5}'),
+    ("dangerous", '\u{e99a}'),
+    ("dark_mode", '\u{e51c}'),
+    ("dashboard", '\u{e871}'),
+    ("dashboard_customize", '\u{e99b}'),
+    ("data_array", '\u{ead1}'),
+    ("data_exploration", '\u{e76f}'),
+    ("data_object", '\u{ead3}'),
+    ("data_saver_off", '\u{eff2}'),
+    ("data_saver_on", '\u{eff3}'),
+    ("data_thresholding", '\u{eb9f}'),
+    ("data_usage", '\u{e1af}'),
+    ("date_range", '\u{e916}'),
+    ("deblur", '\u{eb77}'),
+    ("deck", '\u{ea42}'),
+    ("dehaze", '\u{e3c7}'),
+    ("delete", '\u{e872}'),
+    ("delete_forever", '\u{e92b}'),
+    ("delete_outline", '\u{e92e}'),
+    ("delete_sweep", '\u{e16c}'),
+    ("delivery_dining", '\u{ea72}'),
+    ("density_large", '\u{eba9}'),
+    ("density_medium", '\u{eb9e}'),
+    ("density_small", '\u{eba8}'),
+    ("departure_board", '\u{e576}'),
+    ("description", '\u{e873}'),
+    ("deselect", '\u{ebb6}'),
+    ("design_services", '\u{f10a}'),
+    ("desktop_access_disabled", '\u{e99d}'),
+    ("desktop_mac", '\u{e30b}'),
+    ("desktop_windows", '\u{e30c}'),
+    ("details", '\u{e3c8}'),
+    ("developer_board", '\u{e30d}'),
+    ("developer_board_off", '\u{e4ff}'),
+    ("developer_mode", '\u{e1b0}'),
+    ("device_hub", '\u{e335}'),
+    ("device_thermostat", '\u{e1ff}'),
+    ("device_unknown", '\u{e339}'),
+    ("devices", '\u{e1b1}'),
+    ("devices_fold", '\u{ebde}'),
+    ("devices_other", '\u{e337}'),
+    ("dialer_sip", '\u{e0bb}'),
+    ("dialpad", '\u{e0bc}'),
+    ("diamond", '\u{ead5}'),
+    ("difference", '\u{eb7d}'),
+    ("dining", '\u{eff4}'),
+    ("dinner_dining", '\u{ea57}'),
+    ("directions", '\u{e52e}'),
+    ("directions_bike", '\u{e52f}'),
+    ("directions_boat", '\u{e532}'),
+    ("directions_boat_filled", '\u{eff5}'),
+    ("directions_bus", '\u{e530}'),
+    ("directions_bus_filled", '\u{eff6}'),
+    ("directions_car", '\u{e531}'),
+    ("directions_car_filled", '\u{eff7}'),
+    ("directions_ferry", '\u{e532}'),
+    ("directions_off", '\u{f10f}'),
+    ("directions_railway", '\u{e534}'),
+    ("directions_railway_filled", '\u{eff8}'),
+    ("directions_run", '\u{e566}'),
+    ("directions_subway", '\u{e533}'),
+    ("directions_subway_filled", '\u{eff9}'),
+    ("directions_train", '\u{e534}'),
+    ("directions_transit", '\u{e535}'),
+    ("directions_transit_filled", '\u{effa}'),
+    ("directions_walk", '\u{e536}'),
+    ("dirty_lens", '\u{ef4b}'),
+    ("disabled_by_default", '\u{f230}'),
+    ("disabled_visible", '\u{e76e}'),
+    ("disc_full", '\u{e610}'),
+    ("discord", '\u{ea6c}'),
+    ("discount", '\u{ebc9}'),
+    ("display_settings", '\u{eb97}'),
+    ("dnd_forwardslash", '\u{e611}'),
+    ("dns", '\u{e875}'),
+    ("do_disturb", '\u{f08c}'),
+    ("do_disturb_alt", '\u{f08d}'),
+    ("do_disturb_off", '\u{f08e}'),
+    ("do_disturb_on", '\u{f08f}'),
+    ("do_not_disturb", '\u{e612}'),
+    ("do_not_disturb_alt", '\u{e611}'),
+    ("do_not_disturb_off", '\u{e643}'),
+    ("do_not_disturb_on", '\u{e644}'),
+    ("do_not_disturb_on_total_silence", '\u{effb}'),
+    ("do_not_step", '\u{f19f}'),
+    ("do_not_touch", '\u{f1b0}'),
+    ("dock", '\u{e30e}'),
+    ("document_scanner", '\u{e5fa}'),
+    ("domain", '\u{e7ee}'),
+    ("domain_add", '\u{eb62}'),
+    ("domain_disabled", '\u{e0ef}'),
+    ("domain_verification", '\u{ef4c}'),
+    ("done", '\u{e876}'),
+    ("done_all", '\u{e877}'),
+    ("done_outline", '\u{e92f}'),
+    ("donut_large", '\u{e917}'),
+    ("donut_small", '\u{e918}'),
+    ("door_back", '\u{effc}'),
+    ("door_front", '\u{effd}'),
+    ("door_sliding", '\u{effe}'),
+    ("doorbell", '\u{efff}'),
+    ("double_arrow", '\u{ea50}'),
+    ("downhill_skiing", '\u{e509}'),
+    ("download", '\u{f090}'),
+    ("download_done", '\u{f091}'),
+    ("download_for_offline", '\u{f000}'),
+    ("downloading", '\u{f001}'),
+    ("drafts", '\u{e151}'),
+    ("drag_handle", '\u{e25d}'),
+    ("drag_indicator", '\u{e945}'),
+    ("draw", '\u{e746}'),
+    ("drive_eta", '\u{e613}'),
+    ("drive_file_move", '\u{e675}'),
+    ("drive_file_move_outline", '\u{e9a1}'),
+    ("drive_file_move_rtl", '\u{e76d}'),
+    ("drive_file_rename_outline", '\u{e9a2}'),
+    ("drive_folder_upload", '\u{e9a3}'),
+    ("dry", '\u{f1b3}'),
+    ("dry_cleaning", '\u{ea58}'),
+    ("duo", '\u{e9a5}'),
+    ("dvr", '\u{e1b2}'),
+    ("dynamic_feed", '\u{ea14}'),
+    ("dynamic_form", '\u{f1bf}'),
+    ("e_mobiledata", '\u{f002}'),
+    ("earbuds", '\u{f003}'),
+    ("earbuds_battery", '\u{f004}'),
+    ("east", '\u{f1df}'),
+    ("eco", '\u{ea35}'),
+    ("edgesensor_high", '\u{f005}'),
+    ("edgesensor_low", '\u{f006}'),
+    ("edit", '\u{e3c9}'),
+    ("edit_attributes", '\u{e578}'),
+    ("edit_calendar", '\u{e742}'),
+    ("edit_location", '\u{e568}'),
+    ("edit_location_alt", '\u{e1c5}'),
+    ("edit_note", '\u{e745}'),
+    ("edit_notifications", '\u{e525}'),
+    ("edit_off", '\u{e950}'),
+    ("edit_road", '\u{ef4d}'),
+    ("egg", '\u{eacc}'),
+    ("egg_alt", '\u{eac8}'),
+    ("eject", '\u{e8fb}'),
+    ("elderly", '\u{f21a}'),
+    ("elderly_woman", '\u{eb69}'),
+    ("electric_bike", '\u{eb1b}'),
+    ("electric_car", '\u{eb1c}'),
+    ("electric_moped", '\u{eb1d}'),
+    ("electric_rickshaw", '\u{eb1e}'),
+    ("electric_scooter", '\u{eb1f}'),
+    ("electrical_services", '\u{f102}'),
+    ("elevator", '\u{f1a0}'),
+    ("email", '\u{e0be}'),
+    ("emergency", '\u{e1eb}'),
+    ("emergency_recording", '\u{ebf4}'),
+    ("emergency_share", '\u{ebf6}'),
+    ("emoji_emotions", '\u{ea22}'),
+    ("emoji_events", '\u{ea23}'),
+    ("emoji_flags", '\u{ea1a}'),
+    ("emoji_food_beverage", '\u{ea1b}'),
+    ("emoji_nature", '\u{ea1c}'),
+    ("emoji_objects", '\u{ea24}'),
+    ("emoji_people", '\u{ea1d}'),
+    ("emoji_symbols", '\u{ea1e}'),
+    ("emoji_transportation", '\u{ea1f}'),
+    ("engineering", '\u{ea3d}'),
+    ("enhance_photo_translate", '\u{e8fc}'),
+    ("enhanced_encryption", '\u{e63f}'),
+    ("equalizer", '\u{e01d}'),
+    ("error", '\u{e000}'),
+    ("error_outline", '\u{e001}'),
+    ("escalator", '\u{f1a1}'),
+    ("escalator_warning", '\u{f1ac}'),
+    ("euro", '\u{ea15}'),
+    ("euro_symbol", '\u{e926}'),
+    ("ev_station", '\u{e56d}'),
+    ("event", '\u{e878}'),
+    ("event_available", '\u{e614}'),
+    ("event_busy", '\u{e615}'),
+    ("event_note", '\u{e616}'),
+    ("event_repeat", '\u{eb7b}'),
+    ("event_seat", '\u{e903}'),
+    ("exit_to_app", '\u{e879}'),
+    ("expand", '\u{e94f}'),
+    ("expand_circle_down", '\u{e7cd}'),
+    ("expand_less", '\u{e5ce}'),
+    ("expand_more", '\u{e5cf}'),
+    ("explicit", '\u{e01e}'),
+    ("explore", '\u{e87a}'),
+    ("explore_off", '\u{e9a8}'),
+    ("exposure", '\u{e3ca}'),
+    ("exposure_minus_1", '\u{e3cb}'),
+    ("exposure_minus_2", '\u{e3cc}'),
+    ("exposure_neg_1", '\u{e3cb}'),
+    ("exposure_neg_2", '\u{e3cc}'),
+    ("exposure_plus_1", '\u{e3cd}'),
+    ("exposure_plus_2", '\u{e3ce}'),
+    ("exposure_zero", '\u{e3cf}'),
+    ("extension", '\u{e87b}'),
+    ("extension_off", '\u{e4f5}'),
+    ("face", '\u{e87c}'),
+    ("face_retouching_natural", '\u{ef4e}'),
+    ("face_retouching_off", '\u{f007}'),
+    ("facebook", '\u{f234}'),
+    ("fact_check", '\u{f0c5}'),
+    ("factory", '\u{ebbc}'),
+    ("family_restroom", '\u{f1a2}'),
+    ("fast_forward", '\u{e01f}'),
+    ("fast_rewind", '\u{e020}'),
+    ("fastfood", '\u{e57a}'),
+    ("favorite", '\u{e87d}'),
+    ("favorite_border", '\u{e87e}'),
+    ("favorite_outline", '\u{e87e}'),
+    ("fax", '\u{ead8}'),
+    ("featured_play_list", '\u{e06d}'),
+    ("featured_video", '\u{e06e}'),
+    ("feed", '\u{f009}'),
+    ("feedback", '\u{e87f}'),
+    ("female", '\u{e590}'),
+    ("fence", '\u{f1f6}'),
+    ("festival", '\u{ea68}'),
+    ("fiber_dvr", '\u{e05d}'),
+    ("fiber_manual_record", '\u{e061}'),
+    ("fiber_new", '\u{e05e}'),
+    ("fiber_pin", '\u{e06a}'),
+    ("fiber_smart_record", '\u{e062}'),
+    ("file_copy", '\u{e173}'),
+    ("file_download", '\u{e2c4}'),
+    ("file_download_done", '\u{e9aa}'),
+    ("file_download_off", '\u{e4fe}'),
+    ("file_open", '\u{eaf3}'),
+    ("file_present", '\u{ea0e}'),
+    ("file_upload", '\u{e2c6}'),
+    ("filter", '\u{e3d3}'),
+    ("filter_1", '\u{e3d0}'),
+    ("filter_2", '\u{e3d1}'),
+    ("filter_3", '\u{e3d2}'),
+    ("filter_4", '\u{e3d4}'),
+    ("filter_5", '\u{e3d5}'),
+    ("filter_6", '\u{e3d6}'),
+    ("filter_7", '\u{e3d7}'),
+    ("filter_8", '\u{e3d8}'),
+    ("filter_9", '\u{e3d9}'),
+    ("filter_9_plus", '\u{e3da}'),
+    ("filter_alt", '\u{ef4f}'),
+    ("filter_alt_off", '\u{eb32}'),
+    ("filter_b_and_w", '\u{e3db}'),
+    ("filter_center_focus", '\u{e3dc}'),
+    ("filter_drama", '\u{e3dd}'),
+    ("filter_frames", '\u{e3de}'),
+    ("filter_hdr", '\u{e3df}'),
+    ("filter_list", '\u{e152}'),
+    ("filter_list_alt", '\u{e94e}'),
+    ("filter_list_off", '\u{eb57}'),
+    ("filter_none", '\u{e3e0}'),
+    ("filter_tilt_shift", '\u{e3e2}'),
+    ("filter_vintage", '\u{e3e3}'),
+    ("find_in_page", '\u{e880}'),
+    ("find_replace", '\u{e881}'),
+    ("fingerprint", '\u{e90d}'),
+    ("fire_extinguisher", '\u{f1d8}'),
+    ("fire_hydrant", '\u{f1a3}'),
+    ("fireplace", '\u{ea43}'),
+    ("first_page", '\u{e5dc}'),
+    ("fit_screen", '\u{ea10}'),
+    ("fitbit", '\u{e82b}'),
+    ("fitness_center", '\u{eb43}'),
+    ("flag", '\u{e153}'),
+    ("flag_circle", '\u{eaf8}'),
+    ("flaky", '\u{ef50}'),
+    ("flare", '\u{e3e4}'),
+    ("flash_auto", '\u{e3e5}'),
+    ("flash_off", '\u{e3e6}'),
+    ("flash_on", '\u{e3e7}'),
+    ("flashlight_off", '\u{f00a}'),
+    ("flashlight_on", '\u{f00b}'),
+    ("flatware", '\u{f00c}'),
+    ("flight", '\u{e539}'),
+    ("flight_class", '\u{e7cb}'),
+    ("flight_land", '\u{e904}'),
+    ("flight_takeoff", '\u{e905}'),
+    ("flip", '\u{e3e8}'),
+    ("flip_camera_android", '\u{ea37}'),
+    ("flip_camera_ios", '\u{ea38}'),
+    ("flip_to_back", '\u{e882}'),
+    ("flip_to_front", '\u{e883}'),
+    ("flood", '\u{ebe6}'),
+    ("flourescent", '\u{f00d}'),
+    ("flutter_dash", '\u{e00b}'),
+    ("fmd_bad", '\u{f00e}'),
+    ("fmd_good", '\u{f00f}'),
+    ("foggy", '\u{e818}'),
+    ("folder", '\u{e2c7}'),
+    ("folder_copy", '\u{ebbd}'),
+    ("folder_delete", '\u{eb34}'),
+    ("folder_off", '\u{eb83}'),
+    ("folder_open", '\u{e2c8}'),
+    ("folder_shared", '\u{e2c9}'),
+    ("folder_special", '\u{e617}'),
+    ("folder_zip", '\u{eb2c}'),
+    ("follow_the_signs", '\u{f222}'),
+    ("font_download", '\u{e167}'),
+    ("font_download_off", '\u{e4f9}'),
+    ("food_bank", '\u{f1f2}'),
+    ("forest", '\u{ea99}'),
+    ("fork_left", '\u{eba0}'),
+    ("fork_right", '\u{ebac}'),
+    ("format_align_center", '\u{e234}'),
+    ("format_align_justify", '\u{e235}'),
+    ("format_align_left", '\u{e236}'),
+    ("format_align_right", '\u{e237}'),
+    ("format_bold", '\u{e238}'),
+    ("format_clear", '\u{e239}'),
+    ("format_color_fill", '\u{e23a}'),
+    ("format_color_reset", '\u{e23b}'),
+    ("format_color_text", '\u{e23c}'),
+    ("format_indent_decrease", '\u{e23d}'),
+    ("format_indent_increase", '\u{e23e}'),
+    ("format_italic", '\u{e23f}'),
+    ("format_line_spacing", '\u{e240}'),
+    ("format_list_bulleted", '\u{e241}'),
+    ("format_list_numbered", '\u{e242}'),
+    ("format_list_numbered_rtl", '\u{e267}'),
+    ("format_overline", '\u{eb65}'),
+    ("format_paint", '\u{e243}'),
+    ("format_quote", '\u{e244}'),
+    ("format_shapes", '\u{e25e}'),
+    ("format_size", '\u{e245}'),
+    ("format_strikethrough", '\u{e246}'),
+    ("format_textdirection_l_to_r", '\u{e247}'),
+    ("format_textdirection_r_to_l", '\u{e248}'),
+    ("format_underline", '\u{e249}'),
+    ("format_underlined", '\u{e249}'),
+    ("fort", '\u{eaad}'),
+    ("forum", '\u{e0bf}'),
+    ("forward", '\u{e154}'),
+    ("forward_10", '\u{e056}'),
+    ("forward_30", '\u{e057}'),
+    ("forward_5", '\u{e058}'),
+    ("forward_to_inbox", '\u{f187}'),
+    ("foundation", '\u{f200}'),
+    ("free_breakfast", '\u{eb44}'),
+    ("free_cancellation", '\u{e748}'),
+    ("front_hand", '\u{e769}'),
+    ("fullscreen", '\u{e5d0}'),
+    ("fullscreen_exit", '\u{e5d1}'),
+    ("functions", '\u{e24a}'),
+    ("g_mobiledata", '\u{f010}'),
+    ("g_translate", '\u{e927}'),
+    ("gamepad", '\u{e30f}'),
+    ("games", '\u{e021}'),
+    ("garage", '\u{f011}'),
+    ("gavel", '\u{e90e}'),
+    ("generating_tokens", '\u{e749}'),
+    ("gesture", '\u{e155}'),
+    ("get_app", '\u{e884}'),
+    ("gif", '\u{e908}'),
+    ("gif_box", '\u{e7a3}'),
+    ("girl", '\u{eb68}'),
+    ("gite", '\u{e58b}'),
+    ("golf_course", '\u{eb45}'),
+    ("gpp_bad", '\u{f012}'),
+    ("gpp_good", '\u{f013}'),
+    ("gpp_maybe", '\u{f014}'),
+    ("gps_fixed", '\u{e1b3}'),
+    ("gps_not_fixed", '\u{e1b4}'),
+    ("gps_off", '\u{e1b5}'),
+    ("grade", '\u{e885}'),
+    ("gradient", '\u{e3e9}'),
+    ("grading", '\u{ea4f}'),
+    ("grain", '\u{e3ea}'),
+    ("graphic_eq", '\u{e1b8}'),
+    ("grass", '\u{f205}'),
+    ("grid_3x3", '\u{f015}'),
+    ("grid_4x4", '\u{f016}'),
+    ("grid_goldenratio", '\u{f017}'),
+    ("grid_off", '\u{e3eb}'),
+    ("grid_on", '\u{e3ec}'),
+    ("grid_view", '\u{e9b0}'),
+    ("group", '\u{e7ef}'),
+    ("group_add", '\u{e7f0}'),
+    ("group_off", '\u{e747}'),
+    ("group_remove", '\u{e7ad}'),
+    ("group_work", '\u{e886}'),
+    ("groups", '\u{f233}'),
+    ("h_mobiledata", '\u{f018}'),
+    ("h_plus_mobiledata", '\u{f019}'),
+    ("hail", '\u{e9b1}'),
+    ("handshake", '\u{ebcb}'),
+    ("handyman", '\u{f10b}'),
+    ("hardware", '\u{ea59}'),
+    ("hd", '\u{e052}'),
+    ("hdr_auto", '\u{f01a}'),
+    ("hdr_auto_select", '\u{f01b}'),
+    ("hdr_enhanced_select", '\u{ef51}'),
+    ("hdr_off", '\u{e3ed}'),
+    ("hdr_off_select", '\u{f01c}'),
+    ("hdr_on", '\u{e3ee}'),
+    ("hdr_on_select", '\u{f01d}'),
+    ("hdr_plus", '\u{f01e}'),
+    ("hdr_strong", '\u{e3f1}'),
+    ("hdr_weak", '\u{e3f2}'),
+    ("headphones", '\u{f01f}'),
+    ("headphones_battery", '\u{f020}'),
+    ("headset", '\u{e310}'),
+    ("headset_mic", '\u{e311}'),
+    ("headset_off", '\u{e33a}'),
+    ("healing", '\u{e3f3}'),
+    ("health_and_safety", '\u{e1d5}'),
+    ("hearing", '\u{e023}'),
+    ("hearing_disabled", '\u{f104}'),
+    ("heart_broken", '\u{eac2}'),
+    ("height", '\u{ea16}'),
+    ("help", '\u{e887}'),
+    ("help_center", '\u{f1c0}'),
+    ("help_outline", '\u{e8fd}'),
+    ("hevc", '\u{f021}'),
+    ("hexagon", '\u{eb39}'),
+    ("hide_image", '\u{f022}'),
+    ("hide_source", '\u{f023}'),
+    ("high_quality", '\u{e024}'),
+    ("highlight", '\u{e25f}'),
+    ("highlight_alt", '\u{ef52}'),
+    ("highlight_off", '\u{e888}'),
+    ("highlight_remove", '\u{e888}'),
+    ("hiking", '\u{e50a}'),
+    ("history", '\u{e889}'),
+    ("history_edu", '\u{ea3e}'),
+    ("history_toggle_off", '\u{f17d}'),
+    ("hive", '\u{eaa6}'),
+    ("hls", '\u{eb8a}'),
+    ("hls_off", '\u{eb8c}'),
+    ("holiday_village", '\u{e58a}'),
+    ("home", '\u{e88a}'),
+    ("home_filled", '\u{e9b2}'),
+    ("home_max", '\u{f024}'),
+    ("home_mini", '\u{f025}'),
+    ("home_repair_service", '\u{f100}'),
+    ("home_work", '\u{ea09}'),
+    ("horizontal_distribute", '\u{e014}'),
+    ("horizontal_rule", '\u{f108}'),
+    ("horizontal_split", '\u{e947}'),
+    ("hot_tub", '\u{eb46}'),
+    ("hotel", '\u{e53a}'),
+    ("hotel_class", '\u{e743}'),
+    ("hourglass_bottom", '\u{ea5c}'),
+    ("hourglass_disabled", '\u{ef53}'),
+    ("hourglass_empty", '\u{e88b}'),
+    ("hourglass_full", '\u{e88c}'),
+    ("hourglass_top", '\u{ea5b}'),
+    ("house", '\u{ea44}'),
+    ("house_siding", '\u{f202}'),
+    ("houseboat", '\u{e584}'),
+    ("how_to_reg", '\u{e174}'),
+    ("how_to_vote", '\u{e175}'),
+    ("html", '\u{eb7e}'),
+    ("http", '\u{e902}'),
+    ("https", '\u{e88d}'),
+    ("hub", '\u{e9f4}'),
+    ("hvac", '\u{f10e}'),
+    ("ice_skating", '\u{e50b}'),
+    ("icecream", '\u{ea69}'),
+    ("image", '\u{e3f4}'),
+    ("image_aspect_ratio", '\u{e3f5}'),
+    ("image_not_supported", '\u{f116}'),
+    ("image_search", '\u{e43f}'),
+    ("imagesearch_roller", '\u{e9b4}'),
+    ("import_contacts", '\u{e0e0}'),
+    ("import_export", '\u{e0c3}'),
+    ("important_devices", '\u{e912}'),
+    ("inbox", '\u{e156}'),
+    ("incomplete_circle", '\u{e79b}'),
+    ("indeterminate_check_box", '\u{e909}'),
+    ("info", '\u{e88e}'),
+    ("info_outline", '\u{e88f}'),
+    ("input", '\u{e890}'),
+    ("insert_chart", '\u{e24b}'),
+    ("insert_chart_outlined", '\u{e26a}'),
+    ("insert_comment", '\u{e24c}'),
+    ("insert_drive_file", '\u{e24d}'),
+    ("insert_emoticon", '\u{e24e}'),
+    ("insert_invitation", '\u{e24f}'),
+    ("insert_link", '\u{e250}'),
+    ("insert_page_break", '\u{eaca}'),
+    ("insert_photo", '\u{e251}'),
+    ("insights", '\u{f092}'),
+    ("install_desktop", '\u{eb71}'),
+    ("install_mobile", '\u{eb72}'),
+    ("integration_instructions", '\u{ef54}'),
+    ("interests", '\u{e7c8}'),
+    ("interpreter_mode", '\u{e83b}'),
+    ("inventory", '\u{e179}'),
+    ("inventory_2", '\u{e1a1}'),
+    ("invert_colors", '\u{e891}'),
+    ("invert_colors_off", '\u{e0c4}'),
+    ("invert_colors_on", '\u{e891}'),
+    ("ios_share", '\u{e6b8}'),
+    ("iron", '\u{e583}'),
+    ("iso", '\u{e3f6}'),
+    ("javascript", '\u{eb7c}'),
+    ("join_full", '\u{eaeb}'),
+    ("join_inner", '\u{eaf4}'),
+    ("join_left", '\u{eaf2}'),
+    ("join_right", '\u{eaea}'),
+    ("kayaking", '\u{e50c}'),
+    ("kebab_dining", '\u{e842}'),
+    ("key", '\u{e73c}'),
+    ("key_off", '\u{eb84}'),
+    ("keyboard", '\u{e312}'),
+    ("keyboard_alt", '\u{f028}'),
+    ("keyboard_arrow_down", '\u{e313}'),
+    ("keyboard_arrow_left", '\u{e314}'),
+    ("keyboard_arrow_right", '\u{e315}'),
+    ("keyboard_arrow_up", '\u{e316}'),
+    ("keyboard_backspace", '\u{e317}'),
+    ("keyboard_capslock", '\u{e318}'),
+    ("keyboard_command", '\u{eae0}'),
+    ("keyboard_command_key", '\u{eae7}'),
+    ("keyboard_control", '\u{e5d3}'),
+    ("keyboard_control_key", '\u{eae6}'),
+    ("keyboard_double_arrow_down", '\u{ead0}'),
+    ("keyboard_double_arrow_left", '\u{eac3}'),
+    ("keyboard_double_arrow_right", '\u{eac9}'),
+    ("keyboard_double_arrow_up", '\u{eacf}'),
+    ("keyboard_hide", '\u{e31a}'),
+    ("keyboard_option", '\u{eadf}'),
+    ("keyboard_option_key", '\u{eae8}'),
+    ("keyboard_return", '\u{e31b}'),
+    ("keyboard_tab", '\u{e31c}'),
+    ("keyboard_voice", '\u{e31d}'),
+    ("king_bed", '\u{ea45}'),
+    ("kitchen", '\u{eb47}'),
+    ("kitesurfing", '\u{e50d}'),
+    ("label", '\u{e892}'),
+    ("label_important", '\u{e937}'),
+    ("label_important_outline", '\u{e948}'),
+    ("label_off", '\u{e9b6}'),
+    ("label_outline", '\u{e893}'),
+    ("lan", '\u{eb2f}'),
+    ("landscape", '\u{e3f7}'),
+    ("landslide", '\u{ebd7}'),
+    ("language", '\u{e894}'),
+    ("laptop", '\u{e31e}'),
+    ("laptop_chromebook", '\u{e31f}'),
+    ("laptop_mac", '\u{e320}'),
+    ("laptop_windows", '\u{e321}'),
+    ("last_page", '\u{e5dd}'),
+    ("launch", '\u{e895}'),
+    ("layers", '\u{e53b}'),
+    ("layers_clear", '\u{e53c}'),
+    ("leaderboard", '\u{f20c}'),
+    ("leak_add", '\u{e3f8}'),
+    ("leak_remove", '\u{e3f9}'),
+    ("leave_bags_at_home", '\u{f21b}'),
+    ("legend_toggle", '\u{f11b}'),
+    ("lens", '\u{e3fa}'),
+    ("lens_blur", '\u{f029}'),
+    ("library_add", '\u{e02e}'),
+    ("library_add_check", '\u{e9b7}'),
+    ("library_books", '\u{e02f}'),
+    ("library_music", '\u{e030}'),
+    ("light", '\u{f02a}'),
+    ("light_mode", '\u{e518}'),
+    ("lightbulb", '\u{e0f0}'),
+    ("lightbulb_outline", '\u{e90f}'),
+    ("line_axis", '\u{ea9a}'),
+    ("line_style", '\u{e919}'),
+    ("line_weight", '\u{e91a}'),
+    ("linear_scale", '\u{e260}'),
+    ("link", '\u{e157}'),
+    ("link_off", '\u{e16f}'),
+    ("linked_camera", '\u{e438}'),
+    ("liquor", '\u{ea60}'),
+    ("list", '\u{e896}'),
+    ("list_alt", '\u{e0ee}'),
+    ("live_help", '\u{e0c6}'),
+    ("live_tv", '\u{e639}'),
+    ("living", '\u{f02b}'),
+    ("local_activity", '\u{e53f}'),
+    ("local_airport", '\u{e53d}'),
+    ("local_atm", '\u{e53e}'),
+    ("local_attraction", '\u{e53f}'),
+    ("local_bar", '\u{e540}'),
+    ("local_cafe", '\u{e541}'),
+    ("local_car_wash", '\u{e542}'),
+    ("local_convenience_store", '\u{e543}'),
+    ("local_dining", '\u{e556}'),
+    ("local_drink", '\u{e544}'),
+    ("local_fire_department", '\u{ef55}'),
+    ("local_florist", '\u{e545}'),
+    ("local_gas_station", '\u{e546}'),
+    ("local_grocery_store", '\u{e547}'),
+    ("local_hospital", '\u{e548}'),
+    ("local_hotel", '\u{e549}'),
+    ("local_laundry_service", '\u{e54a}'),
+    ("local_library", '\u{e54b}'),
+    ("local_mall", '\u{e54c}'),
+    ("local_movies", '\u{e54d}'),
+    ("local_offer", '\u{e54e}'),
+    ("local_parking", '\u{e54f}'),
+    ("local_pharmacy", '\u{e550}'),
+    ("local_phone", '\u{e551}'),
+    ("local_pizza", '\u{e552}'),
+    ("local_play", '\u{e553}'),
+    ("local_police", '\u{ef56}'),
+    ("local_post_office", '\u{e554}'),
+    ("local_print_shop", '\u{e555}'),
+    ("local_printshop", '\u{e555}'),
+    ("local_restaurant", '\u{e556}'),
+    ("local_see", '\u{e557}'),
+    ("local_shipping", '\u{e558}'),
+    ("local_taxi", '\u{e559}'),
+    ("location_city", '\u{e7f1}'),
+    ("location_disabled", '\u{e1b6}'),
+    ("location_history", '\u{e55a}'),
+    ("location_off", '\u{e0c7}'),
+    ("location_on", '\u{e0c8}'),
+    ("location_pin", '\u{f1db}'),
+    ("location_searching", '\u{e1b7}'),
+    ("lock", '\u{e897}'),
+    ("lock_clock", '\u{ef57}'),
+    ("lock_open", '\u{e898}'),
+    ("lock_outline", '\u{e899}'),
+    ("lock_reset", '\u{eade}'),
+    ("login", '\u{ea77}'),
+    ("logo_dev", '\u{ead6}'),
+    ("logout", '\u{e9ba}'),
+    ("looks", '\u{e3fc}'),
+    ("looks_3", '\u{e3fb}'),
+    ("looks_4", '\u{e3fd}'),
+    ("looks_5", '\u{e3fe}'),
+    ("looks_6", '\u{e3ff}'),
+    ("looks_one", '\u{e400}'),
+    ("looks_two", '\u{e401}'),
+    ("loop", '\u{e028}'),
+    ("loupe", '\u{e402}'),
+    ("low_priority", '\u{e16d}'),
+    ("loyalty", '\u{e89a}'),
+    ("lte_mobiledata", '\u{f02c}'),
+    ("lte_plus_mobiledata", '\u{f02d}'),
+    ("luggage", '\u{f235}'),
+    ("lunch_dining", '\u{ea61}'),
+    ("mail", '\u{e158}'),
+    ("mail_outline", '\u{e0e1}'),
+    ("male", '\u{e58e}'),
+    ("man", '\u{e4eb}'),
+    ("manage_accounts", '\u{f02e}'),
+    ("manage_history", '\u{ebe7}'),
+    ("manage_search", '\u{f02f}'),
+    ("map", '\u{e55b}'),
+    ("maps_home_work", '\u{f030}'),
+    ("maps_ugc", '\u{ef58}'),
+    ("margin", '\u{e9bb}'),
+    ("mark_as_unread", '\u{e9bc}'),
+    ("mark_chat_read", '\u{f18b}'),
+    ("mark_chat_unread", '\u{f189}'),
+    ("mark_email_read", '\u{f18c}'),
+    ("mark_email_unread", '\u{f18a}'),
+    ("mark_unread_chat_alt", '\u{eb9d}'),
+    ("markunread", '\u{e159}'),
+    ("markunread_mailbox", '\u{e89b}'),
+    ("masks", '\u{f218}'),
+    ("maximize", '\u{e930}'),
+    ("media_bluetooth_off", '\u{f031}'),
+    ("media_bluetooth_on", '\u{f032}'),
+    ("mediation", '\u{efa7}'),
+    ("medical_information", '\u{ebed}'),
+    ("medical_services", '\u{f109}'),
+    ("medication", '\u{f033}'),
+    ("medication_liquid", '\u{ea87}'),
+    ("meeting_room", '\u{eb4f}'),
+    ("memory", '\u{e322}'),
+    ("menu", '\u{e5d2}'),
+    ("menu_book", '\u{ea19}'),
+    ("menu_open", '\u{e9bd}'),
+    ("merge", '\u{eb98}'),
+    ("merge_type", '\u{e252}'),
+    ("message", '\u{e0c9}'),
+    ("messenger", '\u{e0ca}'),
+    ("messenger_outline", '\u{e0cb}'),
+    ("mic", '\u{e029}'),
+    ("mic_external_off", '\u{ef59}'),
+    ("mic_external_on", '\u{ef5a}'),
+    ("mic_none", '\u{e02a}'),
+    ("mic_off", '\u{e02b}'),
+    ("microwave", '\u{f204}'),
+    ("military_tech", '\u{ea3f}'),
+    ("minimize", '\u{e931}'),
+    ("minor_crash", '\u{ebf1}'),
+    ("miscellaneous_services", '\u{f10c}'),
+    ("missed_video_call", '\u{e073}'),
+    ("mms", '\u{e618}'),
+    ("mobile_friendly", '\u{e200}'),
+    ("mobile_off", '\u{e201}'),
+    ("mobile_screen_share", '\u{e0e7}'),
+    ("mobiledata_off", '\u{f034}'),
+    ("mode", '\u{f097}'),
+    ("mode_comment", '\u{e253}'),
+    ("mode_edit", '\u{e254}'),
+    ("mode_edit_outline", '\u{f035}'),
+    ("mode_night", '\u{f036}'),
+    ("mode_of_travel", '\u{e7ce}'),
+    ("mode_standby", '\u{f037}'),
+    ("model_training", '\u{f0cf}'),
+    ("monetization_on", '\u{e263}'),
+    ("money", '\u{e57d}'),
+    ("money_off", '\u{e25c}'),
+    ("money_off_csred", '\u{f038}'),
+    ("monitor", '\u{ef5b}'),
+    ("monitor_heart", '\u{eaa2}'),
+    ("monitor_weight", '\u{f039}'),
+    ("monochrome_photos", '\u{e403}'),
+    ("mood", '\u{e7f2}'),
+    ("mood_bad", '\u{e7f3}'),
+    ("moped", '\u{eb28}'),
+    ("more", '\u{e619}'),
+    ("more_horiz", '\u{e5d3}'),
+    ("more_time", '\u{ea5d}'),
+    ("more_vert", '\u{e5d4}'),
+    ("mosque", '\u{eab2}'),
+    ("motion_photos_auto", '\u{f03a}'),
+    ("motion_photos_off", '\u{e9c0}'),
+    ("motion_photos_on", '\u{e9c1}'),
+    ("motion_photos_pause", '\u{f227}'),
+    ("motion_photos_paused", '\u{e9c2}'),
+    ("motorcycle", '\u{e91b}'),
+    ("mouse", '\u{e323}'),
+    ("move_down", '\u{eb61}'),
+    ("move_to_inbox", '\u{e168}'),
+    ("move_up", '\u{eb64}'),
+    ("movie", '\u{e02c}'),
+    ("movie_creation", '\u{e404}'),
+    ("movie_filter", '\u{e43a}'),
+    ("moving", '\u{e501}'),
+    ("mp", '\u{e9c3}'),
+    ("multiline_chart", '\u{e6df}'),
+    ("multiple_stop", '\u{f1b9}'),
+    ("multitrack_audio", '\u{e1b8}'),
+    ("museum", '\u{ea36}'),
+    ("music_note", '\u{e405}'),
+    ("music_off", '\u{e440}'),
+    ("music_video", '\u{e063}'),
+    ("my_library_add", '\u{e02e}'),
+    ("my_library_books", '\u{e02f}'),
+    ("my_library_music", '\u{e030}'),
+    ("my_location", '\u{e55c}'),
+    ("nat", '\u{ef5c}'),
+    ("nature", '\u{e406}'),
+    ("nature_people", '\u{e407}'),
+    ("navigate_before", '\u{e408}'),
+    ("navigate_next", '\u{e409}'),
+    ("navigation", '\u{e55d}'),
+    ("near_me", '\u{e569}'),
+    ("near_me_disabled", '\u{f1ef}'),
+    ("nearby_error", '\u{f03b}'),
+    ("nearby_off", '\u{f03c}'),
+    ("network_cell", '\u{e1b9}'),
+    ("network_check", '\u{e640}'),
+    ("network_locked", '\u{e61a}'),
+    ("network_ping", '\u{ebca}'),
+    ("network_wifi", '\u{e1ba}'),
+    ("network_wifi_1_bar", '\u{ebe4}'),
+    ("network_wifi_2_bar", '\u{ebd6}'),
+    ("network_wifi_3_bar", '\u{ebe1}'),
+    ("new_label", '\u{e609}'),
+    ("new_releases", '\u{e031}'),
+    ("newspaper", '\u{eb81}'),
+    ("next_plan", '\u{ef5d}'),
+    ("next_week", '\u{e16a}'),
+    ("nfc", '\u{e1bb}'),
+    ("night_shelter", '\u{f1f1}'),
+    ("nightlife", '\u{ea62}'),
+    ("nightlight", '\u{f03d}'),
+    ("nightlight_round", '\u{ef5e}'),
+    ("nights_stay", '\u{ea46}'),
+    ("no_accounts", '\u{f03e}'),
+    ("no_backpack", '\u{f237}'),
+    ("no_cell", '\u{f1a4}'),
+    ("no_crash", '\u{ebf0}'),
+    ("no_drinks", '\u{f1a5}'),
+    ("no_encryption", '\u{e641}'),
+    ("no_encryption_gmailerrorred", '\u{f03f}'),
+    ("no_flash", '\u{f1a6}'),
+    ("no_food", '\u{f1a7}'),
+    ("no_luggage", '\u{f23b}'),
+    ("no_meals", '\u{f1d6}'),
+    ("no_meals_ouline", '\u{f229}'),
+    ("no_meeting_room", '\u{eb4e}'),
+    ("no_photography", '\u{f1a8}'),
+    ("no_sim", '\u{e0cc}'),
+    ("no_stroller", '\u{f1af}'),
+    ("no_transfer", '\u{f1d5}'),
+    ("noise_aware", '\u{ebec}'),
+    ("noise_control_off", '\u{ebf3}'),
+    ("nordic_walking", '\u{e50e}'),
+    ("north", '\u{f1e0}'),
+    ("north_east", '\u{f1e1}'),
+    ("north_west", '\u{f1e2}'),
+    ("not_accessible", '\u{f0fe}'),
+    ("not_interested", '\u{e033}'),
+    ("not_listed_location", '\u{e575}'),
+    ("not_started", '\u{f0d1}'),
+    ("note", '\u{e06f}'),
+    ("note_add", '\u{e89c}'),
+    ("note_alt", '\u{f040}'),
+    ("notes", '\u{e26c}'),
+    ("notification_add", '\u{e399}'),
+    ("notification_important", '\u{e004}'),
+    ("notifications", '\u{e7f4}'),
+    ("notifications_active", '\u{e7f7}'),
+    ("notifications_none", '\u{e7f5}'),
+    ("notifications_off", '\u{e7f6}'),
+    ("notifications_on", '\u{e7f7}'),
+    ("notifications_paused", '\u{e7f8}'),
+    ("now_wallpaper", '\u{e1bc}'),
+    ("now_widgets", '\u{e1bd}'),
+    ("numbers", '\u{eac7}'),
+    ("offline_bolt", '\u{e932}'),
+    ("offline_pin", '\u{e90a}'),
+    ("offline_share", '\u{e9c5}'),
+    ("ondemand_video", '\u{e63a}'),
+    ("online_prediction", '\u{f0eb}'),
+    ("opacity", '\u{e91c}'),
+    ("open_in_browser", '\u{e89d}'),
+    ("open_in_full", '\u{f1ce}'),
+    ("open_in_new", '\u{e89e}'),
+    ("open_in_new_off", '\u{e4f6}'),
+    ("open_with", '\u{e89f}'),
+    ("other_houses", '\u{e58c}'),
+    ("outbond", '\u{f228}'),
+    ("outbound", '\u{e1ca}'),
+    ("outbox", '\u{ef5f}'),
+    ("outdoor_grill", '\u{ea47}'),
+    ("outgoing_mail", '\u{f0d2}'),
+    ("outlet", '\u{f1d4}'),
+    ("outlined_flag", '\u{e16e}'),
+    ("output", '\u{ebbe}'),
+    ("padding", '\u{e9c8}'),
+    ("pages", '\u{e7f9}'),
+    ("pageview", '\u{e8a0}'),
+    ("paid", '\u{f041}'),
+    ("palette", '\u{e40a}'),
+    ("pan_tool", '\u{e925}'),
+    ("pan_tool_alt", '\u{ebb9}'),
+    ("panorama", '\u{e40b}'),
+    ("panorama_fish_eye", '\u{e40c}'),
+    ("panorama_fisheye", '\u{e40c}'),
+    ("panorama_horizontal", '\u{e40d}'),
+    ("panorama_horizontal_select", '\u{ef60}'),
+    ("panorama_photosphere", '\u{e9c9}'),
+    ("panorama_photosphere_select", '\u{e9ca}'),
+    ("panorama_vertical", '\u{e40e}'),
+    ("panorama_vertical_select", '\u{ef61}'),
+    ("panorama_wide_angle", '\u{e40f}'),
+    ("panorama_wide_angle_select", '\u{ef62}'),
+    ("paragliding", '\u{e50f}'),
+    ("park", '\u{ea63}'),
+    ("party_mode", '\u{e7fa}'),
+    ("password", '\u{f042}'),
+    ("pattern", '\u{f043}'),
+    ("pause", '\u{e034}'),
+    ("pause_circle", '\u{e1a2}'),
+    ("pause_circle_filled", '\u{e035}'),
+    ("pause_circle_outline", '\u{e036}'),
+    ("pause_presentation", '\u{e0ea}'),
+    ("payment", '\u{e8a1}'),
+    ("payments", '\u{ef63}'),
+    ("paypal", '\u{ea8d}'),
+    ("pedal_bike", '\u{eb29}'),
+    ("pending", '\u{ef64}'),
+    ("pending_actions", '\u{f1bb}'),
+    ("pentagon", '\u{eb50}'),
+    ("people", '\u{e7fb}'),
+    ("people_alt", '\u{ea21}'),
+    ("people_outline", '\u{e7fc}'),
+    ("percent", '\u{eb58}'),
+    ("perm_camera_mic", '\u{e8a2}'),
+    ("perm_contact_cal", '\u{e8a3}'),
+    ("perm_contact_calendar", '\u{e8a3}'),
+    ("perm_data_setting", '\u{e8a4}'),
+    ("perm_device_info", '\u{e8a5}'),
+    ("perm_device_information", '\u{e8a5}'),
+    ("perm_identity", '\u{e8a6}'),
+    ("perm_media", '\u{e8a7}'),
+    ("perm_phone_msg", '\u{e8a8}'),
+    ("perm_scan_wifi", '\u{e8a9}'),
+    ("person", '\u{e7fd}'),
+    ("person_add", '\u{e7fe}'),
+    ("person_add_alt", '\u{ea4d}'),
+    ("person_add_alt_1", '\u{ef65}'),
+    ("person_add_disabled", '\u{e9cb}'),
+    ("person_off", '\u{e510}'),
+    ("person_outline", '\u{e7ff}'),
+    ("person_pin", '\u{e55a}'),
+    ("person_pin_circle", '\u{e56a}'),
+    ("person_remove", '\u{ef66}'),
+    ("person_remove_alt_1", '\u{ef67}'),
+    ("person_search", '\u{f106}'),
+    ("personal_injury", '\u{e6da}'),
+    ("personal_video", '\u{e63b}'),
+    ("pest_control", '\u{f0fa}'),
+    ("pest_control_rodent", '\u{f0fd}'),
+    ("pets", '\u{e91d}'),
+    ("phishing", '\u{ead7}'),
+    ("phone", '\u{e0cd}'),
+    ("phone_android", '\u{e324}'),
+    ("phone_bluetooth_speaker", '\u{e61b}'),
+    ("phone_callback", '\u{e649}'),
+    ("phone_disabled", '\u{e9cc}'),
+    ("phone_enabled", '\u{e9cd}'),
+    ("phone_forwarded", '\u{e61c}'),
+    ("phone_in_talk", '\u{e61d}'),
+    ("phone_iphone", '\u{e325}'),
+    ("phone_locked", '\u{e61e}'),
+    ("phone_missed", '\u{e61f}'),
+    ("phone_paused", '\u{e620}'),
+    ("phonelink", '\u{e326}'),
+    ("phonelink_erase", '\u{e0db}'),
+    ("phonelink_lock", '\u{e0dc}'),
+    ("phonelink_off", '\u{e327}'),
+    ("phonelink_ring", '\u{e0dd}'),
+    ("phonelink_setup", '\u{e0de}'),
+    ("photo", '\u{e410}'),
+    ("photo_album", '\u{e411}'),
+    ("photo_camera", '\u{e412}'),
+    ("photo_camera_back", '\u{ef68}'),
+    ("photo_camera_front", '\u{ef69}'),
+    ("photo_filter", '\u{e43b}'),
+    ("photo_library", '\u{e413}'),
+    ("photo_size_select_actual", '\u{e432}'),
+    ("photo_size_select_large", '\u{e433}'),
+    ("photo_size_select_small", '\u{e434}'),
+    ("php", '\u{eb8f}'),
+    ("piano", '\u{e521}'),
+    ("piano_off", '\u{e520}'),
+    ("picture_as_pdf", '\u{e415}'),
+    ("picture_in_picture", '\u{e8aa}'),
+    ("picture_in_picture_alt", '\u{e911}'),
+    ("pie_chart", '\u{e6c4}'),
+    ("pie_chart_outline", '\u{f044}'),
+    ("pie_chart_outlined", '\u{e6c5}'),
+    ("pin", '\u{f045}'),
+    ("pin_drop", '\u{e55e}'),
+    ("pin_end", '\u{e767}'),
+    ("pin_invoke", '\u{e763}'),
+    ("pinch", '\u{eb38}'),
+    ("pivot_table_chart", '\u{e9ce}'),
+    ("pix", '\u{eaa3}'),
+    ("place", '\u{e55f}'),
+    ("plagiarism", '\u{ea5a}'),
+    ("play_arrow", '\u{e037}'),
+    ("play_circle", '\u{e1c4}'),
+    ("play_circle_fill", '\u{e038}'),
+    ("play_circle_filled", '\u{e038}'),
+    ("play_circle_outline", '\u{e039}'),
+    ("play_disabled", '\u{ef6a}'),
+    ("play_for_work", '\u{e906}'),
+    ("play_lesson", '\u{f047}'),
+    ("playlist_add", '\u{e03b}'),
+    ("playlist_add_check", '\u{e065}'),
+    ("playlist_add_check_circle", '\u{e7e6}'),
+    ("playlist_add_circle", '\u{e7e5}'),
+    ("playlist_play", '\u{e05f}'),
+    ("playlist_remove", '\u{eb80}'),
+    ("plumbing", '\u{f107}'),
+    ("plus_one", '\u{e800}'),
+    ("podcasts", '\u{f048}'),
+    ("point_of_sale", '\u{f17e}'),
+    ("policy", '\u{ea17}'),
+    ("poll", '\u{e801}'),
+    ("polyline", '\u{ebbb}'),
+    ("polymer", '\u{e8ab}'),
+    ("pool", '\u{eb48}'),
+    ("portable_wifi_off", '\u{e0ce}'),
+    ("portrait", '\u{e416}'),
+    ("post_add", '\u{ea20}'),
+    ("power", '\u{e63c}'),
+    ("power_input", '\u{e336}'),
+    ("power_off", '\u{e646}'),
+    ("power_settings_new", '\u{e8ac}'),
+    ("precision_manufacturing", '\u{f049}'),
+    ("pregnant_woman", '\u{e91e}'),
+    ("present_to_all", '\u{e0df}'),
+    ("preview", '\u{f1c5}'),
+    ("price_change", '\u{f04a}'),
+    ("price_check", '\u{f04b}'),
+    ("print", '\u{e8ad}'),
+    ("print_disabled", '\u{e9cf}'),
+    ("priority_high", '\u{e645}'),
+    ("privacy_tip", '\u{f0dc}'),
+    ("private_connectivity", '\u{e744}'),
+    ("production_quantity_limits", '\u{e1d1}'),
+    ("psychology", '\u{ea4a}'),
+    ("public", '\u{e80b}'),
+    ("public_off", '\u{f1ca}'),
+    ("publish", '\u{e255}'),
+    ("published_with_changes", '\u{f232}'),
+    ("punch_clock", '\u{eaa8}'),
+    ("push_pin", '\u{f10d}'),
+    ("qr_code", '\u{ef6b}'),
+    ("qr_code_2", '\u{e00a}'),
+    ("qr_code_scanner", '\u{f206}'),
+    ("query_builder", '\u{e8ae}'),
+    ("query_stats", '\u{e4fc}'),
+    ("question_answer", '\u{e8af}'),
+    ("question_mark", '\u{eb8b}'),
+    ("queue", '\u{e03c}'),
+    ("queue_music", '\u{e03d}'),
+    ("queue_play_next", '\u{e066}'),
+    ("quick_contacts_dialer", '\u{e0cf}'),
+    ("quick_contacts_mail", '\u{e0d0}'),
+    ("quickreply", '\u{ef6c}'),
+    ("quiz", '\u{f04c}'),
+    ("quora", '\u{ea98}'),
+    ("r_mobiledata", '\u{f04d}'),
+    ("radar", '\u{f04e}'),
+    ("radio", '\u{e03e}'),
+    ("radio_button_checked", '\u{e837}'),
+    ("radio_button_off", '\u{e836}'),
+    ("radio_button_on", '\u{e837}'),
+    ("radio_button_unchecked", '\u{e836}'),
+    ("railway_alert", '\u{e9d1}'),
+    ("ramen_dining", '\u{ea64}'),
+    ("ramp_left", '\u{eb9c}'),
+    ("ramp_right", '\u{eb96}'),
+    ("rate_review", '\u{e560}'),
+    ("raw_off", '\u{f04f}'),
+    ("raw_on", '\u{f050}'),
+    ("read_more", '\u{ef6d}'),
+    ("real_estate_agent", '\u{e73a}'),
+    ("receipt", '\u{e8b0}'),
+    ("receipt_long", '\u{ef6e}'),
+    ("recent_actors", '\u{e03f}'),
+    ("recommend", '\u{e9d2}'),
+    ("record_voice_over", '\u{e91f}'),
+    ("rectangle", '\u{eb54}'),
+    ("recycling", '\u{e760}'),
+    ("reddit", '\u{eaa0}'),
+    ("redeem", '\u{e8b1}'),
+    ("redo", '\u{e15a}'),
+    ("reduce_capacity", '\u{f21c}'),
+    ("refresh", '\u{e5d5}'),
+    ("remember_me", '\u{f051}'),
+    ("remove", '\u{e15b}'),
+    ("remove_circle", '\u{e15c}'),
+    ("remove_circle_outline", '\u{e15d}'),
+    ("remove_done", '\u{e9d3}'),
+    ("remove_from_queue", '\u{e067}'),
+    ("remove_moderator", '\u{e9d4}'),
+    ("remove_red_eye", '\u{e417}'),
+    ("remove_shopping_cart", '\u{e928}'),
+    ("reorder", '\u{e8fe}'),
+    ("repeat", '\u{e040}'),
+    ("repeat_on", '\u{e9d6}'),
+    ("repeat_one", '\u{e041}'),
+    ("repeat_one_on", '\u{e9d7}'),
+    ("replay", '\u{e042}'),
+    ("replay_10", '\u{e059}'),
+    ("replay_30", '\u{e05a}'),
+    ("replay_5", '\u{e05b}'),
+    ("replay_circle_filled", '\u{e9d8}'),
+    ("reply", '\u{e15e}'),
+    ("reply_all", '\u{e15f}'),
+    ("report", '\u{e160}'),
+    ("report_gmailerrorred", '\u{f052}'),
+    ("report_off", '\u{e170}'),
+    ("report_problem", '\u{e8b2}'),
+    ("request_page", '\u{f22c}'),
+    ("request_quote", '\u{f1b6}'),
+    ("reset_tv", '\u{e9d9}'),
+    ("restart_alt", '\u{f053}'),
+    ("restaurant", '\u{e56c}'),
+    ("restaurant_menu", '\u{e561}'),
+    ("restore", '\u{e8b3}'),
+    ("restore_from_trash", '\u{e938}'),
+    ("restore_page", '\u{e929}'),
+    ("reviews", '\u{f054}'),
+    ("rice_bowl", '\u{f1f5}'),
+    ("ring_volume", '\u{e0d1}'),
+    ("rocket", '\u{eba5}'),
+    ("rocket_launch", '\u{eb9b}'),
+    ("roller_skating", '\u{ebcd}'),
+    ("roofing", '\u{f201}'),
+    ("room", '\u{e8b4}'),
+    ("room_preferences", '\u{f1b8}'),
+    ("room_service", '\u{eb49}'),
+    ("rotate_90_degrees_ccw", '\u{e418}'),
+    ("rotate_90_degrees_cw", '\u{eaab}'),
+    ("rotate_left", '\u{e419}'),
+    ("rotate_right", '\u{e41a}'),
+    ("roundabout_left", '\u{eb99}'),
+    ("roundabout_right", '\u{eba3}'),
+    ("rounded_corner", '\u{e920}'),
+    ("route", '\u{eacd}'),
+    ("router", '\u{e328}'),
+    ("rowing", '\u{e921}'),
+    ("rss_feed", '\u{e0e5}'),
+    ("rsvp", '\u{f055}'),
+    ("rtt", '\u{e9ad}'),
+    ("rule", '\u{f1c2}'),
+    ("rule_folder", '\u{f1c9}'),
+    ("run_circle", '\u{ef6f}'),
+    ("running_with_errors", '\u{e51d}'),
+    ("rv_hookup", '\u{e642}'),
+    ("safety_check", '\u{ebef}'),
+    ("safety_divider", '\u{e1cc}'),
+    ("sailing", '\u{e502}'),
+    ("sanitizer", '\u{f21d}'),
+    ("satellite", '\u{e562}'),
+    ("satellite_alt", '\u{eb3a}'),
+    ("save", '\u{e161}'),
+    ("save_alt", '\u{e171}'),
+    ("save_as", '\u{eb60}'),
+    ("saved_search", '\u{ea11}'),
+    ("savings", '\u{e2eb}'),
+    ("scale", '\u{eb5f}'),
+    ("scanner", '\u{e329}'),
+    ("scatter_plot", '\u{e268}'),
+    ("schedule", '\u{e8b5}'),
+    ("schedule_send", '\u{ea0a}'),
+    ("schema", '\u{e4fd}'),
+    ("school", '\u{e80c}'),
+    ("science", '\u{ea4b}'),
+    ("score", '\u{e269}'),
+    ("scoreboard", '\u{ebd0}'),
+    ("screen_lock_landscape", '\u{e1be}'),
+    ("screen_lock_portrait", '\u{e1bf}'),
+    ("screen_lock_rotation", '\u{e1c0}'),
+    ("screen_rotation", '\u{e1c1}'),
+    ("screen_rotation_alt", '\u{ebee}'),
+    ("screen_search_desktop", '\u{ef70}'),
+    ("screen_share", '\u{e0e2}'),
+    ("screenshot", '\u{f056}'),
+    ("scuba_diving", '\u{ebce}'),
+    ("sd", '\u{e9dd}'),
+    ("sd_card", '\u{e623}'),
+    ("sd_card_alert", '\u{f057}'),
+    ("sd_storage", '\u{e1c2}'),
+    ("search", '\u{e8b6}'),
+    ("search_off", '\u{ea76}'),
+    ("security", '\u{e32a}'),
+    ("security_update", '\u{f058}'),
+    ("security_update_good", '\u{f059}'),
+    ("security_update_warning", '\u{f05a}'),
+    ("segment", '\u{e94b}'),
+    ("select_all", '\u{e162}'),
+    ("self_improvement", '\u{ea78}'),
+    ("sell", '\u{f05b}'),
+    ("send", '\u{e163}'),
+    ("send_and_archive", '\u{ea0c}'),
+    ("send_time_extension", '\u{eadb}'),
+    ("send_to_mobile", '\u{f05c}'),
+    ("sensor_door", '\u{f1b5}'),
+    ("sensor_window", '\u{f1b4}'),
+    ("sensors", '\u{e51e}'),
+    ("sensors_off", '\u{e51f}'),
+    ("sentiment_dissatisfied", '\u{e811}'),
+    ("sentiment_neutral", '\u{e812}'),
+    ("sentiment_satisfied", '\u{e813}'),
+    ("sentiment_satisfied_alt", '\u{e0ed}'),
+    ("sentiment_very_dissatisfied", '\u{e814}'),
+    ("sentiment_very_satisfied", '\u{e815}'),
+    ("set_meal", '\u{f1ea}'),
+    ("settings", '\u{e8b8}'),
+    ("settings_accessibility", '\u{f05d}'),
+    ("settings_applications", '\u{e8b9}'),
+    ("settings_backup_restore", '\u{e8ba}'),
+    ("settings_bluetooth", '\u{e8bb}'),
+    ("settings_brightness", '\u{e8bd}'),
+    ("settings_cell", '\u{e8bc}'),
+    ("settings_display", '\u{e8bd}'),
+    ("settings_ethernet", '\u{e8be}'),
+    ("settings_input_antenna", '\u{e8bf}'),
+    ("settings_input_component", '\u{e8c0}'),
+    ("settings_input_composite", '\u{e8c1}'),
+    ("settings_input_hdmi", '\u{e8c2}'),
+    ("settings_input_svideo", '\u{e8c3}'),
+    ("settings_overscan", '\u{e8c4}'),
+    ("settings_phone", '\u{e8c5}'),
+    ("settings_power", '\u{e8c6}'),
+    ("settings_remote", '\u{e8c7}'),
+    ("settings_suggest", '\u{f05e}'),
+    ("settings_system_daydream", '\u{e1c3}'),
+    ("settings_voice", '\u{e8c8}'),
+    ("severe_cold", '\u{ebd3}'),
+    ("share", '\u{e80d}'),
+    ("share_arrival_time", '\u{e524}'),
+    ("share_location", '\u{f05f}'),
+    ("shield", '\u{e9e0}'),
+    ("shield_moon", '\u{eaa9}'),
+    ("shop", '\u{e8c9}'),
+    ("shop_2", '\u{e19e}'),
+    ("shop_two", '\u{e8ca}'),
+    ("shopify", '\u{ea9d}'),
+    ("shopping_bag", '\u{f1cc}'),
+    ("shopping_basket", '\u{e8cb}'),
+    ("shopping_cart", '\u{e8cc}'),
+    ("shopping_cart_checkout", '\u{eb88}'),
+    ("short_text", '\u{e261}'),
+    ("shortcut", '\u{f060}'),
+    ("show_chart", '\u{e6e1}'),
+    ("shower", '\u{f061}'),
+    ("shuffle", '\u{e043}'),
+    ("shuffle_on", '\u{e9e1}'),
+    ("shutter_speed", '\u{e43d}'),
+    ("sick", '\u{f220}'),
+    ("sign_language", '\u{ebe5}'),
+    ("signal_cellular_0_bar", '\u{f0a8}'),
+    ("signal_cellular_4_bar", '\u{e1c8}'),
+    ("signal_cellular_alt", '\u{e202}'),
+    ("signal_cellular_alt_1_bar", '\u{ebdf}'),
+    ("signal_cellular_alt_2_bar", '\u{ebe3}'),
+    ("signal_cellular_connected_no_internet_0_bar", '\u{f0ac}'),
+    ("signal_cellular_connected_no_internet_4_bar", '\u{e1cd}'),
+    ("signal_cellular_no_sim", '\u{e1ce}'),
+    ("signal_cellular_nodata", '\u{f062}'),
+    ("signal_cellular_null", '\u{e1cf}'),
+    ("signal_cellular_off", '\u{e1d0}'),
+    ("signal_wifi_0_bar", '\u{f0b0}'),
+    ("signal_wifi_4_bar", '\u{e1d8}'),
+    ("signal_wifi_4_bar_lock", '\u{e1d9}'),
+    ("signal_wifi_bad", '\u{f063}'),
+    ("signal_wifi_connected_no_internet_4", '\u{f064}'),
+    ("signal_wifi_off", '\u{e1da}'),
+    ("signal_wifi_statusbar_4_bar", '\u{f065}'),
+    ("signal_wifi_statusbar_connected_no_internet_4", '\u{f066}'),
+    ("signal_wifi_statusbar_null", '\u{f067}'),
+    ("signpost", '\u{eb91}'),
+    ("sim_card", '\u{e32b}'),
+    ("sim_card_alert", '\u{e624}'),
+    ("sim_card_download", '\u{f068}'),
+    ("single_bed", '\u{ea48}'),
+    ("sip", '\u{f069}'),
+    ("skateboarding", '\u{e511}'),
+    ("skip_next", '\u{e044}'),
+    ("skip_previous", '\u{e045}'),
+    ("sledding", '\u{e512}'),
+    ("slideshow", '\u{e41b}'),
+    ("slow_motion_video", '\u{e068}'),
+    ("smart_button", '\u{f1c1}'),
+    ("smart_display", '\u{f06a}'),
+    ("smart_screen", '\u{f06b}'),
+    ("smart_toy", '\u{f06c}'),
+    ("smartphone", '\u{e32c}'),
+    ("smoke_free", '\u{eb4a}'),
+    ("smoking_rooms", '\u{eb4b}'),
+    ("sms", '\u{e625}'),
+    ("sms_failed", '\u{e626}'),
+    ("snapchat", '\u{ea6e}'),
+    ("snippet_folder", '\u{f1c7}'),
+    ("snooze", '\u{e046}'),
+    ("snowboarding", '\u{e513}'),
+    ("snowing", '\u{e80f}'),
+    ("snowmobile", '\u{e503}'),
+    ("snowshoeing", '\u{e514}'),
+    ("soap", '\u{f1b2}'),
+    ("social_distance", '\u{e1cb}'),
+    ("sort", '\u{e164}'),
+    ("sort_by_alpha", '\u{e053}'),
+    ("sos", '\u{ebf7}'),
+    ("soup_kitchen", '\u{e7d3}'),
+    ("source", '\u{f1c4}'),
+    ("south", '\u{f1e3}'),
+    ("south_america", '\u{e7e4}'),
+    ("south_east", '\u{f1e4}'),
+    ("south_west", '\u{f1e5}'),
+    ("spa", '\u{eb4c}'),
+    ("space_bar", '\u{e256}'),
+    ("space_dashboard", '\u{e66b}'),
+    ("spatial_audio", '\u{ebeb}'),
+    ("spatial_audio_off", '\u{ebe8}'),
+    ("spatial_tracking", '\u{ebea}'),
+    ("speaker", '\u{e32d}'),
+    ("speaker_group", '\u{e32e}'),
+    ("speaker_notes", '\u{e8cd}'),
+    ("speaker_notes_off", '\u{e92a}'),
+    ("speaker_phone", '\u{e0d2}'),
+    ("speed", '\u{e9e4}'),
+    ("spellcheck", '\u{e8ce}'),
+    ("splitscreen", '\u{f06d}'),
+    ("spoke", '\u{e9a7}'),
+    ("sports", '\u{ea30}'),
+    ("sports_bar", '\u{f1f3}'),
+    ("sports_baseball", '\u{ea51}'),
+    ("sports_basketball", '\u{ea26}'),
+    ("sports_cricket", '\u{ea27}'),
+    ("sports_esports", '\u{ea28}'),
+    ("sports_football", '\u{ea29}'),
+    ("sports_golf", '\u{ea2a}'),
+    ("sports_gymnastics", '\u{ebc4}'),
+    ("sports_handball", '\u{ea33}'),
+    ("sports_hockey", '\u{ea2b}'),
+    ("sports_kabaddi", '\u{ea34}'),
+    ("sports_martial_arts", '\u{eae9}'),
+    ("sports_mma", '\u{ea2c}'),
+    ("sports_motorsports", '\u{ea2d}'),
+    ("sports_rugby", '\u{ea2e}'),
+    ("sports_score", '\u{f06e}'),
+    ("sports_soccer", '\u{ea2f}'),
+    ("sports_tennis", '\u{ea32}'),
+    ("sports_volleyball", '\u{ea31}'),
+    ("square", '\u{eb36}'),
+    ("square_foot", '\u{ea49}'),
+    ("ssid_chart", '\u{eb66}'),
+    ("stacked_bar_chart", '\u{e9e6}'),
+    ("stacked_line_chart", '\u{f22b}'),
+    ("stadium", '\u{eb90}'),
+    ("stairs", '\u{f1a9}'),
+    ("star", '\u{e838}'),
+    ("star_border", '\u{e83a}'),
+    ("star_border_purple500", '\u{f099}'),
+    ("star_half", '\u{e839}'),
+    ("star_outline", '\u{f06f}'),
+    ("star_purple500", '\u{f09a}'),
+    ("star_rate", '\u{f0ec}'),
+    ("stars", '\u{e8d0}'),
+    ("start", '\u{e089}'),
+    ("stay_current_landscape", '\u{e0d3}'),
+    ("stay_current_portrait", '\u{e0d4}'),
+    ("stay_primary_landscape", '\u{e0d5}'),
+    ("stay_primary_portrait", '\u{e0d6}'),
+    ("sticky_note_2", '\u{f1fc}'),
+    ("stop", '\u{e047}'),
+    ("stop_circle", '\u{ef71}'),
+    ("stop_screen_share", '\u{e0e3}'),
+    ("storage", '\u{e1db}'),
+    ("store", '\u{e8d1}'),
+    ("store_mall_directory", '\u{e563}'),
+    ("storefront", '\u{ea12}'),
+    ("storm", '\u{f070}'),
+    ("straight", '\u{eb95}'),
+    ("straighten", '\u{e41c}'),
+    ("stream", '\u{e9e9}'),
+    ("streetview", '\u{e56e}'),
+    ("strikethrough_s", '\u{e257}'),
+    ("stroller", '\u{f1ae}'),
+    ("style", '\u{e41d}'),
+    ("subdirectory_arrow_left", '\u{e5d9}'),
+    ("subdirectory_arrow_right", '\u{e5da}'),
+    ("subject", '\u{e8d2}'),
+    ("subscript", '\u{f111}'),
+    ("subscriptions", '\u{e064}'),
+    ("subtitles", '\u{e048}'),
+    ("subtitles_off", '\u{ef72}'),
+    ("subway", '\u{e56f}'),
+    ("summarize", '\u{f071}'),
+    ("sunny", '\u{e81a}'),
+    ("sunny_snowing", '\u{e819}'),
+    ("superscript", '\u{f112}'),
+    ("supervised_user_circle", '\u{e939}'),
+    ("supervisor_account", '\u{e8d3}'),
+    ("support", '\u{ef73}'),
+    ("support_agent", '\u{f0e2}'),
+    ("surfing", '\u{e515}'),
+    ("surround_sound", '\u{e049}'),
+    ("swap_calls", '\u{e0d7}'),
+    ("swap_horiz", '\u{e8d4}'),
+    ("swap_horizontal_circle", '\u{e933}'),
+    ("swap_vert", '\u{e8d5}'),
+    ("swap_vert_circle", '\u{e8d6}'),
+    ("swap_vertical_circle", '\u{e8d6}'),
+    ("swipe", '\u{e9ec}'),
+    ("swipe_down", '\u{eb53}'),
+    ("swipe_down_alt", '\u{eb30}'),
+    ("swipe_left", '\u{eb59}'),
+    ("swipe_left_alt", '\u{eb33}'),
+    ("swipe_right", '\u{eb52}'),
+    ("swipe_right_alt", '\u{eb56}'),
+    ("swipe_up", '\u{eb2e}'),
+    ("swipe_up_alt", '\u{eb35}'),
+    ("swipe_vertical", '\u{eb51}'),
+    ("switch_access_shortcut", '\u{e7e1}'),
+    ("switch_access_shortcut_add", '\u{e7e2}'),
+    ("switch_account", '\u{e9ed}'),
+    ("switch_camera", '\u{e41e}'),
+    ("switch_left", '\u{f1d1}'),
+    ("switch_right", '\u{f1d2}'),
+    ("switch_video", '\u{e41f}'),
+    ("synagogue", '\u{eab0}'),
+    ("sync", '\u{e627}'),
+    ("sync_alt", '\u{ea18}'),
+    ("sync_disabled", '\u{e628}'),
+    ("sync_lock", '\u{eaee}'),
+    ("sync_problem", '\u{e629}'),
+    ("system_security_update", '\u{f072}'),
+    ("system_security_update_good", '\u{f073}'),
+    ("system_security_update_warning", '\u{f074}'),
+    ("system_update", '\u{e62a}'),
+    ("system_update_alt", '\u{e8d7}'),
+    ("system_update_tv", '\u{e8d7}'),
+    ("tab", '\u{e8d8}'),
+    ("tab_unselected", '\u{e8d9}'),
+    ("table_bar", '\u{ead2}'),
+    ("table_chart", '\u{e265}'),
+    ("table_restaurant", '\u{eac6}'),
+    ("table_rows", '\u{f101}'),
+    ("table_view", '\u{f1be}'),
+    ("tablet", '\u{e32f}'),
+    ("tablet_android", '\u{e330}'),
+    ("tablet_mac", '\u{e331}'),
+    ("tag", '\u{e9ef}'),
+    ("tag_faces", '\u{e420}'),
+    ("takeout_dining", '\u{ea74}'),
+    ("tap_and_play", '\u{e62b}'),
+    ("tapas", '\u{f1e9}'),
+    ("task", '\u{f075}'),
+    ("task_alt", '\u{e2e6}'),
+    ("taxi_alert", '\u{ef74}'),
+    ("telegram", '\u{ea6b}'),
+    ("temple_buddhist", '\u{eab3}'),
+    ("temple_hindu", '\u{eaaf}'),
+    ("terminal", '\u{eb8e}'),
+    ("terrain", '\u{e564}'),
+    ("text_decrease", '\u{eadd}'),
+    ("text_fields", '\u{e262}'),
+    ("text_format", '\u{e165}'),
+    ("text_increase", '\u{eae2}'),
+    ("text_rotate_up", '\u{e93a}'),
+    ("text_rotate_vertical", '\u{e93b}'),
+    ("text_rotation_angledown", '\u{e93c}'),
+    ("text_rotation_angleup", '\u{e93d}'),
+    ("text_rotation_down", '\u{e93e}'),
+    ("text_rotation_none", '\u{e93f}'),
+    ("text_snippet", '\u{f1c6}'),
+    ("textsms", '\u{e0d8}'),
+    ("texture", '\u{e421}'),
+    ("theater_comedy", '\u{ea66}'),
+    ("theaters", '\u{e8da}'),
+    ("thermostat", '\u{f076}'),
+    ("thermostat_auto", '\u{f077}'),
+    ("thumb_down", '\u{e8db}'),
+    ("thumb_down_alt", '\u{e816}'),
+    ("thumb_down_off_alt", '\u{e9f2}'),
+    ("thumb_up", '\u{e8dc}'),
+    ("thumb_up_alt", '\u{e817}'),
+    ("thumb_up_off_alt", '\u{e9f3}'),
+    ("thumbs_up_down", '\u{e8dd}'),
+    ("thunderstorm", '\u{ebdb}'),
+    ("tiktok", '\u{ea7e}'),
+    ("time_to_leave", '\u{e62c}'),
+    ("timelapse", '\u{e422}'),
+    ("timeline", '\u{e922}'),
+    ("timer", '\u{e425}'),
+    ("timer_10", '\u{e423}'),
+    ("timer_10_select", '\u{f07a}'),
+    ("timer_3", '\u{e424}'),
+    ("timer_3_select", '\u{f07b}'),
+    ("timer_off", '\u{e426}'),
+    ("tips_and_updates", '\u{e79a}'),
+    ("tire_repair", '\u{ebc8}'),
+    ("title", '\u{e264}'),
+    ("toc", '\u{e8de}'),
+    ("today", '\u{e8df}'),
+    ("toggle_off", '\u{e9f5}'),
+    ("toggle_on", '\u{e9f6}'),
+    ("token", '\u{ea25}'),
+    ("toll", '\u{e8e0}'),
+    ("tonality", '\u{e427}'),
+    ("topic", '\u{f1c8}'),
+    ("touch_app", '\u{e913}'),
+    ("tour", '\u{ef75}'),
+    ("toys", '\u{e332}'),
+    ("track_changes", '\u{e8e1}'),
+    ("traffic", '\u{e565}'),
+    ("train", '\u{e570}'),
+    ("tram", '\u{e571}'),
+    ("transfer_within_a_station", '\u{e572}'),
+    ("transform", '\u{e428}'),
+    ("transgender", '\u{e58d}'),
+    ("transit_enterexit", '\u{e579}'),
+    ("translate", '\u{e8e2}'),
+    ("travel_explore", '\u{e2db}'),
+    ("trending_down", '\u{e8e3}'),
+    ("trending_flat", '\u{e8e4}'),
+    ("trending_neutral", '\u{e8e4}'),
+    ("trending_up", '\u{e8e5}'),
+    ("trip_origin", '\u{e57b}'),
+    ("try", '\u{f07c}'),
+    ("tsunami", '\u{ebd8}'),
+    ("tty", '\u{f1aa}'),
+    ("tune", '\u{e429}'),
+    ("tungsten", '\u{f07d}'),
+    ("turn_left", '\u{eba6}'),
+    ("turn_right", '\u{ebab}'),
+    ("turn_sharp_left", '\u{eba7}'),
+    ("turn_sharp_right", '\u{ebaa}'),
+    ("turn_slight_left", '\u{eba4}'),
+    ("turn_slight_right", '\u{eb9a}'),
+    ("turned_in", '\u{e8e6}'),
+    ("turned_in_not", '\u{e8e7}'),
+    ("tv", '\u{e333}'),
+    ("tv_off", '\u{e647}'),
+    ("two_wheeler", '\u{e9f9}'),
+    ("u_turn_left", '\u{eba1}'),
+    ("u_turn_right", '\u{eba2}'),
+    ("umbrella", '\u{f1ad}'),
+    ("unarchive", '\u{e169}'),
+    ("undo", '\u{e166}'),
+    ("unfold_less", '\u{e5d6}'),
+    ("unfold_more", '\u{e5d7}'),
+    ("unpublished", '\u{f236}'),
+    ("unsubscribe", '\u{e0eb}'),
+    ("upcoming", '\u{f07e}'),
+    ("update", '\u{e923}'),
+    ("update_disabled", '\u{e075}'),
+    ("upgrade", '\u{f0fb}'),
+    ("upload", '\u{f09b}'),
+    ("upload_file", '\u{e9fc}'),
+    ("usb", '\u{e1e0}'),
+    ("usb_off", '\u{e4fa}'),
+    ("vaccines", '\u{e138}'),
+    ("vape_free", '\u{ebc6}'),
+    ("vaping_rooms", '\u{ebcf}'),
+    ("verified", '\u{ef76}'),
+    ("verified_user", '\u{e8e8}'),
+    ("vertical_align_bottom", '\u{e258}'),
+    ("vertical_align_center", '\u{e259}'),
+    ("vertical_align_top", '\u{e25a}'),
+    ("vertical_distribute", '\u{e076}'),
+    ("vertical_split", '\u{e949}'),
+    ("vibration", '\u{e62d}'),
+    ("video_call", '\u{e070}'),
+    ("video_camera_back", '\u{f07f}'),
+    ("video_camera_front", '\u{f080}'),
+    ("video_collection", '\u{e04a}'),
+    ("video_file", '\u{eb87}'),
+    ("video_label", '\u{e071}'),
+    ("video_library", '\u{e04a}'),
+    ("video_settings", '\u{ea75}'),
+    ("video_stable", '\u{f081}'),
+    ("videocam", '\u{e04b}'),
+    ("videocam_off", '\u{e04c}'),
+    ("videogame_asset", '\u{e338}'),
+    ("videogame_asset_off", '\u{e500}'),
+    ("view_agenda", '\u{e8e9}'),
+    ("view_array", '\u{e8ea}'),
+    ("view_carousel", '\u{e8eb}'),
+    ("view_column", '\u{e8ec}'),
+    ("view_comfortable", '\u{e42a}'),
+    ("view_comfy", '\u{e42a}'),
+    ("view_comfy_alt", '\u{eb73}'),
+    ("view_compact", '\u{e42b}'),
+    ("view_compact_alt", '\u{eb74}'),
+    ("view_cozy", '\u{eb75}'),
+    ("view_day", '\u{e8ed}'),
+    ("view_headline", '\u{e8ee}'),
+    ("view_in_ar", '\u{e9fe}'),
+    ("view_kanban", '\u{eb7f}'),
+    ("view_list", '\u{e8ef}'),
+    ("view_module", '\u{e8f0}'),
+    ("view_quilt", '\u{e8f1}'),
+    ("view_sidebar", '\u{f114}'),
+    ("view_stream", '\u{e8f2}'),
+    ("view_timeline", '\u{eb85}'),
+    ("view_week", '\u{e8f3}'),
+    ("vignette", '\u{e435}'),
+    ("villa", '\u{e586}'),
+    ("visibility", '\u{e8f4}'),
+    ("visibility_off", '\u{e8f5}'),
+    ("voice_chat", '\u{e62e}'),
+    ("voice_over_off", '\u{e94a}'),
+    ("voicemail", '\u{e0d9}'),
+    ("volcano", '\u{ebda}'),
+    ("volume_down", '\u{e04d}'),
+    ("volume_down_alt", '\u{e79c}'),
+    ("volume_mute", '\u{e04e}'),
+    ("volume_off", '\u{e04f}'),
+    ("volume_up", '\u{e050}'),
+    ("volunteer_activism", '\u{ea70}'),
+    ("vpn_key", '\u{e0da}'),
+    ("vpn_key_off", '\u{eb7a}'),
+    ("vpn_lock", '\u{e62f}'),
+    ("vrpano", '\u{f082}'),
+    ("wallet_giftcard", '\u{e8f6}'),
+    ("wallet_membership", '\u{e8f7}'),
+    ("wallet_travel", '\u{e8f8}'),
+    ("wallpaper", '\u{e1bc}'),
+    ("warehouse", '\u{ebb8}'),
+    ("warning", '\u{e002}'),
+    ("warning_amber", '\u{f083}'),
+    ("wash", '\u{f1b1}'),
+    ("watch", '\u{e334}'),
+    ("watch_later", '\u{e924}'),
+    ("watch_off", '\u{eae3}'),
+    ("water", '\u{f084}'),
+    ("water_damage", '\u{f203}'),
+    ("water_drop", '\u{e798}'),
+    ("waterfall_chart", '\u{ea00}'),
+    ("waves", '\u{e176}'),
+    ("waving_hand", '\u{e766}'),
+    ("wb_auto", '\u{e42c}'),
+    ("wb_cloudy", '\u{e42d}'),
+    ("wb_incandescent", '\u{e42e}'),
+    ("wb_iridescent", '\u{e436}'),
+    ("wb_shade", '\u{ea01}'),
+    ("wb_sunny", '\u{e430}'),
+    ("wb_twighlight", '\u{ea02}'),
+    ("wb_twilight", '\u{e1c6}'),
+    ("wc", '\u{e63d}'),
+    ("web", '\u{e051}'),
+    ("web_asset", '\u{e069}'),
+    ("web_asset_off", '\u{e4f7}'),
+    ("web_stories", '\u{e595}'),
+    ("webhook", '\u{eb92}'),
+    ("wechat", '\u{ea81}'),
+    ("weekend", '\u{e16b}'),
+    ("west", '\u{f1e6}'),
+    ("whatsapp", '\u{ea9c}'),
+    ("whatshot", '\u{e80e}'),
+    ("wheelchair_pickup", '\u{f1ab}'),
+    ("where_to_vote", '\u{e177}'),
+    ("widgets", '\u{e1bd}'),
+    ("wifi", '\u{e63e}'),
+    ("wifi_1_bar", '\u{e4ca}'),
+    ("wifi_2_bar", '\u{e4d9}'),
+    ("wifi_calling", '\u{ef77}'),
+    ("wifi_calling_3", '\u{f085}'),
+    ("wifi_channel", '\u{eb6a}'),
+    ("wifi_find", '\u{eb31}'),
+    ("wifi_lock", '\u{e1e1}'),
+    ("wifi_off", '\u{e648}'),
+    ("wifi_password", '\u{eb6b}'),
+    ("wifi_protected_setup", '\u{f0fc}'),
+    ("wifi_tethering", '\u{e1e2}'),
+    ("wifi_tethering_error", '\u{ead9}'),
+    ("wifi_tethering_error_rounded", '\u{f086}'),
+    ("wifi_tethering_off", '\u{f087}'),
+    ("window", '\u{f088}'),
+    ("wine_bar", '\u{f1e8}'),
+    ("woman", '\u{e13e}'),
+    ("woo_commerce", '\u{ea6d}'),
+    ("wordpress", '\u{ea9f}'),
+    ("work", '\u{e8f9}'),
+    ("work_off", '\u{e942}'),
+    ("work_outline", '\u{e943}'),
+    ("workspace_premium", '\u{e7af}'),
+    ("workspaces", '\u{e1a0}'),
+    ("workspaces_filled", '\u{ea0d}'),
+    ("workspaces_outline", '\u{ea0f}'),
+    ("wrap_text", '\u{e25b}'),
+    ("wrong_location", '\u{ef78}'),
+    ("wysiwyg", '\u{f1c3}'),
+    ("yard", '\u{f089}'),
+    ("youtube_searched_for", '\u{e8fa}'),
+    ("zoom_in", '\u{e8ff}'),
+    ("zoom_in_map", '\u{eb2d}'),
+    ("zoom_out", '\u{e900}'),
+    ("zoom_out_map", '\u{e56b}'),
+];
@@ -0,0 +1,215 @@
+use crate::cache::BoundingBox;
+use crate::prelude::*;
+
+/// The side of the anchor view on which an anchored view is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnchorSide {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl AnchorSide {
+    fn opposite(self) -> Self {
+        match self {
+            AnchorSide::Top => AnchorSide::Bottom,
+            AnchorSide::Bottom => AnchorSide::Top,
+            AnchorSide::Left => AnchorSide::Right,
+            AnchorSide::Right => AnchorSide::Left,
+        }
+    }
+}
+
+/// How an anchored view is aligned along the side of the anchor view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnchorAlign {
+    /// Aligns the left or top edges of the two views.
+    Start,
+    /// Centers the anchored view on the anchor view.
+    Center,
+    /// Aligns the right or bottom edges of the two views.
+    End,
+}
+
+/// Places a view next to another view, the anchor, after layout.
+///
+/// The anchored view keeps the size given to it by layout but is moved, along with its descendants,
+/// to the chosen side of the anchor. If it would overflow the window it is flipped to the opposite
+/// side, when there is more room there, and shifted along the side of the anchor to stay in view.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// let button = Button::new(cx, |_| {}, |cx| Label::new(cx, "Open")).entity();
+/// VStack::new(cx, |_| {})
+///     .anchor(Anchor::new(button, AnchorSide::Bottom).align(AnchorAlign::Center).offset(4.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anchor {
+    /// The view to place the anchored view next to.
+    pub target: Entity,
+    /// The preferred side of the target.
+    pub side: AnchorSide,
+    /// The alignment along the side of the target.
+    pub align: AnchorAlign,
+    /// The gap between the two views, in logical pixels.
+    pub offset: f32,
+    /// Whether to flip to the opposite side when the view would overflow the window.
+    pub flip: bool,
+    /// Whether to shift along the side of the target to keep the view within the window.
+    pub shift: bool,
+}
+
+impl Anchor {
+    /// Creates an anchor which places a view on the given side of the target, aligned to its start.
+    pub fn new(target: Entity, side: AnchorSide) -> Self {
+        Self { target, side, align: AnchorAlign::Start, offset: 0.0, flip: true, shift: true }
+    }
+
+    /// Sets the alignment along the side of the target.
+    pub fn align(mut self, align: AnchorAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Sets the gap between the two views, in logical pixels.
+    pub fn offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets whether to flip to the opposite side when the view would overflow the window.
+    pub fn flip(mut self, flip: bool) -> Self {
+        self.flip = flip;
+        self
+    }
+
+    /// Sets whether to shift along the side of the target to keep the view within the window.
+    pub fn shift(mut self, shift: bool) -> Self {
+        self.shift = shift;
+        self
+    }
+
+    /// Returns the position of a view of the given size placed next to the target bounds, kept
+    /// within the window bounds. The offset is scaled by `scale`.
+    pub(crate) fn place(
+        &self,
+        target: BoundingBox,
+        width: f32,
+        height: f32,
+        window: BoundingBox,
+        scale: f32,
+    ) -> (f32, f32) {
+        let gap = self.offset * scale;
+
+        // The position along the axis away from the target, for each side
+        let main = |side: AnchorSide| match side {
+            AnchorSide::Top => target.top() - gap - height,
+            AnchorSide::Bottom => target.bottom() + gap,
+            AnchorSide::Left => target.left() - gap - width,
+            AnchorSide::Right => target.right() + gap,
+        };
+
+        // The amount by which a view placed on a side would overflow the window
+        let overflow = |side: AnchorSide| match side {
+            AnchorSide::Top => window.top() - main(side),
+            AnchorSide::Bottom => main(side) + height - window.bottom(),
+            AnchorSide::Left => window.left() - main(side),
+            AnchorSide::Right => main(side) + width - window.right(),
+        };
+
+        let mut side = self.side;
+        if self.flip && overflow(side) > 0.0 && overflow(side.opposite()) < overflow(side) {
+            side = side.opposite();
+        }
+
+        let vertical = matches!(side, AnchorSide::Top | AnchorSide::Bottom);
+        let (start, length, size) = if vertical {
+            (target.left(), target.width(), width)
+        } else {
+            (target.top(), target.height(), height)
+        };
+
+        let mut cross = match self.align {
+            AnchorAlign::Start => start,
+            AnchorAlign::Center => start + (length - size) / 2.0,
+            AnchorAlign::End => start + length - size,
+        };
+
+        if self.shift {
+            let (min, max) = if vertical {
+                (window.left(), window.right() - size)
+            } else {
+                (window.top(), window.bottom() - size)
+            };
+
+            // A view larger than the window is aligned to its start
+            cross = cross.min(max).max(min);
+        }
+
+        if vertical {
+            (cross, main(side))
+        } else {
+            (main(side), cross)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: BoundingBox = BoundingBox { x: 0.0, y: 0.0, w: 400.0, h: 300.0 };
+
+    fn anchor(side: AnchorSide) -> Anchor {
+        Anchor::new(Entity::root(), side)
+    }
+
+    #[test]
+    fn places_on_side() {
+        let target = BoundingBox { x: 100.0, y: 100.0, w: 50.0, h: 20.0 };
+        assert_eq!(
+            anchor(AnchorSide::Bottom).place(target, 80.0, 40.0, WINDOW, 1.0),
+            (100.0, 120.0)
+        );
+        assert_eq!(anchor(AnchorSide::Top).place(target, 80.0, 40.0, WINDOW, 1.0), (100.0, 60.0));
+        assert_eq!(
+            anchor(AnchorSide::Right).place(target, 80.0, 40.0, WINDOW, 1.0),
+            (150.0, 100.0)
+        );
+        assert_eq!(anchor(AnchorSide::Left).place(target, 80.0, 40.0, WINDOW, 1.0), (20.0, 100.0));
+    }
+
+    #[test]
+    fn aligns_and_offsets() {
+        let target = BoundingBox { x: 100.0, y: 100.0, w: 50.0, h: 20.0 };
+        let center = anchor(AnchorSide::Bottom).align(AnchorAlign::Center).offset(4.0);
+        assert_eq!(center.place(target, 80.0, 40.0, WINDOW, 2.0), (85.0, 128.0));
+        let end = anchor(AnchorSide::Right).align(AnchorAlign::End);
+        assert_eq!(end.place(target, 80.0, 40.0, WINDOW, 1.0), (150.0, 80.0));
+    }
+
+    #[test]
+    fn flips_when_overflowing() {
+        let target = BoundingBox { x: 100.0, y: 250.0, w: 50.0, h: 20.0 };
+        assert_eq!(
+            anchor(AnchorSide::Bottom).place(target, 80.0, 40.0, WINDOW, 1.0),
+            (100.0, 210.0)
+        );
+        let fixed = anchor(AnchorSide::Bottom).flip(false);
+        assert_eq!(fixed.place(target, 80.0, 40.0, WINDOW, 1.0), (100.0, 270.0));
+    }
+
+    #[test]
+    fn shifts_into_window() {
+        let target = BoundingBox { x: 350.0, y: 100.0, w: 50.0, h: 20.0 };
+        assert_eq!(
+            anchor(AnchorSide::Bottom).place(target, 80.0, 40.0, WINDOW, 1.0),
+            (320.0, 120.0)
+        );
+        let wide = anchor(AnchorSide::Bottom);
+        assert_eq!(wide.place(target, 500.0, 40.0, WINDOW, 1.0), (0.0, 120.0));
+    }
+}
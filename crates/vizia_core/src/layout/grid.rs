@@ -0,0 +1,270 @@
+use morphorm::Units;
+
+use crate::prelude::*;
+
+/// The cells of a grid covered by one of its children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct GridPlacement {
+    pub row: usize,
+    pub col: usize,
+    pub row_span: usize,
+    pub col_span: usize,
+}
+
+/// A child of a grid, which is either placed by its row and column or by an area, or is placed in
+/// the first free cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GridItem {
+    Placed(GridPlacement),
+    Auto { row_span: usize, col_span: usize },
+}
+
+/// Places the children of a grid with the given number of explicit rows and columns.
+///
+/// Placed children keep their cells, and the other children are placed in order in the first free
+/// cells they fit in, filling rows or columns depending on the flow. Rows or columns are added as
+/// needed. Returns the placement of each child along with the number of rows and columns.
+pub(crate) fn place_grid_items(
+    rows: usize,
+    cols: usize,
+    flow: GridAutoFlow,
+    items: &[GridItem],
+) -> (Vec<GridPlacement>, usize, usize) {
+    // Placement works in terms of lines, which are the rows or columns filled in turn, and the
+    // cells across them
+    let transpose = |placement: GridPlacement| match flow {
+        GridAutoFlow::Row => placement,
+        GridAutoFlow::Column => GridPlacement {
+            row: placement.col,
+            col: placement.row,
+            row_span: placement.col_span,
+            col_span: placement.row_span,
+        },
+    };
+
+    let mut cells = match flow {
+        GridAutoFlow::Row => cols,
+        GridAutoFlow::Column => rows,
+    };
+    for item in items {
+        if let GridItem::Placed(placement) = item {
+            let placement = transpose(*placement);
+            cells = cells.max(placement.col + placement.col_span);
+        }
+    }
+    let cells = cells.max(1);
+
+    let mut occupied: Vec<Vec<bool>> = Vec::new();
+    let occupy = |occupied: &mut Vec<Vec<bool>>, placement: GridPlacement| {
+        for line in placement.row..placement.row + placement.row_span {
+            while occupied.len() <= line {
+                occupied.push(vec![false; cells]);
+            }
+
+            for cell in placement.col..placement.col + placement.col_span {
+                occupied[line][cell] = true;
+            }
+        }
+    };
+
+    for item in items {
+        if let GridItem::Placed(placement) = item {
+            occupy(&mut occupied, transpose(*placement));
+        }
+    }
+
+    let is_free = |occupied: &Vec<Vec<bool>>, line: usize, cell: usize, span: (usize, usize)| {
+        (line..line + span.0).all(|line| {
+            (cell..cell + span.1)
+                .all(|cell| occupied.get(line).map_or(true, |occupied| !occupied[cell]))
+        })
+    };
+
+    // Children are placed after the previous auto placed child, so the order is kept
+    let (mut line, mut cell) = (0, 0);
+    let mut placements = Vec::with_capacity(items.len());
+    for item in items {
+        let placement = match *item {
+            GridItem::Placed(placement) => placement,
+
+            GridItem::Auto { row_span, col_span } => {
+                let span = transpose(GridPlacement { row: 0, col: 0, row_span, col_span });
+                let span = (span.row_span.max(1), span.col_span.max(1).min(cells));
+                loop {
+                    if cell + span.1 > cells {
+                        line += 1;
+                        cell = 0;
+                    } else if is_free(&occupied, line, cell, span) {
+                        break;
+                    } else {
+                        cell += 1;
+                    }
+                }
+
+                let placement =
+                    GridPlacement { row: line, col: cell, row_span: span.0, col_span: span.1 };
+                occupy(&mut occupied, placement);
+                cell += span.1;
+                transpose(placement)
+            }
+        };
+
+        placements.push(placement);
+    }
+
+    let lines = occupied.len();
+    match flow {
+        GridAutoFlow::Row => (placements, rows.max(lines), cells),
+        GridAutoFlow::Column => (placements, cells, cols.max(lines)),
+    }
+}
+
+/// Resolves grid tracks to sizes in physical pixels, given the space available to them.
+///
+/// Tracks with a fixed size keep it, tracks with a fixed maximum grow towards it, and the space
+/// left over is shared by the stretch tracks, which don't shrink below their minimums. Auto tracks
+/// are left for layout to size, and are given no space here.
+pub(crate) fn resolve_grid_tracks(tracks: &[GridTrack], available: f32, scale: f32) -> Vec<Units> {
+    let fixed = |units: Units| match units {
+        Units::Pixels(val) => Some(val * scale),
+        Units::Percentage(val) => Some(available * val / 100.0),
+        _ => None,
+    };
+
+    let limits = tracks
+        .iter()
+        .map(|track| match *track {
+            GridTrack::Size(size) => (size, size),
+            GridTrack::MinMax(min, max) => (min, max),
+        })
+        .collect::<Vec<_>>();
+
+    let mut sizes = limits.iter().map(|&(min, _)| fixed(min).unwrap_or(0.0)).collect::<Vec<_>>();
+    let mut free = available - sizes.iter().sum::<f32>();
+
+    // Tracks with a fixed maximum grow towards it, in proportion to how far they have to grow
+    let growth = limits
+        .iter()
+        .zip(sizes.iter())
+        .map(|(&(_, max), &size)| fixed(max).map_or(0.0, |max| (max - size).max(0.0)))
+        .collect::<Vec<_>>();
+    let total_growth = growth.iter().sum::<f32>();
+    if total_growth > 0.0 && free > 0.0 {
+        let fraction = (free / total_growth).min(1.0);
+        for (size, growth) in sizes.iter_mut().zip(growth.iter()) {
+            *size += growth * fraction;
+        }
+
+        free -= total_growth * fraction;
+    }
+
+    // Stretch tracks share the free space along with their minimums, and a track which would get
+    // less than its minimum keeps it instead, leaving the rest to be shared again
+    let mut stretching = limits
+        .iter()
+        .enumerate()
+        .filter_map(|(index, &(_, max))| match max {
+            Units::Stretch(factor) if factor > 0.0 => Some((index, factor)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let mut space = free + stretching.iter().map(|&(index, _)| sizes[index]).sum::<f32>();
+    loop {
+        let total = stretching.iter().map(|&(_, factor)| factor).sum::<f32>();
+        let below_min = stretching
+            .iter()
+            .position(|&(index, factor)| space.max(0.0) * factor / total < sizes[index]);
+
+        match below_min {
+            Some(position) => {
+                let (index, _) = stretching.remove(position);
+                space -= sizes[index];
+            }
+
+            None => {
+                for &(index, factor) in stretching.iter() {
+                    sizes[index] = space.max(0.0) * factor / total;
+                }
+
+                break;
+            }
+        }
+    }
+
+    limits
+        .iter()
+        .zip(sizes)
+        .map(|(&(min, max), size)| match (min, max) {
+            (Units::Auto, Units::Auto) => Units::Auto,
+            _ => Units::Pixels(size),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn auto(count: usize) -> Vec<GridItem> {
+        vec![GridItem::Auto { row_span: 1, col_span: 1 }; count]
+    }
+
+    fn at(row: usize, col: usize, row_span: usize, col_span: usize) -> GridPlacement {
+        GridPlacement { row, col, row_span, col_span }
+    }
+
+    #[test]
+    fn places_in_row_flow() {
+        let (placements, rows, cols) = place_grid_items(1, 2, GridAutoFlow::Row, &auto(5));
+        assert_eq!(rows, 3);
+        assert_eq!(cols, 2);
+        assert_eq!(placements[1], at(0, 1, 1, 1));
+        assert_eq!(placements[2], at(1, 0, 1, 1));
+        assert_eq!(placements[4], at(2, 0, 1, 1));
+    }
+
+    #[test]
+    fn places_in_column_flow() {
+        let (placements, rows, cols) = place_grid_items(2, 1, GridAutoFlow::Column, &auto(3));
+        assert_eq!((rows, cols), (2, 2));
+        assert_eq!(placements[1], at(1, 0, 1, 1));
+        assert_eq!(placements[2], at(0, 1, 1, 1));
+    }
+
+    #[test]
+    fn skips_placed_cells() {
+        let items = [
+            GridItem::Placed(at(0, 0, 1, 2)),
+            GridItem::Auto { row_span: 1, col_span: 1 },
+            GridItem::Auto { row_span: 1, col_span: 2 },
+            GridItem::Auto { row_span: 1, col_span: 1 },
+        ];
+        let (placements, rows, _) = place_grid_items(0, 3, GridAutoFlow::Row, &items);
+        assert_eq!(placements[0], at(0, 0, 1, 2));
+        assert_eq!(placements[1], at(0, 2, 1, 1));
+        assert_eq!(placements[2], at(1, 0, 1, 2));
+        assert_eq!(placements[3], at(1, 2, 1, 1));
+        assert_eq!(rows, 2);
+    }
+
+    #[test]
+    fn resolves_tracks() {
+        let tracks = [
+            GridTrack::Size(Units::Pixels(100.0)),
+            GridTrack::MinMax(Units::Pixels(150.0), Units::Stretch(1.0)),
+            GridTrack::Size(Units::Stretch(1.0)),
+        ];
+        assert_eq!(
+            resolve_grid_tracks(&tracks, 500.0, 1.0),
+            vec![Units::Pixels(100.0), Units::Pixels(200.0), Units::Pixels(200.0)]
+        );
+        assert_eq!(
+            resolve_grid_tracks(&tracks, 300.0, 1.0),
+            vec![Units::Pixels(100.0), Units::Pixels(150.0), Units::Pixels(50.0)]
+        );
+
+        let tracks = [GridTrack::MinMax(Units::Pixels(50.0), Units::Pixels(100.0))];
+        assert_eq!(resolve_grid_tracks(&tracks, 80.0, 2.0), vec![Units::Pixels(100.0)]);
+        assert_eq!(resolve_grid_tracks(&tracks, 300.0, 2.0), vec![Units::Pixels(200.0)]);
+    }
+}
@@ -2,9 +2,13 @@
 //! Layout determines the size and position of entities on the screen.
 //!
 //! All layout calculations are handled by the Morphorm crate.
+mod anchor;
 pub(crate) mod cache;
+pub(crate) mod grid;
 pub(crate) mod node;
 
+pub use anchor::*;
+
 use crate::prelude::*;
 pub use morphorm::GeometryChanged;
 use morphorm::{Cache, Hierarchy};
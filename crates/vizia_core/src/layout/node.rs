@@ -12,11 +12,20 @@ impl<'w> Node<'w> for Entity {
         store.layout_type.get(*self).cloned()
     }
 
+    // The children of wrapping layouts are placed where the wrap system put them
     fn position_type(&self, store: &Self::Data) -> Option<morphorm::PositionType> {
+        if store.wrap_placement.contains(*self) {
+            return Some(morphorm::PositionType::SelfDirected);
+        }
+
         store.position_type.get(*self).cloned()
     }
 
     fn left(&self, store: &Self::Data) -> Option<morphorm::Units> {
+        if let Some(placement) = store.wrap_placement.get(*self) {
+            return Some(Units::Pixels(placement.x));
+        }
+
         store.left.get(*self).cloned().map(|l| match l {
             Units::Pixels(val) => Units::Pixels(val * store.dpi_factor as f32),
             t => t,
@@ -38,6 +47,10 @@ impl<'w> Node<'w> for Entity {
     }
 
     fn right(&self, store: &Self::Data) -> Option<morphorm::Units> {
+        if store.wrap_placement.contains(*self) {
+            return Some(Units::Pixels(0.0));
+        }
+
         store.right.get(*self).cloned().map(|r| match r {
             Units::Pixels(val) => Units::Pixels(val * store.dpi_factor as f32),
             t => t,
@@ -59,6 +72,10 @@ impl<'w> Node<'w> for Entity {
     }
 
     fn top(&self, store: &Self::Data) -> Option<morphorm::Units> {
+        if let Some(placement) = store.wrap_placement.get(*self) {
+            return Some(Units::Pixels(placement.y));
+        }
+
         store.top.get(*self).cloned().map(|t| match t {
             Units::Pixels(val) => Units::Pixels(val * store.dpi_factor as f32),
             t => t,
@@ -80,6 +97,10 @@ impl<'w> Node<'w> for Entity {
     }
 
     fn bottom(&self, store: &Self::Data) -> Option<morphorm::Units> {
+        if store.wrap_placement.contains(*self) {
+            return Some(Units::Pixels(0.0));
+        }
+
         store.bottom.get(*self).cloned().map(|b| match b {
             Units::Pixels(val) => Units::Pixels(val * store.dpi_factor as f32),
             t => t,
@@ -101,6 +122,18 @@ impl<'w> Node<'w> for Entity {
     }
 
     fn width(&self, store: &Self::Data) -> Option<morphorm::Units> {
+        if let Some(width) = store.aspect_width.get(*self) {
+            return Some(Units::Pixels(*width));
+        }
+
+        if let Some(placement) = store.wrap_placement.get(*self) {
+            return Some(Units::Pixels(placement.w));
+        }
+
+        if let Some(width) = store.wrap_width.get(*self) {
+            return Some(Units::Pixels(*width));
+        }
+
         store.width.get(*self).cloned().map(|w| match w {
             Units::Pixels(val) => Units::Pixels(val * store.dpi_factor as f32),
             t => t,
@@ -159,6 +192,18 @@ impl<'w> Node<'w> for Entity {
     }
 
     fn height(&self, store: &Self::Data) -> Option<morphorm::Units> {
+        if let Some(height) = store.aspect_height.get(*self) {
+            return Some(Units::Pixels(*height));
+        }
+
+        if let Some(placement) = store.wrap_placement.get(*self) {
+            return Some(Units::Pixels(placement.h));
+        }
+
+        if let Some(height) = store.wrap_height.get(*self) {
+            return Some(Units::Pixels(*height));
+        }
+
         store.height.get(*self).cloned().map(|h| match h {
             Units::Pixels(val) => Units::Pixels(val * store.dpi_factor as f32),
             t => t,
@@ -207,12 +252,21 @@ impl<'w> Node<'w> for Entity {
         })
     }
 
+    // Grids use the tracks resolved by the grid system, which include any implicit tracks
     fn grid_cols(&self, store: &Self::Data) -> Option<Vec<morphorm::Units>> {
-        store.grid_cols.get(*self).cloned()
+        if let Some(cols) = store.grid_track_cols.get(*self) {
+            return Some(cols.clone());
+        }
+
+        store.grid_cols.get(*self).map(|cols| grid_track_units(cols, store.dpi_factor as f32))
     }
 
     fn grid_rows(&self, store: &Self::Data) -> Option<Vec<morphorm::Units>> {
-        store.grid_rows.get(*self).cloned()
+        if let Some(rows) = store.grid_track_rows.get(*self) {
+            return Some(rows.clone());
+        }
+
+        store.grid_rows.get(*self).map(|rows| grid_track_units(rows, store.dpi_factor as f32))
     }
 
     fn row_between(&self, store: &Self::Data) -> Option<morphorm::Units> {
@@ -257,19 +311,55 @@ impl<'w> Node<'w> for Entity {
         })
     }
 
+    // The children of grids are placed in the cells given to them by the grid system
     fn row_index(&self, store: &Self::Data) -> Option<usize> {
+        if let Some(placement) = store.grid_placement.get(*self) {
+            return Some(placement.row);
+        }
+
         store.row_index.get(*self).cloned()
     }
 
     fn row_span(&self, store: &Self::Data) -> Option<usize> {
+        if let Some(placement) = store.grid_placement.get(*self) {
+            return Some(placement.row_span);
+        }
+
         store.row_span.get(*self).cloned()
     }
 
     fn col_index(&self, store: &Self::Data) -> Option<usize> {
+        if let Some(placement) = store.grid_placement.get(*self) {
+            return Some(placement.col);
+        }
+
         store.col_index.get(*self).cloned()
     }
 
     fn col_span(&self, store: &Self::Data) -> Option<usize> {
+        if let Some(placement) = store.grid_placement.get(*self) {
+            return Some(placement.col_span);
+        }
+
         store.col_span.get(*self).cloned()
     }
 }
+
+// Converts grid tracks to the sizes used by layout, before the tracks with a minimum and maximum
+// have been resolved. Those tracks are given their maximum size.
+pub(crate) fn grid_track_units(tracks: &[GridTrack], scale: f32) -> Vec<Units> {
+    tracks
+        .iter()
+        .map(|track| {
+            let size = match *track {
+                GridTrack::Size(size) => size,
+                GridTrack::MinMax(_, max) => max,
+            };
+
+            match size {
+                Units::Pixels(val) => Units::Pixels(val * scale),
+                t => t,
+            }
+        })
+        .collect()
+}
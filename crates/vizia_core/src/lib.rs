@@ -36,6 +36,12 @@ pub mod image {
     pub use image::*;
 }
 
+/// This is a re-export of [chrono](https://docs.rs/chrono/latest/chrono/).
+#[cfg(feature = "chrono")]
+pub mod chrono {
+    pub use chrono::*;
+}
+
 /// Members which we recommend you wildcard-import.
 pub mod prelude {
     pub use super::animation::{AnimExt, Animation, AnimationBuilder};
@@ -47,6 +53,7 @@ pub mod prelude {
     pub use super::events::{Event, Propagation};
     pub use super::handle::Handle;
     pub use super::input::{Keymap, KeymapEntry, KeymapEvent};
+    pub use super::layout::{Anchor, AnchorAlign, AnchorSide};
     pub use super::localization::Localized;
     pub use super::modifiers::{
         AbilityModifiers, ActionModifiers, LayoutModifiers, StyleModifiers, TextModifiers,
@@ -62,8 +69,9 @@ pub mod prelude {
     pub use vizia_window::{CursorIcon, WindowDescription, WindowEvent, WindowSize};
 
     pub use super::style::{
-        Abilities, BorderCornerShape, Color, Display, GradientDirection, GradientStop,
-        LinearGradient, Opacity, Overflow, PseudoClass, Visibility,
+        Abilities, BackgroundRepeat, BackgroundSize, BorderCornerShape, BorderImageSlice, ClipPath,
+        Color, Display, GradientDirection, GradientStop, GridAreas, GridAutoFlow, GridTrack,
+        ImagePosition, LinearGradient, ObjectFit, Opacity, Overflow, PseudoClass, Visibility,
     };
 
     pub use cosmic_text::{FamilyOwned, Style as FontStyle, Weight};
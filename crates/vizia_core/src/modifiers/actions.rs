@@ -447,6 +447,34 @@ pub trait ActionModifiers<V> {
     fn on_geo_changed<F>(self, action: F) -> Self
     where
         F: 'static + Fn(&mut EventHandle<V>, GeometryChanged) + Send + Sync;
+
+    /// Adds a tooltip to the view, with content built by the given closure each time it is shown.
+    ///
+    /// The tooltip is shown once the mouse pointer has rested on the view, or the view has been
+    /// focused with the keyboard, for the tooltip delay, and is hidden when the pointer leaves the
+    /// view or on a press, scroll or escape.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).tooltip(|cx| {
+    ///     Label::new(cx, "An element");
+    /// });
+    /// ```
+    fn tooltip<F>(self, content: F) -> Self
+    where
+        F: 'static + Fn(&mut Context);
+
+    /// Adds a tooltip containing the given text to the view.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).tooltip_text("An element");
+    /// ```
+    fn tooltip_text<U: ToString>(self, text: impl Res<U>) -> Self;
 }
 
 // If the entity doesn't have an `ActionsModel` then add one to the entity
@@ -643,4 +671,32 @@ impl<'a, V: View> ActionModifiers<V> for Handle<'a, V> {
 
         self
     }
+
+    fn tooltip<F>(self, content: F) -> Self
+    where
+        F: 'static + Fn(&mut Context),
+    {
+        let entity = self.entity;
+        self.cx.with_current(entity, |cx| {
+            let tooltip = Tooltip::new(cx, content).entity();
+            if let Some(previous) = cx.tooltips.tooltips.insert(entity, tooltip) {
+                cx.remove(previous);
+            }
+        });
+
+        self
+    }
+
+    fn tooltip_text<U: ToString>(self, text: impl Res<U>) -> Self {
+        let entity = self.entity;
+        text.set_or_bind(self.cx, entity, |cx, entity, text| {
+            cx.style.tooltip.insert(entity, text.to_string()).expect("Failed to set tooltip");
+        });
+
+        // The text is read when the tooltip is shown
+        self.tooltip(move |cx| {
+            let text = cx.style.tooltip.get(entity).cloned().unwrap_or_default();
+            Label::new(cx, &text);
+        })
+    }
 }
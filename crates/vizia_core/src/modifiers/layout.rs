@@ -34,6 +34,33 @@ pub trait LayoutModifiers: internal::Modifiable {
         LayoutType
     );
 
+    modifier!(
+        /// Sets whether the children of a row or column wrap onto new lines.
+        ///
+        /// Children are placed along the row or column until the next one doesn't fit, and then start
+        /// a new line. Lines are separated by `row_between` in a row and `col_between` in a column,
+        /// and stretch spaces and sizes share the free space left in each line. A wrapping row with an
+        /// auto height is as tall as its lines, and a wrapping column with an auto width is as wide
+        /// as its lines. In CSS this is set with `layout-type: wrap-row` or `wrap-column`.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// HStack::new(cx, |cx| {
+        ///     for tag in ["audio", "synth", "reverb", "delay"] {
+        ///         Label::new(cx, tag);
+        ///     }
+        /// })
+        /// .layout_wrap(true)
+        /// .height(Auto)
+        /// .col_between(Pixels(4.0))
+        /// .row_between(Pixels(4.0));
+        /// ```
+        layout_wrap,
+        bool
+    );
+
     modifier!(
         /// Sets the position type of the view.
         ///
@@ -54,6 +81,58 @@ pub trait LayoutModifiers: internal::Modifiable {
         PositionType
     );
 
+    modifier!(
+        /// Sets whether the view sticks within the nearest scroll view.
+        ///
+        /// A sticky view is laid out as normal, and is then moved to stay within the distance from the
+        /// edges of the scroll view given by `sticky_top` and `sticky_left`, without leaving its parent.
+        /// Views after it in the tree are drawn over it unless it has a higher z-index.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// Label::new(cx, "Header").sticky(true).sticky_top(Pixels(0.0)).z_order(1);
+        /// ```
+        sticky,
+        bool
+    );
+
+    modifier!(
+        /// Sets the distance from the top of the nearest scroll view which a sticky view stays within.
+        ///
+        /// A percentage is relative to the height of the scroll view.
+        sticky_top,
+        Units
+    );
+
+    modifier!(
+        /// Sets the distance from the left of the nearest scroll view which a sticky view stays within.
+        ///
+        /// A percentage is relative to the width of the scroll view.
+        sticky_left,
+        Units
+    );
+
+    /// Places the view next to another view after layout.
+    ///
+    /// The view is moved, along with its descendants, to the side of the anchor view given by the
+    /// [`Anchor`], and is flipped and shifted to stay within the window.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// let label = Label::new(cx, "Anchor").entity();
+    /// Element::new(cx).anchor(Anchor::new(label, AnchorSide::Right).offset(8.0));
+    /// ```
+    fn anchor(mut self, anchor: Anchor) -> Self {
+        let entity = self.entity();
+        self.context().style.anchor.insert(entity, anchor).expect("Failed to set anchor");
+        self.context().style.redraw(entity);
+        self
+    }
+
     modifier!(
         /// Sets the space on the left side of the view.
         ///
@@ -144,8 +223,8 @@ pub trait LayoutModifiers: internal::Modifiable {
             cx.style.top.insert(entity, value);
             cx.style.bottom.insert(entity, value);
 
-            cx.need_relayout();
-            cx.need_redraw();
+            cx.style.relayout(entity);
+            cx.style.redraw(entity);
         });
 
         self
@@ -171,8 +250,8 @@ pub trait LayoutModifiers: internal::Modifiable {
             cx.style.width.insert(entity, value);
             cx.style.height.insert(entity, value);
 
-            cx.need_relayout();
-            cx.need_redraw();
+            cx.style.relayout(entity);
+            cx.style.redraw(entity);
         });
 
         self
@@ -222,8 +301,8 @@ pub trait LayoutModifiers: internal::Modifiable {
             cx.style.child_top.insert(entity, value);
             cx.style.child_bottom.insert(entity, value);
 
-            cx.need_relayout();
-            cx.need_redraw();
+            cx.style.relayout(entity);
+            cx.style.redraw(entity);
         });
 
         self
@@ -261,8 +340,8 @@ pub trait LayoutModifiers: internal::Modifiable {
             cx.style.min_width.insert(entity, value);
             cx.style.min_height.insert(entity, value);
 
-            cx.need_relayout();
-            cx.need_redraw();
+            cx.style.relayout(entity);
+            cx.style.redraw(entity);
         });
 
         self
@@ -288,13 +367,30 @@ pub trait LayoutModifiers: internal::Modifiable {
             cx.style.max_width.insert(entity, value);
             cx.style.max_height.insert(entity, value);
 
-            cx.need_relayout();
-            cx.need_redraw();
+            cx.style.relayout(entity);
+            cx.style.redraw(entity);
         });
 
         self
     }
 
+    modifier!(
+        /// Sets the ratio of the width of the view to its height.
+        ///
+        /// An auto width or height follows the other size. Otherwise the view shrinks to fit the
+        /// space it was given, such as a stretching grid cell, while keeping within its minimum and
+        /// maximum sizes.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// Element::new(cx).width(Stretch(1.0)).height(Auto).aspect_ratio(16.0 / 9.0);
+        /// ```
+        aspect_ratio,
+        f32
+    );
+
     modifier!(
         /// Sets the minimum left space of the view.
         min_left,
@@ -329,8 +425,8 @@ pub trait LayoutModifiers: internal::Modifiable {
             cx.style.min_top.insert(entity, value);
             cx.style.min_bottom.insert(entity, value);
 
-            cx.need_relayout();
-            cx.need_redraw();
+            cx.style.relayout(entity);
+            cx.style.redraw(entity);
         });
 
         self
@@ -370,29 +466,103 @@ pub trait LayoutModifiers: internal::Modifiable {
             cx.style.max_top.insert(entity, value);
             cx.style.max_bottom.insert(entity, value);
 
-            cx.need_relayout();
-            cx.need_redraw();
+            cx.style.relayout(entity);
+            cx.style.redraw(entity);
         });
 
         self
     }
 
     /// Sets the grid rows of the view.
-    fn grid_rows(mut self, rows: Vec<Units>) -> Self {
+    ///
+    /// Rows are given as sizes or as [`GridTrack`]s, which can also have a minimum and maximum size.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx)
+    ///     .layout_type(LayoutType::Grid)
+    ///     .grid_rows(vec![Pixels(30.0), Stretch(1.0)])
+    ///     .grid_cols(vec![
+    ///         GridTrack::Size(Pixels(100.0)),
+    ///         GridTrack::MinMax(Pixels(200.0), Stretch(1.0)),
+    ///     ]);
+    /// ```
+    fn grid_rows<T: Into<GridTrack>>(mut self, rows: Vec<T>) -> Self {
         let entity = self.entity();
+        let rows = rows.into_iter().map(|row| row.into()).collect();
         self.context().style.grid_rows.insert(entity, rows);
-        self.context().need_relayout();
+        self.context().style.relayout(entity);
         self
     }
 
     /// Sets the grid columns of the view.
-    fn grid_cols(mut self, cols: Vec<Units>) -> Self {
+    ///
+    /// Columns are given as sizes or as [`GridTrack`]s, which can also have a minimum and maximum
+    /// size.
+    fn grid_cols<T: Into<GridTrack>>(mut self, cols: Vec<T>) -> Self {
         let entity = self.entity();
+        let cols = cols.into_iter().map(|col| col.into()).collect();
         self.context().style.grid_cols.insert(entity, cols);
-        self.context().need_relayout();
+        self.context().style.relayout(entity);
+        self
+    }
+
+    /// Sets the named areas of a grid, which children can be placed into with `grid_area`.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// VStack::new(cx, |cx| {
+    ///     Label::new(cx, "Title").grid_area("title");
+    ///     Label::new(cx, "Name");
+    ///     Label::new(cx, "Ada").grid_area("field");
+    /// })
+    /// .layout_type(LayoutType::Grid)
+    /// .grid_areas(GridAreas::new(&["title title", "label field"]));
+    /// ```
+    fn grid_areas(mut self, areas: GridAreas) -> Self {
+        let entity = self.entity();
+        self.context().style.grid_areas.insert(entity, areas);
+        self.context().style.relayout(entity);
         self
     }
 
+    /// Places the view in the named area of its parent grid.
+    ///
+    /// An explicit row or column index takes precedence over the area.
+    fn grid_area(mut self, name: &str) -> Self {
+        let entity = self.entity();
+        self.context().style.grid_area.insert(entity, name.to_owned());
+        self.context().style.relayout(entity);
+        self
+    }
+
+    modifier!(
+        /// Sets the direction in which children of the grid without a row, column or area are
+        /// placed, in the first free cells.
+        grid_auto_flow,
+        GridAutoFlow
+    );
+
+    modifier!(
+        /// Sets the size of the rows added to the grid when its children don't fit in its rows.
+        ///
+        /// Implicit rows stretch by default.
+        grid_auto_rows,
+        Units
+    );
+
+    modifier!(
+        /// Sets the size of the columns added to the grid when its children don't fit in its columns.
+        ///
+        /// Implicit columns stretch by default.
+        grid_auto_cols,
+        Units
+    );
+
     modifier!(
         /// Sets the grid row index of the view.
         ///
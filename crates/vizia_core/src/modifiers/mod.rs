@@ -13,8 +13,8 @@ macro_rules! modifier {
             value.set_or_bind(self.context(), entity, |cx, entity, v| {
                 cx.style.$name.insert(entity, v.into());
 
-                cx.need_relayout();
-                cx.need_redraw();
+                cx.style.relayout(entity);
+                cx.style.redraw(entity);
             });
 
             self
@@ -24,7 +24,7 @@ pub trait StyleModifiers: internal::Modifiable {
         let id = id.into();
         let entity = self.entity();
         self.context().style.ids.insert(entity, id.clone()).expect("Could not insert id");
-        self.context().need_restyle();
+        self.context().style.restyle_branch(entity);
 
         self.context().entity_identifiers.insert(id, entity);
 
@@ -38,7 +38,7 @@ pub trait StyleModifiers: internal::Modifiable {
             class_list.insert(name.to_string());
         }
 
-        self.context().need_restyle();
+        self.context().style.restyle_branch(entity);
 
         self
     }
@@ -56,7 +56,7 @@ pub trait StyleModifiers: internal::Modifiable {
                 }
             }
 
-            cx.need_restyle();
+            cx.style.restyle_branch(entity);
         });
 
         self
@@ -77,7 +77,7 @@ pub trait StyleModifiers: internal::Modifiable {
                 cx.style.pseudo_classes.insert(entity, pseudoclass).unwrap();
             }
 
-            cx.need_restyle();
+            cx.style.restyle(entity);
         });
 
         self
@@ -116,6 +116,19 @@ pub trait StyleModifiers: internal::Modifiable {
         i32
     );
 
+    modifier!(
+        /// Sets the opacity of the view and its descendants, between 0.0 and 1.0.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// Element::new(cx).opacity(0.5);
+        /// ```
+        opacity,
+        Opacity
+    );
+
     modifier!(
         /// Sets the overflow behavior of the view.
         ///
@@ -124,6 +137,36 @@ pub trait StyleModifiers: internal::Modifiable {
         Overflow
     );
 
+    modifier!(
+        /// Sets the shape the view and its descendants are clipped to.
+        ///
+        /// Unlike `overflow`, which clips to the bounds of the view, the clip path can be a circle, an
+        /// ellipse or a rounded rectangle, and hovering outside of the shape has no effect.
+        clip_path,
+        ClipPath
+    );
+
+    modifier!(
+        /// Sets whether the view and its descendants are rendered into a cached layer.
+        ///
+        /// A cached layer is rendered once and then composited on later frames until something
+        /// within it changes. This speeds up drawing of complex views which rarely change, such as
+        /// rulers and grids. Translation, opacity and clipping of the view are applied when the layer
+        /// is composited, so don't cause it to be rendered again.
+        cache_layer,
+        bool
+    );
+
+    modifier!(
+        /// Sets whether the view is placed in the overlay layer.
+        ///
+        /// Views in the overlay layer, along with their descendants, are drawn and hit-tested above
+        /// all other views and are not clipped by their ancestors. They are still laid out, and
+        /// receive events and bindings, through their parent. Used for popups, menus and tooltips.
+        overlay,
+        bool
+    );
+
     // Background Properties
     modifier!(
         /// Sets the background color of the view.
@@ -138,6 +181,24 @@ pub trait StyleModifiers: internal::Modifiable {
         String
     );
 
+    modifier!(
+        /// Sets the size of the background image of the view.
+        background_size,
+        BackgroundSize
+    );
+
+    modifier!(
+        /// Sets the position of the background image within the view.
+        background_position,
+        ImagePosition
+    );
+
+    modifier!(
+        /// Sets whether the background image of the view is tiled horizontally and/or vertically.
+        background_repeat,
+        BackgroundRepeat
+    );
+
     // TODO: Docs for this.
     fn image<U: ToString>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
@@ -147,18 +208,30 @@ pub trait StyleModifiers: internal::Modifiable {
                 if prev_data != &val {
                     cx.style.image.insert(entity, val);
 
-                    cx.need_redraw();
+                    cx.style.redraw(entity);
                 }
             } else {
                 cx.style.image.insert(entity, val);
 
-                cx.need_redraw();
+                cx.style.redraw(entity);
             }
         });
 
         self
     }
 
+    modifier!(
+        /// Sets how the image of the view is resized to fit its content box.
+        object_fit,
+        ObjectFit
+    );
+
+    modifier!(
+        /// Sets the position of the image within the content box of the view.
+        object_position,
+        ImagePosition
+    );
+
     // Border Properties
     modifier!(
         /// Sets the border width of the view.
@@ -203,7 +276,7 @@ pub trait StyleModifiers: internal::Modifiable {
             cx.style.border_radius_bottom_left.insert(entity, value);
             cx.style.border_radius_bottom_right.insert(entity, value);
 
-            cx.need_redraw();
+            cx.style.redraw(entity);
         });
 
         self
@@ -240,12 +313,27 @@ pub trait StyleModifiers: internal::Modifiable {
             cx.style.border_shape_bottom_left.insert(entity, value);
             cx.style.border_shape_bottom_right.insert(entity, value);
 
-            cx.need_redraw();
+            cx.style.redraw(entity);
         });
 
         self
     }
 
+    // Border Image Properties
+    modifier!(
+        /// Sets the name of an image which is drawn as a nine-slice frame over the view.
+        ///
+        /// The image is divided into regions by the [`border_image_slice`](Self::border_image_slice) modifier.
+        border_image,
+        String
+    );
+
+    modifier!(
+        /// Sets the insets which divide the border image into corners, edges and middle.
+        border_image_slice,
+        BorderImageSlice
+    );
+
     // Outine Properties
     modifier!(
         /// Sets the outline width of the view.
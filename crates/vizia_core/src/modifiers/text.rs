@@ -11,8 +11,8 @@ pub trait TextModifiers: internal::Modifiable {
             let text_data = val.to_string();
             cx.text_context.set_text(entity, &text_data);
 
-            cx.need_relayout();
-            cx.need_redraw();
+            cx.style.relayout(entity);
+            cx.style.redraw(entity);
         });
 
         self
@@ -68,6 +68,12 @@ pub trait TextModifiers: internal::Modifiable {
         Color
     );
 
+    modifier!(
+        /// Sets the color used to highlight search matches within the text of the view.
+        match_color,
+        Color
+    );
+
     modifier!(
         /// Sets whether the text of the view should be allowed to wrap.
         text_wrap,
@@ -46,6 +46,40 @@ impl ImageOrId {
     }
 }
 
+/// The source of an SVG image, which is rasterized again whenever the scale factor changes.
+#[cfg(feature = "svg")]
+pub(crate) struct StoredSvg {
+    pub tree: usvg::Tree,
+    pub scale: f64,
+}
+
+/// Renders an SVG image at the given scale factor.
+///
+/// Returns `None` if the scaled size of the SVG is too large to allocate a pixmap for.
+#[cfg(feature = "svg")]
+pub(crate) fn rasterize_svg(tree: &usvg::Tree, scale: f64) -> Option<image::DynamicImage> {
+    let width = (tree.size.width() * scale).ceil().max(1.0) as u32;
+    let height = (tree.size.height() * scale).ceil().max(1.0) as u32;
+    let mut pixmap = tiny_skia::Pixmap::new(width, height)?;
+    resvg::render(
+        tree,
+        usvg::FitTo::Zoom(scale as f32),
+        tiny_skia::Transform::default(),
+        pixmap.as_mut(),
+    );
+
+    // tiny-skia produces premultiplied alpha.
+    let pixels = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect();
+    image::RgbaImage::from_raw(width, height, pixels).map(image::DynamicImage::ImageRgba8)
+}
+
 #[derive(Copy, Clone, PartialEq)]
 pub enum ImageRetentionPolicy {
     Forever,
@@ -61,6 +95,8 @@ pub struct ResourceManager {
     pub stylesheets: Vec<PathBuf>, // Stylesheets refer to a file path
     pub themes: Vec<String>,       // Themes are the string content stylesheets
     pub(crate) images: HashMap<String, StoredImage>,
+    #[cfg(feature = "svg")]
+    pub(crate) svgs: HashMap<String, StoredSvg>,
     pub translations: HashMap<LanguageIdentifier, FluentBundle<FluentResource>>,
     pub language: LanguageIdentifier,
 
@@ -77,6 +113,8 @@ impl ResourceManager {
             stylesheets: Vec::new(),
             themes: Vec::new(),
             images: HashMap::new(),
+            #[cfg(feature = "svg")]
+            svgs: HashMap::new(),
             translations: HashMap::from([(
                 LanguageIdentifier::default(),
                 FluentBundle::new(vec![LanguageIdentifier::default()]),
@@ -156,5 +194,8 @@ impl ResourceManager {
 
             ImageRetentionPolicy::Forever => true,
         });
+
+        #[cfg(feature = "svg")]
+        self.svgs.retain(|name, _| self.images.contains_key(name));
     }
 }
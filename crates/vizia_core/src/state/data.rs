@@ -61,6 +61,14 @@ impl_data_simple!(std::net::SocketAddr);
 impl_data_simple!(std::ops::RangeFull);
 impl_data_simple!(std::path::PathBuf);
 impl_data_simple!(LanguageIdentifier);
+#[cfg(feature = "chrono")]
+impl_data_simple!(chrono::NaiveDate);
+#[cfg(feature = "chrono")]
+impl_data_simple!(chrono::NaiveTime);
+#[cfg(feature = "chrono")]
+impl_data_simple!(chrono::NaiveDateTime);
+#[cfg(feature = "chrono")]
+impl_data_simple!(chrono::Weekday);
 
 impl_data_simple!(String);
 
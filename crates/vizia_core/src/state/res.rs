@@ -51,6 +51,13 @@ impl_res_simple!(CursorIcon);
 impl_res_simple!(Overflow);
 impl_res_simple!(Weight);
 impl_res_simple!(FontStyle);
+impl_res_simple!(ObjectFit);
+impl_res_simple!(ClipPath);
+impl_res_simple!(GridAutoFlow);
+impl_res_simple!(ImagePosition);
+impl_res_simple!(BackgroundSize);
+impl_res_simple!(BackgroundRepeat);
+impl_res_simple!(BorderImageSlice);
 
 impl<T, L> Res<T> for L
 where
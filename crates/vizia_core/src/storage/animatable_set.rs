@@ -366,13 +366,18 @@ where
         }
     }
 
-    pub fn tick(&mut self, time: instant::Instant) {
+    /// Advances the active animations to the given time, returning the entities whose animated
+    /// value may have changed.
+    pub fn tick(&mut self, time: instant::Instant) -> Vec<Entity> {
+        let mut animated = Vec::new();
         for state in self.active_animations.iter_mut() {
             // If the animation is already finished then return false
             if state.t0 == 1.0 {
                 continue;
             }
 
+            animated.extend(state.entities.iter().copied());
+
             let start = state.keyframes.first().unwrap();
             let end = state.keyframes.last().unwrap();
 
@@ -409,6 +414,8 @@ where
         }
 
         self.remove_innactive_animations();
+
+        animated
     }
 
     pub fn is_animating(&mut self, entity: Entity, animation: Animation) -> bool {
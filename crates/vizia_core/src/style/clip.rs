@@ -0,0 +1,228 @@
+use crate::cache::BoundingBox;
+use crate::style::fmt_units;
+use femtovg::Path;
+use morphorm::Units;
+use std::fmt::Formatter;
+
+/// A shape which a view and its descendants are clipped to, set with the `clip-path` property.
+///
+/// Shapes are positioned relative to the bounds of the view, and hovering the view or its
+/// descendants outside of the shape has no effect.
+///
+/// This type is part of the prelude.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ClipPath {
+    /// A circle with the given radius, centered on the view. A percentage is relative to the
+    /// smaller of the width and height of the view, so `50%` is the largest circle which fits.
+    Circle(Units),
+    /// An ellipse with the given horizontal and vertical radii, centered on the view. Percentages
+    /// are relative to the width and height of the view.
+    Ellipse(Units, Units),
+    /// The bounds of the view inset by the given distances, with corners rounded by the given
+    /// radius. Percentages are relative to the width or height of the view.
+    Inset { top: Units, right: Units, bottom: Units, left: Units, radius: Units },
+}
+
+impl ClipPath {
+    /// Returns a rectangle covering the whole view with corners rounded by the given radius.
+    pub fn rounded_rect(radius: Units) -> Self {
+        ClipPath::Inset {
+            top: Units::Pixels(0.0),
+            right: Units::Pixels(0.0),
+            bottom: Units::Pixels(0.0),
+            left: Units::Pixels(0.0),
+            radius,
+        }
+    }
+
+    /// Returns the shape clipping a view with the given bounds.
+    ///
+    /// Pixel values are multiplied by `scale` to convert them to physical pixels.
+    pub(crate) fn shape(&self, bounds: BoundingBox, scale: f32) -> ClipShape {
+        let resolve = |units: Units, length: f32| match units {
+            Units::Pixels(val) => val * scale,
+            Units::Percentage(val) => length * val / 100.0,
+            _ => 0.0,
+        };
+
+        let (x, y) = bounds.center();
+        match *self {
+            ClipPath::Circle(radius) => {
+                let radius = resolve(radius, bounds.w.min(bounds.h));
+                ClipShape::Ellipse { x, y, rx: radius, ry: radius }
+            }
+
+            ClipPath::Ellipse(rx, ry) => {
+                ClipShape::Ellipse { x, y, rx: resolve(rx, bounds.w), ry: resolve(ry, bounds.h) }
+            }
+
+            ClipPath::Inset { top, right, bottom, left, radius } => {
+                let left = resolve(left, bounds.w);
+                let top = resolve(top, bounds.h);
+                let right = resolve(right, bounds.w);
+                let bottom = resolve(bottom, bounds.h);
+                let rect = BoundingBox {
+                    x: bounds.x + left,
+                    y: bounds.y + top,
+                    w: (bounds.w - left - right).max(0.0),
+                    h: (bounds.h - top - bottom).max(0.0),
+                };
+
+                let radius = resolve(radius, rect.w.min(rect.h));
+                ClipShape::Rect { bounds: rect, radii: [radius; 4] }
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ClipPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClipPath::Circle(radius) => write!(f, "circle({})", fmt_units(radius)),
+            ClipPath::Ellipse(rx, ry) => write!(f, "ellipse({} {})", fmt_units(rx), fmt_units(ry)),
+            ClipPath::Inset { top, right, bottom, left, radius } => write!(
+                f,
+                "inset({} {} {} {} round {})",
+                fmt_units(top),
+                fmt_units(right),
+                fmt_units(bottom),
+                fmt_units(left),
+                fmt_units(radius)
+            ),
+        }
+    }
+}
+
+impl Default for ClipPath {
+    fn default() -> Self {
+        ClipPath::rounded_rect(Units::Pixels(0.0))
+    }
+}
+
+/// A resolved clip shape, in the untransformed physical coordinates of the view which clips.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum ClipShape {
+    /// A rectangle with the radii of its top-left, top-right, bottom-right and bottom-left corners.
+    Rect { bounds: BoundingBox, radii: [f32; 4] },
+    /// An ellipse with the given center and radii.
+    Ellipse { x: f32, y: f32, rx: f32, ry: f32 },
+}
+
+impl ClipShape {
+    /// Returns the path of the shape, for filling with the content it clips.
+    pub fn path(&self) -> Path {
+        let mut path = Path::new();
+        match *self {
+            ClipShape::Rect { bounds, radii } => {
+                let radii = self.clamped_radii(bounds, radii);
+                path.rounded_rect_varying(
+                    bounds.x, bounds.y, bounds.w, bounds.h, radii[0], radii[1], radii[2], radii[3],
+                );
+            }
+
+            ClipShape::Ellipse { x, y, rx, ry } => path.ellipse(x, y, rx, ry),
+        }
+
+        path
+    }
+
+    /// Returns true if the point is within the shape.
+    pub fn contains(&self, px: f32, py: f32) -> bool {
+        match *self {
+            ClipShape::Rect { bounds, radii } => {
+                if px < bounds.left()
+                    || px >= bounds.right()
+                    || py < bounds.top()
+                    || py >= bounds.bottom()
+                {
+                    return false;
+                }
+
+                // The signs point from the center of each corner's circle towards the corner
+                let radii = self.clamped_radii(bounds, radii);
+                let corners = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)];
+                corners.iter().zip(radii).all(|(&(sx, sy), radius)| {
+                    let corner_x = if sx < 0.0 { bounds.left() } else { bounds.right() };
+                    let corner_y = if sy < 0.0 { bounds.top() } else { bounds.bottom() };
+                    let cx = corner_x - sx * radius;
+                    let cy = corner_y - sy * radius;
+                    let dx = (px - cx) * sx;
+                    let dy = (py - cy) * sy;
+                    dx <= 0.0 || dy <= 0.0 || dx * dx + dy * dy <= radius * radius
+                })
+            }
+
+            ClipShape::Ellipse { x, y, rx, ry } => {
+                if rx <= 0.0 || ry <= 0.0 {
+                    return false;
+                }
+
+                let dx = (px - x) / rx;
+                let dy = (py - y) / ry;
+                dx * dx + dy * dy <= 1.0
+            }
+        }
+    }
+
+    // Limits the corner radii to half of the size of the rectangle, as they are when drawn.
+    fn clamped_radii(&self, bounds: BoundingBox, radii: [f32; 4]) -> [f32; 4] {
+        let max = (bounds.w.min(bounds.h) / 2.0).max(0.0);
+        radii.map(|radius| radius.max(0.0).min(max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: BoundingBox = BoundingBox { x: 10.0, y: 20.0, w: 100.0, h: 50.0 };
+
+    #[test]
+    fn resolves_shapes() {
+        assert_eq!(
+            ClipPath::Circle(Units::Percentage(50.0)).shape(BOUNDS, 1.0),
+            ClipShape::Ellipse { x: 60.0, y: 45.0, rx: 25.0, ry: 25.0 }
+        );
+        assert_eq!(
+            ClipPath::Ellipse(Units::Pixels(10.0), Units::Percentage(50.0)).shape(BOUNDS, 2.0),
+            ClipShape::Ellipse { x: 60.0, y: 45.0, rx: 20.0, ry: 25.0 }
+        );
+
+        let inset = ClipPath::Inset {
+            top: Units::Pixels(5.0),
+            right: Units::Percentage(10.0),
+            bottom: Units::Pixels(5.0),
+            left: Units::Pixels(0.0),
+            radius: Units::Pixels(4.0),
+        };
+        assert_eq!(
+            inset.shape(BOUNDS, 1.0),
+            ClipShape::Rect {
+                bounds: BoundingBox { x: 10.0, y: 25.0, w: 90.0, h: 40.0 },
+                radii: [4.0; 4]
+            }
+        );
+    }
+
+    #[test]
+    fn rounded_rect_excludes_corners() {
+        let shape = ClipPath::rounded_rect(Units::Pixels(10.0)).shape(BOUNDS, 1.0);
+        assert!(shape.contains(60.0, 45.0));
+        assert!(shape.contains(15.0, 45.0));
+        assert!(shape.contains(60.0, 21.0));
+        assert!(!shape.contains(11.0, 21.0));
+        assert!(!shape.contains(109.0, 69.0));
+        assert!(shape.contains(13.0, 23.0));
+        assert!(!shape.contains(5.0, 45.0));
+    }
+
+    #[test]
+    fn ellipse_contains() {
+        let shape =
+            ClipPath::Ellipse(Units::Percentage(50.0), Units::Percentage(50.0)).shape(BOUNDS, 1.0);
+        assert!(shape.contains(60.0, 45.0));
+        assert!(shape.contains(105.0, 45.0));
+        assert!(!shape.contains(12.0, 22.0));
+        assert!(!ClipPath::Circle(Units::Pixels(0.0)).shape(BOUNDS, 1.0).contains(60.0, 45.0));
+    }
+}
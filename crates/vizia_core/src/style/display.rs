@@ -112,6 +112,12 @@ impl Default for Opacity {
     }
 }
 
+impl From<f32> for Opacity {
+    fn from(opacity: f32) -> Self {
+        Opacity(opacity)
+    }
+}
+
 impl Interpolator for Opacity {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
         return Opacity(start.0 + (end.0 - start.0) * t);
@@ -0,0 +1,202 @@
+use crate::style::fmt_units;
+use morphorm::Units;
+use std::fmt::Formatter;
+
+/// The size of a row or column of a grid layout.
+///
+/// This type is part of the prelude.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GridTrack {
+    /// A track with the given size.
+    Size(Units),
+    /// A track which is at least the first size and at most the second. A stretch maximum shares the
+    /// free space of the grid with the other stretch tracks, but doesn't shrink below the minimum.
+    MinMax(Units, Units),
+}
+
+impl GridTrack {
+    /// Returns the given tracks repeated `count` times.
+    pub fn repeat(count: usize, tracks: &[GridTrack]) -> Vec<GridTrack> {
+        tracks.iter().copied().cycle().take(tracks.len() * count).collect()
+    }
+}
+
+impl From<Units> for GridTrack {
+    fn from(units: Units) -> Self {
+        GridTrack::Size(units)
+    }
+}
+
+impl std::fmt::Display for GridTrack {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridTrack::Size(size) => write!(f, "{}", fmt_units(size)),
+            GridTrack::MinMax(min, max) => {
+                write!(f, "minmax({}, {})", fmt_units(min), fmt_units(max))
+            }
+        }
+    }
+}
+
+/// Named areas of a grid layout, which children are placed into by name with `grid_area`.
+///
+/// Each row of the grid is a string of whitespace separated cell names, and the cells with the same
+/// name form an area. A `.` marks a cell which isn't part of an area. The cells of an area must
+/// form a rectangle, otherwise the area is ignored.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// let areas = GridAreas::new(&["header header", "sidebar content"]);
+/// ```
+///
+/// This type is part of the prelude.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GridAreas {
+    rows: Vec<Vec<Option<String>>>,
+}
+
+impl GridAreas {
+    /// Creates the areas of a grid from its rows of cell names.
+    pub fn new(rows: &[&str]) -> Self {
+        Self {
+            rows: rows
+                .iter()
+                .map(|row| {
+                    row.split_whitespace()
+                        .map(|name| if name == "." { None } else { Some(name.to_owned()) })
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the number of rows of the grid.
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the number of columns of the grid, which is the length of its longest row.
+    pub fn cols(&self) -> usize {
+        self.rows.iter().map(|row| row.len()).max().unwrap_or(0)
+    }
+
+    /// Returns the row, column, row span and column span covering the cells of the named area.
+    pub fn area(&self, name: &str) -> Option<(usize, usize, usize, usize)> {
+        let mut cells = self.rows.iter().enumerate().flat_map(|(row, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .filter(|(_, cell)| cell.as_deref() == Some(name))
+                .map(move |(col, _)| (row, col))
+        });
+
+        let (first_row, first_col) = cells.next()?;
+        let (min_row, min_col, max_row, max_col, count) = cells.fold(
+            (first_row, first_col, first_row, first_col, 1),
+            |(min_row, min_col, max_row, max_col, count), (row, col)| {
+                (min_row.min(row), min_col.min(col), max_row.max(row), max_col.max(col), count + 1)
+            },
+        );
+
+        let row_span = max_row - min_row + 1;
+        let col_span = max_col - min_col + 1;
+
+        // The area is only a rectangle if every cell within its bounds belongs to it.
+        if count != row_span * col_span {
+            return None;
+        }
+
+        Some((min_row, min_col, row_span, col_span))
+    }
+
+    /// Returns true if the cells of every named area form a rectangle.
+    pub fn is_valid(&self) -> bool {
+        self.rows.iter().flatten().flatten().all(|name| self.area(name).is_some())
+    }
+}
+
+impl std::fmt::Display for GridAreas {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| {
+                let cells =
+                    row.iter().map(|cell| cell.as_deref().unwrap_or(".")).collect::<Vec<_>>();
+                format!("\"{}\"", cells.join(" "))
+            })
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", rows.join(" "))
+    }
+}
+
+/// The direction children of a grid without a row and column are placed in.
+///
+/// This type is part of the prelude.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GridAutoFlow {
+    /// Fills each row before starting the next, adding rows as needed.
+    Row,
+    /// Fills each column before starting the next, adding columns as needed.
+    Column,
+}
+
+impl std::fmt::Display for GridAutoFlow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridAutoFlow::Row => write!(f, "row"),
+            GridAutoFlow::Column => write!(f, "column"),
+        }
+    }
+}
+
+impl Default for GridAutoFlow {
+    fn default() -> Self {
+        GridAutoFlow::Row
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn areas() {
+        let areas = GridAreas::new(&["header header header", "label . field", "label . field"]);
+        assert_eq!(areas.rows(), 3);
+        assert_eq!(areas.cols(), 3);
+        assert_eq!(areas.area("header"), Some((0, 0, 1, 3)));
+        assert_eq!(areas.area("label"), Some((1, 0, 2, 1)));
+        assert_eq!(areas.area("field"), Some((1, 2, 2, 1)));
+        assert_eq!(areas.area("footer"), None);
+        assert!(areas.is_valid());
+        assert_eq!(
+            areas.to_string(),
+            "\"header header header\" \"label . field\" \"label . field\""
+        );
+    }
+
+    #[test]
+    fn non_rectangular_areas() {
+        let areas = GridAreas::new(&["a a", "a b"]);
+        assert_eq!(areas.area("a"), None);
+        assert_eq!(areas.area("b"), Some((1, 1, 1, 1)));
+        assert!(!areas.is_valid());
+
+        let areas = GridAreas::new(&["a b a"]);
+        assert_eq!(areas.area("a"), None);
+    }
+
+    #[test]
+    fn repeat() {
+        let tracks = GridTrack::repeat(
+            2,
+            &[GridTrack::Size(Units::Pixels(10.0)), GridTrack::Size(Units::Stretch(1.0))],
+        );
+        assert_eq!(tracks.len(), 4);
+        assert_eq!(tracks[2], GridTrack::Size(Units::Pixels(10.0)));
+        assert_eq!(tracks[3], GridTrack::Size(Units::Stretch(1.0)));
+    }
+}
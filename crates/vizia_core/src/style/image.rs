@@ -0,0 +1,408 @@
+use crate::cache::BoundingBox;
+use crate::style::fmt_units;
+use morphorm::Units;
+use std::fmt::Formatter;
+
+/// Determines how the image of an [`Image`](crate::views::Image) view is resized to fit its content box.
+///
+/// This type is part of the prelude.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ObjectFit {
+    /// The image is stretched to fill the content box, ignoring its aspect ratio.
+    Fill,
+    /// The image is scaled to fit inside the content box, keeping its aspect ratio.
+    Contain,
+    /// The image is scaled to cover the whole content box, keeping its aspect ratio.
+    Cover,
+    /// The image is drawn at its natural size.
+    None,
+    /// The image is drawn as `None` or `Contain`, whichever is smaller.
+    ScaleDown,
+}
+
+impl ObjectFit {
+    /// Returns the size of an image with the given natural size when fitted into `container`.
+    pub(crate) fn size(&self, image: (f32, f32), container: (f32, f32)) -> (f32, f32) {
+        let (image_width, image_height) = image;
+        if image_width <= 0.0 || image_height <= 0.0 {
+            return container;
+        }
+
+        let contain = (container.0 / image_width).min(container.1 / image_height);
+        let cover = (container.0 / image_width).max(container.1 / image_height);
+
+        let scale = match self {
+            ObjectFit::Fill => return container,
+            ObjectFit::Contain => contain,
+            ObjectFit::Cover => cover,
+            ObjectFit::None => 1.0,
+            ObjectFit::ScaleDown => contain.min(1.0),
+        };
+
+        (image_width * scale, image_height * scale)
+    }
+}
+
+impl std::fmt::Display for ObjectFit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ObjectFit::Fill => "fill",
+                ObjectFit::Contain => "contain",
+                ObjectFit::Cover => "cover",
+                ObjectFit::None => "none",
+                ObjectFit::ScaleDown => "scale-down",
+            }
+        )
+    }
+}
+
+impl Default for ObjectFit {
+    fn default() -> Self {
+        ObjectFit::Fill
+    }
+}
+
+/// The position of an image within a box, used by `object-position` and `background-position`.
+///
+/// A percentage aligns the same point of the image and the box, so `50%` centers the image, while
+/// pixels offset the image from the top-left corner of the box.
+///
+/// This type is part of the prelude.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ImagePosition {
+    pub x: Units,
+    pub y: Units,
+}
+
+impl ImagePosition {
+    /// Aligns the top-left corner of the image with the top-left corner of the box.
+    pub const TOP_LEFT: Self =
+        ImagePosition { x: Units::Percentage(0.0), y: Units::Percentage(0.0) };
+
+    /// Centers the image within the box.
+    pub const CENTER: Self =
+        ImagePosition { x: Units::Percentage(50.0), y: Units::Percentage(50.0) };
+
+    pub fn new(x: Units, y: Units) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the offset of an image of the given size from the top-left corner of `container`.
+    ///
+    /// Pixel values are multiplied by `scale` to convert them to physical pixels.
+    pub(crate) fn offset(&self, container: (f32, f32), size: (f32, f32), scale: f32) -> (f32, f32) {
+        let resolve = |units: Units, free_space: f32| match units {
+            Units::Pixels(val) => val * scale,
+            Units::Percentage(val) => free_space * val / 100.0,
+            _ => 0.0,
+        };
+
+        (resolve(self.x, container.0 - size.0), resolve(self.y, container.1 - size.1))
+    }
+}
+
+impl std::fmt::Display for ImagePosition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", fmt_units(&self.x), fmt_units(&self.y))
+    }
+}
+
+impl Default for ImagePosition {
+    fn default() -> Self {
+        ImagePosition::TOP_LEFT
+    }
+}
+
+/// Determines the size of a background image.
+///
+/// This type is part of the prelude.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BackgroundSize {
+    /// The image is drawn at its natural size.
+    Auto,
+    /// The image is scaled to cover the whole view, keeping its aspect ratio.
+    Cover,
+    /// The image is scaled to fit inside the view, keeping its aspect ratio.
+    Contain,
+    /// The image is drawn with the given width and height. Percentages are relative to the size
+    /// of the view, and an `Auto` dimension keeps the aspect ratio of the image.
+    Size(Units, Units),
+}
+
+impl BackgroundSize {
+    /// Returns the size of a background image with the given natural size for a view of size `container`.
+    ///
+    /// Pixel values are multiplied by `scale` to convert them to physical pixels.
+    pub(crate) fn size(&self, image: (f32, f32), container: (f32, f32), scale: f32) -> (f32, f32) {
+        let (width, height) = match self {
+            BackgroundSize::Auto => return image,
+            BackgroundSize::Cover => return ObjectFit::Cover.size(image, container),
+            BackgroundSize::Contain => return ObjectFit::Contain.size(image, container),
+            BackgroundSize::Size(width, height) => (*width, *height),
+        };
+
+        let resolve = |units: Units, length: f32| match units {
+            Units::Pixels(val) => Some(val * scale),
+            Units::Percentage(val) => Some(length * val / 100.0),
+            _ => None,
+        };
+
+        match (resolve(width, container.0), resolve(height, container.1)) {
+            (Some(width), Some(height)) => (width, height),
+            (Some(width), None) if image.0 > 0.0 => (width, width * image.1 / image.0),
+            (None, Some(height)) if image.1 > 0.0 => (height * image.0 / image.1, height),
+            _ => image,
+        }
+    }
+}
+
+impl std::fmt::Display for BackgroundSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackgroundSize::Auto => write!(f, "auto"),
+            BackgroundSize::Cover => write!(f, "cover"),
+            BackgroundSize::Contain => write!(f, "contain"),
+            BackgroundSize::Size(width, height) => {
+                write!(f, "{} {}", fmt_units(width), fmt_units(height))
+            }
+        }
+    }
+}
+
+impl Default for BackgroundSize {
+    fn default() -> Self {
+        BackgroundSize::Auto
+    }
+}
+
+/// Determines whether a background image is tiled to fill the view.
+///
+/// This type is part of the prelude.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BackgroundRepeat {
+    Repeat,
+    RepeatX,
+    RepeatY,
+    NoRepeat,
+}
+
+impl BackgroundRepeat {
+    /// Returns the area of `bounds` covered by the image when one tile is placed at `tile`, or
+    /// `None` if the image covers the whole of `bounds`.
+    pub(crate) fn area(&self, bounds: BoundingBox, tile: BoundingBox) -> Option<BoundingBox> {
+        let (repeat_x, repeat_y) = match self {
+            BackgroundRepeat::Repeat => return None,
+            BackgroundRepeat::RepeatX => (true, false),
+            BackgroundRepeat::RepeatY => (false, true),
+            BackgroundRepeat::NoRepeat => (false, false),
+        };
+
+        let (x, w) = if repeat_x { (bounds.x, bounds.w) } else { (tile.x, tile.w) };
+        let (y, h) = if repeat_y { (bounds.y, bounds.h) } else { (tile.y, tile.h) };
+
+        Some(BoundingBox { x, y, w, h }.intersection(&bounds))
+    }
+}
+
+impl std::fmt::Display for BackgroundRepeat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                BackgroundRepeat::Repeat => "repeat",
+                BackgroundRepeat::RepeatX => "repeat-x",
+                BackgroundRepeat::RepeatY => "repeat-y",
+                BackgroundRepeat::NoRepeat => "no-repeat",
+            }
+        )
+    }
+}
+
+impl Default for BackgroundRepeat {
+    fn default() -> Self {
+        BackgroundRepeat::Repeat
+    }
+}
+
+/// The insets, in image pixels, which divide a border image into nine regions.
+///
+/// The corners are drawn unscaled, the edges are stretched along the sides of the view, and the
+/// middle region is stretched to fill the view if `fill` is set.
+///
+/// This type is part of the prelude.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct BorderImageSlice {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+    pub fill: bool,
+}
+
+impl BorderImageSlice {
+    pub fn new(top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        Self { top, right, bottom, left, fill: false }
+    }
+
+    /// Sets whether the middle region of the image is drawn.
+    pub fn fill(mut self, fill: bool) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Returns pairs of source rectangles within an image of the given size and the destination
+    /// rectangles within `bounds` they should be drawn to.
+    ///
+    /// If `bounds` is too small for the corners they are scaled down uniformly, like CSS does.
+    pub(crate) fn regions(
+        &self,
+        image: (f32, f32),
+        bounds: BoundingBox,
+    ) -> Vec<(BoundingBox, BoundingBox)> {
+        let (image_width, image_height) = image;
+
+        let left = self.left.clamp(0.0, image_width);
+        let right = self.right.clamp(0.0, image_width - left);
+        let top = self.top.clamp(0.0, image_height);
+        let bottom = self.bottom.clamp(0.0, image_height - top);
+
+        let mut scale: f32 = 1.0;
+        if left + right > bounds.w {
+            scale = scale.min(bounds.w / (left + right));
+        }
+        if top + bottom > bounds.h {
+            scale = scale.min(bounds.h / (top + bottom));
+        }
+
+        let src_x = [0.0, left, image_width - right, image_width];
+        let src_y = [0.0, top, image_height - bottom, image_height];
+        let dst_x = [bounds.left(), bounds.left() + left * scale, bounds.right() - right * scale];
+        let dst_x = [dst_x[0], dst_x[1], dst_x[2], bounds.right()];
+        let dst_y = [bounds.top(), bounds.top() + top * scale, bounds.bottom() - bottom * scale];
+        let dst_y = [dst_y[0], dst_y[1], dst_y[2], bounds.bottom()];
+
+        let mut regions = Vec::with_capacity(9);
+        for row in 0..3 {
+            for col in 0..3 {
+                if row == 1 && col == 1 && !self.fill {
+                    continue;
+                }
+
+                let src = BoundingBox::from_min_max(
+                    src_x[col],
+                    src_y[row],
+                    src_x[col + 1],
+                    src_y[row + 1],
+                );
+                let dst = BoundingBox::from_min_max(
+                    dst_x[col],
+                    dst_y[row],
+                    dst_x[col + 1],
+                    dst_y[row + 1],
+                );
+
+                if src.w > 0.0 && src.h > 0.0 && dst.w > 0.0 && dst.h > 0.0 {
+                    regions.push((src, dst));
+                }
+            }
+        }
+
+        regions
+    }
+}
+
+impl std::fmt::Display for BorderImageSlice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {} {}", self.top, self.right, self.bottom, self.left)?;
+        if self.fill {
+            write!(f, " fill")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: f32, y: f32, w: f32, h: f32) -> BoundingBox {
+        BoundingBox { x, y, w, h }
+    }
+
+    #[test]
+    fn object_fit() {
+        let image = (200.0, 100.0);
+        let container = (100.0, 100.0);
+        assert_eq!(ObjectFit::Fill.size(image, container), (100.0, 100.0));
+        assert_eq!(ObjectFit::Contain.size(image, container), (100.0, 50.0));
+        assert_eq!(ObjectFit::Cover.size(image, container), (200.0, 100.0));
+        assert_eq!(ObjectFit::None.size(image, container), (200.0, 100.0));
+        assert_eq!(ObjectFit::ScaleDown.size(image, container), (100.0, 50.0));
+        assert_eq!(ObjectFit::ScaleDown.size((50.0, 20.0), container), (50.0, 20.0));
+    }
+
+    #[test]
+    fn image_position() {
+        let container = (100.0, 100.0);
+        assert_eq!(ImagePosition::CENTER.offset(container, (50.0, 20.0), 1.0), (25.0, 40.0));
+        assert_eq!(ImagePosition::TOP_LEFT.offset(container, (50.0, 20.0), 1.0), (0.0, 0.0));
+
+        let position = ImagePosition::new(Units::Pixels(10.0), Units::Percentage(100.0));
+        assert_eq!(position.offset(container, (50.0, 20.0), 2.0), (20.0, 80.0));
+    }
+
+    #[test]
+    fn background_size() {
+        let image = (200.0, 100.0);
+        let container = (100.0, 100.0);
+        assert_eq!(BackgroundSize::Auto.size(image, container, 1.0), (200.0, 100.0));
+        assert_eq!(BackgroundSize::Contain.size(image, container, 1.0), (100.0, 50.0));
+
+        let size = BackgroundSize::Size(Units::Percentage(50.0), Units::Auto);
+        assert_eq!(size.size(image, container, 1.0), (50.0, 25.0));
+
+        let size = BackgroundSize::Size(Units::Pixels(10.0), Units::Pixels(20.0));
+        assert_eq!(size.size(image, container, 2.0), (20.0, 40.0));
+    }
+
+    #[test]
+    fn background_repeat() {
+        let bounds = rect(0.0, 0.0, 100.0, 100.0);
+        let tile = rect(10.0, 10.0, 20.0, 20.0);
+        assert_eq!(BackgroundRepeat::Repeat.area(bounds, tile), None);
+        assert_eq!(BackgroundRepeat::NoRepeat.area(bounds, tile), Some(tile));
+        assert_eq!(
+            BackgroundRepeat::RepeatX.area(bounds, tile),
+            Some(rect(0.0, 10.0, 100.0, 20.0))
+        );
+        assert_eq!(
+            BackgroundRepeat::RepeatY.area(bounds, tile),
+            Some(rect(10.0, 0.0, 20.0, 100.0))
+        );
+    }
+
+    #[test]
+    fn nine_slice() {
+        let slice = BorderImageSlice::new(10.0, 10.0, 10.0, 10.0);
+        let regions = slice.regions((30.0, 30.0), rect(0.0, 0.0, 100.0, 50.0));
+        assert_eq!(regions.len(), 8);
+        // Corners keep their size while edges stretch
+        assert_eq!(regions[0], (rect(0.0, 0.0, 10.0, 10.0), rect(0.0, 0.0, 10.0, 10.0)));
+        assert_eq!(regions[1], (rect(10.0, 0.0, 10.0, 10.0), rect(10.0, 0.0, 80.0, 10.0)));
+        assert_eq!(regions[7], (rect(20.0, 20.0, 10.0, 10.0), rect(90.0, 40.0, 10.0, 10.0)));
+
+        assert_eq!(slice.fill(true).regions((30.0, 30.0), rect(0.0, 0.0, 100.0, 50.0)).len(), 9);
+    }
+
+    #[test]
+    fn nine_slice_scales_down_corners() {
+        let slice = BorderImageSlice::new(10.0, 10.0, 10.0, 10.0);
+        let regions = slice.regions((30.0, 30.0), rect(0.0, 0.0, 10.0, 100.0));
+        assert_eq!(regions[0], (rect(0.0, 0.0, 10.0, 10.0), rect(0.0, 0.0, 5.0, 5.0)));
+    }
+}
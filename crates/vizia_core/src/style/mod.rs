@@ -4,6 +4,8 @@ use vizia_id::GenerationalId;
 
 use cssparser::{Parser, ParserInput};
 
+use crate::cache::BoundingBox;
+use crate::layout::grid::GridPlacement;
 use crate::prelude::*;
 
 mod color;
@@ -42,6 +44,15 @@ pub use gradient::*;
 mod shadow;
 use shadow::*;
 
+mod image;
+pub use image::*;
+
+mod clip;
+pub use clip::*;
+
+mod grid;
+pub use grid::*;
+
 // mod prop;
 // pub use prop::*;
 
@@ -65,6 +76,9 @@ bitflags! {
         /// The element should be focusable in sequential keyboard navigation -
         /// allowing the equivilant of a negative tabindex in html.
         const NAVIGABLE = 1 << 4;
+        /// The element handles the tab key itself, so pressing tab while it is focused does not
+        /// move keyboard focus.
+        const TAB_INPUT = 1 << 5;
     }
 }
 
@@ -83,6 +97,10 @@ pub struct Style {
     pub(crate) animation_manager: IdManager<Animation>,
 
     pub(crate) rules: Vec<StyleRule>,
+    /// The selectors of the rules which are matched against the ancestors of an entity and depend
+    /// on pseudo-classes, such as `.menu:hover` in `.menu:hover .item`. Built when stylesheets are
+    /// parsed, so that a change of pseudo-classes can be checked without scanning every rule.
+    pub(crate) pseudo_class_dependencies: Vec<Selector>,
 
     pub transitions: HashMap<Rule, Animation>,
 
@@ -116,6 +134,16 @@ pub struct Style {
     pub scale: AnimatableSet<(f32, f32)>,
 
     pub overflow: StyleSet<Overflow>, // TODO
+
+    /// The shape a view and its descendants are clipped to.
+    pub clip_path: StyleSet<ClipPath>,
+
+    /// Whether a view and its descendants are rendered into a cached layer.
+    pub cache_layer: StyleSet<bool>,
+
+    /// Whether a view is placed in the overlay layer, above all other views and outside the clip
+    /// region of its parent.
+    pub overlay: StyleSet<bool>,
     //pub scroll: DenseStorage<Scroll>,     // TODO
 
     // Border
@@ -146,6 +174,13 @@ pub struct Style {
     pub background_color: AnimatableSet<Color>,
     pub background_image: StyleSet<String>,
     pub background_gradient: StyleSet<LinearGradient>,
+    pub background_size: StyleSet<BackgroundSize>,
+    pub background_position: StyleSet<ImagePosition>,
+    pub background_repeat: StyleSet<BackgroundRepeat>,
+
+    // Border Image
+    pub border_image: StyleSet<String>,
+    pub border_image_slice: StyleSet<BorderImageSlice>,
 
     // Outer Shadow
     pub outer_shadow_h_offset: AnimatableSet<Units>,
@@ -168,9 +203,12 @@ pub struct Style {
     pub font_style: StyleSet<FontStyle>,
     pub caret_color: AnimatableSet<Color>,
     pub selection_color: AnimatableSet<Color>,
+    pub match_color: AnimatableSet<Color>,
 
     // Image
     pub image: StyleSet<String>,
+    pub object_fit: StyleSet<ObjectFit>,
+    pub object_position: StyleSet<ImagePosition>,
 
     pub tooltip: SparseSet<String>,
 
@@ -179,9 +217,25 @@ pub struct Style {
     // Layout Type
     pub layout_type: StyleSet<LayoutType>,
 
+    // Whether the children of a row or column wrap onto new lines
+    pub layout_wrap: StyleSet<bool>,
+    // The placements of the children of wrapping layouts, relative to their parents
+    pub(crate) wrap_placement: SparseSet<BoundingBox>,
+    // The sizes of auto-sized wrapping layouts, which depend on how their children wrap
+    pub(crate) wrap_width: SparseSet<f32>,
+    pub(crate) wrap_height: SparseSet<f32>,
+
     // Position Type
     pub position_type: StyleSet<PositionType>,
 
+    // Placement next to another view after layout
+    pub anchor: SparseSet<Anchor>,
+
+    // Sticky positioning within the nearest scroll view
+    pub sticky: StyleSet<bool>,
+    pub sticky_top: StyleSet<Units>,
+    pub sticky_left: StyleSet<Units>,
+
     // Spacing
     pub left: AnimatableSet<Units>,
     pub right: AnimatableSet<Units>,
@@ -200,6 +254,12 @@ pub struct Style {
     pub content_width: StyleSet<f32>,
     pub content_height: StyleSet<f32>,
 
+    // The ratio of width to height, and the sizes of views with an aspect ratio once the space they
+    // stretch into is known
+    pub aspect_ratio: StyleSet<f32>,
+    pub(crate) aspect_width: SparseSet<f32>,
+    pub(crate) aspect_height: SparseSet<f32>,
+
     // Spacing Constraints
     pub min_left: AnimatableSet<Units>,
     pub max_left: AnimatableSet<Units>,
@@ -211,15 +271,25 @@ pub struct Style {
     pub max_bottom: AnimatableSet<Units>,
 
     // Grid
-    pub grid_rows: StyleSet<Vec<Units>>,
+    pub grid_rows: StyleSet<Vec<GridTrack>>,
     pub row_between: AnimatableSet<Units>,
-    pub grid_cols: StyleSet<Vec<Units>>,
+    pub grid_cols: StyleSet<Vec<GridTrack>>,
     pub col_between: AnimatableSet<Units>,
+    pub grid_areas: StyleSet<GridAreas>,
+    pub grid_auto_flow: StyleSet<GridAutoFlow>,
+    pub grid_auto_rows: StyleSet<Units>,
+    pub grid_auto_cols: StyleSet<Units>,
 
     pub row_index: StyleSet<usize>,
     pub col_index: StyleSet<usize>,
     pub row_span: StyleSet<usize>,
     pub col_span: StyleSet<usize>,
+    pub grid_area: StyleSet<String>,
+
+    // The cells of the children of grids, and the tracks of grids including any implicit tracks
+    pub(crate) grid_placement: SparseSet<GridPlacement>,
+    pub(crate) grid_track_rows: SparseSet<Vec<Units>>,
+    pub(crate) grid_track_cols: SparseSet<Vec<Units>>,
 
     // Child Spacing
     pub child_left: AnimatableSet<Units>,
@@ -234,6 +304,17 @@ pub struct Style {
     pub needs_restyle: bool,
     pub needs_relayout: bool,
     pub needs_redraw: bool,
+    /// Entities whose appearance has changed since the last frame. Only the area covered by these
+    /// entities, and by entities which moved or were removed, is redrawn.
+    pub(crate) redraw_list: HashSet<Entity>,
+    /// Entities whose size or position may have changed since the last layout. Unless
+    /// `needs_relayout` is set, only the parts of the tree affected by these entities are laid out.
+    pub(crate) relayout_list: HashSet<Entity>,
+    /// Entities whose classes, pseudo-classes or position in the tree have changed since the last
+    /// restyle, and whether the change also requires their descendants to be restyled. Unless
+    /// `needs_restyle` is set, only these entities, and any descendants matched by selectors which
+    /// depend on them, have their style rules matched again.
+    pub(crate) restyle_list: HashMap<Entity, bool>,
 
     /// This includes both the system's HiDPI scaling factor as well as `cx.user_scale_factor`.
     pub dpi_factor: f64,
@@ -294,6 +375,17 @@ impl Style {
         self.rules.sort_by_key(|rule| rule.specificity());
         self.rules.reverse();
 
+        self.pseudo_class_dependencies = self
+            .rules
+            .iter()
+            .flat_map(|rule| rule.selectors.iter())
+            .filter(|selector| {
+                !matches!(selector.relation, SelectorRelation::None)
+                    && !selector.pseudo_classes.is_empty()
+            })
+            .cloned()
+            .collect();
+
         // for rule in self.rules.iter() {
         //     print!("{}", rule);
         // }
@@ -324,6 +416,10 @@ impl Style {
                         self.overflow.insert_rule(rule_id, value);
                     }
 
+                    Property::ClipPath(value) => {
+                        self.clip_path.insert_rule(rule_id, value);
+                    }
+
                     // Property::BackgroundGradient(value) => {
                     //     self.background_gradient.insert_rule(rule_id, value);
                     // }
@@ -331,6 +427,18 @@ impl Style {
                         self.position_type.insert_rule(rule_id, value);
                     }
 
+                    Property::Sticky(value) => {
+                        self.sticky.insert_rule(rule_id, value);
+                    }
+
+                    Property::StickyTop(value) => {
+                        self.sticky_top.insert_rule(rule_id, value);
+                    }
+
+                    Property::StickyLeft(value) => {
+                        self.sticky_left.insert_rule(rule_id, value);
+                    }
+
                     Property::Space(value) => {
                         self.left.insert_rule(rule_id, value);
                         self.right.insert_rule(rule_id, value);
@@ -409,6 +517,10 @@ impl Style {
                         self.max_height.insert_rule(rule_id, value);
                     }
 
+                    Property::AspectRatio(value) => {
+                        self.aspect_ratio.insert_rule(rule_id, value);
+                    }
+
                     Property::MinHeight(value) => {
                         self.min_height.insert_rule(rule_id, value);
                     }
@@ -510,6 +622,10 @@ impl Style {
                         self.selection_color.insert_rule(rule_id, value);
                     }
 
+                    Property::MatchColor(value) => {
+                        self.match_color.insert_rule(rule_id, value);
+                    }
+
                     Property::CaretColor(value) => {
                         self.caret_color.insert_rule(rule_id, value);
                     }
@@ -523,9 +639,45 @@ impl Style {
                         self.background_image.insert_rule(rule_id, value);
                     }
 
+                    Property::BackgroundSize(value) => {
+                        self.background_size.insert_rule(rule_id, value);
+                    }
+
+                    Property::BackgroundPosition(value) => {
+                        self.background_position.insert_rule(rule_id, value);
+                    }
+
+                    Property::BackgroundRepeat(value) => {
+                        self.background_repeat.insert_rule(rule_id, value);
+                    }
+
+                    // Border Image
+                    Property::BorderImage(value) => {
+                        self.border_image.insert_rule(rule_id, value);
+                    }
+
+                    Property::BorderImageSlice(value) => {
+                        self.border_image_slice.insert_rule(rule_id, value);
+                    }
+
+                    // Image
+                    Property::ObjectFit(value) => {
+                        self.object_fit.insert_rule(rule_id, value);
+                    }
+
+                    Property::ObjectPosition(value) => {
+                        self.object_position.insert_rule(rule_id, value);
+                    }
+
                     // Layout
                     Property::LayoutType(value) => {
                         self.layout_type.insert_rule(rule_id, value);
+                        self.layout_wrap.insert_rule(rule_id, false);
+                    }
+
+                    Property::LayoutWrap(value) => {
+                        self.layout_type.insert_rule(rule_id, value);
+                        self.layout_wrap.insert_rule(rule_id, true);
                     }
 
                     Property::ZIndex(value) => {
@@ -586,6 +738,34 @@ impl Style {
                         self.col_between.insert_rule(rule_id, value);
                     }
 
+                    Property::GridRows(value) => {
+                        self.grid_rows.insert_rule(rule_id, value);
+                    }
+
+                    Property::GridCols(value) => {
+                        self.grid_cols.insert_rule(rule_id, value);
+                    }
+
+                    Property::GridTemplateAreas(value) => {
+                        self.grid_areas.insert_rule(rule_id, value);
+                    }
+
+                    Property::GridAutoFlow(value) => {
+                        self.grid_auto_flow.insert_rule(rule_id, value);
+                    }
+
+                    Property::GridAutoRows(value) => {
+                        self.grid_auto_rows.insert_rule(rule_id, value);
+                    }
+
+                    Property::GridAutoCols(value) => {
+                        self.grid_auto_cols.insert_rule(rule_id, value);
+                    }
+
+                    Property::GridArea(value) => {
+                        self.grid_area.insert_rule(rule_id, value);
+                    }
+
                     Property::Cursor(cursor) => {
                         self.cursor.insert_rule(rule_id, cursor);
                     }
@@ -954,12 +1134,42 @@ impl Style {
         self.abilities.insert(entity, Abilities::default()).expect("Failed to add abilities");
         self.visibility.insert(entity, Default::default());
         self.focus_order.insert(entity, Default::default()).unwrap();
-        self.needs_restyle = true;
-        self.needs_relayout = true;
+        self.restyle(entity);
+        self.relayout(entity);
+        self.redraw(entity);
+    }
+
+    /// Marks an entity as needing its style rules matched again, such as when its pseudo-classes
+    /// change. Its descendants are only restyled if a selector with a pseudo-class could match it.
+    pub(crate) fn restyle(&mut self, entity: Entity) {
+        if entity != Entity::null() {
+            self.restyle_list.entry(entity).or_insert(false);
+        }
+    }
+
+    /// Marks an entity and all of its descendants as needing their style rules matched again, such
+    /// as when the classes or id of the entity change.
+    pub(crate) fn restyle_branch(&mut self, entity: Entity) {
+        if entity != Entity::null() {
+            self.restyle_list.insert(entity, true);
+        }
+    }
+
+    /// Marks an entity as needing to be laid out in the next frame.
+    pub(crate) fn relayout(&mut self, entity: Entity) {
+        self.relayout_list.insert(entity);
+    }
+
+    /// Marks an entity as needing to be redrawn in the next frame.
+    pub(crate) fn redraw(&mut self, entity: Entity) {
+        self.redraw_list.insert(entity);
         self.needs_redraw = true;
     }
 
     pub fn remove(&mut self, entity: Entity) {
+        self.redraw_list.remove(&entity);
+        self.relayout_list.remove(&entity);
+        self.restyle_list.remove(&entity);
         self.elements.remove(entity);
         self.ids.remove(entity);
         self.classes.remove(entity);
@@ -983,6 +1193,9 @@ impl Style {
         self.scale.remove(entity);
 
         self.overflow.remove(entity);
+        self.clip_path.remove(entity);
+        self.cache_layer.remove(entity);
+        self.overlay.remove(entity);
 
         // Border
         self.border_width.remove(entity);
@@ -1010,6 +1223,13 @@ impl Style {
         self.background_color.remove(entity);
         self.background_image.remove(entity);
         self.background_gradient.remove(entity);
+        self.background_size.remove(entity);
+        self.background_position.remove(entity);
+        self.background_repeat.remove(entity);
+
+        // Border Image
+        self.border_image.remove(entity);
+        self.border_image_slice.remove(entity);
 
         self.outer_shadow_h_offset.remove(entity);
         self.outer_shadow_v_offset.remove(entity);
@@ -1022,7 +1242,15 @@ impl Style {
         self.inner_shadow_color.remove(entity);
 
         self.layout_type.remove(entity);
+        self.layout_wrap.remove(entity);
+        self.wrap_placement.remove(entity);
+        self.wrap_width.remove(entity);
+        self.wrap_height.remove(entity);
         self.position_type.remove(entity);
+        self.anchor.remove(entity);
+        self.sticky.remove(entity);
+        self.sticky_top.remove(entity);
+        self.sticky_left.remove(entity);
 
         // Space
         self.left.remove(entity);
@@ -1051,6 +1279,9 @@ impl Style {
         self.max_height.remove(entity);
         self.content_width.remove(entity);
         self.content_height.remove(entity);
+        self.aspect_ratio.remove(entity);
+        self.aspect_width.remove(entity);
+        self.aspect_height.remove(entity);
 
         // Child Space
         self.child_left.remove(entity);
@@ -1063,10 +1294,18 @@ impl Style {
         // Grid
         self.grid_cols.remove(entity);
         self.grid_rows.remove(entity);
+        self.grid_areas.remove(entity);
+        self.grid_auto_flow.remove(entity);
+        self.grid_auto_rows.remove(entity);
+        self.grid_auto_cols.remove(entity);
         self.col_index.remove(entity);
         self.col_span.remove(entity);
         self.row_index.remove(entity);
         self.row_span.remove(entity);
+        self.grid_area.remove(entity);
+        self.grid_placement.remove(entity);
+        self.grid_track_rows.remove(entity);
+        self.grid_track_cols.remove(entity);
 
         // Text and Font
         self.text_wrap.remove(entity);
@@ -1076,6 +1315,7 @@ impl Style {
         self.font_color.remove(entity);
         self.font_size.remove(entity);
         self.selection_color.remove(entity);
+        self.match_color.remove(entity);
         self.caret_color.remove(entity);
 
         self.cursor.remove(entity);
@@ -1083,6 +1323,8 @@ impl Style {
         self.name.remove(entity);
 
         self.image.remove(entity);
+        self.object_fit.remove(entity);
+        self.object_position.remove(entity);
     }
 
     pub fn clear_style_rules(&mut self) {
@@ -1102,6 +1344,7 @@ impl Style {
         self.scale.clear_rules();
 
         self.overflow.clear_rules();
+        self.clip_path.clear_rules();
 
         // Border
         self.border_width.clear_rules();
@@ -1128,6 +1371,13 @@ impl Style {
         self.background_color.clear_rules();
         self.background_image.clear_rules();
         self.background_gradient.clear_rules();
+        self.background_size.clear_rules();
+        self.background_position.clear_rules();
+        self.background_repeat.clear_rules();
+
+        // Border Image
+        self.border_image.clear_rules();
+        self.border_image_slice.clear_rules();
 
         self.outer_shadow_h_offset.clear_rules();
         self.outer_shadow_v_offset.clear_rules();
@@ -1140,7 +1390,11 @@ impl Style {
         self.inner_shadow_color.clear_rules();
 
         self.layout_type.clear_rules();
+        self.layout_wrap.clear_rules();
         self.position_type.clear_rules();
+        self.sticky.clear_rules();
+        self.sticky_top.clear_rules();
+        self.sticky_left.clear_rules();
 
         // Space
         self.left.clear_rules();
@@ -1169,6 +1423,7 @@ impl Style {
         self.max_height.clear_rules();
         self.content_width.clear_rules();
         self.content_height.clear_rules();
+        self.aspect_ratio.clear_rules();
 
         // Child Space
         self.child_left.clear_rules();
@@ -1181,10 +1436,15 @@ impl Style {
         // Grid
         self.grid_cols.clear_rules();
         self.grid_rows.clear_rules();
+        self.grid_areas.clear_rules();
+        self.grid_auto_flow.clear_rules();
+        self.grid_auto_rows.clear_rules();
+        self.grid_auto_cols.clear_rules();
         self.col_index.clear_rules();
         self.col_span.clear_rules();
         self.row_index.clear_rules();
         self.row_span.clear_rules();
+        self.grid_area.clear_rules();
 
         // Text and Font
         self.text_wrap.clear_rules();
@@ -1194,6 +1454,7 @@ impl Style {
         self.font_color.clear_rules();
         self.font_size.clear_rules();
         self.selection_color.clear_rules();
+        self.match_color.clear_rules();
         self.caret_color.clear_rules();
 
         self.cursor.clear_rules();
@@ -1201,5 +1462,7 @@ impl Style {
         self.name.clear_rules();
 
         self.image.clear_rules();
+        self.object_fit.clear_rules();
+        self.object_position.clear_rules();
     }
 }
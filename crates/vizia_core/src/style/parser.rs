@@ -276,6 +276,7 @@ fn parse_selectors<'i, 't>(
                     "focus-within" => selector.pseudo_classes.insert(PseudoClass::FOCUS_WITHIN),
                     "focus-visible" => selector.pseudo_classes.insert(PseudoClass::FOCUS_VISIBLE),
                     "root" => selector.pseudo_classes.insert(PseudoClass::ROOT),
+                    "invalid" => selector.pseudo_classes.insert(PseudoClass::INVALID),
 
                     _ => {
                         let parse_error = ParseError {
@@ -364,9 +365,19 @@ impl<'i> cssparser::DeclarationParser<'i> for DeclarationParser {
             "background-color" => Property::BackgroundColor(parse_color(input)?),
             "color" => Property::FontColor(parse_color(input)?),
             "background-image" => Property::BackgroundImage(parse_string(input)?),
+            "background-size" => Property::BackgroundSize(parse_background_size(input)?),
+            "background-position" => Property::BackgroundPosition(parse_image_position(input)?),
+            "background-repeat" => Property::BackgroundRepeat(parse_background_repeat(input)?),
+
+            // Image
+            "object-fit" => Property::ObjectFit(parse_object_fit(input)?),
+            "object-position" => Property::ObjectPosition(parse_image_position(input)?),
 
             // Position
-            "position" | "position-type" => Property::PositionType(parse_position_type(input)?),
+            "position" => parse_position(input)?,
+            "position-type" => Property::PositionType(parse_position_type(input)?),
+            "sticky-top" => Property::StickyTop(parse_units(input)?),
+            "sticky-left" => Property::StickyLeft(parse_units(input)?),
 
             "left" => Property::Left(parse_units(input)?),
             "right" => Property::Right(parse_units(input)?),
@@ -383,7 +394,7 @@ impl<'i> cssparser::DeclarationParser<'i> for DeclarationParser {
             "min-bottom" => Property::MinBottom(parse_units(input)?),
             "max-bottom" => Property::MaxBottom(parse_units(input)?),
 
-            "layout-type" => Property::LayoutType(parse_layout_type(input)?),
+            "layout-type" => parse_layout(input)?,
 
             // Size
             "width" => Property::Width(parse_units(input)?),
@@ -394,6 +405,7 @@ impl<'i> cssparser::DeclarationParser<'i> for DeclarationParser {
             "min-height" => Property::MinHeight(parse_units(input)?),
             "max-width" => Property::MaxWidth(parse_units(input)?),
             "max-height" => Property::MaxHeight(parse_units(input)?),
+            "aspect-ratio" => Property::AspectRatio(parse_aspect_ratio(input)?),
 
             "child-space" => Property::ChildSpace(parse_units(input)?),
             "child-left" => Property::ChildLeft(parse_units(input)?),
@@ -402,12 +414,22 @@ impl<'i> cssparser::DeclarationParser<'i> for DeclarationParser {
             "child-bottom" => Property::ChildBottom(parse_units(input)?),
             "row-between" => Property::RowBetween(parse_units(input)?),
             "col-between" => Property::ColBetween(parse_units(input)?),
+
+            "grid-rows" => Property::GridRows(parse_grid_tracks(input)?),
+            "grid-cols" => Property::GridCols(parse_grid_tracks(input)?),
+            "grid-template-areas" => Property::GridTemplateAreas(parse_grid_areas(input)?),
+            "grid-auto-flow" => Property::GridAutoFlow(parse_grid_auto_flow(input)?),
+            "grid-auto-rows" => Property::GridAutoRows(parse_units(input)?),
+            "grid-auto-cols" => Property::GridAutoCols(parse_units(input)?),
+            "grid-area" => Property::GridArea(input.expect_ident()?.to_string()),
+
             "font-size" => Property::FontSize(parse_font_size(input)?),
             "font-family" => Property::FontFamily(input.parse_comma_separated(parse_font_family)?),
             "font-weight" => Property::FontWeight(parse_font_weight(input)?),
             "font-style" => Property::FontStyle(parse_font_style(input)?),
             "text-wrap" => Property::TextWrap(parse_bool(input)?),
             "selection-color" => Property::SelectionColor(parse_color(input)?),
+            "match-color" => Property::MatchColor(parse_color(input)?),
             "caret-color" => Property::CaretColor(parse_color(input)?),
 
             // Border
@@ -434,6 +456,9 @@ impl<'i> cssparser::DeclarationParser<'i> for DeclarationParser {
                 Property::BorderBottomRightShape(parse_border_corner_shape(input)?)
             }
 
+            "border-image-source" => Property::BorderImage(parse_string(input)?),
+            "border-image-slice" => Property::BorderImageSlice(parse_border_image_slice(input)?),
+
             "outline-width" => Property::OutlineWidth(parse_units(input)?),
 
             "outline-color" => Property::OutlineColor(parse_color(input)?),
@@ -446,6 +471,7 @@ impl<'i> cssparser::DeclarationParser<'i> for DeclarationParser {
             "visibility" => Property::Visibility(parse_visibility(input)?),
 
             "overflow" => Property::Overflow(parse_overflow(input)?),
+            "clip-path" => Property::ClipPath(parse_clip_path(input)?),
 
             "outer-shadow" => Property::OuterShadow(parse_box_shadow(input)?),
             "outer-shadow-h-offset" => Property::OuterShadowHOffset(parse_units(input)?),
@@ -834,6 +860,119 @@ fn parse_units<'i, 't>(
     })
 }
 
+// The most tracks a grid can have, which stops a large `repeat` count from exhausting memory.
+const MAX_GRID_TRACKS: usize = 10_000;
+
+// Parses a list of grid tracks, which are sizes, `minmax(min, max)` or `repeat(count, tracks)`.
+fn parse_grid_tracks<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<Vec<GridTrack>, ParseError<'i, CustomParseError>> {
+    let mut tracks = Vec::new();
+    loop {
+        if let Ok(size) = input.try_parse(parse_units) {
+            tracks.push(GridTrack::Size(size));
+            continue;
+        }
+
+        let name = match input.try_parse(|input| input.expect_function().map(|name| name.clone())) {
+            Ok(name) => name,
+            Err(_) => break,
+        };
+
+        input.parse_nested_block(|input| {
+            match name.as_ref() {
+                "minmax" => {
+                    let min = parse_units(input)?;
+                    input.expect_comma()?;
+                    let max = parse_units(input)?;
+                    tracks.push(GridTrack::MinMax(min, max));
+                }
+
+                "repeat" => {
+                    let count = input.expect_integer()?.max(0) as usize;
+                    input.expect_comma()?;
+                    let repeated = parse_grid_tracks(input)?;
+                    if count.saturating_mul(repeated.len()) + tracks.len() > MAX_GRID_TRACKS {
+                        return Err(CustomParseError::InvalidValue("grid tracks".to_owned()).into());
+                    }
+
+                    tracks.extend(GridTrack::repeat(count, &repeated));
+                }
+
+                _ => {
+                    return Err(CustomParseError::InvalidStringName(name.to_string()).into());
+                }
+            }
+
+            Ok(())
+        })?;
+    }
+
+    if tracks.is_empty() {
+        return Err(CustomParseError::InvalidValue("grid tracks".to_owned()).into());
+    }
+
+    Ok(tracks)
+}
+
+// Parses the rows of named grid areas, given as strings of cell names.
+fn parse_grid_areas<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<GridAreas, ParseError<'i, CustomParseError>> {
+    let mut rows = vec![input.expect_string()?.to_string()];
+    while let Ok(row) = input.try_parse(|input| input.expect_string().map(|row| row.to_string())) {
+        rows.push(row);
+    }
+
+    let rows = rows.iter().map(|row| row.as_str()).collect::<Vec<_>>();
+    let areas = GridAreas::new(&rows);
+    if !areas.is_valid() {
+        return Err(CustomParseError::InvalidValue("grid areas".to_owned()).into());
+    }
+
+    Ok(areas)
+}
+
+fn parse_grid_auto_flow<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<GridAutoFlow, ParseError<'i, CustomParseError>> {
+    let location = input.current_source_location();
+
+    Ok(match input.next()? {
+        Token::Ident(name) => match name.as_ref() {
+            "row" => GridAutoFlow::Row,
+            "column" => GridAutoFlow::Column,
+
+            _ => {
+                return Err(CustomParseError::InvalidStringName(name.to_owned().to_string()).into());
+            }
+        },
+
+        t => {
+            let basic_error = BasicParseError {
+                kind: BasicParseErrorKind::UnexpectedToken(t.to_owned()),
+                location,
+            };
+            return Err(basic_error.into());
+        }
+    })
+}
+
+// Parses the `position` property, which is either a position type or whether the view is sticky.
+fn parse_position<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<Property, ParseError<'i, CustomParseError>> {
+    if input.try_parse(|input| input.expect_ident_matching("sticky")).is_ok() {
+        return Ok(Property::Sticky(true));
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("static")).is_ok() {
+        return Ok(Property::Sticky(false));
+    }
+
+    Ok(Property::PositionType(parse_position_type(input)?))
+}
+
 fn parse_position_type<'i, 't>(
     input: &mut Parser<'i, 't>,
 ) -> Result<PositionType, ParseError<'i, CustomParseError>> {
@@ -998,6 +1137,25 @@ fn parse_visibility<'i, 't>(
     })
 }
 
+// Parses the `aspect-ratio` property, which is a ratio of width to height such as `16 / 9`, or a
+// single number.
+fn parse_aspect_ratio<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<f32, ParseError<'i, CustomParseError>> {
+    let width = input.expect_number()?;
+    let height = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+        input.expect_number()?
+    } else {
+        1.0
+    };
+
+    if width <= 0.0 || height <= 0.0 {
+        return Err(CustomParseError::InvalidValue("aspect ratio".to_owned()).into());
+    }
+
+    Ok(width / height)
+}
+
 fn parse_overflow<'i, 't>(
     input: &mut Parser<'i, 't>,
 ) -> Result<Overflow, ParseError<'i, CustomParseError>> {
@@ -1023,6 +1181,57 @@ fn parse_overflow<'i, 't>(
     })
 }
 
+// Parses a `circle(radius)`, `ellipse(rx ry)` or `inset(insets [round radius])` clip path.
+fn parse_clip_path<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<ClipPath, ParseError<'i, CustomParseError>> {
+    let name = input.expect_function()?.clone();
+
+    input.parse_nested_block(|input| {
+        Ok(match name.as_ref() {
+            "circle" => ClipPath::Circle(parse_units(input)?),
+
+            "ellipse" => {
+                let rx = parse_units(input)?;
+                let ry = parse_units(input)?;
+                ClipPath::Ellipse(rx, ry)
+            }
+
+            "inset" => {
+                // One to four insets, in the same order as the sides of a margin
+                let mut insets = vec![parse_units(input)?];
+                while insets.len() < 4 {
+                    match input.try_parse(parse_units) {
+                        Ok(inset) => insets.push(inset),
+                        Err(_) => break,
+                    }
+                }
+
+                let (top, right, bottom, left) = match insets[..] {
+                    [all] => (all, all, all, all),
+                    [vertical, horizontal] => (vertical, horizontal, vertical, horizontal),
+                    [top, horizontal, bottom] => (top, horizontal, bottom, horizontal),
+                    [top, right, bottom, left, ..] => (top, right, bottom, left),
+                    [] => unreachable!(),
+                };
+
+                let radius =
+                    if input.try_parse(|input| input.expect_ident_matching("round")).is_ok() {
+                        parse_units(input)?
+                    } else {
+                        Units::Pixels(0.0)
+                    };
+
+                ClipPath::Inset { top, right, bottom, left, radius }
+            }
+
+            _ => {
+                return Err(CustomParseError::InvalidStringName(name.to_string()).into());
+            }
+        })
+    })
+}
+
 fn parse_border_corner_shape<'i, 't>(
     input: &mut Parser<'i, 't>,
 ) -> Result<BorderCornerShape, ParseError<'i, CustomParseError>> {
@@ -1048,6 +1257,166 @@ fn parse_border_corner_shape<'i, 't>(
     })
 }
 
+fn parse_object_fit<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<ObjectFit, ParseError<'i, CustomParseError>> {
+    let location = input.current_source_location();
+
+    Ok(match input.next()? {
+        Token::Ident(name) => match name.as_ref() {
+            "fill" => ObjectFit::Fill,
+            "contain" => ObjectFit::Contain,
+            "cover" => ObjectFit::Cover,
+            "none" => ObjectFit::None,
+            "scale-down" => ObjectFit::ScaleDown,
+
+            _ => {
+                return Err(CustomParseError::InvalidStringName(name.to_owned().to_string()).into());
+            }
+        },
+
+        t => {
+            let basic_error = BasicParseError {
+                kind: BasicParseErrorKind::UnexpectedToken(t.to_owned()),
+                location,
+            };
+            return Err(basic_error.into());
+        }
+    })
+}
+
+fn parse_background_repeat<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<BackgroundRepeat, ParseError<'i, CustomParseError>> {
+    let location = input.current_source_location();
+
+    Ok(match input.next()? {
+        Token::Ident(name) => match name.as_ref() {
+            "repeat" => BackgroundRepeat::Repeat,
+            "repeat-x" => BackgroundRepeat::RepeatX,
+            "repeat-y" => BackgroundRepeat::RepeatY,
+            "no-repeat" => BackgroundRepeat::NoRepeat,
+
+            _ => {
+                return Err(CustomParseError::InvalidStringName(name.to_owned().to_string()).into());
+            }
+        },
+
+        t => {
+            let basic_error = BasicParseError {
+                kind: BasicParseErrorKind::UnexpectedToken(t.to_owned()),
+                location,
+            };
+            return Err(basic_error.into());
+        }
+    })
+}
+
+fn parse_background_size<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<BackgroundSize, ParseError<'i, CustomParseError>> {
+    if input.try_parse(|input| input.expect_ident_matching("cover")).is_ok() {
+        return Ok(BackgroundSize::Cover);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("contain")).is_ok() {
+        return Ok(BackgroundSize::Contain);
+    }
+
+    let width = parse_units(input)?;
+    let height = input.try_parse(parse_units).unwrap_or(Units::Auto);
+
+    Ok(match (width, height) {
+        (Units::Auto, Units::Auto) => BackgroundSize::Auto,
+        (width, height) => BackgroundSize::Size(width, height),
+    })
+}
+
+// Parses one component of a position, returning whether a keyword implies the vertical axis.
+fn parse_position_component<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<(Units, Option<bool>), ParseError<'i, CustomParseError>> {
+    if let Ok(name) = input.try_parse(|input| input.expect_ident_cloned()) {
+        return Ok(match name.as_ref() {
+            "left" => (Units::Percentage(0.0), Some(false)),
+            "right" => (Units::Percentage(100.0), Some(false)),
+            "top" => (Units::Percentage(0.0), Some(true)),
+            "bottom" => (Units::Percentage(100.0), Some(true)),
+            "center" => (Units::Percentage(50.0), None),
+
+            _ => {
+                return Err(CustomParseError::InvalidStringName(name.to_string()).into());
+            }
+        });
+    }
+
+    Ok((parse_units(input)?, None))
+}
+
+fn parse_image_position<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<ImagePosition, ParseError<'i, CustomParseError>> {
+    let (first, first_vertical) = parse_position_component(input)?;
+    let (second, second_vertical) =
+        input.try_parse(parse_position_component).unwrap_or((Units::Percentage(50.0), None));
+
+    // Keywords may be given in either order, e.g. `top left`.
+    Ok(if first_vertical == Some(true) || second_vertical == Some(false) {
+        ImagePosition::new(second, first)
+    } else {
+        ImagePosition::new(first, second)
+    })
+}
+
+fn parse_border_image_slice<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<BorderImageSlice, ParseError<'i, CustomParseError>> {
+    let location = input.current_source_location();
+
+    let mut values = Vec::with_capacity(4);
+    let mut fill = false;
+    while let Ok(token) = input.next() {
+        match token {
+            Token::Number { value, .. } if values.len() < 4 => values.push(*value),
+            Token::Ident(name) if name.as_ref() == "fill" => fill = true,
+
+            t => {
+                let basic_error = BasicParseError {
+                    kind: BasicParseErrorKind::UnexpectedToken(t.to_owned()),
+                    location,
+                };
+                return Err(basic_error.into());
+            }
+        }
+    }
+
+    // Missing values are copied from the opposite side, as with CSS margins.
+    let (top, right, bottom, left) = match values.as_slice() {
+        [all] => (*all, *all, *all, *all),
+        [vertical, horizontal] => (*vertical, *horizontal, *vertical, *horizontal),
+        [top, horizontal, bottom] => (*top, *horizontal, *bottom, *horizontal),
+        [top, right, bottom, left] => (*top, *right, *bottom, *left),
+        _ => return Err(input.new_error(BasicParseErrorKind::EndOfInput)),
+    };
+
+    Ok(BorderImageSlice::new(top, right, bottom, left).fill(fill))
+}
+
+// Parses the `layout-type` property, which is a layout type or a wrapping row or column.
+fn parse_layout<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<Property, ParseError<'i, CustomParseError>> {
+    if input.try_parse(|input| input.expect_ident_matching("wrap-row")).is_ok() {
+        return Ok(Property::LayoutWrap(LayoutType::Row));
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("wrap-column")).is_ok() {
+        return Ok(Property::LayoutWrap(LayoutType::Column));
+    }
+
+    Ok(Property::LayoutType(parse_layout_type(input)?))
+}
+
 fn parse_layout_type<'i, 't>(
     input: &mut Parser<'i, 't>,
 ) -> Result<LayoutType, ParseError<'i, CustomParseError>> {
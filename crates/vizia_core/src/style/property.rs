@@ -19,11 +19,16 @@ pub(crate) enum Property {
     Display(Display),
     Visibility(Visibility),
     Overflow(Overflow),
+    ClipPath(ClipPath),
     Opacity(f32),
 
     // Positioning
     LayoutType(LayoutType),
+    LayoutWrap(LayoutType),
     PositionType(PositionType),
+    Sticky(bool),
+    StickyTop(Units),
+    StickyLeft(Units),
 
     // Position and Size
     Space(Units),
@@ -46,6 +51,7 @@ pub(crate) enum Property {
     MaxTop(Units),
     MinHeight(Units),
     MaxHeight(Units),
+    AspectRatio(f32),
     MinBottom(Units),
     MaxBottom(Units),
 
@@ -58,6 +64,15 @@ pub(crate) enum Property {
     RowBetween(Units),
     ColBetween(Units),
 
+    // Grid
+    GridRows(Vec<GridTrack>),
+    GridCols(Vec<GridTrack>),
+    GridTemplateAreas(GridAreas),
+    GridAutoFlow(GridAutoFlow),
+    GridAutoRows(Units),
+    GridAutoCols(Units),
+    GridArea(String),
+
     // Border Radius
     BorderRadius(Units),
     BorderTopLeftRadius(Units),
@@ -86,6 +101,17 @@ pub(crate) enum Property {
     // Background
     BackgroundColor(Color),
     BackgroundImage(String),
+    BackgroundSize(BackgroundSize),
+    BackgroundPosition(ImagePosition),
+    BackgroundRepeat(BackgroundRepeat),
+
+    // Border Image
+    BorderImage(String),
+    BorderImageSlice(BorderImageSlice),
+
+    // Image
+    ObjectFit(ObjectFit),
+    ObjectPosition(ImagePosition),
     // TODO
     //BackgroundGradient(LinearGradient),
 
@@ -96,6 +122,7 @@ pub(crate) enum Property {
     FontWeight(Weight),
     FontStyle(FontStyle),
     SelectionColor(Color),
+    MatchColor(Color),
     CaretColor(Color),
     TextWrap(bool),
 
@@ -141,6 +168,10 @@ fn fmt_layout_type(val: &LayoutType) -> String {
     .to_owned()
 }
 
+fn fmt_grid_tracks(val: &[GridTrack]) -> String {
+    val.iter().map(|track| track.to_string()).collect::<Vec<_>>().join(" ")
+}
+
 fn fmt_position_type(val: &PositionType) -> String {
     match val {
         PositionType::SelfDirected => "self-directed",
@@ -170,11 +201,18 @@ impl std::fmt::Display for Property {
             Property::Display(val) => write!(f, "display: {};", val),
             Property::Visibility(val) => write!(f, "visibility: {};", val),
             Property::Overflow(val) => write!(f, "overflow: {};", val),
+            Property::ClipPath(val) => write!(f, "clip-path: {};", val),
             Property::Opacity(val) => write!(f, "opacity: {};", val),
 
             // Positioning
             Property::LayoutType(val) => write!(f, "layout-type: {};", fmt_layout_type(val)),
+            Property::LayoutWrap(val) => write!(f, "layout-type: wrap-{};", fmt_layout_type(val)),
             Property::PositionType(val) => write!(f, "position-type: {};", fmt_position_type(val)),
+            Property::Sticky(val) => {
+                write!(f, "position: {};", if *val { "sticky" } else { "static" })
+            }
+            Property::StickyTop(val) => write!(f, "sticky-top: {};", fmt_units(val)),
+            Property::StickyLeft(val) => write!(f, "sticky-left: {};", fmt_units(val)),
 
             // Position and Size
             Property::Space(val) => write!(f, "space: {};", fmt_units(val)),
@@ -197,6 +235,7 @@ impl std::fmt::Display for Property {
             Property::MaxTop(val) => write!(f, "max-top: {};", fmt_units(val)),
             Property::MinHeight(val) => write!(f, "min-height: {};", fmt_units(val)),
             Property::MaxHeight(val) => write!(f, "max-height: {};", fmt_units(val)),
+            Property::AspectRatio(val) => write!(f, "aspect-ratio: {};", val),
             Property::MinBottom(val) => write!(f, "min-bottom: {};", fmt_units(val)),
             Property::MaxBottom(val) => write!(f, "max-bottom: {};", fmt_units(val)),
 
@@ -209,6 +248,15 @@ impl std::fmt::Display for Property {
             Property::RowBetween(val) => write!(f, "row-between: {};", fmt_units(val)),
             Property::ColBetween(val) => write!(f, "col-between: {};", fmt_units(val)),
 
+            // Grid
+            Property::GridRows(val) => write!(f, "grid-rows: {};", fmt_grid_tracks(val)),
+            Property::GridCols(val) => write!(f, "grid-cols: {};", fmt_grid_tracks(val)),
+            Property::GridTemplateAreas(val) => write!(f, "grid-template-areas: {};", val),
+            Property::GridAutoFlow(val) => write!(f, "grid-auto-flow: {};", val),
+            Property::GridAutoRows(val) => write!(f, "grid-auto-rows: {};", fmt_units(val)),
+            Property::GridAutoCols(val) => write!(f, "grid-auto-cols: {};", fmt_units(val)),
+            Property::GridArea(val) => write!(f, "grid-area: {};", val),
+
             // Border
             Property::BorderRadius(val) => write!(f, "border-radius: {};", fmt_units(val)),
             Property::BorderTopLeftRadius(val) => {
@@ -236,6 +284,17 @@ impl std::fmt::Display for Property {
             // Background
             Property::BackgroundColor(val) => write!(f, "background-color: {};", val),
             Property::BackgroundImage(val) => write!(f, "background-image: {};", val),
+            Property::BackgroundSize(val) => write!(f, "background-size: {};", val),
+            Property::BackgroundPosition(val) => write!(f, "background-position: {};", val),
+            Property::BackgroundRepeat(val) => write!(f, "background-repeat: {};", val),
+
+            // Border Image
+            Property::BorderImage(val) => write!(f, "border-image-source: {};", val),
+            Property::BorderImageSlice(val) => write!(f, "border-image-slice: {};", val),
+
+            // Image
+            Property::ObjectFit(val) => write!(f, "object-fit: {};", val),
+            Property::ObjectPosition(val) => write!(f, "object-position: {};", val),
 
             // Outline
             Property::OutlineWidth(val) => write!(f, "outline-width: {}", fmt_units(val)),
@@ -253,6 +312,7 @@ impl std::fmt::Display for Property {
             Property::FontWeight(val) => write!(f, "font-weight: {}", val.0),
             Property::FontStyle(val) => write!(f, "font-style: {}", fmt_font_style(val)),
             Property::SelectionColor(val) => write!(f, "selection-color: {}", val),
+            Property::MatchColor(val) => write!(f, "match-color: {}", val),
             Property::CaretColor(val) => write!(f, "caret-color: {}", val),
             Property::TextWrap(val) => write!(f, "text-wrap: {}", val),
 
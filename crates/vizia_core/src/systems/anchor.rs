@@ -0,0 +1,56 @@
+use morphorm::{Cache, GeometryChanged};
+use vizia_id::GenerationalId;
+use vizia_storage::LayoutTreeIterator;
+
+use crate::prelude::*;
+
+// Moves anchored views, along with their descendants, next to their anchor views. This runs after
+// every layout pass and only moves a view by the difference from its placed position, so a view
+// which hasn't been laid out again since it was last placed stays where it is.
+pub(crate) fn anchor_system(cx: &mut Context, tree: &Tree<Entity>) {
+    if cx.style.anchor.is_empty() {
+        return;
+    }
+
+    let window = cx.cache.get_bounds(Entity::root());
+    let scale = cx.style.dpi_factor as f32;
+
+    // Views are placed in tree order, as the anchor of a nested view may have been moved along with
+    // one of its ancestors
+    for entity in tree.into_iter() {
+        let anchor = match cx.style.anchor.get(entity).copied() {
+            Some(anchor) => anchor,
+            None => continue,
+        };
+
+        if !cx.entity_manager.is_alive(anchor.target)
+            || cx.cache.get_display(entity) == Display::None
+            || cx.cache.get_display(anchor.target) == Display::None
+        {
+            continue;
+        }
+
+        let target = cx.cache.get_bounds(anchor.target);
+        let bounds = cx.cache.get_bounds(entity);
+        let (x, y) = anchor.place(target, bounds.w, bounds.h, window, scale);
+
+        let dx = x - bounds.x;
+        let dy = y - bounds.y;
+        if dx == 0.0 && dy == 0.0 {
+            continue;
+        }
+
+        for node in LayoutTreeIterator::subtree(tree, entity) {
+            let bounds = cx.cache.get_bounds(node);
+            cx.cache.set_posx(node, bounds.x + dx);
+            cx.cache.set_posy(node, bounds.y + dy);
+            if dx != 0.0 {
+                cx.cache.set_geo_changed(node, GeometryChanged::POSX_CHANGED, true);
+            }
+
+            if dy != 0.0 {
+                cx.cache.set_geo_changed(node, GeometryChanged::POSY_CHANGED, true);
+            }
+        }
+    }
+}
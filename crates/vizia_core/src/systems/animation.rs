@@ -48,50 +48,61 @@ pub fn has_animations(cx: &Context) -> bool {
 
 pub fn animation_system(cx: &mut Context) {
     let time = instant::Instant::now();
+    // Entities whose animated values only change their appearance
+    let mut animated = Vec::new();
+    // Entities whose animated values change their size or position
+    let mut resized = Vec::new();
 
-    cx.style.display.tick(time);
-    cx.style.visibility.tick(time);
-    cx.style.opacity.tick(time);
-    cx.style.rotate.tick(time);
-    cx.style.translate.tick(time);
-    cx.style.scale.tick(time);
-    cx.style.border_width.tick(time);
-    cx.style.border_color.tick(time);
-    cx.style.border_radius_top_left.tick(time);
-    cx.style.border_radius_top_right.tick(time);
-    cx.style.border_radius_bottom_left.tick(time);
-    cx.style.border_radius_bottom_right.tick(time);
-    cx.style.background_color.tick(time);
-    cx.style.outer_shadow_h_offset.tick(time);
-    cx.style.outer_shadow_v_offset.tick(time);
-    cx.style.outer_shadow_blur.tick(time);
-    cx.style.outer_shadow_color.tick(time);
-    cx.style.font_color.tick(time);
-    cx.style.font_size.tick(time);
-    cx.style.left.tick(time);
-    cx.style.right.tick(time);
-    cx.style.top.tick(time);
-    cx.style.bottom.tick(time);
-    cx.style.width.tick(time);
-    cx.style.height.tick(time);
-    cx.style.max_width.tick(time);
-    cx.style.max_height.tick(time);
-    cx.style.min_width.tick(time);
-    cx.style.min_height.tick(time);
-    cx.style.min_left.tick(time);
-    cx.style.max_left.tick(time);
-    cx.style.min_right.tick(time);
-    cx.style.max_right.tick(time);
-    cx.style.min_top.tick(time);
-    cx.style.max_top.tick(time);
-    cx.style.min_bottom.tick(time);
-    cx.style.max_bottom.tick(time);
-    cx.style.row_between.tick(time);
-    cx.style.col_between.tick(time);
-    cx.style.child_left.tick(time);
-    cx.style.child_right.tick(time);
-    cx.style.child_top.tick(time);
-    cx.style.child_bottom.tick(time);
+    resized.extend(cx.style.display.tick(time));
+    animated.extend(cx.style.visibility.tick(time));
+    animated.extend(cx.style.opacity.tick(time));
+    animated.extend(cx.style.rotate.tick(time));
+    animated.extend(cx.style.translate.tick(time));
+    animated.extend(cx.style.scale.tick(time));
+    animated.extend(cx.style.border_width.tick(time));
+    animated.extend(cx.style.border_color.tick(time));
+    animated.extend(cx.style.border_radius_top_left.tick(time));
+    animated.extend(cx.style.border_radius_top_right.tick(time));
+    animated.extend(cx.style.border_radius_bottom_left.tick(time));
+    animated.extend(cx.style.border_radius_bottom_right.tick(time));
+    animated.extend(cx.style.background_color.tick(time));
+    animated.extend(cx.style.outer_shadow_h_offset.tick(time));
+    animated.extend(cx.style.outer_shadow_v_offset.tick(time));
+    animated.extend(cx.style.outer_shadow_blur.tick(time));
+    animated.extend(cx.style.outer_shadow_color.tick(time));
+    animated.extend(cx.style.font_color.tick(time));
+    resized.extend(cx.style.font_size.tick(time));
+    resized.extend(cx.style.left.tick(time));
+    resized.extend(cx.style.right.tick(time));
+    resized.extend(cx.style.top.tick(time));
+    resized.extend(cx.style.bottom.tick(time));
+    resized.extend(cx.style.width.tick(time));
+    resized.extend(cx.style.height.tick(time));
+    resized.extend(cx.style.max_width.tick(time));
+    resized.extend(cx.style.max_height.tick(time));
+    resized.extend(cx.style.min_width.tick(time));
+    resized.extend(cx.style.min_height.tick(time));
+    resized.extend(cx.style.min_left.tick(time));
+    resized.extend(cx.style.max_left.tick(time));
+    resized.extend(cx.style.min_right.tick(time));
+    resized.extend(cx.style.max_right.tick(time));
+    resized.extend(cx.style.min_top.tick(time));
+    resized.extend(cx.style.max_top.tick(time));
+    resized.extend(cx.style.min_bottom.tick(time));
+    resized.extend(cx.style.max_bottom.tick(time));
+    resized.extend(cx.style.row_between.tick(time));
+    resized.extend(cx.style.col_between.tick(time));
+    resized.extend(cx.style.child_left.tick(time));
+    resized.extend(cx.style.child_right.tick(time));
+    resized.extend(cx.style.child_top.tick(time));
+    resized.extend(cx.style.child_bottom.tick(time));
 
-    cx.style.needs_relayout = true;
+    for entity in animated {
+        cx.style.redraw(entity);
+    }
+
+    for entity in resized {
+        cx.style.relayout(entity);
+        cx.style.redraw(entity);
+    }
 }
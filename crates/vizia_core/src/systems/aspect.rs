@@ -0,0 +1,129 @@
+use morphorm::{Hierarchy, Units};
+use vizia_storage::LayoutTreeIterator;
+
+use crate::prelude::*;
+
+// Removes the sizes set by the aspect system within a subtree which is about to be laid out, so
+// that views with an aspect ratio are first laid out into the space they stretch into. The root
+// keeps its own size, as it isn't sized by laying out its subtree.
+pub(crate) fn clear_aspect_sizes(cx: &mut Context, tree: &Tree<Entity>, root: Entity) {
+    if cx.style.aspect_width.is_empty() && cx.style.aspect_height.is_empty() {
+        return;
+    }
+
+    for entity in LayoutTreeIterator::subtree(tree, root).skip(1) {
+        cx.style.aspect_width.remove(entity);
+        cx.style.aspect_height.remove(entity);
+    }
+}
+
+// Sizes the views with an aspect ratio within a subtree which has just been laid out, using the
+// sizes they were laid out with. Returns whether any views were sized, in which case the subtree
+// needs to be laid out again to apply the sizes.
+pub(crate) fn aspect_system(cx: &mut Context, tree: &Tree<Entity>, root: Entity) -> bool {
+    let scale = cx.style.dpi_factor as f32;
+    let mut sized = false;
+
+    for entity in LayoutTreeIterator::subtree(tree, root).skip(1) {
+        let ratio = match cx.style.aspect_ratio.get(entity) {
+            Some(&ratio) if ratio > 0.0 => ratio,
+            _ => continue,
+        };
+
+        if cx.cache.get_display(entity) == Display::None {
+            continue;
+        }
+
+        let style = &cx.style;
+        let bounds = cx.cache.get_bounds(entity);
+        let parent = cx.cache.get_bounds(tree.get_layout_parent(entity).unwrap_or(root));
+        let fixed = |units: Option<&Units>, parent: f32| match units {
+            Some(Units::Pixels(val)) => Some(val * scale),
+            Some(Units::Percentage(val)) => Some(parent * val / 100.0),
+            _ => None,
+        };
+
+        let (width, height) = aspect_size(
+            ratio,
+            (bounds.w, bounds.h),
+            (
+                style.width.get(entity) == Some(&Units::Auto),
+                style.height.get(entity) == Some(&Units::Auto),
+            ),
+            (
+                fixed(style.min_width.get(entity), parent.w).unwrap_or(0.0),
+                fixed(style.min_height.get(entity), parent.h).unwrap_or(0.0),
+            ),
+            (
+                fixed(style.max_width.get(entity), parent.w).unwrap_or(f32::INFINITY),
+                fixed(style.max_height.get(entity), parent.h).unwrap_or(f32::INFINITY),
+            ),
+        );
+
+        cx.style.aspect_width.insert(entity, width).expect("Failed to set aspect width");
+        cx.style.aspect_height.insert(entity, height).expect("Failed to set aspect height");
+        sized = true;
+    }
+
+    sized
+}
+
+// Returns the size of a view with the given ratio of width to height, from the size it was laid
+// out with. An auto width or height follows the other size, and otherwise the view shrinks to fit
+// within its size. The result is kept within the minimum and maximum sizes, with the height
+// constraints applied last.
+fn aspect_size(
+    ratio: f32,
+    (width, height): (f32, f32),
+    (auto_width, auto_height): (bool, bool),
+    (min_width, min_height): (f32, f32),
+    (max_width, max_height): (f32, f32),
+) -> (f32, f32) {
+    let width = match (auto_width, auto_height) {
+        (false, true) => width,
+        (true, false) => height * ratio,
+        _ => width.min(height * ratio),
+    };
+
+    let width = width.min(max_width).max(min_width);
+    let height = width / ratio;
+    let clamped = height.min(max_height).max(min_height);
+    if clamped != height {
+        (clamped * ratio, clamped)
+    } else {
+        (width, height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UNCONSTRAINED: ((f32, f32), (f32, f32)) = ((0.0, 0.0), (f32::INFINITY, f32::INFINITY));
+
+    #[test]
+    fn fits_within_stretched_size() {
+        let (min, max) = UNCONSTRAINED;
+        assert_eq!(aspect_size(1.0, (200.0, 100.0), (false, false), min, max), (100.0, 100.0));
+        assert_eq!(aspect_size(2.0, (320.0, 400.0), (false, false), min, max), (320.0, 160.0));
+    }
+
+    #[test]
+    fn follows_other_size_when_auto() {
+        let (min, max) = UNCONSTRAINED;
+        assert_eq!(aspect_size(2.0, (200.0, 30.0), (false, true), min, max), (200.0, 100.0));
+        assert_eq!(aspect_size(2.0, (30.0, 50.0), (true, false), min, max), (100.0, 50.0));
+    }
+
+    #[test]
+    fn keeps_within_constraints() {
+        assert_eq!(
+            aspect_size(1.0, (200.0, 300.0), (false, false), (0.0, 0.0), (150.0, f32::INFINITY)),
+            (150.0, 150.0)
+        );
+        assert_eq!(
+            aspect_size(1.0, (20.0, 300.0), (false, false), (40.0, 0.0), (f32::INFINITY, 30.0)),
+            (30.0, 30.0)
+        );
+    }
+}
@@ -1,8 +1,17 @@
+use crate::style::{ClipShape, Transform2D};
+use crate::systems::transform_area;
 use crate::{cache::BoundingBox, prelude::*};
 use vizia_id::GenerationalId;
 
 // Iterate tree and apply clip region to nodes based on specified clip widget and parent clip region.
+//
+// Clipping which can't be applied as a rectangle in the coordinates of the parent clip region, such
+// as to rounded corners, a clip path or a transformed view, is instead given as a clip shape. When
+// the shape cuts into what is drawn, a view with a clip shape is drawn, along with its descendants,
+// into a layer which is filled into the shape.
 pub fn clipping_system(cx: &mut Context, tree: &Tree<Entity>) {
+    let scale = cx.style.dpi_factor as f32;
+
     for entity in tree.into_iter() {
         if entity == Entity::root() {
             continue;
@@ -14,13 +23,44 @@ pub fn clipping_system(cx: &mut Context, tree: &Tree<Entity>) {
 
         let parent = tree.get_layout_parent(entity).unwrap();
 
-        let parent_clip_region = cx.cache.get_clip_region(parent);
+        // Views in the overlay layer escape the clipping of their ancestors
+        let (mut parent_clip_region, mut clip_transform) =
+            if cx.style.overlay.get(entity).copied().unwrap_or_default() {
+                (cx.cache.get_clip_region(Entity::root()), Transform2D::identity())
+            } else {
+                (cx.cache.get_clip_region(parent), cx.cache.get_clip_transform(parent))
+            };
 
         let overflow = cx.style.overflow.get(entity).cloned().unwrap_or_default();
+        let clip_widget = cx.style.clip_widget.get(entity).cloned().unwrap_or(entity);
+        let bounds = cx.cache.get_bounds(entity);
+        let transform = cx.cache.get_transform(entity);
+        let transformed = transform != clip_transform;
 
-        let clip_region = if overflow == Overflow::Hidden {
-            let clip_widget = cx.style.clip_widget.get(entity).cloned().unwrap_or(entity);
+        let clip_shape = if let Some(clip_path) = cx.style.clip_path.get(entity) {
+            Some(clip_path.shape(bounds, scale))
+        } else if overflow == Overflow::Hidden && clip_widget == entity {
+            let radii = border_radii(cx, entity, bounds, scale);
+            (transformed || radii.iter().any(|radius| *radius > 0.0))
+                .then(|| ClipShape::Rect { bounds, radii })
+        } else {
+            None
+        };
 
+        // The descendants of a transformed view with a clip shape are drawn into its layer without
+        // its transform, so their clip regions are in its untransformed coordinates
+        if clip_shape.is_some() && transformed {
+            let mut inverse = transform;
+            inverse.inverse();
+            inverse.premultiply(&clip_transform);
+            parent_clip_region = transform_area(&inverse, parent_clip_region);
+            clip_transform = transform;
+        }
+
+        cx.cache.set_clip_shape(entity, clip_shape);
+        cx.cache.set_clip_transform(entity, clip_transform);
+
+        let clip_region = if overflow == Overflow::Hidden {
             let clip_x = cx.cache.get_posx(clip_widget);
             let clip_y = cx.cache.get_posy(clip_widget);
             let clip_w = cx.cache.get_width(clip_widget);
@@ -58,3 +98,20 @@ pub fn clipping_system(cx: &mut Context, tree: &Tree<Entity>) {
         //}
     }
 }
+
+// Returns the radii of the top-left, top-right, bottom-right and bottom-left corners of a view, in
+// physical pixels.
+fn border_radii(cx: &Context, entity: Entity, bounds: BoundingBox, scale: f32) -> [f32; 4] {
+    let radius = |units: Option<&Units>| match units {
+        Some(Units::Pixels(val)) => val * scale,
+        Some(Units::Percentage(val)) => bounds.w.min(bounds.h) * val / 100.0,
+        _ => 0.0,
+    };
+
+    [
+        radius(cx.style.border_radius_top_left.get(entity)),
+        radius(cx.style.border_radius_top_right.get(entity)),
+        radius(cx.style.border_radius_bottom_right.get(entity)),
+        radius(cx.style.border_radius_bottom_left.get(entity)),
+    ]
+}
@@ -1,18 +1,42 @@
-use crate::context::Context;
+use crate::cache::BoundingBox;
+use crate::context::{Context, Footprint, Layer, SampledColor};
 use crate::prelude::*;
+use crate::style::Transform2D;
+use femtovg::{ImageFlags, ImageId, Paint, Path, PixelFormat, RenderTarget};
+use fnv::FnvHashMap;
 use vizia_id::GenerationalId;
+use vizia_storage::TreeExt;
 
+// Views are drawn into a frame image which persists between frames. Only the region of the window
+// damaged by changed, moved or removed views is redrawn into it, and the frame image is then copied
+// to the screen.
 pub fn draw_system(cx: &mut Context) {
-    let canvas = cx.canvases.get_mut(&Entity::root()).unwrap();
+    let mut canvas = cx.canvases.remove(&Entity::root()).unwrap();
     cx.resource_manager.mark_images_unused();
 
     let window_width = cx.cache.get_width(Entity::root());
     let window_height = cx.cache.get_height(Entity::root());
+    let window = BoundingBox { x: 0.0, y: 0.0, w: window_width, h: window_height };
 
     canvas.set_size(window_width as u32, window_height as u32, 1.0);
     let clear_color =
         cx.style.background_color.get(Entity::root()).cloned().unwrap_or(Color::white());
-    canvas.clear_rect(0, 0, window_width as u32, window_height as u32, clear_color.into());
+
+    // Recreate the frame image, and redraw everything, when the size of the window changes
+    let frame_size = ((window_width as usize).max(1), (window_height as usize).max(1));
+    let frame_image = match cx.draw_cache.frame_image {
+        Some(image) if canvas.image_size(image).ok() == Some(frame_size) => image,
+        previous => {
+            if let Some(image) = previous {
+                canvas.delete_image(image);
+            }
+
+            let image = create_image(&mut canvas, frame_size);
+            cx.draw_cache.frame_image = Some(image);
+            cx.style.redraw_list.insert(Entity::root());
+            image
+        }
+    };
 
     // filter for widgets that should be drawn
     let tree_iter = cx.tree.into_iter();
@@ -36,67 +60,617 @@ pub fn draw_system(cx: &mut Context) {
     // Sort the tree by z order
     draw_tree.sort_by_cached_key(|entity| cx.cache.get_z_index(*entity));
 
-    for entity in draw_tree.into_iter() {
-        // Apply clipping
-        let clip_region = cx.cache.get_clip_region(entity);
+    // Damage the old and new area of any view which moved, appeared or changed its transform,
+    // opacity or z-index since the last frame
+    let mut footprints = FnvHashMap::default();
+    for &entity in draw_tree.iter() {
+        let footprint = footprint(cx, entity);
+        match cx.draw_cache.footprints.remove(&entity) {
+            Some(previous) if previous == footprint => {}
+            Some(previous) => {
+                cx.draw_cache.add_damage(previous.area);
+                cx.draw_cache.add_damage(footprint.area);
+            }
+            None => cx.draw_cache.add_damage(footprint.area),
+        }
 
-        // Skips drawing views with zero-sized clip regions
-        // This skips calling the `draw` method of the view
-        if clip_region.height() == 0.0 || clip_region.width() == 0.0 {
+        footprints.insert(entity, footprint);
+    }
+
+    // Damage the area of any view which is no longer drawn
+    let hidden = std::mem::replace(&mut cx.draw_cache.footprints, footprints);
+    for (_, footprint) in hidden {
+        cx.draw_cache.add_damage(footprint.area);
+    }
+
+    // Find the views which need to be drawn into their own layer this frame
+    cx.draw_cache.layer_roots =
+        draw_tree.iter().copied().filter(|&entity| needs_layer(cx, entity)).collect();
+
+    // Damage the area of any view marked as needing to be redrawn, along with its cached layer
+    for entity in cx.style.redraw_list.drain() {
+        // Bindings aren't drawn, so redraw the view which contains them
+        let entity = if cx.tree.is_ignored(entity) {
+            cx.tree.get_layout_parent(entity).unwrap_or(Entity::root())
+        } else {
+            entity
+        };
+
+        if entity == Entity::root() {
+            cx.draw_cache.add_damage(window);
+            for layer in cx.draw_cache.layers.values_mut() {
+                layer.dirty = true;
+            }
+        } else if let Some(footprint) = cx.draw_cache.footprints.get(&entity) {
+            cx.draw_cache.add_damage(footprint.area);
+            if let Some(root) = layer_root(cx, entity) {
+                if let Some(layer) = cx.draw_cache.layers.get_mut(&root) {
+                    layer.dirty = true;
+                }
+            }
+        }
+    }
+
+    // Group the views drawn into layers by the root view of their layer. The root of a layer is drawn
+    // into its own layer, which is then composited into any layer containing it.
+    let mut layer_members: FnvHashMap<Entity, Vec<Entity>> = FnvHashMap::default();
+    for &entity in draw_tree.iter() {
+        if is_layer_root(cx, entity) {
+            layer_members.entry(entity).or_default().push(entity);
+        }
+
+        if let Some(root) = parent_layer(cx, entity) {
+            layer_members.entry(root).or_default().push(entity);
+        }
+    }
+
+    // Remove the layers of views which are no longer drawn or cached
+    let footprints = &cx.draw_cache.footprints;
+    cx.draw_cache.layers.retain(|root, layer| {
+        let keep = layer_members.contains_key(root) && footprints.contains_key(root);
+        if !keep {
+            canvas.delete_image(layer.image);
+        }
+
+        keep
+    });
+
+    // Render any layers which have changed, innermost first as a layer is composited into the layer
+    // containing it
+    let mut roots: Vec<Entity> = layer_members.keys().copied().collect();
+    roots.sort_by_cached_key(|root| std::cmp::Reverse(root.parent_iter(&cx.tree).count()));
+    for root in roots {
+        let members = &layer_members[&root];
+        if !cx.draw_cache.footprints.contains_key(&root) {
             continue;
         }
 
-        canvas.scissor(clip_region.x, clip_region.y, clip_region.w, clip_region.h);
+        let bounds = cx.cache.get_bounds(root);
+        let margin = visual_margin(cx, root);
+        let area = BoundingBox {
+            x: -margin,
+            y: -margin,
+            w: bounds.w + 2.0 * margin,
+            h: bounds.h + 2.0 * margin,
+        };
+        let to_layer = layer_transform(cx, root, area);
+        let contents: Vec<(Entity, BoundingBox, BoundingBox)> = members
+            .iter()
+            .map(|&entity| {
+                let member = cx.cache.get_bounds(entity);
+                let relative =
+                    BoundingBox { x: member.x - bounds.x, y: member.y - bounds.y, ..member };
+                let composited = entity != root && is_layer_root(cx, entity);
+                (entity, relative, target_clip_region(cx, entity, composited, &to_layer))
+            })
+            .collect();
+
+        let dpi_factor = cx.style.dpi_factor;
+        if cx.draw_cache.layers.get(&root).map_or(false, |layer| {
+            !layer.dirty
+                && layer.area == area
+                && layer.contents == contents
+                && layer.dpi_factor == dpi_factor
+        }) {
+            continue;
+        }
+
+        let size = ((area.w.ceil() as usize).max(1), (area.h.ceil() as usize).max(1));
+        let image = match cx.draw_cache.layers.remove(&root) {
+            Some(layer) if canvas.image_size(layer.image).ok() == Some(size) => layer.image,
+            previous => {
+                if let Some(layer) = previous {
+                    canvas.delete_image(layer.image);
+                }
+
+                create_image(&mut canvas, size)
+            }
+        };
+
+        let layer = Layer { image, area, contents, dpi_factor, dirty: false };
+        render_layer(cx, &mut canvas, root, members, &layer);
+        cx.draw_cache.layers.insert(root, layer);
+
+        cx.draw_cache.add_damage(cx.draw_cache.footprints[&root].area);
+
+        if let Some(parent) = parent_layer(cx, root) {
+            if let Some(layer) = cx.draw_cache.layers.get_mut(&parent) {
+                layer.dirty = true;
+            }
+        }
+    }
+
+    // Snap the damaged region to whole pixels
+    let damage = cx.draw_cache.damage.take().map(|damage| {
+        let damage = damage.intersection(&window);
+        BoundingBox::from_min_max(
+            damage.left().floor(),
+            damage.top().floor(),
+            damage.right().ceil(),
+            damage.bottom().ceil(),
+        )
+    });
+    let damage = damage.filter(|damage| damage.w > 0.0 && damage.h > 0.0);
 
-        // Apply transform
-        let transform = cx.cache.get_transform(entity);
-        canvas.save();
-        canvas.set_transform(
-            transform[0],
-            transform[1],
-            transform[2],
-            transform[3],
-            transform[4],
-            transform[5],
+    if let Some(damage) = damage {
+        canvas.set_render_target(RenderTarget::Image(frame_image));
+        canvas.reset_scissor();
+        canvas.clear_rect(
+            damage.x as u32,
+            damage.y as u32,
+            damage.w as u32,
+            damage.h as u32,
+            clear_color.into(),
         );
 
-        if let Some(view) = cx.views.remove(&entity) {
-            cx.current = entity;
-            view.draw(
-                &mut DrawContext {
-                    current: cx.current,
-                    captured: &cx.captured,
-                    focused: &cx.focused,
-                    hovered: &cx.hovered,
-                    style: &cx.style,
-                    cache: &mut cx.cache,
-                    draw_cache: &mut cx.draw_cache,
-                    tree: &cx.tree,
-                    data: &cx.data,
-                    views: &cx.views,
-                    resource_manager: &cx.resource_manager,
-                    text_context: &mut cx.text_context,
-                    modifiers: &cx.modifiers,
-                    mouse: &cx.mouse,
-                },
-                canvas,
-            );
-
-            cx.views.insert(entity, view);
-        }
-
-        canvas.restore();
-
-        // Uncomment this for debug outlines
-        // TODO - Hook this up to a key in debug mode
-        // let mut path = Path::new();
-        // path.rect(bounds.x, bounds.y, bounds.w, bounds.h);
-        // let mut paint = Paint::color(femtovg::Color::rgb(255, 0, 0));
-        // paint.set_line_width(1.0);
-        // canvas.stroke_path(&mut path, paint);
+        for entity in draw_tree.into_iter() {
+            // Views outside of the damaged region are already correct in the frame image
+            if !cx.draw_cache.footprints[&entity].area.intersects(&damage) {
+                continue;
+            }
+
+            // Views in a layer are drawn when the layer is composited at its root view
+            if parent_layer(cx, entity).is_some() {
+                continue;
+            }
+
+            // Apply clipping
+            let composited = is_layer_root(cx, entity);
+            let clip_region = target_clip_region(cx, entity, composited, &Transform2D::identity())
+                .intersection(&damage);
+
+            // Skips drawing views with zero-sized clip regions
+            // This skips calling the `draw` method of the view
+            if clip_region.height() == 0.0 || clip_region.width() == 0.0 {
+                continue;
+            }
+
+            if composited {
+                composite_layer(cx, &mut canvas, entity, &Transform2D::identity(), clip_region);
+            } else {
+                let transform = cx.cache.get_transform(entity);
+                draw_entity(cx, &mut canvas, entity, transform, clip_region);
+            }
+
+            // Uncomment this for debug outlines
+            // TODO - Hook this up to a key in debug mode
+            // let mut path = Path::new();
+            // path.rect(bounds.x, bounds.y, bounds.w, bounds.h);
+            // let mut paint = Paint::color(femtovg::Color::rgb(255, 0, 0));
+            // paint.set_line_width(1.0);
+            // canvas.stroke_path(&mut path, paint);
+        }
+    }
+
+    // Copy the frame image to the screen
+    canvas.set_render_target(RenderTarget::Screen);
+    canvas.reset_scissor();
+    canvas.clear_rect(0, 0, window_width as u32, window_height as u32, clear_color.into());
+    let mut path = Path::new();
+    path.rect(0.0, 0.0, window_width, window_height);
+    canvas.fill_path(
+        &mut path,
+        &Paint::image(frame_image, 0.0, 0.0, window_width, window_height, 0.0, 1.0),
+    );
+
+    // Read back the colours sampled for eyedroppers, before anything is drawn over the frame
+    let samples = std::mem::take(&mut cx.draw_cache.color_samples);
+    if !samples.is_empty() {
+        if let Ok(screenshot) = canvas.screenshot() {
+            for (entity, x, y) in samples {
+                let x = (x.max(0.0) as usize).min(screenshot.width().saturating_sub(1));
+                let y = (y.max(0.0) as usize).min(screenshot.height().saturating_sub(1));
+                if let Some(pixel) = screenshot.buf().get(y * screenshot.stride() + x) {
+                    let color = Color::rgba(pixel.r, pixel.g, pixel.b, pixel.a);
+                    cx.emit_to(entity, SampledColor(color));
+                }
+            }
+        }
+    }
+
+    // Highlight the redrawn region until the next frame
+    if cx.draw_cache.paint_flashing {
+        if let Some(damage) = damage {
+            let mut path = Path::new();
+            path.rect(damage.x, damage.y, damage.w, damage.h);
+            canvas.fill_path(&mut path, &Paint::color(femtovg::Color::rgba(255, 0, 255, 64)));
+            cx.style.needs_redraw = true;
+        }
     }
 
     canvas.flush();
 
+    cx.canvases.insert(Entity::root(), canvas);
+
     //cx.resource_manager.evict_unused_images();
 }
+
+// Returns true if the entity is drawn, along with its descendants, into a layer of its own in the
+// current frame.
+fn is_layer_root(cx: &Context, entity: Entity) -> bool {
+    cx.draw_cache.layer_roots.contains(&entity)
+}
+
+// Returns true if a view needs its own layer, because it is cached or because its clip shape cuts
+// into what is drawn within it. Anything else is drawn straight into the frame, clipped to its
+// rectangular clip region.
+fn needs_layer(cx: &Context, entity: Entity) -> bool {
+    if cx.style.cache_layer.get(entity).copied().unwrap_or_default() {
+        return true;
+    }
+
+    let shape = match cx.cache.get_clip_shape(entity) {
+        Some(shape) => shape,
+        None => return false,
+    };
+
+    // The shape is in the untransformed coordinates of the view, while footprints are in window
+    // coordinates
+    if cx.cache.get_transform(entity) != Transform2D::identity() {
+        return true;
+    }
+
+    // A view which hides its overflow has a shape matching its own border radius, so only a clip
+    // path can cut into the view itself. Shapes are convex, so an area is within the shape if its
+    // corners are.
+    let clip_path = cx.style.clip_path.get(entity).is_some();
+    let z_index = cx.cache.get_z_index(entity);
+    entity.branch_iter(&cx.tree).any(|descendant| {
+        if descendant == entity && !clip_path {
+            return false;
+        }
+
+        // Views which aren't drawn into the layer of the entity aren't clipped by its shape
+        if cx.cache.get_z_index(descendant) != z_index || in_overlay_within(cx, descendant, entity)
+        {
+            return false;
+        }
+
+        cx.draw_cache.footprints.get(&descendant).map_or(false, |footprint| {
+            let area = footprint.area;
+            [area.top_left(), area.top_right(), area.bottom_left(), area.bottom_right()]
+                .iter()
+                .any(|&(x, y)| !shape.contains(x, y))
+        })
+    })
+}
+
+// Returns true if the entity, or any of its ancestors below the given ancestor, is in the overlay
+// layer.
+fn in_overlay_within(cx: &Context, entity: Entity, ancestor: Entity) -> bool {
+    entity
+        .parent_iter(&cx.tree)
+        .take_while(|&parent| parent != ancestor)
+        .any(|parent| cx.style.overlay.get(parent).copied().unwrap_or_default())
+}
+
+// Returns the innermost view with a layer which contains the entity, including the entity itself.
+// Views in the overlay layer aren't drawn into the layers of their ancestors, and neither are views
+// with a different z-index than the root of the layer, which are drawn at their own z position.
+fn layer_root(cx: &Context, entity: Entity) -> Option<Entity> {
+    let z_index = cx.cache.get_z_index(entity);
+    for ancestor in entity.parent_iter(&cx.tree) {
+        if is_layer_root(cx, ancestor) {
+            return (cx.cache.get_z_index(ancestor) == z_index).then(|| ancestor);
+        }
+
+        if cx.style.overlay.get(ancestor).copied().unwrap_or_default() {
+            break;
+        }
+    }
+
+    None
+}
+
+// Returns the root of the layer an entity is drawn into, if any. The root of a layer is drawn into
+// its own layer, which is composited into the layer containing it.
+fn parent_layer(cx: &Context, entity: Entity) -> Option<Entity> {
+    if !is_layer_root(cx, entity) {
+        return layer_root(cx, entity);
+    }
+
+    if cx.style.overlay.get(entity).copied().unwrap_or_default() {
+        return None;
+    }
+
+    let z_index = cx.cache.get_z_index(entity);
+    cx.tree
+        .get_layout_parent(entity)
+        .and_then(|parent| layer_root(cx, parent))
+        .filter(|&root| cx.cache.get_z_index(root) == z_index)
+}
+
+// Returns the transform from window coordinates to the image of a layer with the given area. Views
+// are drawn into a layer relative to the untransformed bounds of its root view.
+fn layer_transform(cx: &Context, root: Entity, area: BoundingBox) -> Transform2D {
+    let bounds = cx.cache.get_bounds(root);
+    let mut to_layer = cx.cache.get_transform(root);
+    to_layer.inverse();
+    to_layer.multiply(&Transform2D::new(
+        1.0,
+        0.0,
+        0.0,
+        1.0,
+        -(bounds.x + area.x),
+        -(bounds.y + area.y),
+    ));
+
+    to_layer
+}
+
+// Returns the region of the render target an entity is clipped to, given the transform from window
+// coordinates to the target. When compositing the layer of a view with a clip shape the shape
+// replaces its own clipping, so only the clip region of its parent applies.
+fn target_clip_region(
+    cx: &Context,
+    entity: Entity,
+    composited: bool,
+    to_target: &Transform2D,
+) -> BoundingBox {
+    let clipped = if composited && cx.cache.get_clip_shape(entity).is_some() {
+        if cx.style.overlay.get(entity).copied().unwrap_or_default() {
+            Entity::root()
+        } else {
+            cx.tree.get_layout_parent(entity).unwrap_or(Entity::root())
+        }
+    } else {
+        entity
+    };
+
+    let mut transform = cx.cache.get_clip_transform(clipped);
+    transform.multiply(to_target);
+    transform_area(&transform, cx.cache.get_clip_region(clipped))
+}
+
+fn create_image(canvas: &mut Canvas, size: (usize, usize)) -> ImageId {
+    canvas
+        .create_image_empty(
+            size.0,
+            size.1,
+            PixelFormat::Rgba8,
+            ImageFlags::FLIP_Y | ImageFlags::PREMULTIPLIED,
+        )
+        .expect("Failed to create image")
+}
+
+// Draws an entity with the given transform, clipped to the given region of the render target.
+fn draw_entity(
+    cx: &mut Context,
+    canvas: &mut Canvas,
+    entity: Entity,
+    transform: Transform2D,
+    clip_region: BoundingBox,
+) {
+    canvas.scissor(clip_region.x, clip_region.y, clip_region.w, clip_region.h);
+
+    // Apply transform
+    canvas.save();
+    canvas.set_transform(
+        transform[0],
+        transform[1],
+        transform[2],
+        transform[3],
+        transform[4],
+        transform[5],
+    );
+
+    if let Some(view) = cx.views.remove(&entity) {
+        cx.current = entity;
+        view.draw(
+            &mut DrawContext {
+                current: cx.current,
+                captured: &cx.captured,
+                focused: &cx.focused,
+                hovered: &cx.hovered,
+                style: &cx.style,
+                cache: &mut cx.cache,
+                draw_cache: &mut cx.draw_cache,
+                tree: &cx.tree,
+                data: &cx.data,
+                views: &cx.views,
+                resource_manager: &cx.resource_manager,
+                text_context: &mut cx.text_context,
+                modifiers: &cx.modifiers,
+                mouse: &cx.mouse,
+            },
+            canvas,
+        );
+
+        cx.views.insert(entity, view);
+    }
+
+    canvas.restore();
+}
+
+// Renders the views of a layer into its image, compositing the layers of any views within it which
+// have their own. The views are drawn relative to the untransformed bounds of the root view, and a
+// cached layer without the opacity of its root, which are both applied when compositing.
+fn render_layer(
+    cx: &mut Context,
+    canvas: &mut Canvas,
+    root: Entity,
+    members: &[Entity],
+    layer: &Layer,
+) {
+    let (width, height) = canvas.image_size(layer.image).expect("Failed to get image");
+    let local = BoundingBox { x: 0.0, y: 0.0, w: width as f32, h: height as f32 };
+
+    canvas.set_render_target(RenderTarget::Image(layer.image));
+    cx.draw_cache.layer_target = Some(layer.image);
+    canvas.reset_scissor();
+    canvas.clear_rect(0, 0, width as u32, height as u32, femtovg::Color::rgba(0, 0, 0, 0));
+
+    let to_layer = layer_transform(cx, root, layer.area);
+
+    let cached = cx.style.cache_layer.get(root).copied().unwrap_or_default();
+    let opacity = cx.cache.get_opacity(root);
+    if cached {
+        cx.cache.set_opacity(root, 1.0);
+    }
+
+    for &entity in members {
+        let composited = entity != root && is_layer_root(cx, entity);
+        let clip_region =
+            target_clip_region(cx, entity, composited, &to_layer).intersection(&local);
+        if clip_region.height() == 0.0 || clip_region.width() == 0.0 {
+            continue;
+        }
+
+        if composited {
+            composite_layer(cx, canvas, entity, &to_layer, clip_region);
+        } else {
+            let mut transform = cx.cache.get_transform(entity);
+            transform.multiply(&to_layer);
+            draw_entity(cx, canvas, entity, transform, clip_region);
+        }
+    }
+
+    cx.cache.set_opacity(root, opacity);
+    cx.draw_cache.layer_target = None;
+    canvas.set_render_target(cx.draw_cache.render_target());
+}
+
+// Draws the image of a layer with the transform of its root view, filling the clip shape of the root
+// view if it has one. A cached layer is also drawn with the opacity of its root view.
+fn composite_layer(
+    cx: &Context,
+    canvas: &mut Canvas,
+    root: Entity,
+    to_target: &Transform2D,
+    clip_region: BoundingBox,
+) {
+    let layer = match cx.draw_cache.layers.get(&root) {
+        Some(layer) => layer,
+        None => return,
+    };
+
+    let bounds = cx.cache.get_bounds(root);
+    let (width, height) = canvas.image_size(layer.image).expect("Failed to get image");
+    let (width, height) = (width as f32, height as f32);
+    let x = bounds.x + layer.area.x;
+    let y = bounds.y + layer.area.y;
+
+    canvas.scissor(clip_region.x, clip_region.y, clip_region.w, clip_region.h);
+
+    let mut transform = cx.cache.get_transform(root);
+    transform.multiply(to_target);
+    canvas.save();
+    canvas.set_transform(
+        transform[0],
+        transform[1],
+        transform[2],
+        transform[3],
+        transform[4],
+        transform[5],
+    );
+
+    let mut path = match cx.cache.get_clip_shape(root) {
+        Some(shape) => shape.path(),
+        None => {
+            let mut path = Path::new();
+            path.rect(x, y, width, height);
+            path
+        }
+    };
+
+    let opacity = if cx.style.cache_layer.get(root).copied().unwrap_or_default() {
+        cx.cache.get_opacity(root)
+    } else {
+        1.0
+    };
+
+    canvas.fill_path(&mut path, &Paint::image(layer.image, x, y, width, height, 0.0, opacity));
+
+    canvas.restore();
+}
+
+// Returns the bounding box of an area after it has been transformed.
+pub(crate) fn transform_area(transform: &Transform2D, area: BoundingBox) -> BoundingBox {
+    let corners = [area.top_left(), area.top_right(), area.bottom_left(), area.bottom_right()]
+        .map(|(x, y)| transform.transform_point(x, y));
+    BoundingBox::from_min_max(
+        corners.iter().map(|corner| corner.0).fold(f32::INFINITY, f32::min),
+        corners.iter().map(|corner| corner.1).fold(f32::INFINITY, f32::min),
+        corners.iter().map(|corner| corner.0).fold(f32::NEG_INFINITY, f32::max),
+        corners.iter().map(|corner| corner.1).fold(f32::NEG_INFINITY, f32::max),
+    )
+}
+
+// Returns how far an entity can draw outside of its bounds.
+fn visual_margin(cx: &Context, entity: Entity) -> f32 {
+    let scale = cx.style.dpi_factor as f32;
+    let pixels = |units: Option<&Units>| match units {
+        Some(Units::Pixels(val)) => val.abs() * scale,
+        _ => 0.0,
+    };
+
+    // Borders are stroked over the edge of the bounds, while outlines and shadows extend past it
+    let border = pixels(cx.style.border_width.get(entity)) / 2.0;
+    let outline =
+        pixels(cx.style.outline_width.get(entity)) + pixels(cx.style.outline_offset.get(entity));
+    let shadow = if cx.style.outer_shadow_color.get(entity).is_some() {
+        pixels(cx.style.outer_shadow_h_offset.get(entity))
+            .max(pixels(cx.style.outer_shadow_v_offset.get(entity)))
+            + pixels(cx.style.outer_shadow_blur.get(entity)) * 1.25
+    } else {
+        0.0
+    };
+
+    // One extra pixel covers anti-aliasing
+    border.max(outline).max(shadow) + 1.0
+}
+
+// Returns the area of the window an entity can draw to, along with the state which determines
+// how it is drawn there.
+fn footprint(cx: &mut Context, entity: Entity) -> Footprint {
+    let bounds = cx.cache.get_bounds(entity);
+    let mut area = bounds.expand(visual_margin(cx, entity));
+
+    // Text can overflow the bounds, so allow for the whole of the text in any alignment
+    if cx.text_context.has_buffer(entity) {
+        let (text_width, text_height) = cx.text_context.with_buffer(entity, |buf| {
+            let w = buf
+                .layout_runs()
+                .filter_map(|r| (!r.line_w.is_nan()).then(|| r.line_w))
+                .fold(0.0, f32::max);
+            let h = buf.layout_runs().len() as f32 * buf.metrics().line_height as f32;
+            (w, h)
+        });
+
+        let overflow = bounds
+            .expand_horizontal((text_width - bounds.w).max(0.0))
+            .expand_vertical((text_height - bounds.h).max(0.0));
+        area = area.union(&overflow);
+    }
+
+    // Transform the area into window coordinates
+    let transform = cx.cache.get_transform(entity);
+    let area = transform_area(&transform, area);
+
+    Footprint {
+        area: area.intersection(&target_clip_region(cx, entity, false, &Transform2D::identity())),
+        transform,
+        opacity: cx.cache.get_opacity(entity),
+        z_index: cx.cache.get_z_index(entity),
+    }
+}
@@ -0,0 +1,185 @@
+use morphorm::{Hierarchy, LayoutType, PositionType, Units};
+use vizia_storage::LayoutTreeIterator;
+
+use crate::layout::grid::{place_grid_items, resolve_grid_tracks, GridItem, GridPlacement};
+use crate::layout::node::grid_track_units;
+use crate::prelude::*;
+use crate::style::Style;
+
+// Places the children of the grids within a subtree which is about to be laid out, and sets the
+// tracks of the grids, adding implicit tracks for children placed beyond the explicit ones. The
+// root keeps its own placement, as it's placed by its parent.
+pub(crate) fn grid_system(cx: &mut Context, tree: &Tree<Entity>, root: Entity) {
+    let scale = cx.style.dpi_factor as f32;
+
+    for entity in LayoutTreeIterator::subtree(tree, root) {
+        if entity != root {
+            cx.style.grid_placement.remove(entity);
+        }
+
+        cx.style.grid_track_rows.remove(entity);
+        cx.style.grid_track_cols.remove(entity);
+    }
+
+    let entities = LayoutTreeIterator::subtree(tree, root).collect::<Vec<_>>();
+    for container in entities {
+        if cx.style.layout_type.get(container) != Some(&LayoutType::Grid) {
+            continue;
+        }
+
+        let style = &cx.style;
+        let areas = style.grid_areas.get(container);
+        let explicit_rows = style
+            .grid_rows
+            .get(container)
+            .map_or(0, |rows| rows.len())
+            .max(areas.map_or(0, |a| a.rows()));
+        let explicit_cols = style
+            .grid_cols
+            .get(container)
+            .map_or(0, |cols| cols.len())
+            .max(areas.map_or(0, |a| a.cols()));
+        let flow = style.grid_auto_flow.get(container).copied().unwrap_or_default();
+
+        let mut children = Vec::new();
+        let mut items = Vec::new();
+        for child in tree.child_iter(container) {
+            if cx.cache.get_display(child) == Display::None
+                || style.position_type.get(child) == Some(&PositionType::SelfDirected)
+            {
+                continue;
+            }
+
+            let row_span = style.row_span.get(child).copied().unwrap_or(1).max(1);
+            let col_span = style.col_span.get(child).copied().unwrap_or(1).max(1);
+            let row = style.row_index.get(child).copied();
+            let col = style.col_index.get(child).copied();
+            let area = style
+                .grid_area
+                .get(child)
+                .and_then(|name| areas.and_then(|areas| areas.area(name)));
+
+            // An explicit row or column takes precedence over a named area
+            let item = if row.is_some() || col.is_some() {
+                GridItem::Placed(GridPlacement {
+                    row: row.unwrap_or(0),
+                    col: col.unwrap_or(0),
+                    row_span,
+                    col_span,
+                })
+            } else if let Some((row, col, row_span, col_span)) = area {
+                GridItem::Placed(GridPlacement { row, col, row_span, col_span })
+            } else {
+                GridItem::Auto { row_span, col_span }
+            };
+
+            children.push(child);
+            items.push(item);
+        }
+
+        let (placements, rows, cols) = place_grid_items(explicit_rows, explicit_cols, flow, &items);
+
+        let row_tracks = grid_tracks(style, container, true, rows);
+        let col_tracks = grid_tracks(style, container, false, cols);
+
+        for (child, placement) in children.into_iter().zip(placements) {
+            cx.style.grid_placement.insert(child, placement).expect("Failed to set grid placement");
+        }
+
+        cx.style
+            .grid_track_rows
+            .insert(container, grid_track_units(&row_tracks, scale))
+            .expect("Failed to set grid rows");
+        cx.style
+            .grid_track_cols
+            .insert(container, grid_track_units(&col_tracks, scale))
+            .expect("Failed to set grid columns");
+    }
+}
+
+// Resolves the tracks with a minimum and maximum size of the grids within a subtree which has just
+// been laid out, using the sizes the grids were given. Returns whether any tracks were resolved, in
+// which case the subtree needs to be laid out again to apply them.
+pub(crate) fn grid_track_system(cx: &mut Context, tree: &Tree<Entity>, root: Entity) -> bool {
+    let scale = cx.style.dpi_factor as f32;
+    let mut resolved = false;
+
+    for container in LayoutTreeIterator::subtree(tree, root) {
+        if cx.style.layout_type.get(container) != Some(&LayoutType::Grid)
+            || cx.cache.get_display(container) == Display::None
+        {
+            continue;
+        }
+
+        let bounds = cx.cache.get_bounds(container);
+        for horizontal in [false, true] {
+            let style = &cx.style;
+            let (tracks, count) = if horizontal {
+                (style.grid_cols.get(container), style.grid_track_cols.get(container))
+            } else {
+                (style.grid_rows.get(container), style.grid_track_rows.get(container))
+            };
+
+            let has_min_max = tracks.map_or(false, |tracks| {
+                tracks.iter().any(|track| matches!(track, GridTrack::MinMax(..)))
+            });
+            if !has_min_max {
+                continue;
+            }
+
+            let count = count.map_or(0, |count| count.len());
+            let tracks = grid_tracks(style, container, !horizontal, count);
+
+            let fixed = |units: Option<&Units>, parent: f32| match units {
+                Some(Units::Pixels(val)) => val * scale,
+                Some(Units::Percentage(val)) => parent * val / 100.0,
+                _ => 0.0,
+            };
+            let available = if horizontal {
+                bounds.w
+                    - fixed(style.child_left.get(container), bounds.w)
+                    - fixed(style.child_right.get(container), bounds.w)
+                    - fixed(style.col_between.get(container), bounds.w)
+                        * count.saturating_sub(1) as f32
+            } else {
+                bounds.h
+                    - fixed(style.child_top.get(container), bounds.h)
+                    - fixed(style.child_bottom.get(container), bounds.h)
+                    - fixed(style.row_between.get(container), bounds.h)
+                        * count.saturating_sub(1) as f32
+            };
+
+            let sizes = resolve_grid_tracks(&tracks, available.max(0.0), scale);
+            if horizontal {
+                cx.style
+                    .grid_track_cols
+                    .insert(container, sizes)
+                    .expect("Failed to set grid columns");
+            } else {
+                cx.style.grid_track_rows.insert(container, sizes).expect("Failed to set grid rows");
+            }
+
+            resolved = true;
+        }
+    }
+
+    resolved
+}
+
+// Returns the rows or columns of a grid, extended to the given count with the implicit track size,
+// which stretches by default.
+fn grid_tracks(style: &Style, container: Entity, rows: bool, count: usize) -> Vec<GridTrack> {
+    let (explicit, implicit) = if rows {
+        (style.grid_rows.get(container), style.grid_auto_rows.get(container))
+    } else {
+        (style.grid_cols.get(container), style.grid_auto_cols.get(container))
+    };
+
+    let mut tracks = explicit.cloned().unwrap_or_default();
+    let implicit = implicit.copied().unwrap_or(Units::Stretch(1.0));
+    while tracks.len() < count {
+        tracks.push(GridTrack::Size(implicit));
+    }
+
+    tracks
+}
@@ -47,15 +47,19 @@ pub fn hover_system(cx: &mut Context) {
         let height = cx.cache.get_height(entity);
 
         let clip_region = cx.cache.get_clip_region(entity);
+        let mut clip_transform = cx.cache.get_clip_transform(entity);
+        clip_transform.inverse();
+        let (clipx, clipy) = clip_transform.transform_point(cursorx, cursory);
 
         if tx >= posx
-            && tx >= clip_region.x
+            && clipx >= clip_region.x
             && tx < (posx + width)
-            && tx < (clip_region.x + clip_region.w)
+            && clipx < (clip_region.x + clip_region.w)
             && ty >= posy
-            && ty >= clip_region.y
+            && clipy >= clip_region.y
             && ty < (posy + height)
-            && ty < (clip_region.y + clip_region.h)
+            && clipy < (clip_region.y + clip_region.h)
+            && within_clip_shapes(cx, entity, cursorx, cursory)
         {
             hovered_widget = entity;
             if cx
@@ -76,6 +80,7 @@ pub fn hover_system(cx: &mut Context) {
                 if let Some(pseudo_class) = cx.style.pseudo_classes.get_mut(entity) {
                     pseudo_class.set(PseudoClass::OVER, true);
                 }
+                cx.style.restyle(entity);
             }
         } else {
             if cx
@@ -94,6 +99,7 @@ pub fn hover_system(cx: &mut Context) {
                 if let Some(pseudo_class) = cx.style.pseudo_classes.get_mut(entity) {
                     pseudo_class.set(PseudoClass::OVER, false);
                 }
+                cx.style.restyle(entity);
             }
         }
     }
@@ -137,8 +143,33 @@ pub fn hover_system(cx: &mut Context) {
         cx.event_queue.push_back(Event::new(WindowEvent::MouseEnter).target(hovered_widget));
         cx.event_queue.push_back(Event::new(WindowEvent::MouseLeave).target(cx.hovered));
 
+        cx.style.restyle(hovered_widget);
+        cx.style.restyle(cx.hovered);
+
         cx.hovered = hovered_widget;
+    }
+}
+
+// Returns true if the cursor is within the clip shapes of the entity and its ancestors, up to the
+// overlay layer the entity is in, if any. As when drawing, the shapes of ancestors with a different
+// z-index don't apply.
+fn within_clip_shapes(cx: &Context, entity: Entity, cursorx: f32, cursory: f32) -> bool {
+    let z_index = cx.cache.get_z_index(entity);
+    for ancestor in entity.parent_iter(&cx.tree) {
+        let shape = cx.cache.get_clip_shape(ancestor);
+        if let Some(shape) = shape.filter(|_| cx.cache.get_z_index(ancestor) == z_index) {
+            let mut transform = cx.cache.get_transform(ancestor);
+            transform.inverse();
+            let (x, y) = transform.transform_point(cursorx, cursory);
+            if !shape.contains(x, y) {
+                return false;
+            }
+        }
 
-        cx.style.needs_restyle = true;
+        if cx.style.overlay.get(ancestor).copied().unwrap_or_default() {
+            break;
+        }
     }
+
+    true
 }
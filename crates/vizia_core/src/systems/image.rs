@@ -1,4 +1,6 @@
 use crate::context::Context;
+#[cfg(feature = "svg")]
+use crate::resource::rasterize_svg;
 use crate::resource::{ImageRetentionPolicy, StoredImage};
 use crate::{prelude::*, resource::ImageOrId};
 use std::collections::HashSet;
@@ -6,6 +8,9 @@ use vizia_id::GenerationalId;
 
 // Iterate he tree and load any images used by entities which aren't already loaded. Remove any images no longer being used.
 pub fn image_system(cx: &mut Context) {
+    #[cfg(feature = "svg")]
+    rescale_svgs(cx);
+
     cx.resource_manager.mark_images_unused();
 
     // Iterate the tree and load any defined images that aren't already loaded
@@ -19,11 +24,53 @@ pub fn image_system(cx: &mut Context) {
         if let Some(image_name) = cx.style.image.get(entity).cloned() {
             load_image(cx, entity, &image_name);
         }
+
+        // Load a border-image if the entity has one
+        if let Some(border_image) = cx.style.border_image.get(entity).cloned() {
+            load_image(cx, entity, &border_image);
+        }
     }
 
     cx.resource_manager.evict_unused_images();
 }
 
+// Rasterize SVG images again if the scale factor has changed since they were last rasterized.
+#[cfg(feature = "svg")]
+fn rescale_svgs(cx: &mut Context) {
+    let scale = cx.style.dpi_factor;
+    for (name, svg) in cx.resource_manager.svgs.iter_mut() {
+        if svg.scale == scale {
+            continue;
+        }
+
+        svg.scale = scale;
+        if let Some(image_store) = cx.resource_manager.images.get_mut(name) {
+            // Keep the old rasterization if the new scale is too large to rasterize.
+            let image = match rasterize_svg(&svg.tree, scale) {
+                Some(image) => image,
+                None => continue,
+            };
+
+            // Free the texture of the old rasterization
+            if let ImageOrId::Id(id, _) = image_store.image {
+                if let Some(canvas) = cx.canvases.get_mut(&Entity::root()) {
+                    canvas.delete_image(id);
+                }
+            }
+
+            image_store.image = ImageOrId::Image(
+                image,
+                femtovg::ImageFlags::REPEAT_X | femtovg::ImageFlags::REPEAT_Y,
+            );
+            image_store.dirty = true;
+            for &observer in image_store.observers.iter() {
+                cx.style.relayout(observer);
+                cx.style.redraw(observer);
+            }
+        }
+    }
+}
+
 fn load_image(cx: &mut Context, entity: Entity, image_name: &String) {
     if !try_load_image(cx, entity, image_name) {
         // Image doesn't exists yet so call the image loader
@@ -55,8 +102,8 @@ fn try_load_image(cx: &mut Context, entity: Entity, image_name: &str) -> bool {
                     // This loads the image and sets the image id
                     image_store.image.id(canvas);
                     image_store.used = true;
-                    cx.need_relayout();
-                    cx.need_redraw();
+                    cx.style.relayout(entity);
+                    cx.style.redraw(entity);
                 }
             }
         }
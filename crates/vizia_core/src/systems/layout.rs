@@ -1,19 +1,57 @@
-use morphorm::layout;
+use morphorm::{layout, Cache, GeometryChanged};
+use vizia_id::GenerationalId;
+use vizia_storage::{LayoutTreeIterator, Subtree, TreeExt};
 
 use crate::prelude::*;
+use crate::storage::animatable_set::AnimatableSet;
+use crate::style::Style;
 
-use super::text_constraints_system;
+use super::{
+    aspect_system, clear_aspect_sizes, clear_wrap_placements, grid_system, grid_track_system,
+    sticky_system, text_constraints_system, wrap_system,
+};
 
 pub(crate) fn layout_system(cx: &mut Context, tree: &Tree<Entity>) {
-    if cx.style.needs_relayout {
-        text_constraints_system(cx, tree);
+    let roots = if cx.style.needs_relayout {
+        vec![Entity::root()]
+    } else {
+        relayout_roots(&cx.style, tree)
+    };
 
-        layout(&mut cx.cache, &cx.tree, &cx.style, &mut cx.text_context);
+    cx.style.needs_relayout = false;
+    cx.style.relayout_list.clear();
 
-        cx.style.needs_relayout = false;
+    for &root in roots.iter() {
+        let measured = text_constraints_system(cx, tree, root);
+        clear_wrap_placements(cx, tree, root);
+        clear_aspect_sizes(cx, tree, root);
+        grid_system(cx, tree, root);
 
-        for entity in cx.tree.into_iter() {
-            if cx.text_context.has_buffer(entity) {
+        layout(&mut cx.cache, &Subtree::new(tree, root), &cx.style, &mut cx.text_context);
+
+        // Wrapping layouts are laid out again once their children have been placed in lines, and
+        // grids once the tracks with a minimum and maximum size have been resolved
+        let wrapped = wrap_system(cx, tree, root);
+        let resized = grid_track_system(cx, tree, root);
+        if wrapped || resized {
+            layout(&mut cx.cache, &Subtree::new(tree, root), &cx.style, &mut cx.text_context);
+        }
+
+        // Views with an aspect ratio are sized once the space they stretch into is known, after
+        // which the lines of wrapping layouts are broken again with their new sizes
+        if aspect_system(cx, tree, root) {
+            layout(&mut cx.cache, &Subtree::new(tree, root), &cx.style, &mut cx.text_context);
+            if wrap_system(cx, tree, root) {
+                layout(&mut cx.cache, &Subtree::new(tree, root), &cx.style, &mut cx.text_context);
+            }
+        }
+
+        // Text is wrapped to the width of its entity, so only needs relaying out when that changes
+        for entity in LayoutTreeIterator::subtree(tree, root) {
+            if cx.text_context.has_buffer(entity)
+                && (measured.contains(&entity)
+                    || cx.cache.geometry_changed(entity).contains(GeometryChanged::WIDTH_CHANGED))
+            {
                 let w = cx.cache.bounds.get(entity).unwrap().w;
                 cx.text_context.with_buffer(entity, |buf| {
                     buf.set_size(w as i32, i32::MAX);
@@ -21,4 +59,137 @@ pub(crate) fn layout_system(cx: &mut Context, tree: &Tree<Entity>) {
             }
         }
     }
+
+    if !roots.is_empty() {
+        sticky_system(cx, tree, &roots);
+    }
+}
+
+// Returns the roots of the subtrees which need to be laid out again for the entities marked as
+// needing layout. An entity is positioned by its parent, and any ancestor sized by its content may
+// change size along with it, so layout starts from the nearest ancestor with a size which doesn't
+// depend on its children.
+fn relayout_roots(style: &Style, tree: &Tree<Entity>) -> Vec<Entity> {
+    let mut roots = Vec::new();
+    for &entity in style.relayout_list.iter() {
+        let mut root = tree.get_layout_parent(entity).unwrap_or(Entity::root());
+        while root != Entity::root() && is_content_sized(style, tree, root) {
+            root = tree.get_layout_parent(root).unwrap_or(Entity::root());
+        }
+
+        if !roots.contains(&root) {
+            roots.push(root);
+        }
+    }
+
+    // Subtrees within another subtree are laid out along with it
+    let all = roots.clone();
+    roots.retain(|root| {
+        !all.iter().any(|other| other != root && root.is_descendant_of(tree, *other))
+    });
+
+    roots
+}
+
+// Returns true if the size of an entity may depend on the size of its children. Unset sizes
+// stretch, down to a minimum of the content when the minimum is unset. A stretching entity is
+// taken to be sized by its content only when its parent is, as otherwise its content only changes
+// its size when it overflows the space it stretches into, and the layout of the rest of the tree
+// doesn't follow overflowing content.
+fn is_content_sized(style: &Style, tree: &Tree<Entity>, entity: Entity) -> bool {
+    let parent = tree.get_layout_parent(entity).unwrap_or(Entity::root());
+    let depends_on_content = |size: &AnimatableSet<Units>,
+                              min: &AnimatableSet<Units>,
+                              max: &AnimatableSet<Units>| {
+        let size_of = |entity| size.get(entity).copied().unwrap_or(Units::Stretch(1.0));
+        match size_of(entity) {
+            Units::Auto => true,
+            Units::Stretch(_) if min.get(entity).copied().unwrap_or(Units::Auto) == Units::Auto => {
+                parent != Entity::root() && size_of(parent) == Units::Auto
+            }
+            _ => max.get(entity) == Some(&Units::Auto),
+        }
+    };
+
+    depends_on_content(&style.width, &style.min_width, &style.max_width)
+        || depends_on_content(&style.height, &style.min_height, &style.max_height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Builds a chain of entities, each the child of the one before it, below the root.
+    fn chain(tree: &mut Tree<Entity>, len: u32) -> Vec<Entity> {
+        let mut parent = Entity::root();
+        (1..=len)
+            .map(|index| {
+                let entity = Entity::new(index, 0);
+                tree.add(entity, parent).unwrap();
+                parent = entity;
+                entity
+            })
+            .collect()
+    }
+
+    #[test]
+    fn starts_at_parent_with_fixed_size() {
+        let mut tree = Tree::new();
+        let mut style = Style::default();
+        let entities = chain(&mut tree, 3);
+        style.width.insert(entities[0], Units::Auto);
+        style.height.insert(entities[0], Units::Auto);
+        style.width.insert(entities[1], Units::Pixels(100.0));
+        style.height.insert(entities[1], Units::Pixels(100.0));
+        style.relayout_list.insert(entities[2]);
+
+        assert_eq!(relayout_roots(&style, &tree), vec![entities[1]]);
+    }
+
+    #[test]
+    fn climbs_through_auto_sized_ancestors() {
+        let mut tree = Tree::new();
+        let mut style = Style::default();
+        let entities = chain(&mut tree, 4);
+        style.width.insert(entities[0], Units::Pixels(100.0));
+        style.height.insert(entities[0], Units::Pixels(100.0));
+        style.width.insert(entities[1], Units::Auto);
+        style.height.insert(entities[2], Units::Auto);
+        style.relayout_list.insert(entities[3]);
+
+        assert_eq!(relayout_roots(&style, &tree), vec![entities[0]]);
+    }
+
+    #[test]
+    fn stops_at_stretching_ancestors() {
+        let mut tree = Tree::new();
+        let mut style = Style::default();
+        let entities = chain(&mut tree, 3);
+        style.relayout_list.insert(entities[2]);
+
+        assert_eq!(relayout_roots(&style, &tree), vec![entities[1]]);
+
+        // A stretching entity within an auto sized parent grows with its content
+        style.width.insert(entities[0], Units::Auto);
+        style.height.insert(entities[0], Units::Auto);
+        assert_eq!(relayout_roots(&style, &tree), vec![Entity::root()]);
+    }
+
+    #[test]
+    fn removes_nested_roots() {
+        let mut tree = Tree::new();
+        let mut style = Style::default();
+        let entities = chain(&mut tree, 4);
+        for &entity in entities.iter() {
+            style.width.insert(entity, Units::Pixels(100.0));
+            style.height.insert(entity, Units::Pixels(100.0));
+        }
+        style.relayout_list.insert(entities[1]);
+        style.relayout_list.insert(entities[3]);
+
+        assert_eq!(relayout_roots(&style, &tree), vec![entities[0]]);
+
+        style.relayout_list.insert(entities[0]);
+        assert_eq!(relayout_roots(&style, &tree), vec![Entity::root()]);
+    }
 }
@@ -1,24 +1,36 @@
+pub(crate) mod anchor;
 pub(crate) mod animation;
+pub(crate) mod aspect;
 pub(crate) mod clipping;
 pub(crate) mod draw;
+pub(crate) mod grid;
 pub(crate) mod hover;
 pub(crate) mod image;
 pub(crate) mod layout;
+pub(crate) mod sticky;
 pub(crate) mod style;
 pub(crate) mod text_constraints;
+pub(crate) mod tooltip;
 pub(crate) mod transform;
 pub(crate) mod visibility;
+pub(crate) mod wrap;
 pub(crate) mod z_order;
 
 pub(crate) use self::image::*;
+pub(crate) use anchor::*;
 pub use animation::has_animations;
 pub(crate) use animation::*;
+pub(crate) use aspect::*;
 pub(crate) use clipping::*;
 pub(crate) use draw::*;
+pub(crate) use grid::*;
 pub(crate) use hover::*;
 pub(crate) use layout::*;
+pub(crate) use sticky::*;
 pub(crate) use style::*;
 pub(crate) use text_constraints::*;
+pub(crate) use tooltip::*;
 pub(crate) use transform::*;
 pub(crate) use visibility::*;
+pub(crate) use wrap::*;
 pub(crate) use z_order::*;
@@ -0,0 +1,79 @@
+use morphorm::{Cache, GeometryChanged};
+use vizia_storage::LayoutTreeIterator;
+
+use crate::prelude::*;
+
+// Moves sticky views, along with their descendants, to stay within the edges of their nearest scroll
+// view. This runs after layout with the roots of the subtrees which were laid out. Views within those
+// subtrees are back at their laid out positions, while any other sticky view is still moved by the
+// offset it was given last time, so the offset is subtracted to find where it was laid out.
+pub(crate) fn sticky_system(cx: &mut Context, tree: &Tree<Entity>, roots: &[Entity]) {
+    for &root in roots {
+        for entity in LayoutTreeIterator::subtree(tree, root).skip(1) {
+            cx.cache.set_sticky_offset(entity, (0.0, 0.0));
+        }
+    }
+
+    let scale = cx.style.dpi_factor as f32;
+    let resolve = |units: Option<Units>, length: f32| match units {
+        Some(Units::Pixels(val)) => Some(val * scale),
+        Some(Units::Percentage(val)) => Some(length * val / 100.0),
+        _ => None,
+    };
+
+    // Views are moved in tree order, so that a sticky view within another is placed after its
+    // ancestor has been moved
+    for entity in tree.into_iter() {
+        if cx.style.sticky.get(entity) != Some(&true)
+            || cx.cache.get_display(entity) == Display::None
+        {
+            continue;
+        }
+
+        let scroll_view = entity.parent_iter(tree).skip(1).find(|ancestor| {
+            cx.views.get(ancestor).and_then(|view| view.element()) == Some("scrollview")
+        });
+
+        let (scroll_view, parent) = match (scroll_view, tree.get_layout_parent(entity)) {
+            (Some(scroll_view), Some(parent)) => (scroll_view, parent),
+            _ => continue,
+        };
+
+        let view = cx.cache.get_bounds(scroll_view);
+        let container = cx.cache.get_bounds(parent);
+        let bounds = cx.cache.get_bounds(entity);
+        let (old_x, old_y) = cx.cache.get_sticky_offset(entity);
+        let laid_out_x = bounds.x - old_x;
+        let laid_out_y = bounds.y - old_y;
+
+        // A view is only ever moved forwards, and no further than the end of its parent
+        let offset_x = resolve(cx.style.sticky_left.get(entity).copied(), view.w)
+            .map_or(0.0, |left| {
+                ((view.x + left).min(container.right() - bounds.w) - laid_out_x).max(0.0)
+            });
+        let offset_y = resolve(cx.style.sticky_top.get(entity).copied(), view.h)
+            .map_or(0.0, |top| {
+                ((view.y + top).min(container.bottom() - bounds.h) - laid_out_y).max(0.0)
+            });
+
+        let dx = offset_x - old_x;
+        let dy = offset_y - old_y;
+        cx.cache.set_sticky_offset(entity, (offset_x, offset_y));
+        if dx == 0.0 && dy == 0.0 {
+            continue;
+        }
+
+        for node in LayoutTreeIterator::subtree(tree, entity) {
+            let bounds = cx.cache.get_bounds(node);
+            cx.cache.set_posx(node, bounds.x + dx);
+            cx.cache.set_posy(node, bounds.y + dy);
+            if dx != 0.0 {
+                cx.cache.set_geo_changed(node, GeometryChanged::POSX_CHANGED, true);
+            }
+
+            if dy != 0.0 {
+                cx.cache.set_geo_changed(node, GeometryChanged::POSY_CHANGED, true);
+            }
+        }
+    }
+}
@@ -15,6 +15,7 @@ pub fn inline_inheritance_system(cx: &mut Context, tree: &Tree<Entity>) {
             cx.style.font_style.inherit_inline(entity, parent);
             cx.style.caret_color.inherit_inline(entity, parent);
             cx.style.selection_color.inherit_inline(entity, parent);
+            cx.style.match_color.inherit_inline(entity, parent);
         }
     }
 }
@@ -29,6 +30,7 @@ pub fn shared_inheritance_system(cx: &mut Context, tree: &Tree<Entity>) {
             cx.style.font_style.inherit_shared(entity, parent);
             cx.style.caret_color.inherit_shared(entity, parent);
             cx.style.selection_color.inherit_shared(entity, parent);
+            cx.style.match_color.inherit_shared(entity, parent);
         }
     }
 }
@@ -38,24 +40,28 @@ pub fn hoverability_system(cx: &mut Context, tree: &Tree<Entity>) {
     draw_tree.sort_by_cached_key(|entity| cx.cache.get_z_index(*entity));
 
     for entity in draw_tree.into_iter() {
-        if entity == Entity::root() {
-            continue;
-        }
+        update_hoverability(cx, tree, entity);
+    }
+}
 
-        if tree.is_ignored(entity) {
-            continue;
-        }
+fn update_hoverability(cx: &mut Context, tree: &Tree<Entity>, entity: Entity) {
+    if entity == Entity::root() {
+        return;
+    }
+
+    if tree.is_ignored(entity) {
+        return;
+    }
 
-        let parent = tree.get_layout_parent(entity).unwrap();
+    let parent = tree.get_layout_parent(entity).unwrap();
 
-        if !cx.cache.get_hoverability(parent) {
-            cx.cache.set_hoverability(entity, false);
+    if !cx.cache.get_hoverability(parent) {
+        cx.cache.set_hoverability(entity, false);
+    } else {
+        if let Some(abilities) = cx.style.abilities.get(entity) {
+            cx.cache.set_hoverability(entity, abilities.contains(Abilities::HOVERABLE));
         } else {
-            if let Some(abilities) = cx.style.abilities.get(entity) {
-                cx.cache.set_hoverability(entity, abilities.contains(Abilities::HOVERABLE));
-            } else {
-                cx.cache.set_hoverability(entity, false);
-            }
+            cx.cache.set_hoverability(entity, false);
         }
     }
 }
@@ -242,6 +248,10 @@ fn link_style_data(cx: &mut Context, entity: Entity, matched_rules: &Vec<Rule>)
         should_redraw = true;
     }
 
+    if cx.style.clip_path.link(entity, &matched_rules) {
+        should_redraw = true;
+    }
+
     // Opacity
     if cx.style.opacity.link(entity, &matched_rules) {
         //println!("4");
@@ -304,6 +314,11 @@ fn link_style_data(cx: &mut Context, entity: Entity, matched_rules: &Vec<Rule>)
         should_redraw = true;
     }
 
+    if cx.style.aspect_ratio.link(entity, &matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
     if cx.style.min_height.link(entity, &matched_rules) {
         //println!("15");
         should_relayout = true;
@@ -376,12 +391,32 @@ fn link_style_data(cx: &mut Context, entity: Entity, matched_rules: &Vec<Rule>)
         should_redraw = true;
     }
 
+    if cx.style.layout_wrap.link(entity, &matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
     if cx.style.position_type.link(entity, &matched_rules) {
         //println!("30");
         should_relayout = true;
         should_redraw = true;
     }
 
+    if cx.style.sticky.link(entity, &matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if cx.style.sticky_top.link(entity, &matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if cx.style.sticky_left.link(entity, &matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
     // Background
     if cx.style.background_color.link(entity, &matched_rules) {
         //println!("41");
@@ -393,6 +428,36 @@ fn link_style_data(cx: &mut Context, entity: Entity, matched_rules: &Vec<Rule>)
         should_redraw = true;
     }
 
+    if cx.style.background_size.link(entity, &matched_rules) {
+        should_redraw = true;
+    }
+
+    if cx.style.background_position.link(entity, &matched_rules) {
+        should_redraw = true;
+    }
+
+    if cx.style.background_repeat.link(entity, &matched_rules) {
+        should_redraw = true;
+    }
+
+    // Border Image
+    if cx.style.border_image.link(entity, &matched_rules) {
+        should_redraw = true;
+    }
+
+    if cx.style.border_image_slice.link(entity, &matched_rules) {
+        should_redraw = true;
+    }
+
+    // Image
+    if cx.style.object_fit.link(entity, &matched_rules) {
+        should_redraw = true;
+    }
+
+    if cx.style.object_position.link(entity, &matched_rules) {
+        should_redraw = true;
+    }
+
     // Font
     if cx.style.font_color.link(entity, &matched_rules) {
         //println!("43");
@@ -430,6 +495,10 @@ fn link_style_data(cx: &mut Context, entity: Entity, matched_rules: &Vec<Rule>)
         should_redraw = true;
     }
 
+    if cx.style.match_color.link(entity, &matched_rules) {
+        should_redraw = true;
+    }
+
     if cx.style.caret_color.link(entity, &matched_rules) {
         should_redraw = true;
     }
@@ -506,55 +575,151 @@ fn link_style_data(cx: &mut Context, entity: Entity, matched_rules: &Vec<Rule>)
         should_redraw = true;
     }
 
+    if cx.style.grid_rows.link(entity, &matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if cx.style.grid_cols.link(entity, &matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if cx.style.grid_areas.link(entity, &matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if cx.style.grid_auto_flow.link(entity, &matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if cx.style.grid_auto_rows.link(entity, &matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if cx.style.grid_auto_cols.link(entity, &matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if cx.style.grid_area.link(entity, &matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
     if cx.style.cursor.link(entity, &matched_rules) {
         should_redraw = true;
     }
 
     if should_relayout {
-        cx.style.needs_relayout = true;
+        cx.style.relayout(entity);
     }
 
     if should_redraw {
-        cx.style.needs_redraw = true;
+        cx.style.redraw(entity);
     }
 }
 
+// Returns true if the entity could match the selector with some combination of pseudo-classes.
+fn could_match(cx: &Context, entity: Entity, selector: &Selector) -> bool {
+    if selector.asterisk {
+        return true;
+    }
+
+    if let Some(id) = &selector.id {
+        if Some(id) != cx.style.ids.get(entity) {
+            return false;
+        }
+    }
+
+    if let Some(selector_element) = &selector.element {
+        let element = if entity == Entity::root() {
+            Some("root")
+        } else {
+            cx.views.get(&entity).and_then(|view| view.element())
+        };
+
+        if element != Some(selector_element.as_str()) {
+            return false;
+        }
+    }
+
+    match cx.style.classes.get(entity) {
+        Some(classes) => selector.classes.is_subset(classes),
+        None => selector.classes.is_empty(),
+    }
+}
+
+// Returns the entities which need restyling, with every entity after its parent. A change to the
+// pseudo-classes of an entity can only change the rules matched by its descendants if it could
+// match one of the selectors which depend on pseudo-classes.
+fn restyle_entities(cx: &Context, tree: &Tree<Entity>) -> Vec<Entity> {
+    let mut roots = cx
+        .style
+        .restyle_list
+        .iter()
+        .map(|(&entity, &branch)| {
+            let branch = branch
+                || cx
+                    .style
+                    .pseudo_class_dependencies
+                    .iter()
+                    .any(|selector| could_match(cx, entity, selector));
+            (entity, branch)
+        })
+        .collect::<Vec<_>>();
+
+    // Entities within a branch which is restyled are restyled along with it
+    let branches =
+        roots.iter().filter(|(_, branch)| *branch).map(|(entity, _)| *entity).collect::<Vec<_>>();
+    roots.retain(|(entity, _)| {
+        !branches.iter().any(|other| other != entity && entity.is_descendant_of(tree, *other))
+    });
+
+    // Ancestors are restyled first, as the hoverability of an entity depends on its parent
+    roots.sort_by_cached_key(|(entity, _)| entity.parent_iter(tree).count());
+
+    let mut entities = Vec::new();
+    for (entity, branch) in roots {
+        if branch {
+            entities.extend(LayoutTreeIterator::subtree(tree, entity));
+        } else if !tree.is_ignored(entity) {
+            entities.push(entity);
+        }
+    }
+
+    entities
+}
+
 // Iterate tree and determine the matched style rules for each entity. Link the entity to the style data.
 pub fn style_system(cx: &mut Context, tree: &Tree<Entity>) {
     if cx.style.needs_restyle {
         hoverability_system(cx, tree);
 
-        let mut matched_rule_ids = Vec::with_capacity(100);
-        let mut prev_matched_rule_ids = Vec::with_capacity(100);
-
         let iterator = LayoutTreeIterator::full(tree);
 
         // Loop through all entities
         for entity in iterator {
-            // If the entity and the previous entity have the same parent and selectors then they share the same rules
-            //if let Some(prev) = prev_entity {
-            //    if let Some(parent) = tree.get_layout_parent(entity) {
-            //        if let Some(prev_parent) = tree.get_layout_parent(prev) {
-            //            if parent == prev_parent {
-            //                if entity_selector(cx, entity).same(&entity_selector(cx, prev)) {
-            //                    prev_entity = Some(entity);
-            //                    link_style_data(cx, entity, &prev_matched_rule_ids);
-            //                    continue 'ent;
-            //                }
-            //            }
-            //        }
-            //    }
-            //}
-
-            let mut matched_rules = Vec::with_capacity(100);
-            compute_matched_rules(cx, tree, entity, &mut matched_rules);
-            matched_rule_ids.extend(matched_rules.into_iter().map(|r| r.id));
-            link_style_data(cx, entity, &matched_rule_ids);
-
-            prev_matched_rule_ids.clear();
-            prev_matched_rule_ids.append(&mut matched_rule_ids);
+            restyle_entity(cx, tree, entity);
         }
 
         cx.style.needs_restyle = false;
+    } else if !cx.style.restyle_list.is_empty() {
+        for entity in restyle_entities(cx, tree) {
+            update_hoverability(cx, tree, entity);
+            restyle_entity(cx, tree, entity);
+        }
     }
+
+    cx.style.restyle_list.clear();
+}
+
+fn restyle_entity(cx: &mut Context, tree: &Tree<Entity>, entity: Entity) {
+    let mut matched_rules = Vec::with_capacity(100);
+    compute_matched_rules(cx, tree, entity, &mut matched_rules);
+    let matched_rule_ids: Vec<Rule> = matched_rules.into_iter().map(|r| r.id).collect();
+    link_style_data(cx, entity, &matched_rule_ids);
 }
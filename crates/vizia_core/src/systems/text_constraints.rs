@@ -1,13 +1,15 @@
 use crate::prelude::*;
 use vizia_id::GenerationalId;
+use vizia_storage::TreeIterator;
 
 // Apply this before layout
 // THE GOAL OF THIS FUNCTION: set content-width and content-height
-pub fn text_constraints_system(cx: &mut Context, tree: &Tree<Entity>) {
-    let mut draw_tree: Vec<Entity> = tree.into_iter().collect();
-    draw_tree.sort_by_cached_key(|entity| cx.cache.get_z_index(*entity));
+// Only the subtree below `root` is measured. Returns the entities whose text was measured, as their
+// text buffers need resizing to their new width after layout.
+pub fn text_constraints_system(cx: &mut Context, tree: &Tree<Entity>, root: Entity) -> Vec<Entity> {
+    let mut measured = Vec::new();
 
-    for entity in draw_tree.into_iter() {
+    for entity in TreeIterator::subtree(tree, root) {
         if entity == Entity::root() {
             continue;
         }
@@ -80,6 +82,7 @@ pub fn text_constraints_system(cx: &mut Context, tree: &Tree<Entity>) {
                     let h = buf.layout_runs().len() as f32 * buf.metrics().line_height as f32;
                     (w, h)
                 });
+                measured.push(entity);
 
                 // Add an extra pixel to account for AA
                 let text_width = text_width.ceil() + 1.0 + child_space_x;
@@ -112,4 +115,6 @@ pub fn text_constraints_system(cx: &mut Context, tree: &Tree<Entity>) {
             cx.style.content_height.insert(entity, content_height / cx.style.dpi_factor as f32);
         }
     }
+
+    measured
 }
@@ -0,0 +1,111 @@
+use instant::Instant;
+use morphorm::PositionType;
+use vizia_id::GenerationalId;
+
+use crate::prelude::*;
+
+// Shows the tooltip of the hovered view, or of the view focused with the keyboard, once the
+// tooltip delay has passed, and hides it when another view is hovered or focused or the tooltip is
+// dismissed.
+pub(crate) fn tooltip_system(cx: &mut Context) {
+    if cx.tooltips.tooltips.is_empty() {
+        return;
+    }
+
+    let focus_visible = cx
+        .style
+        .pseudo_classes
+        .get(cx.focused)
+        .map_or(false, |pseudo_classes| pseudo_classes.contains(PseudoClass::FOCUS_VISIBLE));
+
+    let target = tooltip_owner(cx, cx.hovered).or_else(|| {
+        if focus_visible {
+            tooltip_owner(cx, cx.focused)
+        } else {
+            None
+        }
+    });
+
+    if target != cx.tooltips.target {
+        hide_tooltip(cx);
+        cx.tooltips.target = target;
+        cx.tooltips.since = Instant::now();
+        cx.tooltips.dismissed = false;
+    }
+
+    if let Some(target) = target {
+        if cx.tooltips.dismissed {
+            hide_tooltip(cx);
+        } else if !cx.tooltips.shown && cx.tooltips.since.elapsed() >= cx.tooltips.delay {
+            show_tooltip(cx, target);
+        }
+    }
+}
+
+// Returns the nearest view with a tooltip which contains the entity, if any.
+fn tooltip_owner(cx: &Context, entity: Entity) -> Option<Entity> {
+    if entity == Entity::null() {
+        return None;
+    }
+
+    entity.parent_iter(&cx.tree).find(|ancestor| cx.tooltips.tooltips.contains_key(ancestor))
+}
+
+fn show_tooltip(cx: &mut Context, target: Entity) {
+    let tooltip = match cx.tooltips.tooltips.get(&target) {
+        Some(tooltip) => *tooltip,
+        None => return,
+    };
+
+    let anchor = match cx.tooltips.placement {
+        TooltipPlacement::Anchored(side) => {
+            Anchor::new(target, side).align(AnchorAlign::Center).offset(4.0)
+        }
+
+        TooltipPlacement::Cursor => {
+            let cursor = cursor_marker(cx);
+            let scale = cx.style.dpi_factor as f32;
+            cx.style.left.insert(cursor, Pixels(cx.mouse.cursorx / scale));
+            cx.style.top.insert(cursor, Pixels(cx.mouse.cursory / scale));
+            cx.style.relayout(cursor);
+
+            Anchor::new(cursor, AnchorSide::Bottom).offset(16.0)
+        }
+    };
+
+    cx.style.anchor.insert(tooltip, anchor).expect("Failed to set anchor");
+    cx.event_queue.push_back(Event::new(TooltipEvent::Show).target(tooltip).origin(tooltip));
+    cx.tooltips.shown = true;
+}
+
+fn hide_tooltip(cx: &mut Context) {
+    if !cx.tooltips.shown {
+        return;
+    }
+
+    if let Some(tooltip) = cx.tooltips.target.and_then(|target| cx.tooltips.tooltips.get(&target)) {
+        cx.event_queue.push_back(Event::new(TooltipEvent::Hide).target(*tooltip).origin(*tooltip));
+    }
+
+    cx.tooltips.shown = false;
+}
+
+// Returns the zero-sized view which is moved to the cursor for tooltips placed at the cursor,
+// creating it the first time it's needed.
+fn cursor_marker(cx: &mut Context) -> Entity {
+    if let Some(cursor) = cx.tooltips.cursor {
+        return cursor;
+    }
+
+    let mut cursor = Entity::null();
+    cx.with_current(Entity::root(), |cx| {
+        cursor = Element::new(cx)
+            .position_type(PositionType::SelfDirected)
+            .size(Pixels(0.0))
+            .hoverable(false)
+            .entity();
+    });
+
+    cx.tooltips.cursor = Some(cursor);
+    cursor
+}
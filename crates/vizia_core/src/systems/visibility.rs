@@ -36,7 +36,13 @@ pub fn visibility_system(cx: &mut Context, tree: &Tree<Entity>) {
             }
         }
 
-        let parent_opacity = cx.cache.get_opacity(parent);
+        // The opacity of a cached layer is applied when it is composited, so isn't inherited by the
+        // views drawn into it
+        let parent_opacity = if cx.style.cache_layer.get(parent) == Some(&true) {
+            1.0
+        } else {
+            cx.cache.get_opacity(parent)
+        };
 
         let opacity = cx.style.opacity.get(entity).cloned().unwrap_or_default();
 
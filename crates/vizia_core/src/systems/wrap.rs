@@ -0,0 +1,397 @@
+use morphorm::{Hierarchy, LayoutType, PositionType, Units};
+use vizia_storage::LayoutTreeIterator;
+
+use crate::cache::BoundingBox;
+use crate::prelude::*;
+
+// A space or size along one axis, in physical pixels unless it stretches.
+#[derive(Debug, Clone, Copy)]
+enum Length {
+    Fixed(f32),
+    Stretch(f32),
+}
+
+impl Length {
+    // Auto spaces are resolved to zero, as the gaps between children are added separately.
+    fn space(units: Option<Units>, parent: f32, scale: f32) -> Self {
+        match units {
+            Some(Units::Pixels(val)) => Length::Fixed(val * scale),
+            Some(Units::Percentage(val)) => Length::Fixed(parent * val / 100.0),
+            Some(Units::Stretch(factor)) => Length::Stretch(factor),
+            _ => Length::Fixed(0.0),
+        }
+    }
+
+    // Auto sizes are the sizes the view was laid out with, and unset sizes stretch as they do in
+    // other layouts.
+    fn size(units: Option<Units>, laid_out: f32, parent: f32, scale: f32) -> Self {
+        match units {
+            Some(Units::Auto) => Length::Fixed(laid_out),
+            None => Length::Stretch(1.0),
+            units => Length::space(units, parent, scale),
+        }
+    }
+
+    fn fixed(self) -> f32 {
+        match self {
+            Length::Fixed(val) => val,
+            Length::Stretch(_) => 0.0,
+        }
+    }
+
+    fn stretch(self) -> f32 {
+        match self {
+            Length::Fixed(_) => 0.0,
+            Length::Stretch(factor) => factor,
+        }
+    }
+}
+
+// The lengths of a child of a wrapping layout along the main axis, which its lines run along, and
+// the cross axis.
+struct Item {
+    entity: Entity,
+    main: [Length; 3],
+    cross: [Length; 3],
+    // The sizes a stretching child starts from before it's given any free space
+    min_main: f32,
+    min_cross: f32,
+}
+
+impl Item {
+    fn main_size(&self) -> f32 {
+        self.main[0].fixed() + self.main[2].fixed() + self.main[1].fixed().max(self.min_main)
+    }
+
+    fn cross_size(&self) -> f32 {
+        self.cross[0].fixed() + self.cross[2].fixed() + self.cross[1].fixed().max(self.min_cross)
+    }
+}
+
+// Removes the placements made by the wrap system within a subtree which is about to be laid out,
+// so that wrapping layouts are first laid out as plain rows and columns. The root keeps its own
+// placement, as it isn't positioned or sized by laying out its subtree.
+pub(crate) fn clear_wrap_placements(cx: &mut Context, tree: &Tree<Entity>, root: Entity) {
+    if cx.style.wrap_placement.is_empty()
+        && cx.style.wrap_width.is_empty()
+        && cx.style.wrap_height.is_empty()
+    {
+        return;
+    }
+
+    for entity in LayoutTreeIterator::subtree(tree, root).skip(1) {
+        cx.style.wrap_placement.remove(entity);
+        cx.style.wrap_width.remove(entity);
+        cx.style.wrap_height.remove(entity);
+    }
+}
+
+// Places the children of the wrapping rows and columns within a subtree which has just been laid
+// out, using the sizes they were given as plain rows and columns. Returns whether any children
+// were placed, in which case the subtree needs to be laid out again to apply the placements.
+pub(crate) fn wrap_system(cx: &mut Context, tree: &Tree<Entity>, root: Entity) -> bool {
+    let mut placed = false;
+
+    // Nested wrapping layouts are placed first, so that their wrapped sizes are known when placing
+    // the layouts containing them
+    let entities = LayoutTreeIterator::subtree(tree, root).collect::<Vec<_>>();
+    for &container in entities.iter().rev() {
+        if cx.style.layout_wrap.get(container) != Some(&true)
+            || cx.cache.get_display(container) == Display::None
+        {
+            continue;
+        }
+
+        let horizontal = match cx.style.layout_type.get(container) {
+            Some(LayoutType::Row) => true,
+            Some(LayoutType::Column) => false,
+            _ => continue,
+        };
+
+        place_children(cx, tree, container, horizontal);
+        placed = true;
+    }
+
+    placed
+}
+
+// Breaks the children of a wrapping layout into lines and places them, along with sizing the
+// layout to its lines if its cross size is auto.
+fn place_children(cx: &mut Context, tree: &Tree<Entity>, container: Entity, horizontal: bool) {
+    let scale = cx.style.dpi_factor as f32;
+    let style = &cx.style;
+    let bounds = cx.cache.get_bounds(container);
+
+    // Lengths are read in the order of the main axis and then the cross axis
+    let (main_size, cross_size) =
+        if horizontal { (bounds.w, bounds.h) } else { (bounds.h, bounds.w) };
+    let fixed =
+        |units: Option<&Units>, parent: f32| Length::space(units.copied(), parent, scale).fixed();
+    let (padding, gaps) = if horizontal {
+        (
+            [
+                fixed(style.child_left.get(container), main_size),
+                fixed(style.child_right.get(container), main_size),
+                fixed(style.child_top.get(container), cross_size),
+                fixed(style.child_bottom.get(container), cross_size),
+            ],
+            [
+                fixed(style.col_between.get(container), main_size),
+                fixed(style.row_between.get(container), cross_size),
+            ],
+        )
+    } else {
+        (
+            [
+                fixed(style.child_top.get(container), main_size),
+                fixed(style.child_bottom.get(container), main_size),
+                fixed(style.child_left.get(container), cross_size),
+                fixed(style.child_right.get(container), cross_size),
+            ],
+            [
+                fixed(style.row_between.get(container), main_size),
+                fixed(style.col_between.get(container), cross_size),
+            ],
+        )
+    };
+
+    let mut items = Vec::new();
+    for child in tree.child_iter(container) {
+        if cx.cache.get_display(child) == Display::None
+            || style.position_type.get(child) == Some(&PositionType::SelfDirected)
+        {
+            continue;
+        }
+
+        let child_bounds = cx.cache.get_bounds(child);
+        let space =
+            |units: Option<&Units>, parent: f32| Length::space(units.copied(), parent, scale);
+        let size = |units: Option<&Units>, laid_out: f32, wrapped: Option<&f32>, parent: f32| {
+            Length::size(units.copied(), wrapped.copied().unwrap_or(laid_out), parent, scale)
+        };
+
+        let (mut width, mut height) = (
+            size(style.width.get(child), child_bounds.w, style.wrap_width.get(child), bounds.w),
+            size(style.height.get(child), child_bounds.h, style.wrap_height.get(child), bounds.h),
+        );
+
+        // Children which have been sized to their aspect ratio keep that size
+        if let Some((&w, &h)) = style.aspect_width.get(child).zip(style.aspect_height.get(child)) {
+            width = Length::Fixed(w);
+            height = Length::Fixed(h);
+        }
+        let (min_width, min_height) = (
+            fixed(style.min_width.get(child), bounds.w),
+            fixed(style.min_height.get(child), bounds.h),
+        );
+        let horizontal_lengths = [
+            space(style.left.get(child), bounds.w),
+            width,
+            space(style.right.get(child), bounds.w),
+        ];
+        let vertical_lengths = [
+            space(style.top.get(child), bounds.h),
+            height,
+            space(style.bottom.get(child), bounds.h),
+        ];
+
+        items.push(if horizontal {
+            Item {
+                entity: child,
+                main: horizontal_lengths,
+                cross: vertical_lengths,
+                min_main: min_width,
+                min_cross: min_height,
+            }
+        } else {
+            Item {
+                entity: child,
+                main: vertical_lengths,
+                cross: horizontal_lengths,
+                min_main: min_height,
+                min_cross: min_width,
+            }
+        });
+    }
+
+    let (placements, content_size) = place_items(&items, main_size, padding, gaps);
+
+    for (entity, placement) in placements {
+        let placement = if horizontal {
+            placement
+        } else {
+            BoundingBox { x: placement.y, y: placement.x, w: placement.h, h: placement.w }
+        };
+        cx.style.wrap_placement.insert(entity, placement).expect("Failed to set wrap placement");
+    }
+
+    if horizontal && cx.style.height.get(container) == Some(&Units::Auto) {
+        cx.style.wrap_height.insert(container, content_size).expect("Failed to set wrap height");
+    } else if !horizontal && cx.style.width.get(container) == Some(&Units::Auto) {
+        cx.style.wrap_width.insert(container, content_size).expect("Failed to set wrap width");
+    }
+}
+
+// Breaks items into lines along a main axis of the given size and places them, with the padding
+// before and after the lines along the main axis and then the cross axis, and the gaps between
+// items and then between lines. The placements have the main axis along x and the cross axis along
+// y. Returns the placements along with the size of the lines across the main axis, including the
+// padding.
+fn place_items(
+    items: &[Item],
+    main_size: f32,
+    padding: [f32; 4],
+    gaps: [f32; 2],
+) -> (Vec<(Entity, BoundingBox)>, f32) {
+    // Children are added to a line until the next one doesn't fit
+    let available = main_size - padding[0] - padding[1];
+    let mut lines: Vec<&[Item]> = Vec::new();
+    let mut start = 0;
+    let mut used = 0.0;
+    for (index, item) in items.iter().enumerate() {
+        let size = item.main_size();
+        if index > start && used + gaps[0] + size > available {
+            lines.push(&items[start..index]);
+            start = index;
+            used = size;
+        } else if index > start {
+            used += gaps[0] + size;
+        } else {
+            used = size;
+        }
+    }
+
+    if start < items.len() {
+        lines.push(&items[start..]);
+    }
+
+    let mut placements = Vec::with_capacity(items.len());
+    let mut cross_pos = padding[2];
+    for (line_index, line) in lines.iter().enumerate() {
+        if line_index > 0 {
+            cross_pos += gaps[1];
+        }
+
+        // Stretch spaces and sizes share the space left over in the line
+        let used = line.iter().map(|item| item.main_size()).sum::<f32>()
+            + gaps[0] * (line.len() - 1) as f32;
+        let stretch =
+            line.iter().flat_map(|item| item.main.iter()).map(|l| l.stretch()).sum::<f32>();
+        let unit = if stretch > 0.0 { (available - used).max(0.0) / stretch } else { 0.0 };
+
+        // Children are aligned within the line by their stretch cross spaces
+        let line_size = line.iter().map(|item| item.cross_size()).fold(0.0, f32::max);
+
+        let mut main_pos = padding[0];
+        for item in line.iter() {
+            let [before, size, after] = item.main;
+            let main = main_pos + before.fixed() + before.stretch() * unit;
+            let main_len = size.fixed().max(item.min_main) + size.stretch() * unit;
+            main_pos = main + main_len + after.fixed() + after.stretch() * unit + gaps[0];
+
+            let [cross_before, cross_len, cross_after] = item.cross;
+            let cross_stretch =
+                cross_before.stretch() + cross_len.stretch() + cross_after.stretch();
+            let cross_unit = if cross_stretch > 0.0 {
+                (line_size - item.cross_size()).max(0.0) / cross_stretch
+            } else {
+                0.0
+            };
+            let cross = cross_pos + cross_before.fixed() + cross_before.stretch() * cross_unit;
+            let cross_len =
+                cross_len.fixed().max(item.min_cross) + cross_len.stretch() * cross_unit;
+
+            placements
+                .push((item.entity, BoundingBox { x: main, y: cross, w: main_len, h: cross_len }));
+        }
+
+        cross_pos += line_size;
+    }
+
+    (placements, cross_pos + padding[3])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vizia_id::GenerationalId;
+
+    const NONE: Length = Length::Fixed(0.0);
+
+    fn item(index: u32, main: [Length; 3], cross: f32) -> Item {
+        Item {
+            entity: Entity::new(index, 0),
+            main,
+            cross: [NONE, Length::Fixed(cross), NONE],
+            min_main: 0.0,
+            min_cross: 0.0,
+        }
+    }
+
+    fn fixed(index: u32, main: f32, cross: f32) -> Item {
+        item(index, [NONE, Length::Fixed(main), NONE], cross)
+    }
+
+    fn boxes(placements: &[(Entity, BoundingBox)]) -> Vec<(f32, f32, f32, f32)> {
+        placements.iter().map(|(_, b)| (b.x, b.y, b.w, b.h)).collect()
+    }
+
+    #[test]
+    fn places_nothing_without_items() {
+        let (placements, size) = place_items(&[], 100.0, [1.0, 2.0, 3.0, 4.0], [5.0, 6.0]);
+        assert!(placements.is_empty());
+        assert_eq!(size, 7.0);
+    }
+
+    #[test]
+    fn gives_wide_item_its_own_line() {
+        let items = [fixed(1, 50.0, 10.0), fixed(2, 150.0, 20.0), fixed(3, 50.0, 10.0)];
+        let (placements, size) = place_items(&items, 100.0, [0.0; 4], [0.0; 2]);
+        assert_eq!(
+            boxes(&placements),
+            vec![(0.0, 0.0, 50.0, 10.0), (0.0, 10.0, 150.0, 20.0), (0.0, 30.0, 50.0, 10.0)]
+        );
+        assert_eq!(size, 40.0);
+    }
+
+    #[test]
+    fn leaves_gaps_out_at_line_boundaries() {
+        let items = [fixed(1, 40.0, 10.0), fixed(2, 40.0, 10.0), fixed(3, 40.0, 10.0)];
+
+        // The items fit exactly along with the gaps between them
+        let (placements, _) = place_items(&items, 140.0, [0.0; 4], [10.0, 5.0]);
+        assert_eq!(
+            boxes(&placements),
+            vec![(0.0, 0.0, 40.0, 10.0), (50.0, 0.0, 40.0, 10.0), (100.0, 0.0, 40.0, 10.0)]
+        );
+
+        // The gap before the last item doesn't fit, which starts the next line with no gap
+        let (placements, size) = place_items(&items, 130.0, [2.0, 2.0, 1.0, 1.0], [10.0, 5.0]);
+        assert_eq!(
+            boxes(&placements),
+            vec![(2.0, 1.0, 40.0, 10.0), (52.0, 1.0, 40.0, 10.0), (2.0, 16.0, 40.0, 10.0)]
+        );
+        assert_eq!(size, 27.0);
+    }
+
+    #[test]
+    fn shares_free_space_between_stretch_lengths() {
+        let items = [
+            item(1, [Length::Stretch(1.0), Length::Fixed(20.0), NONE], 10.0),
+            item(2, [NONE, Length::Stretch(3.0), NONE], 10.0),
+        ];
+        let (placements, _) = place_items(&items, 100.0, [0.0; 4], [0.0; 2]);
+        assert_eq!(boxes(&placements), vec![(20.0, 0.0, 20.0, 10.0), (40.0, 0.0, 60.0, 10.0)]);
+    }
+
+    #[test]
+    fn clamps_stretch_share_to_zero() {
+        let mut stretch = item(2, [NONE, Length::Stretch(1.0), NONE], 10.0);
+        stretch.min_main = 30.0;
+        let items = [item(1, [Length::Stretch(1.0), Length::Fixed(120.0), NONE], 10.0), stretch];
+        let (placements, _) = place_items(&items, 100.0, [0.0; 4], [0.0; 2]);
+
+        // The first line overflows, while the stretch size in the next line grows from its minimum
+        assert_eq!(boxes(&placements), vec![(0.0, 0.0, 120.0, 10.0), (0.0, 10.0, 100.0, 10.0)]);
+    }
+}
@@ -1,7 +1,15 @@
 use crate::prelude::*;
+use std::collections::HashSet;
 use vizia_id::GenerationalId;
 
+/// The z-index added to views in the overlay layer, so that they are drawn and hit-tested above all
+/// other views.
+const OVERLAY_Z_INDEX: i32 = 1 << 24;
+
 pub fn z_ordering_system(cx: &mut Context, tree: &Tree<Entity>) {
+    // Views in the overlay layer and their descendants
+    let mut overlay = HashSet::new();
+
     for entity in tree.into_iter() {
         if entity == Entity::root() {
             continue;
@@ -13,11 +21,21 @@ pub fn z_ordering_system(cx: &mut Context, tree: &Tree<Entity>) {
 
         let parent = tree.get_layout_parent(entity).unwrap();
 
-        if let Some(z_order) = cx.style.z_order.get(entity).copied() {
-            cx.cache.set_z_index(entity, z_order);
-        } else {
-            let parent_z_order = cx.cache.get_z_index(parent);
-            cx.cache.set_z_index(entity, parent_z_order);
+        // Whether the parent is in the overlay layer, which raises all of its descendants
+        let parent_in_overlay = overlay.contains(&parent);
+        let in_overlay =
+            parent_in_overlay || cx.style.overlay.get(entity).copied().unwrap_or_default();
+        if in_overlay {
+            overlay.insert(entity);
         }
+
+        let z_index = match cx.style.z_order.get(entity).copied() {
+            Some(z_order) if in_overlay => z_order.saturating_add(OVERLAY_Z_INDEX),
+            Some(z_order) => z_order,
+            None if in_overlay && !parent_in_overlay => OVERLAY_Z_INDEX,
+            None => cx.cache.get_z_index(parent),
+        };
+
+        cx.cache.set_z_index(entity, z_index);
     }
 }
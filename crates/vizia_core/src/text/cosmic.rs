@@ -1,10 +1,11 @@
 use crate::entity::Entity;
 use crate::prelude::Color;
 use crate::style::Style;
+use crate::text::{HighlightCache, Highlighter, Selection};
 use cosmic_text::{
     fontdb::{Database, Query},
-    Attrs, AttrsList, Buffer, CacheKey, Color as FontColor, Edit, Editor, Family, FontSystem,
-    Metrics, SubpixelBin, Wrap,
+    Attrs, AttrsList, Buffer, CacheKey, Color as FontColor, Cursor, Edit, Editor, Family,
+    FontSystem, Metrics, SubpixelBin, Wrap,
 };
 use femtovg::imgref::{Img, ImgRef};
 use femtovg::rgb::RGBA8;
@@ -38,6 +39,8 @@ struct TextContextInternal<'a> {
     rendered_glyphs: FnvHashMap<CacheKey, Option<RenderedGlyph>>,
     glyph_textures: Vec<FontTexture>,
     buffers: HashMap<Entity, Editor<'a>>,
+    highlighters: HashMap<Entity, HighlightCache>,
+    search_matches: HashMap<Entity, Vec<Selection>>,
 }
 
 impl TextContext {
@@ -48,9 +51,55 @@ impl TextContext {
     pub fn clear_buffer(&mut self, entity: Entity) {
         self.with_int_mut(move |int: &mut TextContextInternal| {
             int.buffers.remove(&entity);
+            int.highlighters.remove(&entity);
+            int.search_matches.remove(&entity);
         });
     }
 
+    /// Sets the highlighter which produces the text attributes of each line of the buffer, in place
+    /// of the attributes of the entity's style.
+    pub fn set_highlighter(&mut self, entity: Entity, highlighter: Option<Box<dyn Highlighter>>) {
+        self.with_int_mut(move |int: &mut TextContextInternal| {
+            if let Some(highlighter) = highlighter {
+                int.highlighters.insert(entity, HighlightCache::new(highlighter));
+            } else {
+                int.highlighters.remove(&entity);
+            }
+        });
+    }
+
+    /// Sets the search matches of the buffer, as byte ranges of its text, which are highlighted
+    /// when the text is drawn.
+    pub fn set_search_matches(&mut self, entity: Entity, matches: Vec<Selection>) {
+        self.with_int_mut(move |int: &mut TextContextInternal| {
+            if matches.is_empty() {
+                int.search_matches.remove(&entity);
+            } else {
+                int.search_matches.insert(entity, matches);
+            }
+        });
+    }
+
+    /// Selects a byte range of the text of the buffer, placing the cursor at the active end.
+    pub fn select_range(&mut self, entity: Entity, selection: Selection) {
+        self.with_editor(entity, |buf| {
+            let anchor = offset_to_cursor(buf.buffer(), selection.anchor);
+            let active = offset_to_cursor(buf.buffer(), selection.active);
+            buf.set_cursor(active);
+            buf.set_select_opt(if selection.is_caret() { None } else { Some(anchor) });
+        });
+    }
+
+    /// Returns the selected byte range of the text of the buffer.
+    pub fn selection(&mut self, entity: Entity) -> Selection {
+        self.with_editor(entity, |buf| {
+            let active = cursor_to_offset(buf.buffer(), buf.cursor());
+            let anchor =
+                buf.select_opt().map_or(active, |select| cursor_to_offset(buf.buffer(), select));
+            Selection::new(anchor, active)
+        })
+    }
+
     pub fn has_buffer(&self, entity: Entity) -> bool {
         self.with_int(move |int: &TextContextInternal| int.buffers.contains_key(&entity))
     }
@@ -93,7 +142,12 @@ impl TextContext {
             (font.info.family.clone(), font.info.weight, font.info.style, font.info.monospaced)
         });
         let color = style.font_color.get(entity).copied().unwrap_or(Color::rgb(0, 0, 0));
-        self.with_buffer(entity, |buf| {
+        self.with_int_mut(move |int: &mut TextContextInternal| {
+            let buf = int
+                .buffers
+                .entry(entity)
+                .or_insert_with(|| Editor::new(Buffer::new(&int.font_system, Metrics::new(18, 20))))
+                .buffer_mut();
             let attrs = Attrs::new()
                 .family(Family::Name(&family))
                 .weight(weight)
@@ -106,9 +160,13 @@ impl TextContext {
                 Wrap::None
             };
             buf.set_wrap(wrap);
-            for line in buf.lines.iter_mut() {
-                // TODO spans
-                line.set_attrs_list(AttrsList::new(attrs));
+            if let Some(highlighter) = int.highlighters.get_mut(&entity) {
+                highlighter.update(&mut buf.lines, attrs);
+            } else {
+                for line in buf.lines.iter_mut() {
+                    // TODO spans
+                    line.set_attrs_list(AttrsList::new(attrs));
+                }
             }
             let font_size =
                 style.font_size.get(entity).copied().unwrap_or(16.0) * style.dpi_factor as f32;
@@ -280,23 +338,69 @@ impl TextContext {
         position: (f32, f32),
         justify: (f32, f32),
     ) -> Vec<(f32, f32, f32, f32)> {
-        self.with_editor(entity, |buf| {
-            let mut result = vec![];
-            if let Some(cursor_end) = buf.select_opt() {
-                let (cursor_start, cursor_end) = if buf.cursor() < cursor_end {
+        let selection = self.with_editor(entity, |buf| {
+            buf.select_opt().map(|cursor_end| {
+                if buf.cursor() < cursor_end {
                     (buf.cursor(), cursor_end)
                 } else {
                     (cursor_end, buf.cursor())
-                };
-                let buffer = buf.buffer();
-                let total_height = buffer.layout_runs().len() as i32 * buffer.metrics().line_height;
-                for run in buffer.layout_runs() {
-                    if let Some((x, w)) = run.highlight(cursor_start, cursor_end) {
-                        let y = run.line_y as f32 - buffer.metrics().font_size as f32;
-                        let x = x + position.0 - run.line_w * justify.0;
-                        let y = y + position.1 - total_height as f32 * justify.1;
-                        result.push((x, y, w, buffer.metrics().line_height as f32));
-                    }
+                }
+            })
+        });
+
+        if let Some((cursor_start, cursor_end)) = selection {
+            self.layout_range(entity, cursor_start, cursor_end, position, justify)
+        } else {
+            vec![]
+        }
+    }
+
+    pub(crate) fn layout_search_matches(
+        &mut self,
+        entity: Entity,
+        position: (f32, f32),
+        justify: (f32, f32),
+    ) -> Vec<(f32, f32, f32, f32)> {
+        let ranges = self.with_int_mut(|int: &mut TextContextInternal| {
+            let (matches, buf) = match (int.search_matches.get(&entity), int.buffers.get(&entity)) {
+                (Some(matches), Some(buf)) => (matches, buf),
+                _ => return vec![],
+            };
+            matches
+                .iter()
+                .map(|selection| {
+                    (
+                        offset_to_cursor(buf.buffer(), selection.min()),
+                        offset_to_cursor(buf.buffer(), selection.max()),
+                    )
+                })
+                .collect::<Vec<_>>()
+        });
+
+        ranges
+            .into_iter()
+            .flat_map(|(start, end)| self.layout_range(entity, start, end, position, justify))
+            .collect()
+    }
+
+    /// Returns the rectangles covered by the text between two cursors, one for each layout run.
+    pub(crate) fn layout_range(
+        &mut self,
+        entity: Entity,
+        cursor_start: Cursor,
+        cursor_end: Cursor,
+        position: (f32, f32),
+        justify: (f32, f32),
+    ) -> Vec<(f32, f32, f32, f32)> {
+        self.with_buffer(entity, |buffer| {
+            let mut result = vec![];
+            let total_height = buffer.layout_runs().len() as i32 * buffer.metrics().line_height;
+            for run in buffer.layout_runs() {
+                if let Some((x, w)) = run.highlight(cursor_start, cursor_end) {
+                    let y = run.line_y as f32 - buffer.metrics().font_size as f32;
+                    let x = x + position.0 - run.line_w * justify.0;
+                    let y = y + position.1 - total_height as f32 * justify.1;
+                    result.push((x, y, w, buffer.metrics().line_height as f32));
                 }
             }
             result
@@ -353,6 +457,8 @@ impl TextContext {
                 rendered_glyphs: FnvHashMap::default(),
                 glyph_textures: vec![],
                 buffers: HashMap::new(),
+                highlighters: HashMap::new(),
+                search_matches: HashMap::new(),
             },
         }
         .build()
@@ -381,3 +487,26 @@ pub struct RenderedGlyph {
 //    Fill,
 //    Stroke(u32),
 //}
+
+/// Converts a byte offset into the text of a buffer, with its lines joined by newlines, into a
+/// cursor.
+pub(crate) fn offset_to_cursor(buffer: &Buffer, offset: usize) -> Cursor {
+    let mut line_start = 0;
+    for (line, buffer_line) in buffer.lines.iter().enumerate() {
+        let len = buffer_line.text().len();
+        if offset <= line_start + len {
+            return Cursor::new(line, offset - line_start);
+        }
+        line_start += len + 1;
+    }
+
+    let last = buffer.lines.len().saturating_sub(1);
+    Cursor::new(last, buffer.lines.get(last).map_or(0, |line| line.text().len()))
+}
+
+/// Converts a cursor into a byte offset into the text of a buffer, with its lines joined by
+/// newlines.
+pub(crate) fn cursor_to_offset(buffer: &Buffer, cursor: Cursor) -> usize {
+    buffer.lines.iter().take(cursor.line).map(|line| line.text().len() + 1).sum::<usize>()
+        + cursor.index
+}
@@ -0,0 +1,369 @@
+use cosmic_text::{Attrs, AttrsList, AttrsOwned, BufferLine};
+
+/// Produces the text attributes for the lines of a text buffer, for example to implement syntax
+/// highlighting.
+///
+/// A highlighter is attached to a text buffer with
+/// [`TextContext::set_highlighter`](crate::text::TextContext::set_highlighter).
+pub trait Highlighter: 'static {
+    /// Returns the attribute spans for the line at `index`, on top of the default attributes
+    /// `attrs` of the text.
+    ///
+    /// After a line has been edited, lines are requested in order starting from that line, so a
+    /// highlighter may keep state from the end of one line to the start of the next.
+    fn highlight_line(&mut self, index: usize, line: &str, attrs: Attrs) -> AttrsList;
+
+    /// Returns true if the state carried from the end of the line at `index` into the next line
+    /// changed when the line was last highlighted, so that the next line is highlighted again even
+    /// if its text hasn't changed.
+    ///
+    /// The default returns true, which highlights every line after an edited line again.
+    fn state_changed(&self, _index: usize) -> bool {
+        true
+    }
+
+    /// Called before the lines from `index` are highlighted again after `removed` lines at `index`
+    /// have been replaced by `inserted` lines, so that a highlighter keeping state for each line
+    /// can move the state of the lines after them.
+    fn lines_replaced(&mut self, _index: usize, _removed: usize, _inserted: usize) {}
+}
+
+/// The highlighter of a text buffer along with the spans it produced for each line, so that only
+/// lines which have changed, or which follow a line carrying a different state into them, need to
+/// be highlighted again.
+pub(crate) struct HighlightCache {
+    pub highlighter: Box<dyn Highlighter>,
+    attrs: Option<AttrsOwned>,
+    lines: Vec<(String, AttrsList)>,
+}
+
+impl HighlightCache {
+    pub fn new(highlighter: Box<dyn Highlighter>) -> Self {
+        Self { highlighter, attrs: None, lines: Vec::new() }
+    }
+
+    /// Sets the spans of each of `lines`, highlighting the lines which differ from the cached text
+    /// and the lines after them until the state carried between lines is unchanged.
+    pub fn update(&mut self, lines: &mut [BufferLine], attrs: Attrs) {
+        if self.attrs.as_ref().map_or(true, |cached| cached.as_attrs() != attrs) {
+            self.attrs = Some(AttrsOwned::new(attrs));
+            self.highlighter.lines_replaced(0, self.lines.len(), 0);
+            self.lines.clear();
+        }
+
+        // Lines which have been inserted or removed move the cached lines after them, which are
+        // matched up with the new lines by the text they have in common with the end of the text.
+        let mut replaced = 0..0;
+        if self.lines.len() != lines.len() {
+            let same =
+                |(cached, line): (&(String, AttrsList), &BufferLine)| cached.0 == line.text();
+            let prefix = self.lines.iter().zip(lines.iter()).take_while(|&pair| same(pair)).count();
+            let suffix = self
+                .lines
+                .iter()
+                .rev()
+                .zip(lines.iter().rev())
+                .take(self.lines.len().min(lines.len()) - prefix)
+                .take_while(|&pair| same(pair))
+                .count();
+            let removed = self.lines.len() - prefix - suffix;
+            let inserted = lines.len() - prefix - suffix;
+
+            self.highlighter.lines_replaced(prefix, removed, inserted);
+            let placeholders = lines[prefix..prefix + inserted]
+                .iter()
+                .map(|line| (line.text().to_owned(), AttrsList::new(attrs)));
+            self.lines.splice(prefix..prefix + removed, placeholders);
+            replaced = prefix..prefix + inserted;
+
+            // The state carried into the line after removed lines can't be compared, so that line
+            // is highlighted again
+            if inserted == 0 && removed > 0 {
+                replaced.end += 1;
+            }
+        }
+
+        let mut carry = false;
+        for (index, line) in lines.iter_mut().enumerate() {
+            let changed = replaced.contains(&index) || self.lines[index].0 != line.text();
+            if changed || carry {
+                let spans = self.highlighter.highlight_line(index, line.text(), attrs);
+                self.lines[index] = (line.text().to_owned(), spans);
+                carry = self.highlighter.state_changed(index);
+            } else {
+                carry = false;
+            }
+
+            let spans = &self.lines[index].1;
+            if line.attrs_list() != spans {
+                line.set_attrs_list(spans.clone());
+            }
+        }
+    }
+}
+
+/// A [`Highlighter`] backed by [syntect](https://docs.rs/syntect), using its bundled syntaxes and
+/// themes.
+#[cfg(feature = "syntect")]
+pub struct SyntectHighlighter {
+    syntax_set: syntect::parsing::SyntaxSet,
+    syntax: syntect::parsing::SyntaxReference,
+    theme: syntect::highlighting::Theme,
+    // The parse state at the start of each line.
+    states: Vec<(syntect::parsing::ParseState, syntect::highlighting::HighlightState)>,
+    // Whether the state at the end of the last highlighted line differed from the state the next
+    // line was highlighted with.
+    state_changed: bool,
+}
+
+#[cfg(feature = "syntect")]
+impl SyntectHighlighter {
+    /// Creates a highlighter for the language with the given file extension, e.g. `"rs"`, using the
+    /// `InspiredGitHub` theme.
+    pub fn new(extension: &str) -> Self {
+        let theme = syntect::highlighting::ThemeSet::load_defaults()
+            .themes
+            .remove("InspiredGitHub")
+            .unwrap_or_default();
+        Self::with_syntect_theme(extension, theme)
+    }
+
+    /// Creates a highlighter for the language with the given file extension, using one of the
+    /// themes bundled with syntect, or returns `None` if there is no bundled theme with that name.
+    pub fn with_theme(extension: &str, theme: &str) -> Option<Self> {
+        let theme = syntect::highlighting::ThemeSet::load_defaults().themes.remove(theme)?;
+        Some(Self::with_syntect_theme(extension, theme))
+    }
+
+    /// Creates a highlighter for the language with the given file extension, using a theme
+    /// loaded with syntect, for example from a `.tmTheme` file.
+    pub fn with_syntect_theme(extension: &str, theme: syntect::highlighting::Theme) -> Self {
+        use syntect::parsing::SyntaxSet;
+
+        let syntax_set = SyntaxSet::load_defaults_nonewlines();
+        let syntax = syntax_set
+            .find_syntax_by_extension(extension)
+            .unwrap_or_else(|| syntax_set.find_syntax_plain_text())
+            .clone();
+
+        let mut highlighter =
+            Self { syntax_set, syntax, theme, states: Vec::new(), state_changed: true };
+        highlighter.states.push(highlighter.initial_state());
+        highlighter
+    }
+
+    fn initial_state(
+        &self,
+    ) -> (syntect::parsing::ParseState, syntect::highlighting::HighlightState) {
+        use syntect::highlighting::{HighlightState, Highlighter};
+        use syntect::parsing::{ParseState, ScopeStack};
+
+        let highlighter = Highlighter::new(&self.theme);
+        (ParseState::new(&self.syntax), HighlightState::new(&highlighter, ScopeStack::new()))
+    }
+}
+
+#[cfg(feature = "syntect")]
+impl Highlighter for SyntectHighlighter {
+    fn highlight_line(&mut self, index: usize, line: &str, attrs: Attrs) -> AttrsList {
+        use cosmic_text::{Color as FontColor, Style, Weight};
+        use syntect::highlighting::{FontStyle, HighlightIterator};
+
+        let (mut parse_state, mut highlight_state) =
+            self.states.get(index).cloned().unwrap_or_else(|| self.initial_state());
+
+        let mut spans = AttrsList::new(attrs);
+        let ops = parse_state.parse_line(line, &self.syntax_set).unwrap_or_default();
+        let highlighter = syntect::highlighting::Highlighter::new(&self.theme);
+        let mut start = 0;
+        for (style, text) in HighlightIterator::new(&mut highlight_state, &ops, line, &highlighter)
+        {
+            let end = start + text.len();
+            let color = style.foreground;
+            let mut span = attrs.color(FontColor::rgba(color.r, color.g, color.b, color.a));
+            if style.font_style.contains(FontStyle::BOLD) {
+                span = span.weight(Weight::BOLD);
+            }
+            if style.font_style.contains(FontStyle::ITALIC) {
+                span = span.style(Style::Italic);
+            }
+            spans.add_span(start..end, span);
+            start = end;
+        }
+
+        // The state at the start of the next line is kept until it has been compared, so that the
+        // lines after an edit are only highlighted again while the state differs
+        let state = (parse_state, highlight_state);
+        self.state_changed = self.states.get(index + 1) != Some(&state);
+        if index + 1 < self.states.len() {
+            self.states[index + 1] = state;
+        } else if index + 1 == self.states.len() {
+            self.states.push(state);
+        }
+
+        spans
+    }
+
+    fn state_changed(&self, _index: usize) -> bool {
+        self.state_changed
+    }
+
+    fn lines_replaced(&mut self, index: usize, removed: usize, inserted: usize) {
+        if self.states.len() <= index {
+            return;
+        }
+
+        // The states at the start of the replaced lines, except the first, become placeholders
+        // which are overwritten as the inserted lines are highlighted. The state at the start of
+        // the line after them is kept to compare with once the last inserted line is highlighted.
+        let mut tail = self.states.split_off(index + 1);
+        if inserted > 0 {
+            if removed > 0 {
+                tail.drain(..(removed - 1).min(tail.len()));
+            } else {
+                tail.insert(0, self.states[index].clone());
+            }
+
+            let placeholder = self.states[index].clone();
+            self.states.extend(std::iter::repeat(placeholder).take(inserted - 1));
+        } else {
+            tail.drain(..removed.min(tail.len()));
+        }
+
+        self.states.extend(tail);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    // Highlights block comments, recording the index of each line it highlights.
+    struct CommentHighlighter {
+        highlighted: Rc<RefCell<Vec<usize>>>,
+        // Whether each line ends inside a comment, or `None` if not highlighted yet.
+        ends: Vec<Option<bool>>,
+        state_changed: bool,
+    }
+
+    impl Highlighter for CommentHighlighter {
+        fn highlight_line(&mut self, index: usize, line: &str, attrs: Attrs) -> AttrsList {
+            self.highlighted.borrow_mut().push(index);
+
+            let start = index > 0 && self.ends[index - 1] == Some(true);
+            let end = match (line.rfind("/*"), line.rfind("*/")) {
+                (Some(open), Some(close)) => open > close,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => start,
+            };
+
+            self.state_changed = self.ends[index] != Some(end);
+            self.ends[index] = Some(end);
+            AttrsList::new(attrs)
+        }
+
+        fn state_changed(&self, _index: usize) -> bool {
+            self.state_changed
+        }
+
+        fn lines_replaced(&mut self, index: usize, removed: usize, inserted: usize) {
+            // The last inserted line ends with the state the line after them used to start with,
+            // so that highlighting stops there if the state is unchanged.
+            let carried = if removed > 0 {
+                self.ends.get(index + removed - 1).copied().flatten()
+            } else if index > 0 {
+                self.ends[index - 1]
+            } else {
+                Some(false)
+            };
+            let mut placeholders = vec![None; inserted];
+            if let Some(last) = placeholders.last_mut() {
+                *last = carried;
+            }
+
+            let end = (index + removed).min(self.ends.len());
+            self.ends.splice(index..end, placeholders);
+        }
+    }
+
+    struct Fixture {
+        cache: HighlightCache,
+        highlighted: Rc<RefCell<Vec<usize>>>,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let highlighted = Rc::new(RefCell::new(Vec::new()));
+            let highlighter = CommentHighlighter {
+                highlighted: highlighted.clone(),
+                ends: Vec::new(),
+                state_changed: false,
+            };
+            Self { cache: HighlightCache::new(Box::new(highlighter)), highlighted }
+        }
+
+        // Updates the cache with the lines and returns the indices of the highlighted lines.
+        fn update(&mut self, lines: &[&str]) -> Vec<usize> {
+            let mut lines: Vec<BufferLine> = lines
+                .iter()
+                .map(|line| BufferLine::new(*line, AttrsList::new(Attrs::new())))
+                .collect();
+            self.cache.update(&mut lines, Attrs::new());
+            self.highlighted.replace(Vec::new())
+        }
+    }
+
+    #[test]
+    fn highlights_only_changed_lines() {
+        let mut fixture = Fixture::new();
+        assert_eq!(fixture.update(&["a", "b", "c"]), vec![0, 1, 2]);
+        assert_eq!(fixture.update(&["a", "b", "c"]), Vec::<usize>::new());
+        assert_eq!(fixture.update(&["a", "x", "c"]), vec![1]);
+    }
+
+    #[test]
+    fn highlights_inserted_lines() {
+        let mut fixture = Fixture::new();
+        fixture.update(&["a", "b", "c"]);
+        assert_eq!(fixture.update(&["a", "x", "y", "b", "c"]), vec![1, 2]);
+        assert_eq!(fixture.update(&["z", "a", "x", "y", "b", "c"]), vec![0]);
+    }
+
+    #[test]
+    fn highlights_line_after_removed_lines() {
+        let mut fixture = Fixture::new();
+        fixture.update(&["a", "b", "c", "d"]);
+        assert_eq!(fixture.update(&["a", "d"]), vec![1]);
+        assert_eq!(fixture.update(&["a"]), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn highlights_replaced_lines() {
+        let mut fixture = Fixture::new();
+        fixture.update(&["a", "b", "c", "d"]);
+        assert_eq!(fixture.update(&["a", "x", "y", "z", "d"]), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn highlights_following_lines_while_state_changes() {
+        let mut fixture = Fixture::new();
+        fixture.update(&["a", "b", "c", "d"]);
+
+        // Opening a block comment changes the state carried into every following line.
+        assert_eq!(fixture.update(&["/* a", "b", "c", "d"]), vec![0, 1, 2, 3]);
+        assert_eq!(fixture.update(&["/* a", "b */", "c", "d"]), vec![1, 2, 3]);
+        assert_eq!(fixture.update(&["/* a", "b */", "c", "d /*"]), vec![3]);
+    }
+
+    #[test]
+    fn stops_at_line_with_unchanged_state() {
+        let mut fixture = Fixture::new();
+        fixture.update(&["a", "b */", "c", "d"]);
+
+        // The comment opened in the first line is closed in the second one.
+        assert_eq!(fixture.update(&["/* a", "b */", "c", "d"]), vec![0, 1]);
+    }
+}
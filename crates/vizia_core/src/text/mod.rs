@@ -1,9 +1,15 @@
 mod edit;
 pub use edit::*;
 
+mod highlight;
+pub use highlight::*;
+
 mod movement;
 pub use movement::*;
 
+mod search;
+pub use search::*;
+
 mod selection;
 pub use selection::*;
 
@@ -0,0 +1,139 @@
+use crate::prelude::*;
+use crate::text::Selection;
+
+/// Options controlling how a search query is matched against text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SearchOptions {
+    /// Whether letters must match case exactly.
+    pub case_sensitive: bool,
+    /// Whether matches must be surrounded by word boundaries.
+    pub whole_word: bool,
+    /// Whether the query is a regular expression.
+    #[cfg(feature = "regex")]
+    pub regex: bool,
+}
+
+impl Data for SearchOptions {
+    fn same(&self, other: &Self) -> bool {
+        *self == *other
+    }
+}
+
+/// Returns the non-overlapping ranges of `text` which match `query`, in order.
+///
+/// The returned selections are byte offsets into `text`, anchored at the start of each match.
+pub fn find_matches(text: &str, query: &str, options: SearchOptions) -> Vec<Selection> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    #[cfg(feature = "regex")]
+    if options.regex {
+        return find_regex_matches(text, query, options);
+    }
+
+    let mut matches = Vec::new();
+    let mut search_from = 0;
+    for (start, _) in text.char_indices() {
+        if start < search_from {
+            continue;
+        }
+
+        if let Some(end) = match_at(text, start, query, options.case_sensitive) {
+            if !options.whole_word || is_whole_word(text, start, end) {
+                matches.push(Selection::new(start, end));
+                search_from = end;
+            }
+        }
+    }
+
+    matches
+}
+
+/// Returns the end of the match if `query` occurs in `text` at byte offset `start`.
+fn match_at(text: &str, start: usize, query: &str, case_sensitive: bool) -> Option<usize> {
+    let mut chars = text[start..].char_indices();
+    for expected in query.chars() {
+        let (_, c) = chars.next()?;
+        let same = if case_sensitive {
+            c == expected
+        } else {
+            c == expected || c.to_lowercase().eq(expected.to_lowercase())
+        };
+        if !same {
+            return None;
+        }
+    }
+
+    Some(chars.next().map_or(text.len(), |(index, _)| start + index))
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn is_whole_word(text: &str, start: usize, end: usize) -> bool {
+    !text[..start].chars().next_back().map_or(false, is_word_char)
+        && !text[end..].chars().next().map_or(false, is_word_char)
+}
+
+#[cfg(feature = "regex")]
+fn find_regex_matches(text: &str, query: &str, options: SearchOptions) -> Vec<Selection> {
+    let pattern = if options.whole_word { format!(r"\b(?:{})\b", query) } else { query.to_owned() };
+
+    // An invalid expression, e.g. one which is still being typed, simply matches nothing.
+    let regex = match regex::RegexBuilder::new(&pattern)
+        .case_insensitive(!options.case_sensitive)
+        .build()
+    {
+        Ok(regex) => regex,
+        Err(_) => return Vec::new(),
+    };
+
+    regex
+        .find_iter(text)
+        .filter(|found| !found.as_str().is_empty())
+        .map(|found| Selection::new(found.start(), found.end()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(text: &str, query: &str, options: SearchOptions) -> Vec<std::ops::Range<usize>> {
+        find_matches(text, query, options).iter().map(|selection| selection.range()).collect()
+    }
+
+    #[test]
+    fn case_insensitive_by_default() {
+        assert_eq!(ranges("Foo foo FOO", "foo", SearchOptions::default()), vec![0..3, 4..7, 8..11]);
+    }
+
+    #[test]
+    fn case_sensitive() {
+        let options = SearchOptions { case_sensitive: true, ..Default::default() };
+        assert_eq!(ranges("Foo foo FOO", "foo", options), vec![4..7]);
+    }
+
+    #[test]
+    fn whole_word() {
+        let options = SearchOptions { whole_word: true, ..Default::default() };
+        assert_eq!(ranges("foo food foo_bar (foo)", "foo", options), vec![0..3, 18..21]);
+    }
+
+    #[test]
+    fn matches_do_not_overlap() {
+        assert_eq!(ranges("aaaa", "aa", SearchOptions::default()), vec![0..2, 2..4]);
+    }
+
+    #[test]
+    fn multi_byte_text() {
+        assert_eq!(ranges("héllo HÉLLO", "héllo", SearchOptions::default()), vec![0..6, 7..13]);
+    }
+
+    #[test]
+    fn matches_across_lines() {
+        assert_eq!(ranges("one\ntwo", "e\nt", SearchOptions::default()), vec![2..5]);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use std::{any::Any, collections::HashMap};
 
+use crate::cache::BoundingBox;
 use crate::events::ViewHandler;
 use crate::resource::ImageOrId;
 use crate::state::ModelDataStore;
@@ -384,7 +385,7 @@ fn draw_view(cx: &mut DrawContext, canvas: &mut Canvas) {
             source
         };
 
-        canvas.set_render_target(RenderTarget::Screen);
+        canvas.set_render_target(cx.draw_cache.render_target());
 
         canvas.save();
         canvas.translate(outer_shadow_h_offset, outer_shadow_v_offset);
@@ -435,17 +436,27 @@ fn draw_view(cx: &mut DrawContext, canvas: &mut Canvas) {
         );
     }
 
-    // background-image overrides gradient
-    // TODO should we draw image on top of colors?
-    if let Some(background_image) = cx.background_image() {
-        if let Some(img) = cx.resource_manager.images.get(background_image) {
-            match img.image {
-                ImageOrId::Id(id, dim) => {
-                    paint =
-                        Paint::image(id, bounds.x, bounds.y, dim.0 as f32, dim.1 as f32, 0.0, 1.0);
-                }
+    // background-image overrides gradient, except where a non-repeating image doesn't cover the view
+    let mut background_image = None;
+    if let Some(image_name) = cx.background_image() {
+        if let Some(img) = cx.resource_manager.images.get(image_name) {
+            if let ImageOrId::Id(id, dim) = img.image {
+                let scale = cx.style.dpi_factor as f32;
+                let (w, h) = cx.background_size().copied().unwrap_or_default().size(
+                    (dim.0 as f32, dim.1 as f32),
+                    (bounds.w, bounds.h),
+                    scale,
+                );
+                let (x, y) = cx.background_position().copied().unwrap_or_default().offset(
+                    (bounds.w, bounds.h),
+                    (w, h),
+                    scale,
+                );
+                let tile = BoundingBox { x: bounds.x + x, y: bounds.y + y, w, h };
+                let area = cx.background_repeat().copied().unwrap_or_default().area(bounds, tile);
 
-                _ => {}
+                background_image =
+                    Some((Paint::image(id, tile.x, tile.y, tile.w, tile.h, 0.0, 1.0), area));
             }
         }
     }
@@ -453,7 +464,26 @@ fn draw_view(cx: &mut DrawContext, canvas: &mut Canvas) {
     //canvas.global_composite_blend_func(BlendFactor::DstColor, BlendFactor::OneMinusSrcAlpha);
 
     // Fill the quad
-    canvas.fill_path(&mut path, &paint);
+    match background_image {
+        Some((image_paint, None)) => canvas.fill_path(&mut path, &image_paint),
+        Some((image_paint, Some(area))) => {
+            canvas.fill_path(&mut path, &paint);
+            let mut image_path = Path::new();
+            image_path.rect(area.x, area.y, area.w, area.h);
+            canvas.fill_path(&mut image_path, &image_paint);
+        }
+        None => canvas.fill_path(&mut path, &paint),
+    }
+
+    // Draw border image
+    if let Some(image_name) = cx.border_image() {
+        if let Some(img) = cx.resource_manager.images.get(image_name) {
+            if let ImageOrId::Id(id, dim) = img.image {
+                let slice = cx.border_image_slice().copied().unwrap_or_default();
+                draw_nine_slice(canvas, id, (dim.0 as f32, dim.1 as f32), slice, bounds);
+            }
+        }
+    }
 
     //println!("{:.2?} seconds for whatever you did.", start.elapsed());
 
@@ -538,9 +568,32 @@ fn draw_view(cx: &mut DrawContext, canvas: &mut Canvas) {
         // Draw image
         if let Some(image_name) = cx.image() {
             if let Some(img) = cx.resource_manager.images.get(image_name) {
-                if let ImageOrId::Id(id, _) = img.image {
-                    let paint = Paint::image(id, box_x, box_y, box_w, box_h, 0.0, 1.0);
-                    canvas.fill_path(&mut path, &paint);
+                if let ImageOrId::Id(id, dim) = img.image {
+                    match cx.object_fit().copied().unwrap_or_default() {
+                        ObjectFit::Fill => {
+                            let paint = Paint::image(id, box_x, box_y, box_w, box_h, 0.0, 1.0);
+                            canvas.fill_path(&mut path, &paint);
+                        }
+
+                        object_fit => {
+                            let (w, h) =
+                                object_fit.size((dim.0 as f32, dim.1 as f32), (box_w, box_h));
+                            let (x, y) = cx
+                                .object_position()
+                                .copied()
+                                .unwrap_or(ImagePosition::CENTER)
+                                .offset((box_w, box_h), (w, h), cx.style.dpi_factor as f32);
+                            let paint = Paint::image(id, box_x + x, box_y + y, w, h, 0.0, 1.0);
+
+                            // Only the part of the image inside the content box is drawn
+                            let content = BoundingBox { x: box_x, y: box_y, w: box_w, h: box_h };
+                            let area = BoundingBox { x: box_x + x, y: box_y + y, w, h }
+                                .intersection(&content);
+                            let mut image_path = Path::new();
+                            image_path.rect(area.x, area.y, area.w, area.h);
+                            canvas.fill_path(&mut image_path, &paint);
+                        }
+                    }
                 }
             }
         }
@@ -581,3 +634,31 @@ fn draw_view(cx: &mut DrawContext, canvas: &mut Canvas) {
         }
     }
 }
+
+// Draws an image as a nine-slice frame, stretching its edges and middle to fit the bounds.
+fn draw_nine_slice(
+    canvas: &mut Canvas,
+    id: femtovg::ImageId,
+    image: (f32, f32),
+    slice: BorderImageSlice,
+    bounds: BoundingBox,
+) {
+    for (src, dst) in slice.regions(image, bounds) {
+        // Map the whole image so that the source region lands on the destination region
+        let scale_x = dst.w / src.w;
+        let scale_y = dst.h / src.h;
+        let paint = Paint::image(
+            id,
+            dst.x - src.x * scale_x,
+            dst.y - src.y * scale_y,
+            image.0 * scale_x,
+            image.1 * scale_y,
+            0.0,
+            1.0,
+        );
+
+        let mut path = Path::new();
+        path.rect(dst.x, dst.y, dst.w, dst.h);
+        canvas.fill_path(&mut path, &paint);
+    }
+}
@@ -0,0 +1,438 @@
+use std::sync::Arc;
+
+use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
+use unic_langid::LanguageIdentifier;
+
+use crate::fonts::icons_names::{LEFT, RIGHT};
+use crate::prelude::*;
+
+/// The dates which can be picked, shared by the [`Calendar`] and the [`DatePicker`].
+#[derive(Clone, Default)]
+pub struct DateConstraints {
+    pub(crate) min: Option<NaiveDate>,
+    pub(crate) max: Option<NaiveDate>,
+    pub(crate) disabled: Option<Arc<dyn Fn(NaiveDate) -> bool + Send + Sync>>,
+}
+
+impl Data for DateConstraints {
+    fn same(&self, other: &Self) -> bool {
+        self.min == other.min
+            && self.max == other.max
+            && match (&self.disabled, &other.disabled) {
+                (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+impl DateConstraints {
+    // Returns the date if it can be picked, or why it can't.
+    pub(crate) fn check(&self, date: NaiveDate) -> Result<NaiveDate, String> {
+        match (self.min, self.max) {
+            (Some(min), _) if date < min => Err(format!("{} is before {}", date, min)),
+            (_, Some(max)) if date > max => Err(format!("{} is after {}", date, max)),
+            _ if self.disabled.as_ref().map_or(false, |disabled| (disabled)(date)) => {
+                Err(format!("{} is unavailable", date))
+            }
+            _ => Ok(date),
+        }
+    }
+
+    pub(crate) fn allows(&self, date: NaiveDate) -> bool {
+        self.check(date).is_ok()
+    }
+
+    // Returns the closest date to the given date within the minimum and maximum dates.
+    fn clamp(&self, date: NaiveDate) -> NaiveDate {
+        let date = self.min.map_or(date, |min| date.max(min));
+        self.max.map_or(date, |max| date.min(max))
+    }
+}
+
+// Returns the first day of the week in the region of the locale, or for the language of the
+// locale if it has no region.
+fn first_weekday(locale: &LanguageIdentifier) -> Weekday {
+    const SATURDAY: &[&str] =
+        &["AE", "AF", "BH", "DJ", "DZ", "EG", "IQ", "IR", "JO", "KW", "LY", "OM", "QA", "SD", "SY"];
+    const SUNDAY: &[&str] = &[
+        "AG", "AS", "BD", "BR", "BS", "BT", "BW", "BZ", "CA", "CN", "CO", "DM", "DO", "ET", "GT",
+        "GU", "HK", "HN", "ID", "IL", "IN", "JM", "JP", "KE", "KH", "KR", "LA", "MH", "MM", "MO",
+        "MT", "MX", "MZ", "NI", "NP", "PA", "PE", "PH", "PK", "PR", "PT", "PY", "SA", "SG", "SV",
+        "TH", "TT", "TW", "UM", "US", "VE", "VI", "WS", "YE", "ZA", "ZW",
+    ];
+
+    match locale.region.as_ref().map(|region| region.as_str()) {
+        Some(region) if SATURDAY.contains(&region) => Weekday::Sat,
+        Some(region) if SUNDAY.contains(&region) => Weekday::Sun,
+        Some(_) => Weekday::Mon,
+        None => match locale.language.as_str() {
+            "ar" | "fa" => Weekday::Sat,
+            "en" | "he" | "hi" | "ja" | "ko" | "th" | "zh" => Weekday::Sun,
+            _ => Weekday::Mon,
+        },
+    }
+}
+
+// Returns the date the given number of months after the date, on the same day of the month or the
+// last day of a shorter month.
+fn add_months(date: NaiveDate, months: i32) -> NaiveDate {
+    let month = date.year() * 12 + date.month0() as i32 + months;
+    let (year, month) = (month.div_euclid(12), month.rem_euclid(12) as u32 + 1);
+    (1..=date.day()).rev().find_map(|day| NaiveDate::from_ymd_opt(year, month, day)).unwrap_or(date)
+}
+
+// Returns the six weeks of days shown for the month of the date, starting on the given weekday.
+fn month_days(month: NaiveDate, first_weekday: Weekday) -> Vec<NaiveDate> {
+    let first = month.with_day(1).unwrap_or(month);
+    let offset =
+        (7 + first.weekday().num_days_from_monday() - first_weekday.num_days_from_monday()) % 7;
+    let start = first - Duration::days(offset as i64);
+    (0..42).map(|day| start + Duration::days(day)).collect()
+}
+
+#[derive(Clone, Data)]
+pub struct CalendarState {
+    selected: NaiveDate,
+    // The first day of the month shown
+    month: NaiveDate,
+    // The day moved by the keyboard
+    cursor: NaiveDate,
+    first_weekday: Weekday,
+    week_numbers: bool,
+    constraints: DateConstraints,
+}
+
+enum CalendarEventInternal {
+    Show(NaiveDate),
+    SetFirstWeekday(Weekday),
+    ShiftMonths(i32),
+    Select(NaiveDate),
+}
+
+/// A grid of the days of a month, from which a date can be picked.
+///
+/// The calendar shows the month of the bound date, and the buttons in its header move between
+/// months. When focused, the arrow keys move between days, `Home` and `End` move to the start and
+/// end of the week, `PageUp` and `PageDown` move between months, or years with `Shift`, and
+/// `Enter` or `Space` pick the day. Weeks start on the first weekday of the region of the
+/// [`Environment`] locale, but the names of months and weekdays are always in English.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # use vizia_core::chrono::NaiveDate;
+/// # use vizia_derive::*;
+/// # let cx = &mut Context::default();
+/// # #[derive(Lens)]
+/// # pub struct AppData {
+/// #     date: NaiveDate,
+/// # }
+/// # impl Model for AppData {}
+/// # AppData { date: NaiveDate::from_ymd_opt(2023, 3, 14).unwrap() }.build(cx);
+/// Calendar::new(cx, AppData::date)
+///     .week_numbers(true)
+///     .min_date(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap())
+///     .on_select(|_, date| println!("Picked {}", date));
+/// ```
+#[derive(Lens)]
+pub struct Calendar<L: Lens> {
+    lens: L,
+    state: CalendarState,
+    on_select: Option<Box<dyn Fn(&mut EventContext, NaiveDate)>>,
+}
+
+impl<L> Calendar<L>
+where
+    L: Lens<Target = NaiveDate>,
+{
+    /// Creates a new calendar showing the date targeted by the lens.
+    pub fn new(cx: &mut Context, lens: L) -> Handle<Self> {
+        let selected = lens.get(cx);
+
+        Self {
+            lens: lens.clone(),
+            state: CalendarState {
+                selected,
+                month: selected.with_day(1).unwrap_or(selected),
+                cursor: selected,
+                first_weekday: first_weekday(&cx.environment().locale),
+                week_numbers: false,
+                constraints: DateConstraints::default(),
+            },
+            on_select: None,
+        }
+        .build(cx, |cx| {
+            Binding::new(cx, lens, |cx, date| {
+                let date = date.get(cx);
+                cx.emit(CalendarEventInternal::Show(date));
+            });
+
+            Binding::new(cx, Environment::locale, |cx, locale| {
+                let weekday = first_weekday(&locale.get(cx));
+                cx.emit(CalendarEventInternal::SetFirstWeekday(weekday));
+            });
+
+            HStack::new(cx, |cx| {
+                Button::new(
+                    cx,
+                    |cx| cx.emit(CalendarEventInternal::ShiftMonths(-1)),
+                    |cx| Label::new(cx, LEFT).class("icon"),
+                )
+                .class("previous-month");
+
+                Label::new(
+                    cx,
+                    Calendar::<L>::state.map(|state| state.month.format("%B %Y").to_string()),
+                )
+                .class("month");
+
+                Button::new(
+                    cx,
+                    |cx| cx.emit(CalendarEventInternal::ShiftMonths(1)),
+                    |cx| Label::new(cx, RIGHT).class("icon"),
+                )
+                .class("next-month");
+            })
+            .class("calendar-header");
+
+            Binding::new(cx, Calendar::<L>::state, |cx, state| {
+                let state = state.get(cx);
+                let today = Local::now().naive_local().date();
+                let days = month_days(state.month, state.first_weekday);
+
+                HStack::new(cx, |cx| {
+                    if state.week_numbers {
+                        Element::new(cx).class("week-number");
+                    }
+
+                    for day in &days[..7] {
+                        let name: String = day.format("%a").to_string().chars().take(2).collect();
+                        Label::new(cx, &name).class("weekday");
+                    }
+                })
+                .class("calendar-week");
+
+                for week in days.chunks(7) {
+                    HStack::new(cx, |cx| {
+                        if state.week_numbers {
+                            // Most days of the row share the ISO week of its Thursday
+                            let thursday = week.iter().find(|day| day.weekday() == Weekday::Thu);
+                            if let Some(thursday) = thursday {
+                                Label::new(cx, thursday.iso_week().week()).class("week-number");
+                            }
+                        }
+
+                        for day in week {
+                            CalendarDay::new(cx, *day)
+                                .checked(*day == state.selected)
+                                .toggle_class("outside", day.month() != state.month.month())
+                                .toggle_class("today", *day == today)
+                                .toggle_class("cursor", *day == state.cursor)
+                                .disabled(!state.constraints.allows(*day));
+                        }
+                    })
+                    .class("calendar-week");
+                }
+            });
+        })
+        .navigable(true)
+    }
+}
+
+impl<L: Lens> Calendar<L> {
+    fn move_cursor(&mut self, date: NaiveDate) {
+        let date = self.state.constraints.clamp(date);
+        self.state.cursor = date;
+        self.state.month = date.with_day(1).unwrap_or(date);
+    }
+
+    fn select(&mut self, cx: &mut EventContext, date: NaiveDate) {
+        if self.state.constraints.allows(date) {
+            self.move_cursor(date);
+            if let Some(callback) = &self.on_select {
+                (callback)(cx, date);
+            }
+        }
+    }
+}
+
+impl<L: Lens> View for Calendar<L> {
+    fn element(&self) -> Option<&'static str> {
+        Some("calendar")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|calendar_event, meta| {
+            match calendar_event {
+                CalendarEventInternal::Show(date) => {
+                    self.state.selected = *date;
+                    self.move_cursor(*date);
+                }
+
+                CalendarEventInternal::SetFirstWeekday(weekday) => {
+                    self.state.first_weekday = *weekday;
+                }
+
+                CalendarEventInternal::ShiftMonths(months) => {
+                    self.state.month = add_months(self.state.month, *months);
+                    self.state.cursor = add_months(self.state.cursor, *months);
+                }
+
+                CalendarEventInternal::Select(date) => self.select(cx, *date),
+            }
+
+            meta.consume();
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::KeyDown(code, _) if meta.target == cx.current() => {
+                let cursor = self.state.cursor;
+                let months = if cx.modifiers.contains(Modifiers::SHIFT) { 12 } else { 1 };
+                let weekday = (7 + cursor.weekday().num_days_from_monday()
+                    - self.state.first_weekday.num_days_from_monday())
+                    % 7;
+
+                let date = match code {
+                    Code::ArrowLeft => cursor - Duration::days(1),
+                    Code::ArrowRight => cursor + Duration::days(1),
+                    Code::ArrowUp => cursor - Duration::days(7),
+                    Code::ArrowDown => cursor + Duration::days(7),
+                    Code::Home => cursor - Duration::days(weekday as i64),
+                    Code::End => cursor + Duration::days(6 - weekday as i64),
+                    Code::PageUp => add_months(cursor, -months),
+                    Code::PageDown => add_months(cursor, months),
+                    _ => return,
+                };
+
+                self.move_cursor(date);
+                meta.consume();
+            }
+
+            WindowEvent::PressDown { mouse: false } if meta.target == cx.current() => {
+                self.select(cx, self.state.cursor);
+            }
+
+            _ => {}
+        });
+    }
+}
+
+impl<L: Lens> Handle<'_, Calendar<L>> {
+    /// Sets whether the ISO week number is shown beside each week.
+    pub fn week_numbers(self, week_numbers: bool) -> Self {
+        self.modify(|calendar| calendar.state.week_numbers = week_numbers)
+    }
+
+    /// Sets the earliest date which can be picked.
+    pub fn min_date(self, date: NaiveDate) -> Self {
+        self.modify(|calendar| calendar.state.constraints.min = Some(date))
+    }
+
+    /// Sets the latest date which can be picked.
+    pub fn max_date(self, date: NaiveDate) -> Self {
+        self.modify(|calendar| calendar.state.constraints.max = Some(date))
+    }
+
+    /// Sets a function returning whether a date is disabled, such as for weekends or holidays.
+    pub fn disabled_dates<F>(self, disabled: F) -> Self
+    where
+        F: 'static + Fn(NaiveDate) -> bool + Send + Sync,
+    {
+        self.modify(|calendar| calendar.state.constraints.disabled = Some(Arc::new(disabled)))
+    }
+
+    pub(crate) fn constraints(self, constraints: DateConstraints) -> Self {
+        self.modify(|calendar| calendar.state.constraints = constraints)
+    }
+
+    /// Sets the callback triggered when a date is picked.
+    pub fn on_select<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, NaiveDate),
+    {
+        self.modify(|calendar| calendar.on_select = Some(Box::new(callback)))
+    }
+}
+
+// A day in the grid of a calendar.
+struct CalendarDay {
+    date: NaiveDate,
+}
+
+impl CalendarDay {
+    fn new(cx: &mut Context, date: NaiveDate) -> Handle<Self> {
+        Self { date }.build(cx, |_| {}).text(date.day())
+    }
+}
+
+impl View for CalendarDay {
+    fn element(&self) -> Option<&'static str> {
+        Some("day")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, _| {
+            if let WindowEvent::PressDown { mouse: true } = window_event {
+                cx.emit(CalendarEventInternal::Select(self.date));
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn starts_weeks_by_region() {
+        let weekday = |locale: &str| first_weekday(&locale.parse().unwrap());
+        assert_eq!(weekday("en-US"), Weekday::Sun);
+        assert_eq!(weekday("en-GB"), Weekday::Mon);
+        assert_eq!(weekday("ar-EG"), Weekday::Sat);
+        assert_eq!(weekday("de"), Weekday::Mon);
+        assert_eq!(weekday("ja"), Weekday::Sun);
+    }
+
+    #[test]
+    fn adds_months() {
+        assert_eq!(add_months(date(2023, 1, 31), 1), date(2023, 2, 28));
+        assert_eq!(add_months(date(2024, 1, 31), 1), date(2024, 2, 29));
+        assert_eq!(add_months(date(2023, 1, 15), -1), date(2022, 12, 15));
+        assert_eq!(add_months(date(2023, 3, 15), -15), date(2021, 12, 15));
+    }
+
+    #[test]
+    fn fills_six_weeks() {
+        // March 2023 starts on a Wednesday
+        let days = month_days(date(2023, 3, 14), Weekday::Mon);
+        assert_eq!(days.len(), 42);
+        assert_eq!(days[0], date(2023, 2, 27));
+        assert_eq!(days[41], date(2023, 4, 9));
+
+        let days = month_days(date(2023, 3, 14), Weekday::Sun);
+        assert_eq!(days[0], date(2023, 2, 26));
+
+        // A month starting on the first weekday starts the grid
+        assert_eq!(month_days(date(2023, 5, 20), Weekday::Mon)[0], date(2023, 5, 1));
+    }
+
+    #[test]
+    fn checks_constraints() {
+        let constraints = DateConstraints {
+            min: Some(date(2023, 3, 1)),
+            max: Some(date(2023, 3, 31)),
+            disabled: Some(Arc::new(|date| date.weekday() == Weekday::Sun)),
+        };
+
+        assert!(constraints.allows(date(2023, 3, 1)));
+        assert!(!constraints.allows(date(2023, 2, 28)));
+        assert!(!constraints.allows(date(2023, 4, 1)));
+        assert!(!constraints.allows(date(2023, 3, 12)));
+        assert_eq!(constraints.clamp(date(2023, 5, 1)), date(2023, 3, 31));
+    }
+}
@@ -0,0 +1,428 @@
+use crate::prelude::*;
+use crate::text::{Highlighter, Selection};
+use crate::views::textbox::TextboxData;
+use cosmic_text::{Buffer, Cursor, Edit};
+use std::sync::Arc;
+use vizia_input::Code;
+
+/// The pairs of characters which are matched against each other by bracket matching.
+const BRACKETS: [(char, char); 3] = [('(', ')'), ('[', ']'), ('{', '}')];
+
+/// A multi-line text editor for source code, with a line number gutter, current line highlight,
+/// indentation handling and bracket matching.
+///
+/// Syntax highlighting can be added with a [`Highlighter`], such as the syntect-backed
+/// `SyntectHighlighter` which is available with the `syntect` feature.
+///
+/// Tab inserts a level of indentation at the cursor, or indents the selected lines, and Shift+Tab
+/// outdents the selected lines or the line of the cursor.
+///
+/// Text events sent to the editor, for example [`TextEvent::Find`], are passed on to the textbox
+/// inside it.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     source: String,
+/// # }
+/// # impl Model for AppData {}
+/// # let cx = &mut Context::default();
+/// # AppData { source: String::from("fn main() {}") }.build(cx);
+/// CodeEditor::new(cx, AppData::source)
+///     .on_edit(|_, text| println!("Source changed: {}", text));
+/// ```
+#[derive(Lens)]
+pub struct CodeEditor {
+    textbox: Entity,
+    content: Entity,
+    line_numbers_entity: Entity,
+    indent: String,
+    highlighter: Option<Box<dyn Highlighter>>,
+
+    line_count: usize,
+    line_numbers: String,
+    gutter_offset: f32,
+    current_line: (f32, f32),
+    brackets: Option<((f32, f32, f32, f32), (f32, f32, f32, f32))>,
+}
+
+impl CodeEditor {
+    /// Creates a new code editor showing the text targeted by the lens.
+    pub fn new<L>(cx: &mut Context, lens: L) -> Handle<Self>
+    where
+        L: Lens,
+        <L as Lens>::Target: Data + Clone + ToString,
+    {
+        let mut textbox = Entity::null();
+        let mut line_numbers_entity = Entity::null();
+
+        Self {
+            textbox: Entity::null(),
+            content: Entity::null(),
+            line_numbers_entity: Entity::null(),
+            indent: "    ".to_owned(),
+            highlighter: None,
+            line_count: 1,
+            line_numbers: "1".to_owned(),
+            gutter_offset: 0.0,
+            current_line: (0.0, 0.0),
+            brackets: None,
+        }
+        .build(cx, |cx| {
+            // Highlights are drawn before the textbox so that they sit underneath the text.
+            Element::new(cx)
+                .class("current_line")
+                .hoverable(false)
+                .top(CodeEditor::current_line.map(|(top, _)| Pixels(*top)))
+                .height(CodeEditor::current_line.map(|(_, height)| Pixels(*height)));
+
+            for index in 0..2 {
+                let rect =
+                    move |brackets: &Option<((f32, f32, f32, f32), (f32, f32, f32, f32))>| {
+                        brackets
+                            .map(|(first, second)| if index == 0 { first } else { second })
+                            .unwrap_or_default()
+                    };
+                Element::new(cx)
+                    .class("matching_bracket")
+                    .hoverable(false)
+                    .display(CodeEditor::brackets.map(|brackets| brackets.is_some()))
+                    .left(CodeEditor::brackets.map(move |brackets| Pixels(rect(brackets).0)))
+                    .top(CodeEditor::brackets.map(move |brackets| Pixels(rect(brackets).1)))
+                    .width(CodeEditor::brackets.map(move |brackets| Pixels(rect(brackets).2)))
+                    .height(CodeEditor::brackets.map(move |brackets| Pixels(rect(brackets).3)));
+            }
+
+            VStack::new(cx, |cx| {
+                line_numbers_entity = Element::new(cx)
+                    .class("line_numbers")
+                    .hoverable(false)
+                    .text(CodeEditor::line_numbers)
+                    .translate(CodeEditor::gutter_offset.map(|offset| (0.0, *offset)))
+                    .entity;
+            })
+            .class("gutter")
+            .hoverable(false);
+
+            textbox = Textbox::new_multiline(cx, lens, false).entity;
+            if let Some(abilities) = cx.style.abilities.get_mut(textbox) {
+                abilities.insert(Abilities::TAB_INPUT);
+            }
+        })
+        .modify(|editor| {
+            editor.textbox = textbox;
+            editor.line_numbers_entity = line_numbers_entity;
+        })
+    }
+
+    /// Indents the lines of the selection, or the line of the cursor, by one level, or outdents
+    /// them by up to one level. The lines are replaced with a single edit and stay selected.
+    fn indent_lines(&self, cx: &mut EventContext, outdent: bool) {
+        let selection = cx.text_context.selection(self.content);
+        let text = cx.text_context.with_buffer(self.content, |buf| {
+            buf.lines.iter().map(|line| line.text()).collect::<Vec<_>>().join("\n")
+        });
+
+        // A selection ending at the start of a line doesn't include that line
+        let mut last = selection.max();
+        if last > selection.min() && text[..last].ends_with('\n') {
+            last -= 1;
+        }
+        let start = text[..selection.min()].rfind('\n').map_or(0, |index| index + 1);
+        let end = text[last..].find('\n').map_or(text.len(), |index| last + index);
+
+        let width = self.indent.chars().count();
+        let lines = text[start..end]
+            .split('\n')
+            .map(|line| {
+                if outdent {
+                    let indent = line
+                        .chars()
+                        .take_while(|c| *c == ' ' || *c == '\t')
+                        .take(width)
+                        .map(char::len_utf8)
+                        .sum::<usize>();
+                    line[indent..].to_owned()
+                } else {
+                    format!("{}{}", self.indent, line)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        if lines == text[start..end] {
+            return;
+        }
+
+        // The cursor stays where it was within an outdented line
+        let selected = if selection.is_caret() {
+            let removed = (end - start) - lines.len();
+            Selection::caret(selection.active.saturating_sub(removed).max(start))
+        } else {
+            Selection::new(start, start + lines.len())
+        };
+
+        self.emit_to_textbox(cx, TextEvent::SelectRange(Selection::new(start, end)));
+        self.emit_to_textbox(cx, TextEvent::InsertText(lines));
+        self.emit_to_textbox(cx, TextEvent::SelectRange(selected));
+    }
+
+    // Sends an event to the textbox which bubbles back up to the editor.
+    fn emit_to_textbox(&self, cx: &mut EventContext, message: TextEvent) {
+        cx.emit_custom(
+            Event::new(message)
+                .target(self.textbox)
+                .origin(cx.current())
+                .propagate(Propagation::Up),
+        );
+    }
+
+    /// Recomputes the line numbers and the position of the highlights from the textbox content.
+    fn update(&mut self, cx: &mut EventContext) {
+        if self.content == Entity::null() {
+            return;
+        }
+
+        let scale = cx.style.dpi_factor as f32;
+
+        let current = cx.current;
+        cx.current = self.textbox;
+        let transform = cx.data::<TextboxData>().map(|data| data.transform).unwrap_or_default();
+        cx.current = current;
+
+        let bounds = cx.cache.get_bounds(cx.current());
+        let content_bounds = cx.cache.get_bounds(self.content);
+        let line_numbers_bounds = cx.cache.get_bounds(self.line_numbers_entity);
+        let origin =
+            (content_bounds.x + transform.0 * scale, content_bounds.y + transform.1 * scale);
+
+        let line_count = cx.text_context.with_buffer(self.content, |buf| buf.lines.len());
+        if line_count != self.line_count {
+            self.line_count = line_count;
+            self.line_numbers =
+                (1..=line_count).map(|line| line.to_string()).collect::<Vec<_>>().join("\n");
+        }
+
+        self.gutter_offset = (content_bounds.y - line_numbers_bounds.y) / scale + transform.1;
+
+        if let Some((_, y, _, h)) =
+            cx.text_context.layout_caret(self.content, origin, (0.0, 0.0), 1.0)
+        {
+            self.current_line = ((y - bounds.y) / scale, h / scale);
+        }
+
+        let brackets = cx
+            .text_context
+            .with_editor(self.content, |buf| matching_brackets(buf.buffer(), buf.cursor()));
+
+        self.brackets = brackets.and_then(|(first, second)| {
+            let mut rect = |cursor: Cursor| {
+                let end = cx.text_context.with_buffer(self.content, |buf| {
+                    let line = buf.lines[cursor.line].text();
+                    let len = line[cursor.index..].chars().next().map_or(0, char::len_utf8);
+                    Cursor::new(cursor.line, cursor.index + len)
+                });
+                cx.text_context
+                    .layout_range(self.content, cursor, end, origin, (0.0, 0.0))
+                    .first()
+                    .map(|(x, y, w, h)| {
+                        ((x - bounds.x) / scale, (y - bounds.y) / scale, w / scale, h / scale)
+                    })
+            };
+
+            Some((rect(first)?, rect(second)?))
+        });
+    }
+}
+
+impl View for CodeEditor {
+    fn element(&self) -> Option<&'static str> {
+        Some("codeeditor")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        // Text events sent to the editor itself, such as searches, are meant for its textbox.
+        if event.meta.target == cx.current() {
+            if let Some(text_event) = event.take::<TextEvent>() {
+                self.emit_to_textbox(cx, text_event);
+                return;
+            }
+        }
+
+        let mut needs_update = false;
+
+        event.map(|text_event, _| {
+            if let TextEvent::InitContent(content, _) = text_event {
+                self.content = *content;
+                if let Some(highlighter) = self.highlighter.take() {
+                    cx.text_context.set_highlighter(self.content, Some(highlighter));
+                }
+            }
+
+            needs_update = true;
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::KeyDown(Code::Tab, _) => {
+                let selection = cx.text_context.selection(self.content);
+                if cx.modifiers.contains(Modifiers::SHIFT) || !selection.is_caret() {
+                    self.indent_lines(cx, cx.modifiers.contains(Modifiers::SHIFT));
+                } else {
+                    self.emit_to_textbox(cx, TextEvent::InsertText(self.indent.clone()));
+                }
+
+                meta.consume();
+            }
+
+            WindowEvent::KeyDown(Code::Enter, _) => {
+                // The textbox has already queued the newline, so this follows it.
+                let indent = cx.text_context.with_editor(self.content, |buf| {
+                    let cursor = buf.cursor();
+                    let line = buf.buffer().lines[cursor.line].text();
+                    let before = &line[..cursor.index];
+                    let mut indent =
+                        before.chars().take_while(|c| c.is_whitespace()).collect::<String>();
+                    if before.trim_end().ends_with(|c| BRACKETS.iter().any(|(open, _)| *open == c))
+                    {
+                        indent.push_str(&self.indent);
+                    }
+                    indent
+                });
+
+                if !indent.is_empty() {
+                    self.emit_to_textbox(cx, TextEvent::InsertText(indent));
+                }
+            }
+
+            WindowEvent::GeometryChanged(_)
+            | WindowEvent::MouseScroll(_, _)
+            | WindowEvent::MouseScrollPixels(_, _) => {
+                needs_update = true;
+            }
+
+            _ => {}
+        });
+
+        if needs_update {
+            self.update(cx);
+        }
+    }
+}
+
+impl<'a> Handle<'a, CodeEditor> {
+    fn textbox(&self) -> Entity {
+        self.cx
+            .views
+            .get(&self.entity)
+            .and_then(|view| view.downcast_ref::<CodeEditor>())
+            .map_or(Entity::null(), |editor| editor.textbox)
+    }
+
+    /// Sets the callback triggered when the text is edited.
+    pub fn on_edit<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, String) + Send + Sync,
+    {
+        let textbox = self.textbox();
+        self.cx.emit_to(textbox, TextEvent::SetOnEdit(Some(Arc::new(callback))));
+
+        self
+    }
+
+    /// Sets the callback triggered when the search matches or the selected match change, as for a
+    /// [`Textbox`].
+    pub fn on_find<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, Vec<Selection>, Option<usize>) + Send + Sync,
+    {
+        let textbox = self.textbox();
+        self.cx.emit_to(textbox, TextEvent::SetOnFind(Some(Arc::new(callback))));
+
+        self
+    }
+
+    /// Sets the callback triggered when `Ctrl+F` is pressed in the editor, as for a [`Textbox`].
+    pub fn on_open_find<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, String) + Send + Sync,
+    {
+        let textbox = self.textbox();
+        self.cx.emit_to(textbox, TextEvent::SetOnOpenFind(Some(Arc::new(callback))));
+
+        self
+    }
+
+    /// Sets the highlighter used to color the text, for example for syntax highlighting.
+    pub fn highlighter(self, highlighter: impl Highlighter) -> Self {
+        self.modify(|editor| editor.highlighter = Some(Box::new(highlighter)))
+    }
+
+    /// Sets the text inserted by the tab key and used for automatic indentation. Defaults to four
+    /// spaces.
+    pub fn indent(self, indent: impl Into<String>) -> Self {
+        let indent = indent.into();
+        self.modify(|editor| editor.indent = indent)
+    }
+}
+
+/// Finds the bracket next to the cursor and the bracket matching it, as cursors pointing at the
+/// start of each bracket.
+fn matching_brackets(buffer: &Buffer, cursor: Cursor) -> Option<(Cursor, Cursor)> {
+    let line = buffer.lines[cursor.line].text();
+
+    // Prefer the bracket after the cursor over the one before it.
+    let after = line[cursor.index..].chars().next().map(|c| (cursor.index, c));
+    let before = line[..cursor.index].char_indices().next_back();
+
+    [after, before].into_iter().flatten().find_map(|(index, c)| {
+        let start = Cursor::new(cursor.line, index);
+        if let Some((open, close)) = BRACKETS.iter().find(|(open, _)| *open == c) {
+            find_forward(buffer, start, *open, *close).map(|end| (start, end))
+        } else if let Some((open, close)) = BRACKETS.iter().find(|(_, close)| *close == c) {
+            find_backward(buffer, start, *open, *close).map(|end| (start, end))
+        } else {
+            None
+        }
+    })
+}
+
+fn find_forward(buffer: &Buffer, start: Cursor, open: char, close: char) -> Option<Cursor> {
+    let mut depth = 0;
+    for (line_index, line) in buffer.lines.iter().enumerate().skip(start.line) {
+        let from = if line_index == start.line { start.index } else { 0 };
+        for (index, c) in line.text()[from..].char_indices() {
+            if c == open {
+                depth += 1;
+            } else if c == close {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(Cursor::new(line_index, from + index));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn find_backward(buffer: &Buffer, start: Cursor, open: char, close: char) -> Option<Cursor> {
+    let mut depth = 0;
+    for (line_index, line) in buffer.lines.iter().enumerate().take(start.line + 1).rev() {
+        let text = line.text();
+        let to = if line_index == start.line { start.index + close.len_utf8() } else { text.len() };
+        for (index, c) in text[..to].char_indices().rev() {
+            if c == close {
+                depth += 1;
+            } else if c == open {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(Cursor::new(line_index, index));
+                }
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,764 @@
+use femtovg::{Paint, Path};
+
+use crate::context::{InternalEvent, SampledColor};
+use crate::fonts::icons_names::PLUS;
+use crate::fonts::material_names::COLORIZE;
+use crate::prelude::*;
+
+/// A colour as hue, saturation, value and alpha, which is what the picker edits.
+///
+/// The hue is in degrees and the other components are in the range [0, 1].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Data)]
+pub struct Hsva {
+    h: f32,
+    s: f32,
+    v: f32,
+    a: f32,
+}
+
+impl Hsva {
+    // Converts a colour, keeping the hue and saturation of the previous colour where the colour
+    // doesn't have one, which is for greys and for black respectively.
+    fn from_color(color: Color, previous: Hsva) -> Self {
+        let (r, g, b) = (unit(color.r()), unit(color.g()), unit(color.b()));
+        let max = r.max(g).max(b);
+        let chroma = max - r.min(g).min(b);
+
+        let h = if chroma == 0.0 {
+            previous.h
+        } else if max == r {
+            60.0 * ((g - b) / chroma).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / chroma + 2.0)
+        } else {
+            60.0 * ((r - g) / chroma + 4.0)
+        };
+
+        let s = if max == 0.0 { previous.s } else { chroma / max };
+
+        Self { h, s, v: max, a: unit(color.a()) }
+    }
+
+    fn to_color(self) -> Color {
+        let (r, g, b) = self.rgb();
+        Color::rgba(channel(r), channel(g), channel(b), channel(self.a))
+    }
+
+    // Returns the red, green and blue components, in the range [0, 1].
+    fn rgb(self) -> (f32, f32, f32) {
+        let chroma = self.v * self.s;
+        let h = self.h.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+        let (r, g, b) = match h as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        let m = self.v - chroma;
+        (r + m, g + m, b + m)
+    }
+
+    // Returns the saturation and lightness of the colour in HSL.
+    fn hsl(self) -> (f32, f32) {
+        let l = self.v * (1.0 - self.s / 2.0);
+        let s = if l <= 0.0 || l >= 1.0 { 0.0 } else { (self.v - l) / l.min(1.0 - l) };
+        (s, l)
+    }
+
+    // Returns the colour with the given saturation and lightness in HSL.
+    fn with_hsl(self, s: f32, l: f32) -> Self {
+        let v = l + s * l.min(1.0 - l);
+        let s = if v <= 0.0 { self.s } else { 2.0 * (1.0 - l / v) };
+        Self { s, v, ..self }
+    }
+
+    fn apply(self, input: ColorInput) -> Self {
+        let color = self.to_color();
+        match input {
+            ColorInput::Red(red) => {
+                Self::from_color(Color::rgba(red, color.g(), color.b(), color.a()), self)
+            }
+            ColorInput::Green(green) => {
+                Self::from_color(Color::rgba(color.r(), green, color.b(), color.a()), self)
+            }
+            ColorInput::Blue(blue) => {
+                Self::from_color(Color::rgba(color.r(), color.g(), blue, color.a()), self)
+            }
+            ColorInput::Hue(h) => Self { h, ..self },
+            ColorInput::Saturation(s) => self.with_hsl(s, self.hsl().1),
+            ColorInput::Lightness(l) => self.with_hsl(self.hsl().0, l),
+            ColorInput::Rgba(color) => Self::from_color(color, self),
+        }
+    }
+}
+
+fn unit(channel: u8) -> f32 {
+    channel as f32 / 255.0
+}
+
+fn channel(unit: f32) -> u8 {
+    (unit.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+// Formats a colour as `#rrggbb`, or as `#rrggbbaa` if it's translucent.
+fn to_hex(color: Color) -> String {
+    let hex = format!("#{:02x}{:02x}{:02x}", color.r(), color.g(), color.b());
+    if color.a() == 255 {
+        hex
+    } else {
+        format!("{}{:02x}", hex, color.a())
+    }
+}
+
+// Parses a colour in the `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` format, where the `#` is
+// optional.
+fn parse_hex(text: &str) -> Result<Color, String> {
+    let text = text.trim();
+    let digits = text.strip_prefix('#').unwrap_or(text);
+    if !digits.chars().all(|digit| digit.is_ascii_hexdigit()) {
+        return Err(format!("{} isn't a hexadecimal colour", text));
+    }
+
+    let value = |index: usize, width: usize| {
+        let value = u8::from_str_radix(&digits[index * width..(index + 1) * width], 16).unwrap();
+        if width == 1 {
+            value * 17
+        } else {
+            value
+        }
+    };
+
+    match digits.len() {
+        3 => Ok(Color::rgb(value(0, 1), value(1, 1), value(2, 1))),
+        4 => Ok(Color::rgba(value(0, 1), value(1, 1), value(2, 1), value(3, 1))),
+        6 => Ok(Color::rgb(value(0, 2), value(1, 2), value(2, 2))),
+        8 => Ok(Color::rgba(value(0, 2), value(1, 2), value(2, 2), value(3, 2))),
+        _ => Err(format!("{} should have 3, 4, 6 or 8 digits", text)),
+    }
+}
+
+// Parses a number between zero and the maximum.
+fn parse_number(text: &str, max: f32) -> Result<f32, String> {
+    let number = text.trim().parse::<f32>().map_err(|error| error.to_string())?;
+    if (0.0..=max).contains(&number) {
+        Ok(number)
+    } else {
+        Err(format!("{} isn't between 0 and {}", number, max))
+    }
+}
+
+// Returns the fraction of the length covered up to the position, clamped to [0, 1].
+fn fraction(position: f32, start: f32, length: f32) -> f32 {
+    if length > 0.0 {
+        ((position - start) / length).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+// A component of the colour typed into one of the inputs of the picker.
+#[derive(Debug, Clone, Copy)]
+enum ColorInput {
+    Red(u8),
+    Green(u8),
+    Blue(u8),
+    Hue(f32),
+    Saturation(f32),
+    Lightness(f32),
+    Rgba(Color),
+}
+
+enum ColorPickerEventInternal {
+    // The bound colour has changed
+    Show(Color),
+    Set(Hsva),
+    Input(ColorInput),
+    SaveSwatch,
+    RemoveSwatch(usize),
+    StartSampling,
+}
+
+/// A view for picking a colour, with a saturation and value square, hue and alpha sliders, inputs
+/// for the RGB, HSL and hexadecimal values of the colour, and a palette of saved swatches.
+///
+/// The colour is saved to the palette with the add button, and a swatch is removed by right
+/// clicking it. Changes to the palette are reported with `on_swatches_change`. The eyedropper
+/// button picks the colour of whichever point of the window is clicked next, which can be
+/// cancelled with a right click or the escape key.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # use vizia_derive::*;
+/// # let cx = &mut Context::default();
+/// # #[derive(Lens)]
+/// # pub struct AppData {
+/// #     color: Color,
+/// # }
+/// # impl Model for AppData {}
+/// # AppData { color: Color::rgb(200, 80, 40) }.build(cx);
+/// ColorPicker::new(cx, AppData::color)
+///     .swatches(vec![Color::black(), Color::white()])
+///     .on_change(|_, color| println!("Picked {}", color))
+///     .on_swatches_change(|_, swatches| println!("Saved {} swatches", swatches.len()));
+/// ```
+#[derive(Lens)]
+pub struct ColorPicker<L: Lens> {
+    lens: L,
+    hsva: Hsva,
+    swatches: Vec<Color>,
+    is_sampling: bool,
+    on_change: Option<Box<dyn Fn(&mut EventContext, Color)>>,
+    on_swatches_change: Option<Box<dyn Fn(&mut EventContext, Vec<Color>)>>,
+}
+
+impl<L> ColorPicker<L>
+where
+    L: Lens<Target = Color>,
+{
+    /// Creates a new colour picker bound to the colour targeted by the lens.
+    pub fn new(cx: &mut Context, lens: L) -> Handle<Self> {
+        Self {
+            lens: lens.clone(),
+            hsva: Hsva::from_color(lens.get(cx), Hsva::default()),
+            swatches: Vec::new(),
+            is_sampling: false,
+            on_change: None,
+            on_swatches_change: None,
+        }
+        .build(cx, |cx| {
+            Binding::new(cx, lens, |cx, color| {
+                let color = color.get(cx);
+                cx.emit(ColorPickerEventInternal::Show(color));
+            });
+
+            SaturationValueArea::new(cx, ColorPicker::<L>::hsva);
+
+            HStack::new(cx, |cx| {
+                Element::new(cx)
+                    .class("preview")
+                    .background_color(ColorPicker::<L>::hsva.map(|hsva| hsva.to_color()));
+
+                VStack::new(cx, |cx| {
+                    ColorSlider::new(cx, ColorPicker::<L>::hsva, ColorChannel::Hue).class("hue");
+                    ColorSlider::new(cx, ColorPicker::<L>::hsva, ColorChannel::Alpha)
+                        .class("alpha");
+                })
+                .class("sliders");
+
+                Button::new(
+                    cx,
+                    |cx| cx.emit(ColorPickerEventInternal::StartSampling),
+                    |cx| Label::new(cx, COLORIZE).class("material-icon"),
+                )
+                .class("eyedropper");
+            })
+            .class("color-row");
+
+            HStack::new(cx, |cx| {
+                color_input(
+                    cx,
+                    "R",
+                    ColorPicker::<L>::hsva.map(|hsva| hsva.to_color().r().to_string()),
+                    |text| parse_number(text, 255.0).map(|red| ColorInput::Red(red as u8)),
+                );
+                color_input(
+                    cx,
+                    "G",
+                    ColorPicker::<L>::hsva.map(|hsva| hsva.to_color().g().to_string()),
+                    |text| parse_number(text, 255.0).map(|green| ColorInput::Green(green as u8)),
+                );
+                color_input(
+                    cx,
+                    "B",
+                    ColorPicker::<L>::hsva.map(|hsva| hsva.to_color().b().to_string()),
+                    |text| parse_number(text, 255.0).map(|blue| ColorInput::Blue(blue as u8)),
+                );
+            })
+            .class("color-row");
+
+            HStack::new(cx, |cx| {
+                let degrees = |hsva: &Hsva| (hsva.h.round() as u32 % 360).to_string();
+                let percent = |unit: f32| ((unit * 100.0).round() as u32).to_string();
+                color_input(cx, "H", ColorPicker::<L>::hsva.map(degrees), |text| {
+                    parse_number(text, 360.0).map(ColorInput::Hue)
+                });
+                color_input(
+                    cx,
+                    "S",
+                    ColorPicker::<L>::hsva.map(move |hsva| percent(hsva.hsl().0)),
+                    |text| parse_number(text, 100.0).map(|s| ColorInput::Saturation(s / 100.0)),
+                );
+                color_input(
+                    cx,
+                    "L",
+                    ColorPicker::<L>::hsva.map(move |hsva| percent(hsva.hsl().1)),
+                    |text| parse_number(text, 100.0).map(|l| ColorInput::Lightness(l / 100.0)),
+                );
+            })
+            .class("color-row");
+
+            HStack::new(cx, |cx| {
+                color_input(
+                    cx,
+                    "#",
+                    ColorPicker::<L>::hsva.map(|hsva| to_hex(hsva.to_color())),
+                    |text| parse_hex(text).map(ColorInput::Rgba),
+                );
+            })
+            .class("color-row");
+
+            HStack::new(cx, |cx| {
+                Binding::new(cx, ColorPicker::<L>::swatches, |cx, swatches| {
+                    for (index, color) in swatches.get(cx).into_iter().enumerate() {
+                        Element::new(cx)
+                            .class("swatch")
+                            .background_color(color)
+                            .on_press(move |cx| {
+                                cx.emit(ColorPickerEventInternal::Input(ColorInput::Rgba(color)))
+                            })
+                            .on_mouse_down(move |cx, button| {
+                                if button == MouseButton::Right {
+                                    cx.emit(ColorPickerEventInternal::RemoveSwatch(index));
+                                }
+                            });
+                    }
+                });
+
+                Button::new(
+                    cx,
+                    |cx| cx.emit(ColorPickerEventInternal::SaveSwatch),
+                    |cx| Label::new(cx, PLUS),
+                )
+                .class("save");
+            })
+            .class("swatches");
+        })
+    }
+}
+
+// Builds a textbox for a component of the colour, labelled with its name, which sets the
+// component when a valid value is submitted.
+fn color_input<F>(cx: &mut Context, name: &str, text: impl Lens<Target = String>, parse: F)
+where
+    F: 'static + Fn(&str) -> Result<ColorInput, String> + Send + Sync + Copy,
+{
+    Label::new(cx, name).class("input-name");
+    Textbox::new(cx, text).validate(parse).on_submit(move |cx, text, _| {
+        if let Ok(input) = parse(&text) {
+            cx.emit(ColorPickerEventInternal::Input(input));
+        }
+    });
+}
+
+impl<L: Lens<Target = Color>> View for ColorPicker<L> {
+    fn element(&self) -> Option<&'static str> {
+        Some("colorpicker")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|color_picker_event, meta| {
+            match color_picker_event {
+                ColorPickerEventInternal::Show(color) => {
+                    // Keep the hue and saturation of greys picked with the picker
+                    if self.hsva.to_color() != *color {
+                        self.hsva = Hsva::from_color(*color, self.hsva);
+                    }
+                }
+
+                ColorPickerEventInternal::Set(hsva) => self.pick(cx, *hsva),
+                ColorPickerEventInternal::Input(input) => self.pick(cx, self.hsva.apply(*input)),
+
+                ColorPickerEventInternal::SaveSwatch => {
+                    let color = self.hsva.to_color();
+                    if !self.swatches.contains(&color) {
+                        self.swatches.push(color);
+                        self.swatches_changed(cx);
+                    }
+                }
+
+                ColorPickerEventInternal::RemoveSwatch(index) => {
+                    if *index < self.swatches.len() {
+                        self.swatches.remove(*index);
+                        self.swatches_changed(cx);
+                    }
+                }
+
+                ColorPickerEventInternal::StartSampling => {
+                    self.is_sampling = true;
+                    cx.capture();
+                    cx.emit(WindowEvent::SetCursor(CursorIcon::Crosshair));
+                    cx.lock_cursor_icon();
+                }
+            }
+
+            meta.consume();
+        });
+
+        event.map(|sampled_color: &SampledColor, meta| {
+            self.pick(cx, self.hsva.apply(ColorInput::Rgba(sampled_color.0)));
+            meta.consume();
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) if self.is_sampling => {
+                cx.emit(InternalEvent::SampleColor {
+                    target: cx.current(),
+                    x: cx.mouse.cursorx,
+                    y: cx.mouse.cursory,
+                });
+
+                self.stop_sampling(cx);
+                meta.consume();
+            }
+
+            WindowEvent::MouseDown(MouseButton::Right) if self.is_sampling => {
+                self.stop_sampling(cx);
+                meta.consume();
+            }
+
+            WindowEvent::KeyDown(Code::Escape, _) if self.is_sampling => {
+                self.stop_sampling(cx);
+                cx.release();
+                meta.consume();
+            }
+
+            // The capture is kept until the button is released, so the sampled view isn't pressed
+            WindowEvent::MouseUp(_) if !self.is_sampling => cx.release(),
+
+            _ => {}
+        });
+    }
+}
+
+impl<L: Lens<Target = Color>> ColorPicker<L> {
+    fn pick(&mut self, cx: &mut EventContext, hsva: Hsva) {
+        self.hsva = hsva;
+        if let Some(callback) = &self.on_change {
+            (callback)(cx, hsva.to_color());
+        }
+    }
+
+    fn swatches_changed(&self, cx: &mut EventContext) {
+        if let Some(callback) = &self.on_swatches_change {
+            (callback)(cx, self.swatches.clone());
+        }
+    }
+
+    fn stop_sampling(&mut self, cx: &mut EventContext) {
+        self.is_sampling = false;
+        cx.unlock_cursor_icon();
+    }
+}
+
+impl<L: Lens> Handle<'_, ColorPicker<L>> {
+    /// Sets the colours saved in the palette of the picker.
+    pub fn swatches(self, swatches: Vec<Color>) -> Self {
+        self.modify(|color_picker| color_picker.swatches = swatches)
+    }
+
+    /// Sets the callback triggered when a colour is picked.
+    pub fn on_change<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, Color),
+    {
+        self.modify(|color_picker| color_picker.on_change = Some(Box::new(callback)))
+    }
+
+    /// Sets the callback triggered when a swatch is saved to or removed from the palette, which
+    /// receives the colours of the palette.
+    pub fn on_swatches_change<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, Vec<Color>),
+    {
+        self.modify(|color_picker| color_picker.on_swatches_change = Some(Box::new(callback)))
+    }
+}
+
+// The square picking the saturation and value of the colour, at the hue of the colour.
+struct SaturationValueArea {
+    hsva: Hsva,
+    is_dragging: bool,
+}
+
+impl SaturationValueArea {
+    fn new(cx: &mut Context, lens: impl Lens<Target = Hsva>) -> Handle<Self> {
+        Self { hsva: lens.get(cx), is_dragging: false }.build(cx, |_| {}).bind(
+            lens,
+            |handle, hsva| {
+                let hsva = hsva.get(handle.cx);
+                handle.modify(|area| area.hsva = hsva).cx.need_redraw();
+            },
+        )
+    }
+
+    // Picks the saturation and value under the cursor.
+    fn pick(&self, cx: &mut EventContext) {
+        let bounds = cx.cache.get_bounds(cx.current());
+        let s = fraction(cx.mouse.cursorx, bounds.x, bounds.w);
+        let v = 1.0 - fraction(cx.mouse.cursory, bounds.y, bounds.h);
+        cx.emit(ColorPickerEventInternal::Set(Hsva { s, v, ..self.hsva }));
+    }
+}
+
+impl View for SaturationValueArea {
+    fn element(&self) -> Option<&'static str> {
+        Some("colorarea")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                self.is_dragging = true;
+                cx.capture();
+                self.pick(cx);
+                meta.consume();
+            }
+
+            WindowEvent::MouseMove(_, _) if self.is_dragging => self.pick(cx),
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                self.is_dragging = false;
+                cx.release();
+            }
+
+            _ => {}
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &mut Canvas) {
+        let bounds = cx.bounds();
+        let opacity = cx.opacity();
+        let (x, y, w, h) = (bounds.x, bounds.y, bounds.w, bounds.h);
+
+        // The hue is lightened towards white on the left and darkened towards black at the bottom
+        let mut hue: femtovg::Color =
+            Hsva { s: 1.0, v: 1.0, a: 1.0, ..self.hsva }.to_color().into();
+        hue.set_alphaf(opacity);
+        let white = femtovg::Color::rgbaf(1.0, 1.0, 1.0, opacity);
+        let clear_white = femtovg::Color::rgbaf(1.0, 1.0, 1.0, 0.0);
+        let black = femtovg::Color::rgbaf(0.0, 0.0, 0.0, opacity);
+        let clear_black = femtovg::Color::rgbaf(0.0, 0.0, 0.0, 0.0);
+
+        let mut path = Path::new();
+        path.rect(x, y, w, h);
+        canvas.fill_path(&mut path, &Paint::color(hue));
+        canvas.fill_path(&mut path, &Paint::linear_gradient(x, y, x + w, y, white, clear_white));
+        canvas.fill_path(&mut path, &Paint::linear_gradient(x, y, x, y + h, clear_black, black));
+
+        let radius = cx.logical_to_physical(5.0);
+        let mut marker = Path::new();
+        marker.circle(x + self.hsva.s * w, y + (1.0 - self.hsva.v) * h, radius);
+        let mut paint = Paint::color(white);
+        paint.set_line_width(cx.logical_to_physical(2.0));
+        canvas.stroke_path(&mut marker, &paint);
+    }
+}
+
+// The component of the colour set by a colour slider.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ColorChannel {
+    Hue,
+    Alpha,
+}
+
+// A horizontal slider for the hue or the alpha of the colour, showing the range of the component.
+struct ColorSlider {
+    channel: ColorChannel,
+    hsva: Hsva,
+    is_dragging: bool,
+}
+
+impl ColorSlider {
+    fn new(
+        cx: &mut Context,
+        lens: impl Lens<Target = Hsva>,
+        channel: ColorChannel,
+    ) -> Handle<Self> {
+        Self { channel, hsva: lens.get(cx), is_dragging: false }.build(cx, |_| {}).bind(
+            lens,
+            |handle, hsva| {
+                let hsva = hsva.get(handle.cx);
+                handle.modify(|slider| slider.hsva = hsva).cx.need_redraw();
+            },
+        )
+    }
+
+    // Returns the position of the component along the slider, in the range [0, 1].
+    fn position(&self) -> f32 {
+        match self.channel {
+            ColorChannel::Hue => self.hsva.h / 360.0,
+            ColorChannel::Alpha => self.hsva.a,
+        }
+    }
+
+    // Picks the component under the cursor.
+    fn pick(&self, cx: &mut EventContext) {
+        let bounds = cx.cache.get_bounds(cx.current());
+        let position = fraction(cx.mouse.cursorx, bounds.x, bounds.w);
+        let hsva = match self.channel {
+            ColorChannel::Hue => Hsva { h: position * 360.0, ..self.hsva },
+            ColorChannel::Alpha => Hsva { a: position, ..self.hsva },
+        };
+
+        cx.emit(ColorPickerEventInternal::Set(hsva));
+    }
+}
+
+impl View for ColorSlider {
+    fn element(&self) -> Option<&'static str> {
+        Some("colorslider")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                self.is_dragging = true;
+                cx.capture();
+                self.pick(cx);
+                meta.consume();
+            }
+
+            WindowEvent::MouseMove(_, _) if self.is_dragging => self.pick(cx),
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                self.is_dragging = false;
+                cx.release();
+            }
+
+            _ => {}
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &mut Canvas) {
+        let bounds = cx.bounds();
+        let opacity = cx.opacity();
+        let (x, y, w, h) = (bounds.x, bounds.y, bounds.w, bounds.h);
+        let with_opacity = |color: Color| {
+            let mut color: femtovg::Color = color.into();
+            color.set_alphaf(color.a * opacity);
+            color
+        };
+
+        let mut path = Path::new();
+        path.rect(x, y, w, h);
+
+        match self.channel {
+            ColorChannel::Hue => {
+                let stops = (0..=6)
+                    .map(|stop| {
+                        let hue = Hsva { h: stop as f32 * 60.0, s: 1.0, v: 1.0, a: 1.0 };
+                        (stop as f32 / 6.0, with_opacity(hue.to_color()))
+                    })
+                    .collect::<Vec<_>>();
+
+                canvas.fill_path(&mut path, &Paint::linear_gradient_stops(x, y, x + w, y, &stops));
+            }
+
+            ColorChannel::Alpha => {
+                // A checkerboard shows through the translucent part of the colour
+                let size = (h / 2.0).max(1.0);
+                canvas.fill_path(&mut path, &Paint::color(with_opacity(Color::white())));
+                let mut checks = Path::new();
+                for column in 0..(w / size).ceil() as usize {
+                    let left = x + column as f32 * size;
+                    let top = if column % 2 == 0 { y } else { y + size };
+                    checks.rect(left, top, size.min(x + w - left), size.min(y + h - top));
+                }
+
+                canvas
+                    .fill_path(&mut checks, &Paint::color(with_opacity(Color::rgb(204, 204, 204))));
+
+                let opaque = Hsva { a: 1.0, ..self.hsva }.to_color();
+                let clear = Hsva { a: 0.0, ..self.hsva }.to_color();
+                canvas.fill_path(
+                    &mut path,
+                    &Paint::linear_gradient(
+                        x,
+                        y,
+                        x + w,
+                        y,
+                        with_opacity(clear),
+                        with_opacity(opaque),
+                    ),
+                );
+            }
+        }
+
+        let thumb_width = cx.logical_to_physical(4.0);
+        let mut thumb = Path::new();
+        thumb.rect(x + self.position() * (w - thumb_width), y, thumb_width, h);
+        let mut paint = Paint::color(with_opacity(Color::white()));
+        paint.set_line_width(cx.logical_to_physical(2.0));
+        canvas.stroke_path(&mut thumb, &paint);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_between_rgb_and_hsv() {
+        let colors = [
+            Color::rgb(200, 80, 40),
+            Color::rgb(12, 250, 99),
+            Color::rgba(1, 2, 255, 128),
+            Color::rgb(255, 255, 0),
+            Color::black(),
+            Color::white(),
+        ];
+
+        for color in colors {
+            assert_eq!(Hsva::from_color(color, Hsva::default()).to_color(), color);
+        }
+
+        let red = Hsva::from_color(Color::rgb(255, 0, 0), Hsva::default());
+        assert_eq!((red.h, red.s, red.v), (0.0, 1.0, 1.0));
+        let blue = Hsva::from_color(Color::rgb(0, 0, 255), Hsva::default());
+        assert_eq!(blue.h, 240.0);
+    }
+
+    #[test]
+    fn keeps_the_hue_of_greys() {
+        let previous = Hsva { h: 120.0, s: 0.5, v: 0.5, a: 1.0 };
+        let grey = Hsva::from_color(Color::rgb(128, 128, 128), previous);
+        assert_eq!((grey.h, grey.s), (120.0, 0.0));
+
+        let black = Hsva::from_color(Color::black(), previous);
+        assert_eq!((black.h, black.s, black.v), (120.0, 0.5, 0.0));
+    }
+
+    #[test]
+    fn converts_between_hsv_and_hsl() {
+        let hsva = Hsva { h: 30.0, s: 0.8, v: 0.6, a: 1.0 };
+        let (s, l) = hsva.hsl();
+        assert!((l - 0.36).abs() < 1e-5);
+        assert!((s - 0.6667).abs() < 1e-3);
+
+        let back = hsva.with_hsl(s, l);
+        assert!((back.s - hsva.s).abs() < 1e-5 && (back.v - hsva.v).abs() < 1e-5);
+
+        let lighter = hsva.apply(ColorInput::Lightness(0.5));
+        assert!((lighter.hsl().1 - 0.5).abs() < 1e-5);
+        assert_eq!(lighter.h, hsva.h);
+    }
+
+    #[test]
+    fn parses_hex_colors() {
+        assert_eq!(parse_hex("#c85028"), Ok(Color::rgb(200, 80, 40)));
+        assert_eq!(parse_hex(" c8502880 "), Ok(Color::rgba(200, 80, 40, 128)));
+        assert_eq!(parse_hex("#f80"), Ok(Color::rgb(255, 136, 0)));
+        assert_eq!(parse_hex("#f808"), Ok(Color::rgba(255, 136, 0, 136)));
+        assert!(parse_hex("#c8502").is_err());
+        assert!(parse_hex("#g85028").is_err());
+        assert!(parse_hex("#ñ85").is_err());
+
+        assert_eq!(to_hex(Color::rgb(200, 80, 40)), "#c85028");
+        assert_eq!(to_hex(Color::rgba(200, 80, 40, 0)), "#c8502800");
+    }
+}
@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+
+use crate::fonts::material_names::CALENDAR;
+use crate::prelude::*;
+use crate::views::calendar::DateConstraints;
+
+// Parses a date typed in the given format, which must be a date that can be picked.
+fn parse_date(
+    text: &str,
+    format: &str,
+    constraints: &DateConstraints,
+) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(text.trim(), format)
+        .map_err(|error| error.to_string())
+        .and_then(|date| constraints.check(date))
+}
+
+enum DatePickerEventInternal {
+    Select(NaiveDate),
+}
+
+/// A textbox for typing a date, with a button opening a [`Calendar`] to pick the date from.
+///
+/// Typed dates are parsed with the format of the picker, which is ISO 8601 (`%Y-%m-%d`) by default,
+/// and must satisfy the same constraints as dates picked from the calendar. The textbox has the
+/// `:invalid` pseudo-class while its text isn't a valid date.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # use vizia_core::chrono::{Datelike, NaiveDate, Weekday};
+/// # use vizia_derive::*;
+/// # let cx = &mut Context::default();
+/// # #[derive(Lens)]
+/// # pub struct AppData {
+/// #     date: NaiveDate,
+/// # }
+/// # impl Model for AppData {}
+/// # AppData { date: NaiveDate::from_ymd_opt(2023, 3, 14).unwrap() }.build(cx);
+/// DatePicker::new(cx, AppData::date)
+///     .format("%d/%m/%Y")
+///     .disabled_dates(|date| matches!(date.weekday(), Weekday::Sat | Weekday::Sun))
+///     .on_select(|_, date| println!("Picked {}", date));
+/// ```
+#[derive(Lens)]
+pub struct DatePicker<L: Lens> {
+    lens: L,
+    format: String,
+    constraints: DateConstraints,
+    is_open: bool,
+    on_select: Option<Box<dyn Fn(&mut EventContext, NaiveDate)>>,
+}
+
+impl<L> DatePicker<L>
+where
+    L: Lens<Target = NaiveDate>,
+{
+    /// Creates a new date picker bound to the date targeted by the lens.
+    pub fn new(cx: &mut Context, lens: L) -> Handle<Self> {
+        Self {
+            lens: lens.clone(),
+            format: String::from("%Y-%m-%d"),
+            constraints: DateConstraints::default(),
+            is_open: false,
+            on_select: None,
+        }
+        .build(cx, |cx| {
+            let picker = cx.current();
+
+            let text_lens = lens.clone();
+            Binding::new(cx, DatePicker::<L>::format, move |cx, format| {
+                let format = format.get(cx);
+                let lens = text_lens.clone();
+                Binding::new(cx, DatePicker::<L>::constraints, move |cx, constraints| {
+                    let constraints = constraints.get(cx);
+                    let (display, parse, submit) = (format.clone(), format.clone(), format.clone());
+                    Textbox::new(
+                        cx,
+                        lens.clone().map(move |date| date.format(&display).to_string()),
+                    )
+                    .validate(move |text| parse_date(text, &parse, &constraints))
+                    .on_submit(move |cx, text, _| {
+                        if let Ok(date) = NaiveDate::parse_from_str(text.trim(), &submit) {
+                            cx.emit(DatePickerEventInternal::Select(date));
+                        }
+                    })
+                    .width(Stretch(1.0));
+                });
+            });
+
+            Button::new(
+                cx,
+                |cx| cx.emit(PopupEvent::Switch),
+                |cx| Label::new(cx, CALENDAR).class("material-icon"),
+            )
+            .class("open");
+
+            Popup::new(cx, DatePicker::<L>::is_open, true, move |cx| {
+                let constraints = DatePicker::<L>::constraints.get(cx);
+                Calendar::new(cx, lens.clone())
+                    .constraints(constraints)
+                    .on_select(|cx, date| cx.emit(DatePickerEventInternal::Select(date)));
+            })
+            .on_blur(|cx| cx.emit(PopupEvent::Close))
+            .anchor(Anchor::new(picker, AnchorSide::Bottom).offset(2.0))
+            .size(Auto);
+        })
+    }
+}
+
+impl<L: Lens> View for DatePicker<L> {
+    fn element(&self) -> Option<&'static str> {
+        Some("datepicker")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|date_picker_event, meta| match date_picker_event {
+            DatePickerEventInternal::Select(date) => {
+                self.is_open = false;
+                if let Some(callback) = &self.on_select {
+                    (callback)(cx, *date);
+                }
+
+                meta.consume();
+            }
+        });
+
+        event.map(|popup_event, meta| {
+            match popup_event {
+                PopupEvent::Open => self.is_open = true,
+                PopupEvent::Close => self.is_open = false,
+                PopupEvent::Switch => self.is_open ^= true,
+            }
+
+            meta.consume();
+        });
+    }
+}
+
+impl<L: Lens> Handle<'_, DatePicker<L>> {
+    /// Sets the [`chrono::format::strftime`] format in which dates are shown and typed.
+    pub fn format(self, format: impl Into<String>) -> Self {
+        let format = format.into();
+        self.modify(|date_picker| date_picker.format = format)
+    }
+
+    /// Sets the earliest date which can be picked.
+    pub fn min_date(self, date: NaiveDate) -> Self {
+        self.modify(|date_picker| date_picker.constraints.min = Some(date))
+    }
+
+    /// Sets the latest date which can be picked.
+    pub fn max_date(self, date: NaiveDate) -> Self {
+        self.modify(|date_picker| date_picker.constraints.max = Some(date))
+    }
+
+    /// Sets a function returning whether a date is disabled, such as for weekends or holidays.
+    pub fn disabled_dates<F>(self, disabled: F) -> Self
+    where
+        F: 'static + Fn(NaiveDate) -> bool + Send + Sync,
+    {
+        self.modify(|date_picker| date_picker.constraints.disabled = Some(Arc::new(disabled)))
+    }
+
+    /// Sets the callback triggered when a date is typed or picked from the calendar.
+    pub fn on_select<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, NaiveDate),
+    {
+        self.modify(|date_picker| date_picker.on_select = Some(Box::new(callback)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_allowed_dates() {
+        let constraints =
+            DateConstraints { min: NaiveDate::from_ymd_opt(2023, 1, 1), ..Default::default() };
+
+        assert_eq!(
+            parse_date(" 14/03/2023 ", "%d/%m/%Y", &constraints),
+            Ok(NaiveDate::from_ymd_opt(2023, 3, 14).unwrap())
+        );
+        assert!(parse_date("2023-03-14", "%d/%m/%Y", &constraints).is_err());
+        assert!(parse_date("14/03/2022", "%d/%m/%Y", &constraints).is_err());
+    }
+}
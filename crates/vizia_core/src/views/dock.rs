@@ -0,0 +1,1069 @@
+use std::rc::Rc;
+
+use morphorm::PositionType;
+
+use crate::cache::BoundingBox;
+use crate::prelude::*;
+use crate::views::scrollview::DRAG_THRESHOLD;
+use crate::views::Orientation;
+
+/// The fraction of a tab group at each edge in which a dragged panel is docked beside the group.
+const EDGE_FRACTION: f32 = 0.25;
+/// The size of a floating region when a panel is first undocked, in logical pixels.
+const FLOATING_SIZE: (f32, f32) = (300.0, 200.0);
+/// The smallest size a floating region can be resized to, in logical pixels.
+const MIN_FLOATING_SIZE: (f32, f32) = (100.0, 60.0);
+
+/// The arrangement of the panels of a [`DockArea`].
+///
+/// Panels are identified by name. The layout is a tree of tab groups and splits, along with the
+/// regions floating above it and the names of closed panels, so that it can be saved and restored
+/// with the `serde` feature.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DockLayout {
+    /// The docked panels, or `None` if every panel is floating or closed.
+    pub root: Option<DockNode>,
+    /// The regions floating above the docked panels.
+    pub floating: Vec<FloatingDock>,
+    /// The names of closed panels, which can be restored.
+    pub closed: Vec<String>,
+}
+
+/// A node of a [`DockLayout`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum DockNode {
+    /// Panels shown as tabs, with the index of the selected panel.
+    Tabs { panels: Vec<String>, selected: usize },
+    /// Nodes shown side by side in a [`SplitView`], with their sizes in logical pixels. Nodes
+    /// without a size share the remaining space.
+    Split { orientation: Orientation, children: Vec<DockNode>, sizes: Vec<f32> },
+}
+
+/// A region of a [`DockLayout`] floating above the docked panels, positioned in logical pixels
+/// relative to the [`DockArea`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FloatingDock {
+    pub node: DockNode,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// Where a panel is docked relative to a tab group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DockPosition {
+    /// As a tab of the group.
+    Center,
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl Data for DockLayout {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl DockNode {
+    /// Creates a tab group of the given panels, with the first panel selected.
+    pub fn tabs(panels: &[&str]) -> Self {
+        DockNode::Tabs {
+            panels: panels.iter().map(|panel| panel.to_string()).collect(),
+            selected: 0,
+        }
+    }
+
+    /// Creates a split of the given nodes, which share the space of the split.
+    pub fn split(orientation: Orientation, children: Vec<DockNode>) -> Self {
+        DockNode::Split { orientation, children, sizes: Vec::new() }
+    }
+
+    fn contains(&self, panel: &str) -> bool {
+        match self {
+            DockNode::Tabs { panels, .. } => panels.iter().any(|p| p == panel),
+            DockNode::Split { children, .. } => children.iter().any(|child| child.contains(panel)),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            DockNode::Tabs { panels, .. } => panels.is_empty(),
+            DockNode::Split { children, .. } => children.is_empty(),
+        }
+    }
+
+    // Returns the panels of the tab group containing the panel.
+    fn group(&self, panel: &str) -> Option<&[String]> {
+        match self {
+            DockNode::Tabs { panels, .. } => {
+                if panels.iter().any(|p| p == panel) {
+                    Some(panels)
+                } else {
+                    None
+                }
+            }
+
+            DockNode::Split { children, .. } => {
+                children.iter().find_map(|child| child.group(panel))
+            }
+        }
+    }
+
+    // Removes the panel, along with any tab groups and splits left empty. Splits left with a single
+    // node are replaced by it.
+    fn remove(&mut self, panel: &str) -> bool {
+        let removed = match self {
+            DockNode::Tabs { panels, selected } => match panels.iter().position(|p| p == panel) {
+                Some(index) => {
+                    panels.remove(index);
+                    if *selected > index || *selected >= panels.len() {
+                        *selected = selected.saturating_sub(1);
+                    }
+
+                    true
+                }
+
+                None => false,
+            },
+
+            DockNode::Split { children, sizes, .. } => {
+                match children.iter_mut().position(|child| child.remove(panel)) {
+                    Some(index) => {
+                        if children[index].is_empty() {
+                            children.remove(index);
+                            if index < sizes.len() {
+                                sizes.remove(index);
+                            }
+                        }
+
+                        true
+                    }
+
+                    None => false,
+                }
+            }
+        };
+
+        if let DockNode::Split { children, .. } = self {
+            if children.len() == 1 {
+                *self = children.remove(0);
+            }
+        }
+
+        removed
+    }
+
+    // Docks the panel beside or into the tab group containing the target panel.
+    fn dock(&mut self, panel: &str, target: &str, position: DockPosition) -> bool {
+        let (orientation, before) = match position {
+            DockPosition::Center => {
+                if let DockNode::Tabs { panels, selected } = self {
+                    if panels.iter().any(|p| p == target) {
+                        panels.push(panel.to_owned());
+                        *selected = panels.len() - 1;
+                        return true;
+                    }
+                }
+
+                (Orientation::Horizontal, false)
+            }
+
+            DockPosition::Left => (Orientation::Horizontal, true),
+            DockPosition::Right => (Orientation::Horizontal, false),
+            DockPosition::Top => (Orientation::Vertical, true),
+            DockPosition::Bottom => (Orientation::Vertical, false),
+        };
+
+        match self {
+            DockNode::Tabs { panels, .. } => {
+                if position == DockPosition::Center || !panels.iter().any(|p| p == target) {
+                    return false;
+                }
+
+                let group = std::mem::replace(self, DockNode::tabs(&[]));
+                let new = DockNode::tabs(&[panel]);
+                let children = if before { vec![new, group] } else { vec![group, new] };
+                *self = DockNode::split(orientation, children);
+                true
+            }
+
+            DockNode::Split { orientation: split_orientation, children, sizes } => {
+                // A panel docked beside a group in a split of the same orientation is added to the
+                // split, rather than splitting the group
+                if *split_orientation == orientation && position != DockPosition::Center {
+                    let index = children.iter().position(|child| match child {
+                        DockNode::Tabs { panels, .. } => panels.iter().any(|p| p == target),
+                        DockNode::Split { .. } => false,
+                    });
+
+                    if let Some(index) = index {
+                        let index = if before { index } else { index + 1 };
+                        children.insert(index, DockNode::tabs(&[panel]));
+                        // Nodes from the new one onwards share the space they had
+                        sizes.truncate(index);
+
+                        return true;
+                    }
+                }
+
+                children.iter_mut().any(|child| child.dock(panel, target, position))
+            }
+        }
+    }
+
+    fn select(&mut self, panel: &str) -> bool {
+        match self {
+            DockNode::Tabs { panels, selected } => match panels.iter().position(|p| p == panel) {
+                Some(index) => {
+                    *selected = index;
+                    true
+                }
+
+                None => false,
+            },
+
+            DockNode::Split { children, .. } => {
+                children.iter_mut().any(|child| child.select(panel))
+            }
+        }
+    }
+
+    fn first_group(&mut self) -> &mut DockNode {
+        match self {
+            DockNode::Split { children, .. } if !children.is_empty() => children[0].first_group(),
+            node => node,
+        }
+    }
+
+    fn node_mut(&mut self, path: &[usize]) -> Option<&mut DockNode> {
+        match path.split_first() {
+            None => Some(self),
+            Some((index, rest)) => match self {
+                DockNode::Split { children, .. } => children.get_mut(*index)?.node_mut(rest),
+                DockNode::Tabs { .. } => None,
+            },
+        }
+    }
+
+    fn node(&self, path: &[usize]) -> Option<&DockNode> {
+        match path.split_first() {
+            None => Some(self),
+            Some((index, rest)) => match self {
+                DockNode::Split { children, .. } => children.get(*index)?.node(rest),
+                DockNode::Tabs { .. } => None,
+            },
+        }
+    }
+
+    // Clears the sizes of splits and the selected tabs of groups.
+    fn clear_state(&mut self) {
+        match self {
+            DockNode::Tabs { selected, .. } => *selected = 0,
+            DockNode::Split { children, sizes, .. } => {
+                sizes.clear();
+                children.iter_mut().for_each(|child| child.clear_state());
+            }
+        }
+    }
+}
+
+impl DockLayout {
+    /// Creates a layout with the given docked panels.
+    pub fn new(root: DockNode) -> Self {
+        Self { root: Some(root), floating: Vec::new(), closed: Vec::new() }
+    }
+
+    /// Returns whether the panel is docked or floating.
+    pub fn contains(&self, panel: &str) -> bool {
+        self.root.as_ref().map_or(false, |root| root.contains(panel))
+            || self.floating.iter().any(|floating| floating.node.contains(panel))
+    }
+
+    /// Docks the panel into or beside the tab group containing the target panel, moving it from
+    /// wherever it was. Does nothing if the target isn't docked or floating.
+    pub fn dock(&mut self, panel: &str, target: &str, position: DockPosition) {
+        if panel == target {
+            return;
+        }
+
+        // The panel is left where it is if there's no group containing the target to dock beside
+        if self.group(target).is_none() {
+            return;
+        }
+
+        self.remove(panel);
+        self.closed.retain(|p| p != panel);
+
+        let docked = self.root.as_mut().map_or(false, |root| root.dock(panel, target, position));
+        if !docked {
+            for floating in self.floating.iter_mut() {
+                if floating.node.dock(panel, target, position) {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Moves the panel into a new region floating at the given position and size, in logical
+    /// pixels.
+    pub fn float(&mut self, panel: &str, x: f32, y: f32, width: f32, height: f32) {
+        self.remove(panel);
+        self.closed.retain(|p| p != panel);
+        self.floating.push(FloatingDock { node: DockNode::tabs(&[panel]), x, y, width, height });
+    }
+
+    /// Closes the panel, so that it can later be restored.
+    pub fn close(&mut self, panel: &str) {
+        if self.remove(panel) && !self.closed.iter().any(|p| p == panel) {
+            self.closed.push(panel.to_owned());
+        }
+    }
+
+    /// Restores a closed panel, or adds a new panel, as a tab of the first docked tab group.
+    pub fn restore(&mut self, panel: &str) {
+        if self.contains(panel) {
+            return;
+        }
+
+        self.closed.retain(|p| p != panel);
+        match &mut self.root {
+            Some(root) => {
+                if let DockNode::Tabs { panels, selected } = root.first_group() {
+                    panels.push(panel.to_owned());
+                    *selected = panels.len() - 1;
+                }
+            }
+
+            None => self.root = Some(DockNode::tabs(&[panel])),
+        }
+    }
+
+    /// Selects the panel within its tab group.
+    pub fn select(&mut self, panel: &str) {
+        let selected = self.root.as_mut().map_or(false, |root| root.select(panel));
+        if !selected {
+            for floating in self.floating.iter_mut() {
+                if floating.node.select(panel) {
+                    break;
+                }
+            }
+        }
+    }
+
+    fn group(&self, panel: &str) -> Option<&[String]> {
+        self.root
+            .as_ref()
+            .and_then(|root| root.group(panel))
+            .or_else(|| self.floating.iter().find_map(|floating| floating.node.group(panel)))
+    }
+
+    fn remove(&mut self, panel: &str) -> bool {
+        let mut removed = false;
+        if let Some(root) = &mut self.root {
+            removed = root.remove(panel);
+            if root.is_empty() {
+                self.root = None;
+            }
+        }
+
+        if !removed {
+            if let Some(index) =
+                self.floating.iter_mut().position(|floating| floating.node.remove(panel))
+            {
+                if self.floating[index].node.is_empty() {
+                    self.floating.remove(index);
+                }
+
+                removed = true;
+            }
+        }
+
+        removed
+    }
+
+    // Returns the node at the path within the docked panels, or within a floating region.
+    fn node(&self, floating: Option<usize>, path: &[usize]) -> Option<&DockNode> {
+        match floating {
+            Some(index) => self.floating.get(index)?.node.node(path),
+            None => self.root.as_ref()?.node(path),
+        }
+    }
+
+    fn node_mut(&mut self, floating: Option<usize>, path: &[usize]) -> Option<&mut DockNode> {
+        match floating {
+            Some(index) => self.floating.get_mut(index)?.node.node_mut(path),
+            None => self.root.as_mut()?.node_mut(path),
+        }
+    }
+
+    // Returns the layout without the sizes of its splits, the selected tabs of its groups and the
+    // positions of its floating regions, which change without the views of the layout being
+    // rebuilt.
+    fn structure(&self) -> DockLayout {
+        let mut structure = self.clone();
+        if let Some(root) = &mut structure.root {
+            root.clear_state();
+        }
+
+        for floating in structure.floating.iter_mut() {
+            floating.node.clear_state();
+            floating.x = 0.0;
+            floating.y = 0.0;
+            floating.width = 0.0;
+            floating.height = 0.0;
+        }
+
+        structure
+    }
+}
+
+enum DockEventInternal {
+    SetIndicator(Entity),
+    StartDrag(String),
+    StartMove(usize),
+    StartResize(usize),
+    Close(String),
+    Resize(Option<usize>, Vec<usize>, Vec<f32>),
+}
+
+// A panel being dragged by its tab.
+struct PanelDrag {
+    panel: String,
+    dragging: bool,
+}
+
+// A floating region being moved by its bar or resized by its corner, along with the position and
+// size it had when the drag started.
+struct RegionDrag {
+    index: usize,
+    resize: bool,
+    start: (f32, f32, f32, f32),
+}
+
+// Where a dragged panel is dropped.
+#[derive(Debug, Clone, PartialEq)]
+enum DropTarget {
+    Dock(String, DockPosition),
+    Float(f32, f32),
+}
+
+/// A view which arranges named panels in tab groups, splits and floating regions, which are
+/// rearranged by dragging the tabs of the panels.
+///
+/// Dropping a tab in the middle of a tab group adds the panel to the group, and dropping it near
+/// an edge of a group docks the panel beside the group. Dropping it anywhere else undocks the panel
+/// into a floating region, which is moved by dragging the bar at its top and resized by dragging
+/// its bottom-right corner. Tabs have a button to close their panel, and closed panels are kept in
+/// [`DockLayout::closed`] so that they can be restored with [`DockLayout::restore`].
+///
+/// The arrangement is read from the bound [`DockLayout`], and changes to it call the `on_change`
+/// callback with the new layout, which can be used to update the bound data.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # use vizia_derive::*;
+/// # let cx = &mut Context::default();
+/// # #[derive(Lens)]
+/// # pub struct AppData {
+/// #     layout: DockLayout,
+/// # }
+/// # pub enum AppEvent {
+/// #     SetLayout(DockLayout),
+/// # }
+/// # impl Model for AppData {
+/// #     fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+/// #         event.map(|app_event, _| match app_event {
+/// #             AppEvent::SetLayout(layout) => self.layout = layout.clone(),
+/// #         });
+/// #     }
+/// # }
+/// # AppData {
+/// #     layout: DockLayout::new(DockNode::split(
+/// #         Orientation::Horizontal,
+/// #         vec![DockNode::tabs(&["Files"]), DockNode::tabs(&["Editor", "Preview"])],
+/// #     )),
+/// # }
+/// # .build(cx);
+/// DockArea::new(cx, AppData::layout, |cx, panel| {
+///     Label::new(cx, &format!("The {} panel", panel));
+/// })
+/// .on_change(|cx, layout| cx.emit(AppEvent::SetLayout(layout)));
+/// ```
+pub struct DockArea<L> {
+    lens: L,
+    drag: Option<PanelDrag>,
+    region_drag: Option<RegionDrag>,
+    target: Option<DropTarget>,
+    indicator: Entity,
+    on_change: Option<Box<dyn Fn(&mut EventContext, DockLayout)>>,
+}
+
+impl<L> DockArea<L>
+where
+    L: Lens<Target = DockLayout>,
+{
+    /// Creates a new dock area with the layout targeted by the lens, and with the contents of each
+    /// panel built by the content closure from the name of the panel.
+    pub fn new<F>(cx: &mut Context, lens: L, content: F) -> Handle<Self>
+    where
+        F: 'static + Fn(&mut Context, &str),
+    {
+        let content: Rc<dyn Fn(&mut Context, &str)> = Rc::new(content);
+
+        Self {
+            lens: lens.clone(),
+            drag: None,
+            region_drag: None,
+            target: None,
+            indicator: Entity::null(),
+            on_change: None,
+        }
+        .build(cx, move |cx| {
+            let structure = lens.clone().map(|layout: &DockLayout| layout.structure());
+            Binding::new(cx, structure, move |cx, structure| {
+                let layout = structure.get(cx);
+                if let Some(root) = &layout.root {
+                    Self::build_node(cx, lens.clone(), content.clone(), root, None, Vec::new());
+                }
+
+                for (index, floating) in layout.floating.iter().enumerate() {
+                    let lens = lens.clone();
+                    let content = content.clone();
+                    let node = floating.node.clone();
+                    let region = move |layout: &DockLayout| {
+                        layout
+                            .floating
+                            .get(index)
+                            .cloned()
+                            .map_or((0.0, 0.0, 0.0, 0.0), |f| (f.x, f.y, f.width, f.height))
+                    };
+                    let region = lens.clone().map(region);
+
+                    VStack::new(cx, move |cx| {
+                        Element::new(cx)
+                            .class("dock-floating-bar")
+                            .cursor(CursorIcon::Move)
+                            .on_mouse_down(move |cx, button| {
+                                if button == MouseButton::Left {
+                                    cx.emit(DockEventInternal::StartMove(index));
+                                }
+                            });
+
+                        Self::build_node(cx, lens, content, &node, Some(index), Vec::new());
+
+                        Element::new(cx)
+                            .class("dock-resize")
+                            .position_type(PositionType::SelfDirected)
+                            .cursor(CursorIcon::NwseResize)
+                            .on_mouse_down(move |cx, button| {
+                                if button == MouseButton::Left {
+                                    cx.emit(DockEventInternal::StartResize(index));
+                                }
+                            });
+                    })
+                    .class("dock-floating")
+                    .position_type(PositionType::SelfDirected)
+                    .left(region.clone().map(|region| Pixels(region.0)))
+                    .top(region.clone().map(|region| Pixels(region.1)))
+                    .width(region.clone().map(|region| Pixels(region.2)))
+                    .height(region.map(|region| Pixels(region.3)))
+                    .overlay(true);
+                }
+            });
+
+            // Shows where a dragged panel will be docked, above any floating regions
+            let indicator = Element::new(cx)
+                .class("dock-indicator")
+                .position_type(PositionType::SelfDirected)
+                .display(Display::None)
+                .hoverable(false)
+                .overlay(true)
+                .z_order(1)
+                .entity();
+            cx.emit(DockEventInternal::SetIndicator(indicator));
+        })
+    }
+
+    fn build_node(
+        cx: &mut Context,
+        lens: L,
+        content: Rc<dyn Fn(&mut Context, &str)>,
+        node: &DockNode,
+        floating: Option<usize>,
+        path: Vec<usize>,
+    ) {
+        match node {
+            DockNode::Tabs { panels, .. } => {
+                let selected =
+                    lens.map(move |layout: &DockLayout| match layout.node(floating, &path) {
+                        Some(DockNode::Tabs { selected, .. }) => *selected,
+                        _ => 0,
+                    });
+
+                DockGroup::new(cx, panels.clone(), selected, content);
+            }
+
+            DockNode::Split { orientation, children, .. } => {
+                let children = children.clone();
+                let sizes_path = path.clone();
+                let sizes = lens.clone().map(move |layout: &DockLayout| {
+                    match layout.node(floating, &sizes_path) {
+                        Some(DockNode::Split { sizes, .. }) => sizes.clone(),
+                        _ => Vec::new(),
+                    }
+                });
+
+                SplitView::new(cx, sizes, *orientation, {
+                    let path = path.clone();
+                    move |cx| {
+                        for (index, child) in children.iter().enumerate() {
+                            let mut path = path.clone();
+                            path.push(index);
+                            Self::build_node(
+                                cx,
+                                lens.clone(),
+                                content.clone(),
+                                child,
+                                floating,
+                                path,
+                            );
+                        }
+                    }
+                })
+                .on_resize(move |cx, sizes| {
+                    cx.emit(DockEventInternal::Resize(floating, path.clone(), sizes));
+                });
+            }
+        }
+    }
+}
+
+impl<L> DockArea<L>
+where
+    L: Lens<Target = DockLayout>,
+{
+    fn change(&self, cx: &mut EventContext, f: impl FnOnce(&mut DockLayout)) {
+        let mut layout = self.lens.get(cx);
+        (f)(&mut layout);
+        if let Some(callback) = &self.on_change {
+            (callback)(cx, layout);
+        }
+    }
+
+    // Returns where a panel would be dropped with the pointer at the given position, along with
+    // the bounds of the drop indicator.
+    fn drop_target(&self, cx: &EventContext, x: f32, y: f32) -> Option<(DropTarget, BoundingBox)> {
+        let area = cx.cache.get_bounds(cx.current());
+        let point = BoundingBox { x, y, w: 0.0, h: 0.0 };
+        if !area.contains(&point) {
+            return None;
+        }
+
+        // Groups are visited in tree order, so the last group under the pointer is the topmost
+        let mut group = None;
+        for entity in cx.current().branch_iter(cx.tree) {
+            if cx.cache.get_display(entity) == Display::None {
+                continue;
+            }
+
+            if let Some(view) = cx.views.get(&entity) {
+                if let Some(dock_group) = view.as_any_ref().downcast_ref::<DockGroup>() {
+                    let bounds = cx.cache.get_bounds(entity);
+                    if bounds.contains(&point) {
+                        if let Some(panel) = dock_group.panels.first() {
+                            group = Some((panel.clone(), bounds));
+                        }
+                    }
+                }
+            }
+        }
+
+        let scale = cx.style.dpi_factor as f32;
+        match group {
+            Some((panel, bounds)) => {
+                let fx = (x - bounds.x) / bounds.w;
+                let fy = (y - bounds.y) / bounds.h;
+                let (position, indicator) = if fx < EDGE_FRACTION {
+                    (DockPosition::Left, BoundingBox { w: bounds.w / 2.0, ..bounds })
+                } else if fx > 1.0 - EDGE_FRACTION {
+                    let w = bounds.w / 2.0;
+                    (DockPosition::Right, BoundingBox { x: bounds.x + w, w, ..bounds })
+                } else if fy < EDGE_FRACTION {
+                    (DockPosition::Top, BoundingBox { h: bounds.h / 2.0, ..bounds })
+                } else if fy > 1.0 - EDGE_FRACTION {
+                    let h = bounds.h / 2.0;
+                    (DockPosition::Bottom, BoundingBox { y: bounds.y + h, h, ..bounds })
+                } else {
+                    (DockPosition::Center, bounds)
+                };
+
+                Some((DropTarget::Dock(panel, position), indicator))
+            }
+
+            None => {
+                let (width, height) = (FLOATING_SIZE.0 * scale, FLOATING_SIZE.1 * scale);
+                let x = (x - width / 2.0).max(area.x).min(area.x + area.w - width).max(area.x);
+                let y = y.max(area.y).min(area.y + area.h - height).max(area.y);
+                let indicator = BoundingBox { x, y, w: width, h: height };
+                Some((DropTarget::Float((x - area.x) / scale, (y - area.y) / scale), indicator))
+            }
+        }
+    }
+
+    // Moves or resizes the dragged floating region by the distance the pointer has moved since the
+    // drag started, keeping it within the dock area.
+    fn drag_region(&self, cx: &mut EventContext, drag: &RegionDrag, x: f32, y: f32) {
+        let area = cx.cache.get_bounds(cx.current());
+        let scale = cx.style.dpi_factor as f32;
+        let (down_x, down_y) = cx.mouse.left.pos_down;
+        let (dx, dy) = ((x - down_x) / scale, (y - down_y) / scale);
+        let (width, height) = (area.w / scale, area.h / scale);
+
+        let (x, y, w, h) = drag.start;
+        let region = if drag.resize {
+            let w = (w + dx).max(MIN_FLOATING_SIZE.0).min(width - x);
+            let h = (h + dy).max(MIN_FLOATING_SIZE.1).min(height - y);
+            (x, y, w, h)
+        } else {
+            let x = (x + dx).min(width - w).max(0.0);
+            let y = (y + dy).min(height - h).max(0.0);
+            (x, y, w, h)
+        };
+
+        let index = drag.index;
+        self.change(cx, |layout| {
+            if let Some(floating) = layout.floating.get_mut(index) {
+                floating.x = region.0;
+                floating.y = region.1;
+                floating.width = region.2;
+                floating.height = region.3;
+            }
+        });
+    }
+
+    fn show_indicator(&self, cx: &mut EventContext, bounds: Option<BoundingBox>) {
+        let indicator = self.indicator;
+        match bounds {
+            Some(bounds) => {
+                let area = cx.cache.get_bounds(cx.current());
+                let scale = cx.style.dpi_factor as f32;
+                cx.style.left.insert(indicator, Pixels((bounds.x - area.x) / scale));
+                cx.style.top.insert(indicator, Pixels((bounds.y - area.y) / scale));
+                cx.style.width.insert(indicator, Pixels(bounds.w / scale));
+                cx.style.height.insert(indicator, Pixels(bounds.h / scale));
+                cx.style.display.insert(indicator, Display::Flex);
+            }
+
+            None => {
+                cx.style.display.insert(indicator, Display::None);
+            }
+        }
+
+        cx.needs_relayout();
+        cx.needs_redraw();
+    }
+}
+
+impl<L> View for DockArea<L>
+where
+    L: Lens<Target = DockLayout>,
+{
+    fn element(&self) -> Option<&'static str> {
+        Some("dockarea")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|dock_event, meta| match dock_event {
+            DockEventInternal::SetIndicator(indicator) => {
+                self.indicator = *indicator;
+                meta.consume();
+            }
+
+            DockEventInternal::StartDrag(panel) => {
+                self.drag = Some(PanelDrag { panel: panel.clone(), dragging: false });
+                cx.capture();
+                meta.consume();
+            }
+
+            DockEventInternal::StartMove(index) | DockEventInternal::StartResize(index) => {
+                let resize = matches!(dock_event, DockEventInternal::StartResize(_));
+                if let Some(floating) = self.lens.get(cx).floating.get(*index) {
+                    let start = (floating.x, floating.y, floating.width, floating.height);
+                    self.region_drag = Some(RegionDrag { index: *index, resize, start });
+                    cx.capture();
+                }
+
+                meta.consume();
+            }
+
+            DockEventInternal::Close(panel) => {
+                self.change(cx, |layout| layout.close(panel));
+                meta.consume();
+            }
+
+            DockEventInternal::Resize(floating, path, sizes) => {
+                self.change(cx, |layout| {
+                    if let Some(DockNode::Split { sizes: split_sizes, .. }) =
+                        layout.node_mut(*floating, path)
+                    {
+                        *split_sizes = sizes.clone();
+                    }
+                });
+                meta.consume();
+            }
+        });
+
+        event.map(|window_event, _| match window_event {
+            WindowEvent::MouseMove(x, y) if self.region_drag.is_some() => {
+                if let Some(drag) = &self.region_drag {
+                    self.drag_region(cx, drag, *x, *y);
+                }
+            }
+
+            WindowEvent::MouseMove(x, y) => {
+                let dragging = match &mut self.drag {
+                    Some(drag) => {
+                        if !drag.dragging {
+                            let (down_x, down_y) = cx.mouse.left.pos_down;
+                            drag.dragging = (x - down_x).hypot(y - down_y) >= DRAG_THRESHOLD;
+                        }
+
+                        drag.dragging
+                    }
+
+                    None => false,
+                };
+
+                if dragging {
+                    let target = self.drop_target(cx, *x, *y);
+                    self.show_indicator(cx, target.as_ref().map(|(_, bounds)| *bounds));
+                    self.target = target.map(|(target, _)| target);
+                }
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if self.region_drag.take().is_some() {
+                    cx.release();
+                }
+
+                if let Some(drag) = self.drag.take() {
+                    cx.release();
+                    self.show_indicator(cx, None);
+
+                    let panel = drag.panel;
+                    match self.target.take() {
+                        _ if !drag.dragging => self.change(cx, |layout| layout.select(&panel)),
+
+                        Some(DropTarget::Dock(target, position)) => {
+                            self.change(cx, |layout| layout.dock(&panel, &target, position))
+                        }
+
+                        Some(DropTarget::Float(x, y)) => self.change(cx, |layout| {
+                            layout.float(&panel, x, y, FLOATING_SIZE.0, FLOATING_SIZE.1)
+                        }),
+
+                        None => {}
+                    }
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
+impl<L: Lens> Handle<'_, DockArea<L>> {
+    /// Sets the callback triggered when the panels are rearranged, closed or resized, or when a
+    /// tab is selected.
+    ///
+    /// The callback is given the new layout of the dock area.
+    pub fn on_change<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, DockLayout),
+    {
+        self.modify(|dock_area| dock_area.on_change = Some(Box::new(callback)))
+    }
+}
+
+// A group of panels shown as tabs, with the contents of the selected panel below the tabs.
+struct DockGroup {
+    panels: Vec<String>,
+}
+
+impl DockGroup {
+    fn new<S>(
+        cx: &mut Context,
+        panels: Vec<String>,
+        selected: S,
+        content: Rc<dyn Fn(&mut Context, &str)>,
+    ) -> Handle<Self>
+    where
+        S: Lens<Target = usize>,
+    {
+        Self { panels: panels.clone() }.build(cx, move |cx| {
+            HStack::new(cx, |cx| {
+                for (index, panel) in panels.iter().enumerate() {
+                    let checked = selected.clone().map(move |selected| *selected == index);
+                    DockTab::new(cx, panel.clone()).checked(checked);
+                }
+            })
+            .class("dock-tabs");
+
+            // Selecting another tab only rebuilds the contents of this group
+            Binding::new(cx, selected, move |cx, selected| {
+                if let Some(panel) = panels.get(selected.get(cx)) {
+                    VStack::new(cx, |cx| (content)(cx, panel)).class("dock-content");
+                }
+            });
+        })
+    }
+}
+
+impl View for DockGroup {
+    fn element(&self) -> Option<&'static str> {
+        Some("dockgroup")
+    }
+}
+
+// The tab of a panel, which starts dragging the panel when pressed.
+struct DockTab {
+    panel: String,
+}
+
+impl DockTab {
+    fn new(cx: &mut Context, panel: String) -> Handle<Self> {
+        Self { panel: panel.clone() }.build(cx, move |cx| {
+            Label::new(cx, &panel).hoverable(false);
+            Label::new(cx, "\u{00d7}")
+                .class("close")
+                .cursor(CursorIcon::Hand)
+                .on_press(move |cx| cx.emit(DockEventInternal::Close(panel.clone())));
+        })
+    }
+}
+
+impl View for DockTab {
+    fn element(&self) -> Option<&'static str> {
+        Some("docktab")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) if meta.target == cx.current() => {
+                cx.emit(DockEventInternal::StartDrag(self.panel.clone()));
+            }
+
+            _ => {}
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout() -> DockLayout {
+        DockLayout::new(DockNode::split(
+            Orientation::Horizontal,
+            vec![DockNode::tabs(&["files"]), DockNode::tabs(&["editor", "preview"])],
+        ))
+    }
+
+    #[test]
+    fn docks_as_tab() {
+        let mut layout = layout();
+        layout.dock("preview", "files", DockPosition::Center);
+        assert_eq!(
+            layout.root,
+            Some(DockNode::split(
+                Orientation::Horizontal,
+                vec![
+                    DockNode::Tabs { panels: vec!["files".into(), "preview".into()], selected: 1 },
+                    DockNode::tabs(&["editor"]),
+                ],
+            ))
+        );
+    }
+
+    #[test]
+    fn docks_beside_group() {
+        let mut layout = layout();
+        layout.dock("preview", "editor", DockPosition::Bottom);
+        assert_eq!(
+            layout.root,
+            Some(DockNode::split(
+                Orientation::Horizontal,
+                vec![
+                    DockNode::tabs(&["files"]),
+                    DockNode::split(
+                        Orientation::Vertical,
+                        vec![DockNode::tabs(&["editor"]), DockNode::tabs(&["preview"])],
+                    ),
+                ],
+            ))
+        );
+
+        // Docking beside a group in a split of the same orientation adds to the split
+        layout.dock("files", "editor", DockPosition::Top);
+        assert_eq!(
+            layout.root,
+            Some(DockNode::split(
+                Orientation::Vertical,
+                vec![
+                    DockNode::tabs(&["files"]),
+                    DockNode::tabs(&["editor"]),
+                    DockNode::tabs(&["preview"]),
+                ],
+            ))
+        );
+    }
+
+    #[test]
+    fn floats_closes_and_restores() {
+        let mut layout = layout();
+        layout.float("files", 10.0, 20.0, 300.0, 200.0);
+        assert_eq!(
+            layout.root,
+            Some(DockNode::Tabs { panels: vec!["editor".into(), "preview".into()], selected: 0 })
+        );
+        assert_eq!(layout.floating.len(), 1);
+
+        layout.dock("preview", "files", DockPosition::Center);
+        assert_eq!(layout.floating[0].node.group("preview").map(|group| group.len()), Some(2));
+
+        layout.close("files");
+        layout.close("preview");
+        assert!(layout.floating.is_empty());
+        assert_eq!(layout.closed, vec!["files".to_string(), "preview".to_string()]);
+
+        layout.restore("files");
+        assert!(layout.contains("files"));
+        assert_eq!(layout.closed, vec!["preview".to_string()]);
+    }
+
+    #[test]
+    fn structure_ignores_selection_and_sizes() {
+        let mut layout = layout();
+        layout.select("preview");
+        layout.float("files", 10.0, 20.0, 300.0, 200.0);
+        let structure = layout.structure();
+
+        layout.select("editor");
+        layout.floating[0].x = 40.0;
+        assert_eq!(layout.structure(), structure);
+    }
+
+    #[test]
+    fn keeps_single_panel_group() {
+        let mut layout = layout();
+        layout.dock("files", "files", DockPosition::Left);
+        assert_eq!(layout, self::layout());
+    }
+}
@@ -146,6 +146,7 @@ impl Dropdown {
     {
         Self {}
             .build(cx, move |cx| {
+                let dropdown = cx.current();
                 PopupData::default().build(cx);
 
                 (label)(cx)
@@ -157,7 +158,7 @@ impl Dropdown {
                     (content)(cx);
                 })
                 .on_blur(|cx| cx.emit(PopupEvent::Close))
-                .top(Percentage(100.0))
+                .anchor(Anchor::new(dropdown, AnchorSide::Bottom))
                 .height(Auto);
             })
             .size(Auto)
@@ -0,0 +1,73 @@
+use crate::fonts::{entypo_icon, material_icon};
+use crate::prelude::*;
+
+/// The bundled icon fonts which an [`Icon`] can be looked up in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IconFont {
+    /// The Entypo font, with names such as `"down-open"` or `"cancel-circled"`.
+    Entypo,
+    /// The Material Icons font, with names such as `"home"` or `"keyboard_arrow_down"`.
+    Material,
+}
+
+impl IconFont {
+    /// Returns the character of the icon with the given name in this font.
+    pub fn icon(&self, name: &str) -> Option<char> {
+        match self {
+            IconFont::Entypo => entypo_icon(name),
+            IconFont::Material => material_icon(name),
+        }
+    }
+}
+
+/// A view which displays an icon from one of the bundled icon fonts, looked up by name.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// Icon::new(cx, "home");
+/// Icon::entypo(cx, "cancel-circled");
+/// ```
+pub struct Icon {}
+
+impl Icon {
+    /// Creates a new icon from the [Material Icons](IconFont::Material) font.
+    pub fn new<T: ToString>(cx: &mut Context, name: impl Res<T>) -> Handle<Self> {
+        Self::with_font(cx, IconFont::Material, name)
+    }
+
+    /// Creates a new icon from the [Entypo](IconFont::Entypo) font.
+    pub fn entypo<T: ToString>(cx: &mut Context, name: impl Res<T>) -> Handle<Self> {
+        Self::with_font(cx, IconFont::Entypo, name)
+    }
+
+    /// Creates a new icon from the given icon font. An unknown name shows no icon.
+    pub fn with_font<T: ToString>(
+        cx: &mut Context,
+        font: IconFont,
+        name: impl Res<T>,
+    ) -> Handle<Self> {
+        let handle = Self {}.build(cx, |_| {}).class(match font {
+            IconFont::Entypo => "entypo",
+            IconFont::Material => "material",
+        });
+
+        let entity = handle.entity;
+        name.set_or_bind(handle.cx, entity, move |cx, entity, name| {
+            let icon = font.icon(&name.to_string()).map(String::from).unwrap_or_default();
+            cx.text_context.set_text(entity, &icon);
+
+            cx.style.relayout(entity);
+            cx.style.redraw(entity);
+        });
+
+        handle
+    }
+}
+
+impl View for Icon {
+    fn element(&self) -> Option<&'static str> {
+        Some("icon")
+    }
+}
@@ -2,6 +2,7 @@ use femtovg::{LineCap, Paint, Path, Solidity};
 use morphorm::{Hierarchy, Units};
 
 use crate::prelude::*;
+use crate::views::scrollview::SCROLL_SENSITIVITY;
 
 static DEFAULT_DRAG_SCALAR: f32 = 0.0042;
 static DEFAULT_WHEEL_SCALAR: f32 = 0.005;
@@ -207,6 +208,16 @@ impl<L: Lens<Target = f32>> View for Knob<L> {
                 }
             }
 
+            WindowEvent::MouseScrollPixels(_, y) => {
+                if *y != 0.0 {
+                    let delta_normal = -*y / SCROLL_SENSITIVITY * self.wheel_scalar;
+
+                    let new_normal = self.continuous_normal - delta_normal;
+
+                    move_virtual_slider(self, cx, new_normal);
+                }
+            }
+
             WindowEvent::MouseDoubleClick(button) if *button == MouseButton::Left => {
                 self.is_dragging = false;
 
@@ -445,7 +456,7 @@ impl Handle<'_, TickKnob> {
             if let Some(view) = cx.views.get_mut(&entity) {
                 if let Some(knob) = view.downcast_mut::<TickKnob>() {
                     knob.normalized_value = value;
-                    cx.style.needs_redraw = true;
+                    cx.style.redraw(entity);
                 }
             }
         });
@@ -578,7 +589,7 @@ impl Handle<'_, ArcTrack> {
             if let Some(view) = cx.views.get_mut(&entity) {
                 if let Some(knob) = view.downcast_mut::<ArcTrack>() {
                     knob.normalized_value = value;
-                    cx.style.needs_redraw = true;
+                    cx.style.redraw(entity);
                 }
             }
         });
@@ -49,6 +49,7 @@ where
 struct MenuData {
     selected: Option<usize>,
     counter: RefCell<usize>,
+    horizontal: bool,
 }
 
 struct MenuControllerData {
@@ -137,9 +138,15 @@ impl View for MenuController {
                             WindowEvent::MouseMove(_, _)
                                 | WindowEvent::PressDown { .. }
                                 | WindowEvent::MouseScroll(_, _)
+                                | WindowEvent::MouseScrollPixels(_, _)
                                 | WindowEvent::MouseDoubleClick(_)
                         ))
-                        || (!is_child && matches!(window_event, WindowEvent::MouseScroll(_, _)))
+                        || (!is_child
+                            && matches!(
+                                window_event,
+                                WindowEvent::MouseScroll(_, _)
+                                    | WindowEvent::MouseScrollPixels(_, _)
+                            ))
                     {
                         cx.event_queue.push_back(
                             Event::new(window_event.clone())
@@ -181,25 +188,29 @@ impl View for MenuController {
 pub struct MenuStack {}
 
 impl MenuStack {
-    fn new<F: FnOnce(&mut Context)>(cx: &mut Context, builder: F) -> Handle<'_, Self> {
+    fn new<F: FnOnce(&mut Context)>(
+        cx: &mut Context,
+        horizontal: bool,
+        builder: F,
+    ) -> Handle<'_, Self> {
         if cx.data::<MenuControllerData>().is_none() {
             panic!("MenuStacks must be built inside a MenuController");
         }
         Self {}.build(cx, move |cx| {
-            MenuData::default().build(cx);
+            MenuData { horizontal, ..Default::default() }.build(cx);
             builder(cx);
         })
     }
 
     pub fn new_vertical<F: FnOnce(&mut Context)>(cx: &mut Context, builder: F) -> Handle<'_, Self> {
-        Self::new(cx, builder).class("vertical")
+        Self::new(cx, false, builder).class("vertical")
     }
 
     pub fn new_horizontal<F: FnOnce(&mut Context)>(
         cx: &mut Context,
         builder: F,
     ) -> Handle<'_, Self> {
-        Self::new(cx, builder).class("horizontal")
+        Self::new(cx, true, builder).class("horizontal")
     }
 }
 
@@ -221,12 +232,17 @@ impl Menu {
         F1: 'static + FnOnce(&mut Context) -> Handle<'_, Lbl>,
         F2: 'static + FnOnce(&mut Context),
     {
+        // Submenus open below the entries of a menu bar and to the side of the entries of a menu
+        let horizontal = cx.data::<MenuData>().map_or(false, |data| data.horizontal);
+        let side = if horizontal { AnchorSide::Bottom } else { AnchorSide::Right };
+
         let result = Self {}.build(cx, move |cx| {
             HStack::new(cx, move |cx| {
                 label(cx);
                 Label::new(cx, RIGHT).class("menu_arrow");
             });
-            MenuStack::new_vertical(cx, items);
+            let menu = cx.current();
+            MenuStack::new_vertical(cx, items).overlay(true).anchor(Anchor::new(menu, side));
         });
         let entity = result.entity;
         setup_menu_entry(
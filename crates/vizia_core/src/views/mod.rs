@@ -3,45 +3,78 @@
 //! Every member of this module is part of the prelude.
 
 mod button;
+#[cfg(feature = "chrono")]
+mod calendar;
 mod checkbox;
+mod code_editor;
+mod colorpicker;
+#[cfg(feature = "chrono")]
+mod datepicker;
+mod dock;
 mod dropdown;
 mod element;
+mod icon;
 mod image;
 mod knob;
 mod label;
 mod list;
 mod menu;
 pub mod normalized_map;
+mod numberinput;
 mod popup;
 mod radio_buttons;
 mod scrollbar;
 mod scrollview;
 mod slider;
+mod splitview;
 mod stack;
 mod table;
+mod tabview;
 mod textbox;
+#[cfg(feature = "chrono")]
+mod timepicker;
+mod tooltip;
+mod treeview;
 
 pub use self::image::Image;
 pub use button::Button;
+#[cfg(feature = "chrono")]
+pub use calendar::Calendar;
 pub use checkbox::Checkbox;
+pub use code_editor::CodeEditor;
+pub use colorpicker::ColorPicker;
+#[cfg(feature = "chrono")]
+pub use datepicker::DatePicker;
+pub use dock::{DockArea, DockLayout, DockNode, DockPosition, FloatingDock};
 pub use dropdown::Dropdown;
 pub use element::Element;
+pub use icon::{Icon, IconFont};
 pub use knob::{ArcTrack, Knob, KnobMode, TickKnob, Ticks};
 pub use label::Label;
 pub use list::List;
 pub use menu::{setup_menu_entry, Menu, MenuButton, MenuController, MenuEvent, MenuStack};
+pub use numberinput::{NumberInput, Numeric};
 pub use popup::{Popup, PopupData, PopupEvent};
 pub use radio_buttons::RadioButton;
 pub use scrollbar::Scrollbar;
 pub use scrollview::{ScrollData, ScrollEvent, ScrollView};
 pub use slider::Slider;
+pub use splitview::SplitView;
 pub use stack::{HStack, VStack, ZStack};
 pub use table::{Table, TableColumn};
+pub use tabview::{TabEvent, TabRetention, TabView};
 pub use textbox::{TextEvent, Textbox};
+#[cfg(feature = "chrono")]
+pub use timepicker::TimePicker;
+pub use tooltip::{Tooltip, TooltipPlacement};
+pub use treeview::{SelectionMode, TreeDrop, TreeNode, TreeNodeLens, TreeView};
+
+pub(crate) use tooltip::TooltipEvent;
 
 use crate::prelude::*;
 
 /// The orientation of a widget, such as a slider or scrollbar
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
 pub enum Orientation {
     Horizontal,
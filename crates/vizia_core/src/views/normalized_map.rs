@@ -17,16 +17,21 @@ pub enum DisplayDecimals {
 }
 
 impl DisplayDecimals {
-    pub fn display_value(&self, value: f32) -> String {
+    /// Returns the number of decimals shown.
+    pub fn count(&self) -> usize {
         match self {
-            DisplayDecimals::Zero => format!("{:.0}", value),
-            DisplayDecimals::One => format!("{:.1}", value),
-            DisplayDecimals::Two => format!("{:.2}", value),
-            DisplayDecimals::Three => format!("{:.3}", value),
-            DisplayDecimals::Four => format!("{:.4}", value),
-            DisplayDecimals::Five => format!("{:.5}", value),
+            DisplayDecimals::Zero => 0,
+            DisplayDecimals::One => 1,
+            DisplayDecimals::Two => 2,
+            DisplayDecimals::Three => 3,
+            DisplayDecimals::Four => 4,
+            DisplayDecimals::Five => 5,
         }
     }
+
+    pub fn display_value(&self, value: f32) -> String {
+        format!("{:.*}", self.count(), value)
+    }
 }
 
 impl Default for DisplayDecimals {
@@ -0,0 +1,441 @@
+use std::ops::RangeInclusive;
+
+use crate::fonts::icons_names::{DOWN, UP};
+use crate::prelude::*;
+use crate::views::normalized_map::DisplayDecimals;
+use crate::views::scrollview::SCROLL_SENSITIVITY;
+
+// The multipliers of the step for coarse (Shift) and fine (Ctrl) increments.
+const COARSE_MULTIPLIER: f64 = 10.0;
+const FINE_MULTIPLIER: f64 = 0.1;
+
+// The distance, in logical pixels, the label is dragged to scrub the value by one step.
+const SCRUB_DISTANCE: f32 = 4.0;
+
+/// A number which can be edited with a [`NumberInput`].
+///
+/// This is implemented for the primitive integer and floating point types.
+pub trait Numeric: 'static + Data + Copy {
+    /// Whether the type only holds whole numbers.
+    const INTEGER: bool;
+
+    fn to_f64(self) -> f64;
+
+    /// Converts from a float, rounding to the nearest value of the type and saturating at its
+    /// bounds.
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_numeric_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                const INTEGER: bool = true;
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value.round() as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl Numeric for f32 {
+    const INTEGER: bool = false;
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+}
+
+impl Numeric for f64 {
+    const INTEGER: bool = false;
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
+
+/// How the value of a [`NumberInput`] is shown.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberFormat {
+    decimals: DisplayDecimals,
+    units: String,
+}
+
+impl Data for NumberFormat {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl NumberFormat {
+    fn display(&self, value: f64) -> String {
+        format!("{:.*}{}", self.decimals.count(), value, self.units)
+    }
+
+    // Parses a typed number, which may be followed by the units.
+    fn parse(&self, text: &str) -> Result<f64, String> {
+        let text = text.trim();
+        let number = text.strip_suffix(self.units.trim()).unwrap_or(text).trim();
+        match number.parse::<f64>() {
+            Ok(value) if value.is_finite() => Ok(value),
+            _ => Err(format!("{} isn't a number", text)),
+        }
+    }
+}
+
+enum NumberInputEventInternal {
+    // Steps the value by a number of steps, scaled by the modifiers
+    Step(f64),
+    Set(f64),
+    StartScrub,
+    // Scrubs the value by a distance dragged, in logical pixels
+    Scrub(f32),
+}
+
+/// A textbox for a number, with stepper buttons and a label which can be dragged to scrub the
+/// value.
+///
+/// The value is stepped with the buttons, the up and down arrow keys, or the mouse wheel while the
+/// input is focused, and scrubbed by dragging the label horizontally. Holding Shift multiplies the
+/// step by ten and holding Ctrl divides it by ten. Typed values are clamped to the range of the
+/// input, and the textbox has the `:invalid` pseudo-class while its text isn't a number.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # use vizia_core::views::normalized_map::DisplayDecimals;
+/// # use vizia_derive::*;
+/// # let cx = &mut Context::default();
+/// # #[derive(Lens)]
+/// # pub struct AppData {
+/// #     gain: f32,
+/// # }
+/// # impl Model for AppData {}
+/// # AppData { gain: 0.0 }.build(cx);
+/// NumberInput::new(cx, AppData::gain)
+///     .label("Gain")
+///     .range(-60.0..=12.0)
+///     .step(0.5)
+///     .decimals(DisplayDecimals::One)
+///     .units(" dB")
+///     .on_change(|_, gain| println!("Gain {}", gain));
+/// ```
+#[derive(Lens)]
+pub struct NumberInput<L: Lens, T: Numeric> {
+    lens: L,
+    label: String,
+    format: NumberFormat,
+    min: f64,
+    max: f64,
+    step: f64,
+    // The unrounded value while the label is being scrubbed
+    scrub_value: f64,
+    // The distance scrolled with a touchpad which hasn't stepped the value yet, in lines
+    scroll_lines: f32,
+    on_change: Option<Box<dyn Fn(&mut EventContext, T)>>,
+}
+
+impl<L, T> NumberInput<L, T>
+where
+    L: Lens<Target = T>,
+    T: Numeric,
+{
+    /// Creates a new number input bound to the number targeted by the lens.
+    pub fn new(cx: &mut Context, lens: L) -> Handle<Self> {
+        let decimals = if T::INTEGER { DisplayDecimals::Zero } else { DisplayDecimals::default() };
+        Self {
+            lens: lens.clone(),
+            label: String::new(),
+            format: NumberFormat { decimals, units: String::new() },
+            min: f64::NEG_INFINITY,
+            max: f64::INFINITY,
+            step: 1.0,
+            scrub_value: 0.0,
+            scroll_lines: 0.0,
+            on_change: None,
+        }
+        .build(cx, |cx| {
+            ScrubLabel::new(cx, NumberInput::<L, T>::label);
+
+            Binding::new(cx, NumberInput::<L, T>::format, move |cx, format| {
+                let format = format.get(cx);
+                let (display, parse, submit) = (format.clone(), format.clone(), format);
+                Textbox::new(cx, lens.clone().map(move |value| display.display(value.to_f64())))
+                    .validate(move |text| parse.parse(text))
+                    .on_submit(move |cx, text, _| {
+                        if let Ok(value) = submit.parse(&text) {
+                            cx.emit(NumberInputEventInternal::Set(value));
+                        }
+                    })
+                    .width(Stretch(1.0));
+            });
+
+            VStack::new(cx, |cx| {
+                Label::new(cx, UP)
+                    .class("icon")
+                    .class("increment")
+                    .on_press(|cx| cx.emit(NumberInputEventInternal::Step(1.0)));
+                Label::new(cx, DOWN)
+                    .class("icon")
+                    .class("decrement")
+                    .on_press(|cx| cx.emit(NumberInputEventInternal::Step(-1.0)));
+            })
+            .class("steppers");
+        })
+    }
+}
+
+impl<L, T> NumberInput<L, T>
+where
+    L: Lens<Target = T>,
+    T: Numeric,
+{
+    // Returns the step, scaled by the modifiers which are held.
+    fn scaled_step(&self, modifiers: &Modifiers) -> f64 {
+        if modifiers.contains(Modifiers::SHIFT) {
+            self.step * COARSE_MULTIPLIER
+        } else if modifiers.contains(Modifiers::CTRL) {
+            // Fine steps of whole numbers are still whole
+            let step = self.step * FINE_MULTIPLIER;
+            if T::INTEGER {
+                step.max(1.0)
+            } else {
+                step
+            }
+        } else {
+            self.step
+        }
+    }
+
+    // Clamps the value to the range and returns it as the type of the input.
+    fn clamp(&self, value: f64) -> T {
+        T::from_f64(value.max(self.min).min(self.max))
+    }
+
+    // Returns the value stepped by a number of steps, scaled by the modifiers which are held.
+    fn stepped(&self, cx: &mut EventContext, steps: f64) -> T {
+        self.clamp(self.lens.get(cx).to_f64() + steps * self.scaled_step(cx.modifiers))
+    }
+
+    // Returns true if the input or one of its descendants, such as the textbox, is focused.
+    fn is_focused(&self, cx: &EventContext) -> bool {
+        let focused = cx.focused();
+        focused == cx.current() || focused.is_descendant_of(cx.tree, cx.current())
+    }
+
+    fn set(&self, cx: &mut EventContext, value: T) {
+        if let Some(callback) = &self.on_change {
+            (callback)(cx, value);
+        }
+    }
+
+    // Steps the value from the keyboard or the mouse wheel, which can be used while the textbox is
+    // being edited. The textbox doesn't follow the value while it's being edited, so the stepped
+    // value is typed into it.
+    fn step_in_textbox(&self, cx: &mut EventContext, steps: f64, target: Entity) {
+        let value = self.stepped(cx, steps);
+        self.set(cx, value);
+        if *cx.captured == target {
+            cx.emit_to(target, TextEvent::SelectAll);
+            cx.emit_to(target, TextEvent::InsertText(self.format.display(value.to_f64())));
+        }
+    }
+}
+
+impl<L, T> View for NumberInput<L, T>
+where
+    L: Lens<Target = T>,
+    T: Numeric,
+{
+    fn element(&self) -> Option<&'static str> {
+        Some("numberinput")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|number_input_event, meta| {
+            match number_input_event {
+                NumberInputEventInternal::Step(steps) => {
+                    let value = self.stepped(cx, *steps);
+                    self.set(cx, value);
+                }
+
+                NumberInputEventInternal::Set(value) => self.set(cx, self.clamp(*value)),
+
+                NumberInputEventInternal::StartScrub => {
+                    self.scrub_value = self.lens.get(cx).to_f64();
+                }
+
+                NumberInputEventInternal::Scrub(distance) => {
+                    let steps = (*distance / SCRUB_DISTANCE) as f64;
+                    self.scrub_value = (self.scrub_value + steps * self.scaled_step(cx.modifiers))
+                        .max(self.min)
+                        .min(self.max);
+                    self.set(cx, self.clamp(self.scrub_value));
+                }
+            }
+
+            meta.consume();
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::KeyDown(Code::ArrowUp, _) => {
+                self.step_in_textbox(cx, 1.0, meta.target);
+                meta.consume();
+            }
+
+            WindowEvent::KeyDown(Code::ArrowDown, _) => {
+                self.step_in_textbox(cx, -1.0, meta.target);
+                meta.consume();
+            }
+
+            // The wheel only steps a focused input, so that scrolling past it scrolls its parent
+            WindowEvent::MouseScroll(_, y) if *y != 0.0 && self.is_focused(cx) => {
+                self.step_in_textbox(cx, y.signum() as f64, meta.target);
+                meta.consume();
+            }
+
+            WindowEvent::MouseScrollPixels(_, y) if *y != 0.0 && self.is_focused(cx) => {
+                self.scroll_lines += *y / SCROLL_SENSITIVITY;
+                let steps = self.scroll_lines.trunc();
+                if steps != 0.0 {
+                    self.scroll_lines -= steps;
+                    self.step_in_textbox(cx, steps as f64, meta.target);
+                }
+
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
+}
+
+impl<L: Lens, T: Numeric> Handle<'_, NumberInput<L, T>> {
+    /// Sets the text of the label, which can be dragged horizontally to scrub the value.
+    pub fn label(self, label: impl Into<String>) -> Self {
+        let label = label.into();
+        self.modify(|number_input| number_input.label = label)
+    }
+
+    /// Sets the range the value is clamped to.
+    pub fn range(self, range: RangeInclusive<T>) -> Self {
+        self.modify(|number_input| {
+            number_input.min = range.start().to_f64();
+            number_input.max = range.end().to_f64();
+        })
+    }
+
+    /// Sets the amount the value is changed by each step, which is one by default.
+    pub fn step(self, step: T) -> Self {
+        self.modify(|number_input| number_input.step = step.to_f64())
+    }
+
+    /// Sets the number of decimals shown, which is none for integers and one otherwise by
+    /// default.
+    pub fn decimals(self, decimals: DisplayDecimals) -> Self {
+        self.modify(|number_input| number_input.format.decimals = decimals)
+    }
+
+    /// Sets the units shown after the value, such as `" dB"`, which may also be typed after it.
+    pub fn units(self, units: impl Into<String>) -> Self {
+        let units = units.into();
+        self.modify(|number_input| number_input.format.units = units)
+    }
+
+    /// Sets the callback triggered when the value is changed.
+    pub fn on_change<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, T),
+    {
+        self.modify(|number_input| number_input.on_change = Some(Box::new(callback)))
+    }
+}
+
+// The label of a number input, which scrubs the value when dragged horizontally.
+struct ScrubLabel {
+    drag_x: Option<f32>,
+}
+
+impl ScrubLabel {
+    fn new(cx: &mut Context, text: impl Lens<Target = String>) -> Handle<Self> {
+        Self { drag_x: None }.build(cx, |cx| {
+            Label::new(cx, text).hoverable(false);
+        })
+    }
+}
+
+impl View for ScrubLabel {
+    fn element(&self) -> Option<&'static str> {
+        Some("scrublabel")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                self.drag_x = Some(cx.mouse.cursorx);
+                cx.capture();
+                cx.emit(WindowEvent::SetCursor(CursorIcon::EwResize));
+                cx.lock_cursor_icon();
+                cx.emit(NumberInputEventInternal::StartScrub);
+                meta.consume();
+            }
+
+            WindowEvent::MouseMove(x, _) => {
+                if let Some(drag_x) = self.drag_x {
+                    self.drag_x = Some(*x);
+                    cx.emit(NumberInputEventInternal::Scrub((*x - drag_x) / cx.dpi_factor()));
+                }
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if self.drag_x.take().is_some() {
+                    cx.release();
+                    cx.unlock_cursor_icon();
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_and_parses_numbers_with_units() {
+        let format = NumberFormat { decimals: DisplayDecimals::Two, units: String::from(" dB") };
+        assert_eq!(format.display(-3.14159), "-3.14 dB");
+        assert_eq!(format.display(16_777_217.0), "16777217.00 dB");
+        assert_eq!(format.parse(" -3.5 dB "), Ok(-3.5));
+        assert_eq!(format.parse("-3.5dB"), Ok(-3.5));
+        assert_eq!(format.parse("12"), Ok(12.0));
+        assert!(format.parse("twelve").is_err());
+        assert!(format.parse("inf").is_err());
+    }
+
+    #[test]
+    fn converts_to_integers() {
+        assert_eq!(i32::from_f64(2.6), 3);
+        assert_eq!(u8::from_f64(300.0), 255);
+        assert_eq!(u8::from_f64(-1.0), 0);
+        assert_eq!(f32::from_f64(0.5), 0.5);
+    }
+}
@@ -43,11 +43,17 @@ impl<L> Popup<L>
 where
     L: Lens<Target = bool>,
 {
+    /// Creates a popup which is shown while the lens is true.
+    ///
+    /// The popup opens below the view it's built in, which can be changed with
+    /// [`anchor`](crate::modifiers::LayoutModifiers::anchor). A popup built directly in the window,
+    /// such as a modal dialog, isn't anchored.
     pub fn new<F>(cx: &mut Context, lens: L, capture_focus: bool, content: F) -> Handle<Self>
     where
         F: 'static + Fn(&mut Context),
     {
-        Self { lens: lens.clone() }
+        let parent = cx.current();
+        let handle = Self { lens: lens.clone() }
             .build(cx, |cx| {
                 Binding::new(cx, lens.clone(), move |cx, lens| {
                     if lens.get(cx) {
@@ -62,6 +68,13 @@ where
             .checked(lens.clone())
             .position_type(PositionType::SelfDirected)
             .z_order(100)
+            .overlay(true);
+
+        if parent != Entity::root() {
+            handle.anchor(Anchor::new(parent, AnchorSide::Bottom))
+        } else {
+            handle
+        }
     }
 }
 
@@ -1,3 +1,4 @@
+use instant::{Duration, Instant};
 use morphorm::{GeometryChanged, PositionType};
 
 use crate::prelude::*;
@@ -6,7 +7,28 @@ use crate::views::Orientation;
 
 pub(crate) const SCROLL_SENSITIVITY: f32 = 35.0;
 
-#[derive(Lens, Data, Clone, Debug)]
+/// How long a smooth scroll takes to reach its destination.
+const SMOOTH_SCROLL_DURATION: Duration = Duration::from_millis(150);
+/// How quickly a fling slows down, as an exponential decay rate per second.
+const FLING_DECELERATION: f32 = 5.0;
+/// The speed, in physical pixels per second, below which a fling stops.
+const FLING_MIN_VELOCITY: f32 = 20.0;
+/// How long the pointer can rest before release without cancelling a fling.
+const FLING_MAX_IDLE: Duration = Duration::from_millis(100);
+/// The distance, in physical pixels, the pointer must move before a press starts dragging.
+pub(crate) const DRAG_THRESHOLD: f32 = 4.0;
+
+/// The scroll position of a [`ScrollView`], along with the sizes of the view and its content.
+///
+/// The scroll position is normalized between 0.0 and 1.0, and the sizes are in physical pixels.
+/// Style modifiers accept lenses, so views can be styled by the scroll position without being
+/// rebuilt as it changes:
+/// ```ignore
+/// Element::new(cx).opacity(ScrollData::scroll_y.map(|y| 1.0 - y));
+/// ```
+/// Views outside of the scroll view can do the same by building the `ScrollData` into a shared
+/// parent and using [`ScrollView::custom`].
+#[derive(Lens, Data, Clone, Debug, Default)]
 pub struct ScrollData {
     pub scroll_x: f32,
     pub scroll_y: f32,
@@ -23,9 +45,22 @@ pub enum ScrollEvent {
     ScrollY(f32),
     ChildGeo(f32, f32),
     ParentGeo(f32, f32),
+    /// Smoothly scrolls any scroll views containing the entity so that it's in view.
+    ScrollTo(Entity),
 }
 
 impl ScrollData {
+    // The distance the content can be scrolled along each axis, in physical pixels.
+    fn max_offset(&self) -> (f32, f32) {
+        ((self.child_x - self.parent_x).max(0.0), (self.child_y - self.parent_y).max(0.0))
+    }
+
+    // The distance the content is scrolled along each axis, in physical pixels.
+    fn offset(&self) -> (f32, f32) {
+        let (max_x, max_y) = self.max_offset();
+        (self.scroll_x * max_x, self.scroll_y * max_y)
+    }
+
     fn reset(&mut self) {
         if self.child_x == self.parent_x {
             self.scroll_x = 0.0;
@@ -55,6 +90,8 @@ impl Model for ScrollData {
                     self.parent_y = *y;
                     self.reset();
                 }
+                // Handled by the scroll views containing the entity
+                ScrollEvent::ScrollTo(_) => return,
             }
 
             meta.consume();
@@ -64,6 +101,31 @@ impl Model for ScrollData {
 
 pub struct ScrollView<L> {
     data: L,
+    // The smooth scroll or fling in progress, if any.
+    motion: Option<Motion>,
+    // The press on the content which may drag it, if any.
+    drag: Option<Drag>,
+}
+
+// An animated change of the scroll offset, in physical pixels.
+#[derive(Debug, Clone, Copy)]
+enum Motion {
+    // Eases from one offset to another.
+    Smooth { from: (f32, f32), to: (f32, f32), start: Instant },
+    // Continues the movement of a drag, slowing down over time.
+    Fling { velocity: (f32, f32), last: Instant },
+}
+
+// A press on the content, which drags it once the pointer has moved far enough.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    // The previous position of the pointer.
+    last: (f32, f32),
+    // When the pointer was previously moved.
+    time: Instant,
+    // The velocity of the content, in physical pixels per second.
+    velocity: (f32, f32),
+    dragging: bool,
 }
 
 impl ScrollView<scroll_data_derived_lenses::root> {
@@ -78,16 +140,8 @@ impl ScrollView<scroll_data_derived_lenses::root> {
     where
         F: 'static + FnOnce(&mut Context),
     {
-        Self { data: ScrollData::root }.build(cx, move |cx| {
-            ScrollData {
-                scroll_x: initial_x,
-                scroll_y: initial_y,
-                child_x: 0.0,
-                child_y: 0.0,
-                parent_x: 0.0,
-                parent_y: 0.0,
-            }
-            .build(cx);
+        Self { data: ScrollData::root, motion: None, drag: None }.build(cx, move |cx| {
+            ScrollData { scroll_x: initial_x, scroll_y: initial_y, ..Default::default() }.build(cx);
 
             Self::common_builder(cx, ScrollData::root, content, scroll_x, scroll_y);
         })
@@ -109,7 +163,7 @@ impl<L: Lens<Target = ScrollData>> ScrollView<L> {
             panic!("ScrollView::custom requires a ScrollData to be built into a parent");
         }
 
-        Self { data: data.clone() }.build(cx, |cx| {
+        Self { data: data.clone(), motion: None, drag: None }.build(cx, |cx| {
             Self::common_builder(cx, data, content, scroll_x, scroll_y);
         })
     }
@@ -168,13 +222,133 @@ impl<L: Lens<Target = ScrollData>> ScrollView<L> {
     }
 }
 
+impl<L: Lens<Target = ScrollData>> ScrollView<L> {
+    // Sets the scroll offset of the content, in physical pixels, clamped to the scrollable distance.
+    fn set_offset(&self, cx: &mut EventContext, (x, y): (f32, f32)) {
+        let (max_x, max_y) = self.data.get(cx).max_offset();
+        if max_x > 0.0 {
+            cx.emit(ScrollEvent::SetX((x / max_x).clamp(0.0, 1.0)));
+        }
+
+        if max_y > 0.0 {
+            cx.emit(ScrollEvent::SetY((y / max_y).clamp(0.0, 1.0)));
+        }
+    }
+
+    // The offset the content is scrolling towards, so that repeated steps accumulate.
+    fn destination(&self, cx: &mut EventContext) -> (f32, f32) {
+        match self.motion {
+            Some(Motion::Smooth { to, .. }) => to,
+            _ => self.data.get(cx).offset(),
+        }
+    }
+
+    // Starts smoothly scrolling the content to the given offset.
+    fn smooth_scroll_to(&mut self, cx: &mut EventContext, (x, y): (f32, f32)) {
+        let data = self.data.get(cx);
+        let (max_x, max_y) = data.max_offset();
+        let to = (x.clamp(0.0, max_x), y.clamp(0.0, max_y));
+        self.motion = Some(Motion::Smooth { from: data.offset(), to, start: Instant::now() });
+        cx.request_animation_frame();
+    }
+
+    // Smoothly scrolls the content so that the entity is in view, if it's within this scroll view.
+    fn scroll_to(&mut self, cx: &mut EventContext, entity: Entity) {
+        let current = cx.current();
+        if entity == current || !entity.is_descendant_of(cx.tree, current) {
+            return;
+        }
+
+        // Moves the view the least distance needed, showing the start of an entity which is
+        // larger than the view
+        let distance = |start: f32, end: f32, view_start: f32, view_end: f32| {
+            if start < view_start {
+                start - view_start
+            } else if end > view_end {
+                (end - view_end).min(start - view_start)
+            } else {
+                0.0
+            }
+        };
+
+        let view = cx.cache.get_bounds(current);
+        let bounds = cx.cache.get_bounds(entity);
+        let dx = distance(bounds.left(), bounds.right(), view.left(), view.right());
+        let dy = distance(bounds.top(), bounds.bottom(), view.top(), view.bottom());
+        if dx != 0.0 || dy != 0.0 {
+            let (x, y) = self.destination(cx);
+            self.smooth_scroll_to(cx, (x + dx, y + dy));
+        }
+    }
+
+    // Advances the smooth scroll or fling in progress by one frame.
+    fn step(&mut self, cx: &mut EventContext) {
+        let data = self.data.get(cx);
+        let (max_x, max_y) = data.max_offset();
+        let offset = match self.motion.as_mut() {
+            Some(Motion::Smooth { from, to, start }) => {
+                let t =
+                    (start.elapsed().as_secs_f32() / SMOOTH_SCROLL_DURATION.as_secs_f32()).min(1.0);
+                // Ease out, so the scroll responds immediately and settles gently
+                let eased = 1.0 - (1.0 - t).powi(3);
+                let offset = (from.0 + (to.0 - from.0) * eased, from.1 + (to.1 - from.1) * eased);
+                if t >= 1.0 {
+                    self.motion = None;
+                }
+
+                offset
+            }
+
+            Some(Motion::Fling { velocity, last }) => {
+                let now = Instant::now();
+                let dt = (now - *last).as_secs_f32();
+                *last = now;
+
+                let (x, y) = data.offset();
+                let offset = (x + velocity.0 * dt, y + velocity.1 * dt);
+                let decay = (-FLING_DECELERATION * dt).exp();
+                velocity.0 *= decay;
+                velocity.1 *= decay;
+
+                // The content stops moving along an axis when it reaches either end
+                if offset.0 <= 0.0 || offset.0 >= max_x {
+                    velocity.0 = 0.0;
+                }
+
+                if offset.1 <= 0.0 || offset.1 >= max_y {
+                    velocity.1 = 0.0;
+                }
+
+                if velocity.0.hypot(velocity.1) < FLING_MIN_VELOCITY {
+                    self.motion = None;
+                }
+
+                offset
+            }
+
+            None => return,
+        };
+
+        self.set_offset(cx, offset);
+        if self.motion.is_some() {
+            cx.request_animation_frame();
+        }
+    }
+}
+
 impl<L: Lens<Target = ScrollData>> View for ScrollView<L> {
     fn element(&self) -> Option<&'static str> {
         Some("scrollview")
     }
 
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
-        event.map(|window_event, _| match window_event {
+        event.map(|scroll_event, _| {
+            if let ScrollEvent::ScrollTo(entity) = scroll_event {
+                self.scroll_to(cx, *entity);
+            }
+        });
+
+        event.map(|window_event, meta| match window_event {
             WindowEvent::GeometryChanged(geo) => {
                 if geo.contains(GeometryChanged::HEIGHT_CHANGED)
                     || geo.contains(GeometryChanged::WIDTH_CHANGED)
@@ -190,18 +364,124 @@ impl<L: Lens<Target = ScrollData>> View for ScrollView<L> {
                 let (x, y) =
                     if cx.modifiers.contains(Modifiers::SHIFT) { (-*y, -*x) } else { (-*x, -*y) };
 
-                // what percentage of the negative space does this cross?
+                let (dest_x, dest_y) = self.destination(cx);
+                self.smooth_scroll_to(
+                    cx,
+                    (dest_x + x * SCROLL_SENSITIVITY, dest_y + y * SCROLL_SENSITIVITY),
+                );
+            }
+
+            WindowEvent::MouseScrollPixels(x, y) => {
+                let (x, y) =
+                    if cx.modifiers.contains(Modifiers::SHIFT) { (-*y, -*x) } else { (-*x, -*y) };
+
+                // Precise deltas follow the fingers directly
+                self.motion = None;
+                let (offset_x, offset_y) = self.data.get(cx).offset();
+                self.set_offset(cx, (offset_x + x, offset_y + y));
+            }
+
+            WindowEvent::KeyDown(code, _) => {
                 let data = self.data.get(cx);
-                if x != 0.0 {
-                    let negative_space = data.child_x - data.parent_x;
-                    let logical_delta = x * SCROLL_SENSITIVITY / negative_space;
-                    cx.emit(ScrollEvent::ScrollX(logical_delta));
+                let (_, max_y) = data.max_offset();
+                if max_y <= 0.0 {
+                    return;
                 }
-                let data = cx.data::<ScrollData>().unwrap();
-                if y != 0.0 {
-                    let negative_space = data.child_y - data.parent_y;
-                    let logical_delta = y * SCROLL_SENSITIVITY / negative_space;
-                    cx.emit(ScrollEvent::ScrollY(logical_delta));
+
+                // A page keeps some of the previous page in view
+                let page = data.parent_y * 0.9;
+                let (x, y) = self.destination(cx);
+                let to = match code {
+                    // Left to tab views, which switch between tabs with them
+                    Code::PageUp | Code::PageDown if cx.modifiers.contains(Modifiers::CTRL) => {
+                        return
+                    }
+                    Code::PageUp => (x, y - page),
+                    Code::PageDown => (x, y + page),
+                    Code::Home => (x, 0.0),
+                    Code::End => (x, max_y),
+                    _ => return,
+                };
+
+                self.smooth_scroll_to(cx, to);
+                meta.consume();
+            }
+
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                // Reached only when the content didn't handle the press
+                self.motion = None;
+                self.drag = Some(Drag {
+                    last: cx.mouse.left.pos_down,
+                    time: Instant::now(),
+                    velocity: (0.0, 0.0),
+                    dragging: false,
+                });
+            }
+
+            WindowEvent::MouseMove(x, y) => {
+                if let Some(mut drag) = self.drag {
+                    if cx.mouse.left.state != MouseButtonState::Pressed {
+                        self.drag = None;
+                        return;
+                    }
+
+                    if !drag.dragging {
+                        let (down_x, down_y) = cx.mouse.left.pos_down;
+                        if (x - down_x).hypot(y - down_y) < DRAG_THRESHOLD {
+                            return;
+                        }
+
+                        drag.dragging = true;
+                        cx.capture();
+                    }
+
+                    let now = Instant::now();
+                    let (dx, dy) = (x - drag.last.0, y - drag.last.1);
+                    let (offset_x, offset_y) = self.data.get(cx).offset();
+                    self.set_offset(cx, (offset_x - dx, offset_y - dy));
+
+                    // Smooth the velocity so that an uneven final movement doesn't dominate
+                    let dt = (now - drag.time).as_secs_f32();
+                    if dt > 0.0 {
+                        drag.velocity = (
+                            0.8 * -dx / dt + 0.2 * drag.velocity.0,
+                            0.8 * -dy / dt + 0.2 * drag.velocity.1,
+                        );
+                    }
+
+                    drag.last = (*x, *y);
+                    drag.time = now;
+                    self.drag = Some(drag);
+                    meta.consume();
+                }
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if let Some(drag) = self.drag.take() {
+                    if drag.dragging {
+                        cx.release();
+                        let (vx, vy) = drag.velocity;
+                        if drag.time.elapsed() < FLING_MAX_IDLE
+                            && vx.hypot(vy) >= FLING_MIN_VELOCITY
+                        {
+                            self.motion = Some(Motion::Fling {
+                                velocity: drag.velocity,
+                                last: Instant::now(),
+                            });
+                            cx.request_animation_frame();
+                        }
+
+                        meta.consume();
+                    }
+                }
+            }
+
+            WindowEvent::AnimationFrame => self.step(cx),
+
+            // Keyboard focus moving into the content brings the focused view into view
+            WindowEvent::FocusIn => {
+                if meta.target != cx.current() {
+                    self.scroll_to(cx, meta.target);
                 }
             }
 
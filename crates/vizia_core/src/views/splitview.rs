@@ -0,0 +1,362 @@
+use std::collections::HashMap;
+
+use morphorm::Units;
+
+use crate::prelude::*;
+use crate::views::Orientation;
+
+/// The thickness of the dividers between panes, in logical pixels.
+const DIVIDER_SIZE: f32 = 6.0;
+/// How far an arrow key moves a focused divider, in logical pixels.
+const KEYBOARD_STEP: f32 = 10.0;
+
+/// A view which arranges its children as panes in a row or column, separated by dividers which
+/// can be dragged to resize the panes.
+///
+/// Each child built by the content closure is a pane. The sizes of the panes, in logical pixels,
+/// are read from the bound `Vec<f32>`, and panes without a size share the remaining space. Resizing
+/// a pane calls the `on_resize` callback with the new sizes, which can be used to update the bound
+/// data, so that the sizes can be persisted along with the rest of the application state.
+///
+/// Panes keep within their minimum and maximum widths or heights while being resized. Double
+/// clicking a divider, or pressing enter while it's focused, collapses the pane before it, and
+/// doing so again restores the pane. Focused dividers are moved with the arrow keys.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # use vizia_derive::*;
+/// # let cx = &mut Context::default();
+/// # #[derive(Lens)]
+/// # pub struct AppData {
+/// #     sizes: Vec<f32>,
+/// # }
+/// # pub enum AppEvent {
+/// #     SetSizes(Vec<f32>),
+/// # }
+/// # impl Model for AppData {
+/// #     fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+/// #         event.map(|app_event, _| match app_event {
+/// #             AppEvent::SetSizes(sizes) => self.sizes = sizes.clone(),
+/// #         });
+/// #     }
+/// # }
+/// # AppData { sizes: vec![200.0] }.build(cx);
+/// SplitView::new(cx, AppData::sizes, Orientation::Horizontal, |cx| {
+///     Label::new(cx, "Sidebar").min_width(Pixels(100.0));
+///     Label::new(cx, "Content");
+/// })
+/// .on_resize(|cx, sizes| cx.emit(AppEvent::SetSizes(sizes)));
+/// ```
+pub struct SplitView<L> {
+    lens: L,
+    orientation: Orientation,
+    drag: Option<Drag>,
+    // The sizes of collapsed panes before they were collapsed, by pane index
+    collapsed: HashMap<usize, f32>,
+    on_resize: Option<Box<dyn Fn(&mut EventContext, Vec<f32>)>>,
+}
+
+// A divider being dragged.
+struct Drag {
+    divider: usize,
+    // The position of the pointer along the orientation of the view when the drag started, in
+    // physical pixels.
+    start: f32,
+    // The sizes of the panes either side of the divider when the drag started, in logical pixels.
+    sizes: (f32, f32),
+}
+
+impl<L> SplitView<L>
+where
+    L: Lens<Target = Vec<f32>>,
+{
+    /// Creates a new split view with the pane sizes targeted by the lens, and with the panes built
+    /// by the content closure.
+    pub fn new<F>(cx: &mut Context, lens: L, orientation: Orientation, content: F) -> Handle<Self>
+    where
+        F: 'static + FnOnce(&mut Context),
+    {
+        Self {
+            lens: lens.clone(),
+            orientation,
+            drag: None,
+            collapsed: HashMap::new(),
+            on_resize: None,
+        }
+        .build(cx, move |cx| {
+            (content)(cx);
+
+            let split_view = cx.current();
+            let panes = split_view
+                .child_iter(&cx.tree)
+                .filter(|child| !cx.tree.is_ignored(*child))
+                .collect::<Vec<_>>();
+
+            // Each divider is moved to follow the pane before it
+            for pane in panes.iter().take(panes.len().saturating_sub(1)) {
+                let divider = Element::new(cx)
+                    .class("divider")
+                    .navigable(true)
+                    .toggle_class("horizontal", orientation == Orientation::Horizontal)
+                    .toggle_class("vertical", orientation == Orientation::Vertical)
+                    .entity();
+
+                let divider_size = Pixels(DIVIDER_SIZE);
+                if orientation == Orientation::Horizontal {
+                    cx.style.width.insert(divider, divider_size);
+                    cx.style.cursor.insert(divider, CursorIcon::ColResize);
+                } else {
+                    cx.style.height.insert(divider, divider_size);
+                    cx.style.cursor.insert(divider, CursorIcon::RowResize);
+                }
+
+                cx.tree.set_next_sibling(*pane, divider).expect("Failed to place divider");
+            }
+
+            Binding::new(cx, lens, move |cx, sizes| {
+                let sizes = sizes.get(cx);
+                for (index, &pane) in panes.iter().enumerate() {
+                    let size = sizes.get(index).copied();
+                    let units = size.map_or(Stretch(1.0), Pixels);
+                    if orientation == Orientation::Horizontal {
+                        cx.style.width.insert(pane, units);
+                    } else {
+                        cx.style.height.insert(pane, units);
+                    }
+
+                    // Collapsed panes are hidden, as their minimum size would otherwise keep them
+                    // from collapsing
+                    let display = if size == Some(0.0) { Display::None } else { Display::Flex };
+                    cx.style.display.insert(pane, display);
+                    cx.style.relayout(pane);
+                }
+
+                cx.need_redraw();
+            });
+        })
+        .layout_type(match orientation {
+            Orientation::Horizontal => LayoutType::Row,
+            Orientation::Vertical => LayoutType::Column,
+        })
+    }
+}
+
+impl<L> SplitView<L>
+where
+    L: Lens<Target = Vec<f32>>,
+{
+    // Returns the panes and dividers of the view, which alternate between its children.
+    fn children(&self, cx: &EventContext) -> (Vec<Entity>, Vec<Entity>) {
+        let children = cx
+            .current()
+            .child_iter(cx.tree)
+            .filter(|child| !cx.tree.is_ignored(*child))
+            .collect::<Vec<_>>();
+
+        let panes = children.iter().step_by(2).copied().collect();
+        let dividers = children.iter().skip(1).step_by(2).copied().collect();
+        (panes, dividers)
+    }
+
+    // Returns the size of a pane along the orientation of the view, in logical pixels.
+    fn pane_size(&self, cx: &EventContext, pane: Entity) -> f32 {
+        let bounds = cx.cache.get_bounds(pane);
+        let size = match self.orientation {
+            Orientation::Horizontal => bounds.w,
+            Orientation::Vertical => bounds.h,
+        };
+
+        size / cx.style.dpi_factor as f32
+    }
+
+    // Returns the minimum and maximum size of a pane along the orientation of the view, in
+    // logical pixels.
+    fn pane_limits(&self, cx: &EventContext, pane: Entity) -> (f32, f32) {
+        let parent = self.pane_size(cx, cx.current());
+        let (min, max) = match self.orientation {
+            Orientation::Horizontal => (cx.style.min_width.get(pane), cx.style.max_width.get(pane)),
+            Orientation::Vertical => (cx.style.min_height.get(pane), cx.style.max_height.get(pane)),
+        };
+
+        let fixed = |units: Option<&Units>| match units {
+            Some(Units::Pixels(val)) => Some(*val),
+            Some(Units::Percentage(val)) => Some(parent * val / 100.0),
+            _ => None,
+        };
+
+        (fixed(min).unwrap_or(0.0), fixed(max).unwrap_or(f32::INFINITY))
+    }
+
+    // Moves a divider by the given distance from where its panes had the given sizes, keeping the
+    // panes either side within their limits, and calls the resize callback with the new sizes.
+    fn move_divider(
+        &mut self,
+        cx: &mut EventContext,
+        divider: usize,
+        start: (f32, f32),
+        delta: f32,
+    ) {
+        let (panes, _) = self.children(cx);
+        let (before, after) = match (panes.get(divider), panes.get(divider + 1)) {
+            (Some(&before), Some(&after)) => (before, after),
+            _ => return,
+        };
+
+        let (min_before, max_before) = self.pane_limits(cx, before);
+        let (min_after, max_after) = self.pane_limits(cx, after);
+        let lower = (min_before - start.0).max(start.1 - max_after);
+        let upper = (max_before - start.0).min(start.1 - min_after);
+        let delta = delta.max(lower).min(upper);
+
+        // Panes without a size up to the divider are given the sizes they were laid out with, so
+        // that the pane before the divider can be resized
+        let mut sizes = self.lens.get(cx);
+        while sizes.len() <= divider {
+            let size = self.pane_size(cx, panes[sizes.len()]);
+            sizes.push(size);
+        }
+
+        sizes[divider] = start.0 + delta;
+        if let Some(size) = sizes.get_mut(divider + 1) {
+            *size = start.1 - delta;
+        }
+
+        self.collapsed.remove(&divider);
+        self.resize(cx, sizes);
+    }
+
+    // Collapses the pane before a divider, or restores it if it's collapsed.
+    fn toggle_collapse(&mut self, cx: &mut EventContext, divider: usize) {
+        let (panes, _) = self.children(cx);
+        if divider + 1 >= panes.len() {
+            return;
+        }
+
+        let mut sizes = self.lens.get(cx);
+        while sizes.len() <= divider {
+            let size = self.pane_size(cx, panes[sizes.len()]);
+            sizes.push(size);
+        }
+
+        if let Some(size) = self.collapsed.remove(&divider) {
+            sizes[divider] = size;
+        } else {
+            self.collapsed.insert(divider, sizes[divider]);
+            sizes[divider] = 0.0;
+        }
+
+        self.resize(cx, sizes);
+    }
+
+    fn resize(&self, cx: &mut EventContext, sizes: Vec<f32>) {
+        if let Some(callback) = &self.on_resize {
+            (callback)(cx, sizes);
+        }
+    }
+}
+
+impl<L> View for SplitView<L>
+where
+    L: Lens<Target = Vec<f32>>,
+{
+    fn element(&self) -> Option<&'static str> {
+        Some("splitview")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| {
+            let (panes, dividers) = self.children(cx);
+            let divider = dividers.iter().position(|divider| *divider == meta.target);
+            let orientation = self.orientation;
+            let position = |(x, y): (f32, f32)| match orientation {
+                Orientation::Horizontal => x,
+                Orientation::Vertical => y,
+            };
+
+            match window_event {
+                WindowEvent::MouseDown(MouseButton::Left) => {
+                    if let Some(divider) = divider {
+                        self.drag = Some(Drag {
+                            divider,
+                            start: position(cx.mouse.left.pos_down),
+                            sizes: (
+                                self.pane_size(cx, panes[divider]),
+                                self.pane_size(cx, panes[divider + 1]),
+                            ),
+                        });
+                        cx.capture();
+                        meta.consume();
+                    }
+                }
+
+                WindowEvent::MouseMove(x, y) => {
+                    if let Some(drag) = &self.drag {
+                        let (divider, sizes) = (drag.divider, drag.sizes);
+                        let delta = (position((*x, *y)) - drag.start) / cx.style.dpi_factor as f32;
+                        self.move_divider(cx, divider, sizes, delta);
+                        meta.consume();
+                    }
+                }
+
+                WindowEvent::MouseUp(MouseButton::Left) => {
+                    if self.drag.take().is_some() {
+                        cx.release();
+                        meta.consume();
+                    }
+                }
+
+                WindowEvent::MouseDoubleClick(MouseButton::Left) => {
+                    if let Some(divider) = divider {
+                        self.toggle_collapse(cx, divider);
+                        meta.consume();
+                    }
+                }
+
+                WindowEvent::KeyDown(code, _) => {
+                    let divider = match divider {
+                        Some(divider) => divider,
+                        None => return,
+                    };
+
+                    let step = match (self.orientation, code) {
+                        (Orientation::Horizontal, Code::ArrowLeft)
+                        | (Orientation::Vertical, Code::ArrowUp) => -KEYBOARD_STEP,
+                        (Orientation::Horizontal, Code::ArrowRight)
+                        | (Orientation::Vertical, Code::ArrowDown) => KEYBOARD_STEP,
+
+                        (_, Code::Enter) => {
+                            self.toggle_collapse(cx, divider);
+                            meta.consume();
+                            return;
+                        }
+
+                        _ => return,
+                    };
+
+                    let sizes = (
+                        self.pane_size(cx, panes[divider]),
+                        self.pane_size(cx, panes[divider + 1]),
+                    );
+                    self.move_divider(cx, divider, sizes, step);
+                    meta.consume();
+                }
+
+                _ => {}
+            }
+        });
+    }
+}
+
+impl<L: Lens> Handle<'_, SplitView<L>> {
+    /// Sets the callback triggered when the panes are resized, either by dragging a divider, by
+    /// moving it with the keyboard, or by collapsing or restoring a pane.
+    ///
+    /// The callback is given the new sizes of the panes, in logical pixels.
+    pub fn on_resize<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, Vec<f32>),
+    {
+        self.modify(|split_view| split_view.on_resize = Some(Box::new(callback)))
+    }
+}
@@ -0,0 +1,640 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::prelude::*;
+use crate::views::scrollview::DRAG_THRESHOLD;
+
+/// Whether a [`TabView`] keeps the content of the tabs which aren't selected.
+///
+/// The content of a tab is built when the tab is first selected in either case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabRetention {
+    /// The content of a tab is kept, hidden, while another tab is selected, so that its state is
+    /// preserved when switching back to it.
+    Keep,
+    /// The content of a tab is removed when another tab is selected, and built again when the tab
+    /// is selected again.
+    Discard,
+}
+
+impl Default for TabRetention {
+    fn default() -> Self {
+        TabRetention::Keep
+    }
+}
+
+/// Events which change the selected tab of a [`TabView`].
+///
+/// Events emitted from within a tab view are handled by the nearest tab view containing the
+/// emitter, and other views can send them to a tab view with `emit_to`.
+pub enum TabEvent {
+    /// Selects the tab at the given index.
+    Select(usize),
+    /// Selects the tab after the selected tab, wrapping around to the first tab.
+    SelectNext,
+    /// Selects the tab before the selected tab, wrapping around to the last tab.
+    SelectPrevious,
+}
+
+enum TabEventInternal {
+    Press(usize, bool),
+    Close(usize),
+    ScrollBackward,
+    ScrollForward,
+}
+
+// The actions of the keymap of a tab view.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TabViewAction {
+    SelectNext,
+    SelectPrevious,
+}
+
+// The key chords which switch between the tabs of a tab view while it contains the focus.
+fn key_chords() -> Vec<(KeyChord, TabViewAction)> {
+    vec![
+        (KeyChord::new(Modifiers::CTRL, Code::Tab), TabViewAction::SelectNext),
+        (
+            KeyChord::new(Modifiers::CTRL | Modifiers::SHIFT, Code::Tab),
+            TabViewAction::SelectPrevious,
+        ),
+        (KeyChord::new(Modifiers::CTRL, Code::PageDown), TabViewAction::SelectNext),
+        (KeyChord::new(Modifiers::CTRL, Code::PageUp), TabViewAction::SelectPrevious),
+    ]
+}
+
+// A press on a tab, which drags the tab once the pointer has moved far enough.
+#[derive(Debug, Clone, Copy)]
+struct TabDrag {
+    // The index the dragged tab has been moved to.
+    index: usize,
+    // The previous horizontal position of the pointer, in physical pixels.
+    last_x: f32,
+    dragging: bool,
+}
+
+/// A view which shows one of a list of tabs at a time, selected from a strip of tab headers.
+///
+/// The tabs are described by the items of the bound `Vec`. The header closure builds the header of
+/// a tab from its item, and the content closure builds the content of a tab from its item when the
+/// tab is selected. Whether the content of a tab is kept while another tab is selected is set with
+/// the `retention` modifier. Content is matched to tabs by their items, so reordering the tabs
+/// keeps their content, while changing the item of a tab builds its content again.
+///
+/// Headers which don't fit within the view can be scrolled to with the arrows at either end of the
+/// header strip. Each header has a button which calls the `on_close` callback, and headers can be
+/// dragged to reorder them, which calls the `on_reorder` callback. Both callbacks are expected to
+/// update the bound data. While the view contains the focus, `Ctrl+Tab` and `Ctrl+PageDown` select
+/// the next tab, and `Ctrl+Shift+Tab` and `Ctrl+PageUp` select the previous tab.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # use vizia_derive::*;
+/// # let cx = &mut Context::default();
+/// # #[derive(Lens)]
+/// # pub struct AppData {
+/// #     tabs: Vec<String>,
+/// # }
+/// # pub enum AppEvent {
+/// #     Close(usize),
+/// #     Reorder(usize, usize),
+/// # }
+/// # impl Model for AppData {
+/// #     fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+/// #         event.map(|app_event, _| match app_event {
+/// #             AppEvent::Close(index) => {
+/// #                 self.tabs.remove(*index);
+/// #             }
+/// #             AppEvent::Reorder(from, to) => {
+/// #                 let tab = self.tabs.remove(*from);
+/// #                 self.tabs.insert(*to, tab);
+/// #             }
+/// #         });
+/// #     }
+/// # }
+/// # AppData { tabs: vec![String::from("First"), String::from("Second")] }.build(cx);
+/// TabView::new(
+///     cx,
+///     AppData::tabs,
+///     |cx, tab| {
+///         Label::new(cx, &tab);
+///     },
+///     |cx, tab| {
+///         Label::new(cx, &format!("The {} tab", tab));
+///     },
+/// )
+/// .on_close(|cx, index| cx.emit(AppEvent::Close(index)))
+/// .on_reorder(|cx, from, to| cx.emit(AppEvent::Reorder(from, to)));
+/// ```
+#[derive(Lens)]
+pub struct TabView<L: Lens> {
+    lens: L,
+    selected: usize,
+    retention: TabRetention,
+    // The scroll view containing the tab headers.
+    strip: Entity,
+    #[lens(ignore)]
+    drag: Option<TabDrag>,
+    on_select: Option<Box<dyn Fn(&mut EventContext, usize)>>,
+    on_close: Option<Box<dyn Fn(&mut EventContext, usize)>>,
+    on_reorder: Option<Box<dyn Fn(&mut EventContext, usize, usize)>>,
+}
+
+impl<L, T> TabView<L>
+where
+    L: Lens<Target = Vec<T>>,
+    T: Data,
+{
+    /// Creates a new tab view with the tabs targeted by the lens, with the header of each tab
+    /// built by the header closure and the content of each tab built by the content closure.
+    pub fn new<H, C>(cx: &mut Context, lens: L, header: H, content: C) -> Handle<Self>
+    where
+        H: 'static + Fn(&mut Context, T),
+        C: 'static + Fn(&mut Context, T),
+    {
+        let mut strip = Entity::null();
+        Self {
+            lens: lens.clone(),
+            selected: 0,
+            retention: TabRetention::default(),
+            strip: Entity::null(),
+            drag: None,
+            on_select: None,
+            on_close: None,
+            on_reorder: None,
+        }
+        .build(cx, |cx| {
+            let entries = key_chords()
+                .into_iter()
+                .map(|(chord, action)| {
+                    let entry = match action {
+                        TabViewAction::SelectNext => {
+                            KeymapEntry::new(action, |cx| cx.emit(TabEvent::SelectNext))
+                        }
+                        TabViewAction::SelectPrevious => {
+                            KeymapEntry::new(action, |cx| cx.emit(TabEvent::SelectPrevious))
+                        }
+                    };
+
+                    (chord, entry)
+                })
+                .collect::<Vec<_>>();
+            Keymap::from(entries).build(cx);
+
+            // The scroll position of the header strip, which shows the arrows when it overflows
+            ScrollData::default().build(cx);
+
+            let overflowing = ScrollData::root.map(|data| data.child_x > data.parent_x);
+            HStack::new(cx, |cx| {
+                Label::new(cx, "\u{2039}")
+                    .class("scroll-backward")
+                    .cursor(CursorIcon::Hand)
+                    .display(overflowing.clone())
+                    .on_press(|cx| cx.emit(TabEventInternal::ScrollBackward));
+
+                let headers = lens.clone();
+                strip = ScrollView::custom(cx, false, false, ScrollData::root, move |cx| {
+                    HStack::new(cx, move |cx| {
+                        Binding::new(cx, headers, move |cx, tabs| {
+                            for (index, tab) in tabs.get(cx).into_iter().enumerate() {
+                                TabHeader::new(cx, index, |cx| (header)(cx, tab)).checked(
+                                    TabView::<L>::selected.map(move |selected| *selected == index),
+                                );
+                            }
+                        });
+                    })
+                    .class("tab-headers");
+                })
+                .class("tab-strip")
+                .entity();
+
+                Label::new(cx, "\u{203a}")
+                    .class("scroll-forward")
+                    .cursor(CursorIcon::Hand)
+                    .display(overflowing)
+                    .on_press(|cx| cx.emit(TabEventInternal::ScrollForward));
+            })
+            .class("tab-bar");
+
+            let area = VStack::new(cx, |_| {}).class("tab-content").entity();
+
+            // The content of the tabs is built into the content area, outside of the bindings, so
+            // that it outlives them as the tabs and the selection change
+            let content: Rc<dyn Fn(&mut Context, T)> = Rc::new(content);
+            let pages = Rc::new(RefCell::new(Vec::new()));
+            Binding::new(cx, lens, move |cx, tabs| {
+                let content = content.clone();
+                let pages = pages.clone();
+                Binding::new(cx, TabView::<L>::selected, move |cx, selected| {
+                    let tabs = tabs.get(cx);
+                    let selected = selected.get(cx).min(tabs.len().saturating_sub(1));
+                    let retention = TabView::<L>::retention.get(cx);
+                    let mut pages = pages.borrow_mut();
+                    update_pages(cx, area, &mut pages, &tabs, selected, retention, &*content);
+                });
+            });
+        })
+        .modify(|tab_view| tab_view.strip = strip)
+    }
+}
+
+// Builds the content of the selected tab into the content area if it isn't built yet, and shows
+// it, hiding or removing the content of the other tabs. The pages of content are matched to the
+// tabs by their items, and pages without a tab are removed.
+fn update_pages<T: Data>(
+    cx: &mut Context,
+    area: Entity,
+    pages: &mut Vec<(T, Entity)>,
+    tabs: &[T],
+    selected: usize,
+    retention: TabRetention,
+    content: &dyn Fn(&mut Context, T),
+) {
+    let mut unmatched = std::mem::take(pages);
+    let matched = tabs
+        .iter()
+        .map(|tab| {
+            let index = unmatched.iter().position(|(item, _)| item.same(tab));
+            index.map(|index| unmatched.remove(index).1)
+        })
+        .collect::<Vec<_>>();
+
+    for (_, page) in unmatched {
+        cx.remove(page);
+    }
+
+    for (index, (tab, page)) in tabs.iter().zip(matched).enumerate() {
+        let page = match page {
+            Some(page) if index == selected || retention == TabRetention::Keep => page,
+
+            Some(page) => {
+                cx.remove(page);
+                continue;
+            }
+
+            None if index == selected => {
+                let mut page = Entity::null();
+                cx.with_current(area, |cx| {
+                    let tab = tab.clone();
+                    page = VStack::new(cx, |cx| (content)(cx, tab)).class("tab-page").entity();
+                });
+                page
+            }
+
+            None => continue,
+        };
+
+        cx.style.display.insert(page, Display::from(index == selected));
+        pages.push((tab.clone(), page));
+    }
+
+    cx.style.relayout(area);
+    cx.need_redraw();
+}
+
+// Returns the index a dragged tab moves to, from the horizontal extents of the tabs, the index of
+// the dragged tab, and the position and movement of the pointer. A tab moves past its neighbors
+// once the pointer passes their centers in the direction it's moving, so that tabs of different
+// widths don't swap back and forth.
+fn reorder_target(extents: &[(f32, f32)], index: usize, x: f32, dx: f32) -> usize {
+    let center = |(left, right): (f32, f32)| (left + right) / 2.0;
+    let mut target = index;
+    if dx > 0.0 {
+        while target + 1 < extents.len() && x > center(extents[target + 1]) {
+            target += 1;
+        }
+    } else if dx < 0.0 {
+        while target > 0 && x < center(extents[target - 1]) {
+            target -= 1;
+        }
+    }
+
+    target
+}
+
+// Returns the index of a tab after the tab at one index is moved to another.
+fn moved_index(index: usize, from: usize, to: usize) -> usize {
+    if index == from {
+        to
+    } else if from < index && index <= to {
+        index - 1
+    } else if to <= index && index < from {
+        index + 1
+    } else {
+        index
+    }
+}
+
+// Returns the index of the selected tab after a tab is closed. Closing the selected tab selects
+// the tab after it, or the tab before it if it was the last tab.
+fn selected_after_close(selected: usize, closed: usize, len: usize) -> usize {
+    if closed < selected || (closed == selected && closed + 1 == len) {
+        selected.saturating_sub(1)
+    } else {
+        selected
+    }
+}
+
+impl<L, T> TabView<L>
+where
+    L: Lens<Target = Vec<T>>,
+    T: Data,
+{
+    // Returns the headers of the tabs, in order.
+    fn headers(&self, cx: &EventContext) -> Vec<Entity> {
+        let mut headers = self
+            .strip
+            .branch_iter(cx.tree)
+            .filter_map(|entity| {
+                let view = cx.views.get(&entity)?;
+                let header = view.as_any_ref().downcast_ref::<TabHeader>()?;
+                Some((header.index, entity))
+            })
+            .collect::<Vec<_>>();
+
+        headers.sort_by_key(|(index, _)| *index);
+        headers.into_iter().map(|(_, entity)| entity).collect()
+    }
+
+    fn select(&mut self, cx: &mut EventContext, index: usize) {
+        if index >= self.lens.get(cx).len() {
+            return;
+        }
+
+        if let Some(&header) = self.headers(cx).get(index) {
+            cx.scroll_to(header);
+        }
+
+        if index != self.selected {
+            self.selected = index;
+            if let Some(callback) = &self.on_select {
+                (callback)(cx, index);
+            }
+        }
+    }
+
+    // Selects the tab at the given offset from the selected tab, wrapping around at either end.
+    fn select_offset(&mut self, cx: &mut EventContext, forward: bool) {
+        let len = self.lens.get(cx).len();
+        if len == 0 {
+            return;
+        }
+
+        let selected = self.selected.min(len - 1);
+        let index = if forward { (selected + 1) % len } else { (selected + len - 1) % len };
+        self.select(cx, index);
+    }
+
+    fn close(&mut self, cx: &mut EventContext, index: usize) {
+        let len = self.lens.get(cx).len();
+        if index >= len {
+            return;
+        }
+
+        let selected = self.selected.min(len - 1);
+        self.selected = selected_after_close(selected, index, len);
+        if let Some(callback) = &self.on_close {
+            (callback)(cx, index);
+        }
+
+        // The tab which takes the place of a closed selected tab is selected
+        if index == selected && len > 1 {
+            if let Some(callback) = &self.on_select {
+                (callback)(cx, self.selected);
+            }
+        }
+    }
+
+    fn reorder(&mut self, cx: &mut EventContext, from: usize, to: usize) {
+        self.selected = moved_index(self.selected, from, to);
+        if let Some(callback) = &self.on_reorder {
+            (callback)(cx, from, to);
+        }
+    }
+
+    // Scrolls the header strip to show the first header which is partly hidden in the direction
+    // of scrolling.
+    fn scroll_headers(&self, cx: &mut EventContext, forward: bool) {
+        let strip = cx.cache.get_bounds(self.strip);
+        let headers = self.headers(cx);
+        let header = if forward {
+            headers
+                .iter()
+                .find(|header| cx.cache.get_bounds(**header).right() > strip.right() + 0.5)
+        } else {
+            headers
+                .iter()
+                .rev()
+                .find(|header| cx.cache.get_bounds(**header).left() < strip.left() - 0.5)
+        };
+
+        if let Some(&header) = header {
+            cx.scroll_to(header);
+        }
+    }
+}
+
+impl<L, T> View for TabView<L>
+where
+    L: Lens<Target = Vec<T>>,
+    T: Data,
+{
+    fn element(&self) -> Option<&'static str> {
+        Some("tabview")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|tab_event, meta| {
+            match tab_event {
+                TabEvent::Select(index) => self.select(cx, *index),
+                TabEvent::SelectNext => self.select_offset(cx, true),
+                TabEvent::SelectPrevious => self.select_offset(cx, false),
+            }
+
+            meta.consume();
+        });
+
+        event.map(|tab_event, meta| {
+            match tab_event {
+                TabEventInternal::Press(index, mouse) => {
+                    self.select(cx, *index);
+                    if *mouse {
+                        let (x, _) = cx.mouse.left.pos_down;
+                        self.drag = Some(TabDrag { index: *index, last_x: x, dragging: false });
+                        cx.capture();
+                    }
+                }
+
+                TabEventInternal::Close(index) => self.close(cx, *index),
+                TabEventInternal::ScrollBackward => self.scroll_headers(cx, false),
+                TabEventInternal::ScrollForward => self.scroll_headers(cx, true),
+            }
+
+            meta.consume();
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseMove(x, _) => {
+                let mut drag = match self.drag {
+                    Some(drag) => drag,
+                    None => return,
+                };
+
+                if !drag.dragging {
+                    let (down_x, _) = cx.mouse.left.pos_down;
+                    if (x - down_x).abs() < DRAG_THRESHOLD {
+                        return;
+                    }
+
+                    drag.dragging = true;
+                }
+
+                let extents = self
+                    .headers(cx)
+                    .into_iter()
+                    .map(|header| {
+                        let bounds = cx.cache.get_bounds(header);
+                        (bounds.left(), bounds.right())
+                    })
+                    .collect::<Vec<_>>();
+
+                let target = reorder_target(&extents, drag.index, *x, x - drag.last_x);
+                if target != drag.index {
+                    self.reorder(cx, drag.index, target);
+                    drag.index = target;
+                }
+
+                drag.last_x = *x;
+                self.drag = Some(drag);
+                meta.consume();
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if self.drag.take().is_some() {
+                    cx.release();
+                }
+            }
+
+            // The key chords which switch tabs have been handled by the keymap of this view, and
+            // are consumed so that any tab views containing this one don't also switch tabs
+            WindowEvent::KeyDown(code, _) => {
+                let chord = KeyChord::new(*cx.modifiers, *code);
+                if key_chords().iter().any(|(key_chord, _)| *key_chord == chord) {
+                    meta.consume();
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
+impl<L: Lens> Handle<'_, TabView<L>> {
+    /// Sets whether the content of the tabs which aren't selected is kept.
+    pub fn retention(self, retention: TabRetention) -> Self {
+        self.modify(|tab_view| tab_view.retention = retention)
+    }
+
+    /// Sets the callback triggered when a tab is selected, which is given the index of the tab.
+    pub fn on_select<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, usize),
+    {
+        self.modify(|tab_view| tab_view.on_select = Some(Box::new(callback)))
+    }
+
+    /// Sets the callback triggered when the close button of a tab is pressed, which is given the
+    /// index of the tab to remove.
+    pub fn on_close<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, usize),
+    {
+        self.modify(|tab_view| tab_view.on_close = Some(Box::new(callback)))
+    }
+
+    /// Sets the callback triggered when a tab is dragged to a new position, which is given the
+    /// index the tab is removed from and the index it's then inserted at.
+    pub fn on_reorder<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, usize, usize),
+    {
+        self.modify(|tab_view| tab_view.on_reorder = Some(Box::new(callback)))
+    }
+}
+
+// The header of a tab, with a button to close the tab.
+struct TabHeader {
+    index: usize,
+    close: Entity,
+}
+
+impl TabHeader {
+    fn new(cx: &mut Context, index: usize, content: impl FnOnce(&mut Context)) -> Handle<Self> {
+        let mut close = Entity::null();
+        Self { index, close: Entity::null() }
+            .build(cx, |cx| {
+                (content)(cx);
+                close = Label::new(cx, "\u{00d7}")
+                    .class("close")
+                    .cursor(CursorIcon::Hand)
+                    .on_press(move |cx| cx.emit(TabEventInternal::Close(index)))
+                    .entity();
+            })
+            .navigable(true)
+            .modify(|header| header.close = close)
+    }
+}
+
+impl View for TabHeader {
+    fn element(&self) -> Option<&'static str> {
+        Some("tab")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::PressDown { mouse } if meta.target != self.close => {
+                cx.emit(TabEventInternal::Press(self.index, *mouse));
+            }
+
+            _ => {}
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drags_past_centers_of_neighbors() {
+        let extents = [(0.0, 50.0), (50.0, 250.0), (250.0, 300.0)];
+        assert_eq!(reorder_target(&extents, 0, 140.0, 10.0), 0);
+        assert_eq!(reorder_target(&extents, 0, 160.0, 10.0), 1);
+        assert_eq!(reorder_target(&extents, 0, 290.0, 10.0), 2);
+        assert_eq!(reorder_target(&extents, 2, 10.0, -10.0), 0);
+
+        // Moving back doesn't swap the tabs again until passing the center of the neighbor
+        let extents = [(0.0, 200.0), (200.0, 250.0), (250.0, 300.0)];
+        assert_eq!(reorder_target(&extents, 1, 160.0, -10.0), 1);
+        assert_eq!(reorder_target(&extents, 1, 90.0, -10.0), 0);
+    }
+
+    #[test]
+    fn follows_moved_tabs() {
+        assert_eq!(moved_index(1, 1, 3), 3);
+        assert_eq!(moved_index(2, 1, 3), 1);
+        assert_eq!(moved_index(0, 1, 3), 0);
+        assert_eq!(moved_index(1, 3, 0), 2);
+        assert_eq!(moved_index(4, 3, 0), 4);
+    }
+
+    #[test]
+    fn selects_neighbor_of_closed_tab() {
+        assert_eq!(selected_after_close(1, 1, 3), 1);
+        assert_eq!(selected_after_close(2, 2, 3), 1);
+        assert_eq!(selected_after_close(2, 0, 3), 1);
+        assert_eq!(selected_after_close(0, 2, 3), 0);
+        assert_eq!(selected_after_close(0, 0, 1), 0);
+    }
+}
@@ -1,21 +1,74 @@
 use crate::cache::BoundingBox;
 use crate::prelude::*;
-use crate::text::{enforce_text_bounds, ensure_visible, Direction, Movement};
+use crate::text::{
+    enforce_text_bounds, ensure_visible, find_matches, Direction, Movement, SearchOptions,
+    Selection,
+};
 use crate::views::scrollview::SCROLL_SENSITIVITY;
 use cosmic_text::{Action, Attrs, Edit};
+use std::ops::Range;
 use std::sync::Arc;
 use vizia_id::GenerationalId;
 use vizia_input::Code;
 use vizia_storage::TreeExt;
 
+/// The character drawn in place of each character of a password textbox.
+const PASSWORD_BULLET: char = '\u{2022}';
+
+/// The number of edits of a textbox which can be undone.
+const UNDO_LIMIT: usize = 100;
+
+/// The text and selection of a textbox before an edit, which are restored by undoing the edit.
+#[derive(Clone)]
+pub struct UndoState {
+    text: String,
+    selection: Selection,
+}
+
+// The actions of the keymap of a textbox.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TextboxAction {
+    OpenFind,
+    Undo,
+    Redo,
+}
+
+// The key chords of the actions of a textbox.
+fn key_chords() -> Vec<(KeyChord, TextboxAction)> {
+    vec![
+        (KeyChord::new(Modifiers::CTRL, Code::KeyF), TextboxAction::OpenFind),
+        (KeyChord::new(Modifiers::CTRL, Code::KeyZ), TextboxAction::Undo),
+        (KeyChord::new(Modifiers::CTRL, Code::KeyY), TextboxAction::Redo),
+        (KeyChord::new(Modifiers::CTRL | Modifiers::SHIFT, Code::KeyZ), TextboxAction::Redo),
+    ]
+}
+
 #[derive(Lens)]
 pub struct TextboxData {
     edit: bool,
-    transform: (f32, f32),
-    content_entity: Entity,
+    pub(crate) transform: (f32, f32),
+    pub(crate) content_entity: Entity,
     kind: TextboxKind,
     on_edit: Option<Arc<dyn Fn(&mut EventContext, String) + Send + Sync>>,
     on_submit: Option<Arc<dyn Fn(&mut EventContext, String, bool) + Send + Sync>>,
+    on_invalid: Option<Arc<dyn Fn(&mut EventContext, String) + Send + Sync>>,
+    validator: Option<Arc<dyn Fn(&str) -> Result<(), String> + Send + Sync>>,
+    allowed_chars: Option<Arc<dyn Fn(char) -> bool + Send + Sync>>,
+    max_length: Option<usize>,
+    password: bool,
+    // The real contents of a password textbox, the buffer only holds bullets.
+    secret: String,
+    placeholder: String,
+    placeholder_shown: bool,
+    search_query: String,
+    search_options: SearchOptions,
+    matches: Vec<Selection>,
+    on_find: Option<Arc<dyn Fn(&mut EventContext, Vec<Selection>, Option<usize>) + Send + Sync>>,
+    on_open_find: Option<Arc<dyn Fn(&mut EventContext, String) + Send + Sync>>,
+    undo_stack: Vec<UndoState>,
+    redo_stack: Vec<UndoState>,
+    // Whether the last edit was typing a word, which following typed characters are undone with.
+    typing: bool,
 }
 
 impl TextboxData {
@@ -27,6 +80,22 @@ impl TextboxData {
             content_entity: Entity::null(),
             kind: TextboxKind::SingleLine,
             on_submit: None,
+            on_invalid: None,
+            validator: None,
+            allowed_chars: None,
+            max_length: None,
+            password: false,
+            secret: String::new(),
+            placeholder: String::new(),
+            placeholder_shown: false,
+            search_query: String::new(),
+            search_options: SearchOptions::default(),
+            matches: Vec::new(),
+            on_find: None,
+            on_open_find: None,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            typing: false,
         }
     }
 
@@ -70,30 +139,291 @@ impl TextboxData {
         self.transform = (tx.round() / scale, ty.round() / scale);
     }
 
-    pub fn insert_text(&mut self, cx: &mut EventContext, text: &str) {
+    pub fn insert_text(&mut self, cx: &mut EventContext, input: &str) {
+        // The characters of the text which aren't replaced count towards the maximum length
+        let kept = if self.max_length.is_some() {
+            let selected = cx.text_context.with_editor(self.content_entity, |buf| {
+                buf.copy_selection().map_or(0, |selected| selected.chars().count())
+            });
+            self.clone_text(cx).chars().count() - selected
+        } else {
+            0
+        };
+        let mut text = self.accepted_input(input, kept);
+
+        // Don't replace the selection if everything that was typed has been rejected.
+        if text.is_empty() && !input.is_empty() {
+            return;
+        }
+
+        if self.password {
+            replace_chars(&mut self.secret, self.selected_range(cx), &text);
+            text = mask(&text);
+        }
+
         cx.text_context.with_editor(self.content_entity, |buf| {
-            buf.insert_string(text, None);
+            buf.insert_string(&text, None);
         });
         cx.needs_relayout();
     }
 
     pub fn delete_text(&mut self, cx: &mut EventContext, movement: Movement) {
+        let len_before = self.displayed_len(cx);
         if cx.text_context.with_editor(self.content_entity, |buf| !buf.delete_selection()) {
             self.move_cursor(cx, movement, true);
             cx.text_context.with_editor(self.content_entity, |buf| {
                 buf.delete_selection();
             });
         }
+
+        if self.password {
+            // After a deletion the cursor sits at the start of the removed range.
+            let start = self.selected_range(cx).start;
+            let removed = len_before - self.displayed_len(cx);
+            replace_chars(&mut self.secret, start..start + removed, "");
+        }
         cx.needs_relayout();
     }
 
     pub fn reset_text(&mut self, cx: &mut EventContext, text: &str) {
+        let text = if self.password {
+            self.secret = text.to_owned();
+            mask(text)
+        } else {
+            text.to_owned()
+        };
         cx.text_context.with_buffer(self.content_entity, |buf| {
-            buf.set_text(text, Attrs::new());
+            buf.set_text(&text, Attrs::new());
         });
         cx.needs_relayout();
     }
 
+    /// Returns the part of the input which can be inserted, without any disallowed characters and
+    /// cut to the maximum length, given the number of characters of the text which are kept.
+    fn accepted_input(&self, input: &str, kept: usize) -> String {
+        let mut text = match &self.allowed_chars {
+            Some(allowed) => input.chars().filter(|c| (allowed)(*c)).collect::<String>(),
+            None => input.to_owned(),
+        };
+
+        if let Some(max_length) = self.max_length {
+            let available = max_length.saturating_sub(kept);
+            if let Some((index, _)) = text.char_indices().nth(available) {
+                text.truncate(index);
+            }
+        }
+
+        text
+    }
+
+    /// Returns the selected range (or the caret position) of a single-line textbox, in characters.
+    fn selected_range(&self, cx: &mut EventContext) -> Range<usize> {
+        cx.text_context.with_editor(self.content_entity, |buf| {
+            let line = &buf.buffer().lines[buf.cursor().line];
+            let to_chars = |index: usize| line.text()[..index].chars().count();
+            let cursor = to_chars(buf.cursor().index);
+            let select = buf.select_opt().map(|select| to_chars(select.index)).unwrap_or(cursor);
+            cursor.min(select)..cursor.max(select)
+        })
+    }
+
+    /// Returns the number of characters currently shown by the textbox.
+    fn displayed_len(&self, cx: &mut EventContext) -> usize {
+        cx.text_context.with_buffer(self.content_entity, |buf| {
+            buf.lines.iter().map(|line| line.text().chars().count()).sum()
+        })
+    }
+
+    /// Runs the validator, if any, over the current text and updates the `:invalid` pseudo-class.
+    ///
+    /// Returns true if the text is valid.
+    fn validate(&mut self, cx: &mut EventContext) -> bool {
+        let text = self.clone_text(cx);
+        match self.check(&text) {
+            Ok(()) => {
+                cx.set_invalid(false);
+                true
+            }
+
+            Err(error) => {
+                cx.set_invalid(true);
+                if let Some(callback) = self.on_invalid.take() {
+                    (callback)(cx, error);
+
+                    self.on_invalid = Some(callback);
+                }
+                false
+            }
+        }
+    }
+
+    /// Returns the result of the validator, if any, for the text.
+    fn check(&self, text: &str) -> Result<(), String> {
+        self.validator.as_ref().map_or(Ok(()), |validator| (validator)(text))
+    }
+
+    /// Validates the text and, if it is valid, passes it to the `on_edit` callback.
+    fn edited(&mut self, cx: &mut EventContext) {
+        self.update_placeholder(cx);
+        if !self.search_query.is_empty() {
+            self.update_matches(cx);
+        }
+
+        if !self.validate(cx) {
+            return;
+        }
+
+        if let Some(callback) = self.on_edit.take() {
+            let text = self.clone_text(cx);
+            (callback)(cx, text);
+
+            self.on_edit = Some(callback);
+        }
+    }
+
+    fn update_placeholder(&mut self, cx: &mut EventContext) {
+        self.placeholder_shown = !self.edit && self.displayed_len(cx) == 0;
+    }
+
+    /// Searches the text for the current query and highlights the matches.
+    fn update_matches(&mut self, cx: &mut EventContext) {
+        // Searching a password textbox would reveal its contents.
+        self.matches = if self.password {
+            Vec::new()
+        } else {
+            find_matches(&self.clone_text(cx), &self.search_query, self.search_options)
+        };
+        cx.text_context.set_search_matches(self.content_entity, self.matches.clone());
+        cx.needs_redraw();
+        self.found(cx);
+    }
+
+    /// Passes the matches and the index of the selected match, if any, to the `on_find` callback.
+    fn found(&mut self, cx: &mut EventContext) {
+        if let Some(callback) = self.on_find.take() {
+            let selection = cx.text_context.selection(self.content_entity);
+            let current = self.matches.iter().position(|found| found.range() == selection.range());
+            (callback)(cx, self.matches.clone(), current);
+
+            self.on_find = Some(callback);
+        }
+    }
+
+    /// Selects the match after (or before) the selection, wrapping around at the end of the text,
+    /// and scrolls it into view.
+    fn select_match(&mut self, cx: &mut EventContext, forward: bool) {
+        let selection = cx.text_context.selection(self.content_entity);
+        let found = if forward {
+            self.matches
+                .iter()
+                .find(|found| {
+                    found.min() > selection.min()
+                        || (found.min() == selection.min() && selection.is_caret())
+                })
+                .or_else(|| self.matches.first())
+        } else {
+            self.matches
+                .iter()
+                .rev()
+                .find(|found| found.min() < selection.min())
+                .or_else(|| self.matches.last())
+        };
+
+        if let Some(found) = found.copied() {
+            cx.text_context.select_range(self.content_entity, found);
+            self.set_caret(cx);
+            cx.needs_redraw();
+            self.found(cx);
+        }
+    }
+
+    /// Replaces the selected match, if the selection is a match, and selects the next one.
+    fn replace(&mut self, cx: &mut EventContext, replacement: &str) {
+        let selection = cx.text_context.selection(self.content_entity);
+        if self.matches.iter().any(|found| found.range() == selection.range()) {
+            self.push_undo(cx);
+            self.insert_text(cx, replacement);
+            self.set_caret(cx);
+            self.edited(cx);
+        }
+
+        self.select_match(cx, true);
+    }
+
+    /// Replaces every match, leaving the cursor after the last replacement. The replacement is
+    /// undone and reported to `on_edit` as a single edit.
+    fn replace_all(&mut self, cx: &mut EventContext, replacement: &str) {
+        if self.matches.is_empty() {
+            return;
+        }
+
+        let replacement = match &self.allowed_chars {
+            Some(allowed) => replacement.chars().filter(|c| (allowed)(*c)).collect::<String>(),
+            None => replacement.to_owned(),
+        };
+
+        // The matches are in order and don't overlap, so the text between them is copied over
+        // in a single pass.
+        let text = self.clone_text(cx);
+        let mut replaced = String::with_capacity(text.len());
+        let mut end = 0;
+        for found in &self.matches {
+            replaced.push_str(&text[end..found.min()]);
+            replaced.push_str(&replacement);
+            end = found.max();
+        }
+        let caret = replaced.len();
+        replaced.push_str(&text[end..]);
+
+        if self.max_length.map_or(false, |max_length| replaced.chars().count() > max_length) {
+            return;
+        }
+
+        self.push_undo(cx);
+        self.reset_text(cx, &replaced);
+        cx.text_context.select_range(self.content_entity, Selection::caret(caret));
+        self.set_caret(cx);
+        self.edited(cx);
+    }
+
+    /// Saves the text and selection to be restored by undoing the next edit.
+    fn push_undo(&mut self, cx: &mut EventContext) {
+        let state = UndoState {
+            text: self.clone_text(cx),
+            selection: cx.text_context.selection(self.content_entity),
+        };
+
+        push_limited(&mut self.undo_stack, state);
+        self.redo_stack.clear();
+        self.typing = false;
+    }
+
+    /// Restores the text and selection from before the last edit, or with `redo`, from before
+    /// the last undo.
+    fn undo(&mut self, cx: &mut EventContext, redo: bool) {
+        let state = if redo { self.redo_stack.pop() } else { self.undo_stack.pop() };
+        let state = match state {
+            Some(state) => state,
+            None => return,
+        };
+
+        let current = UndoState {
+            text: self.clone_text(cx),
+            selection: cx.text_context.selection(self.content_entity),
+        };
+        if redo {
+            push_limited(&mut self.undo_stack, current);
+        } else {
+            self.redo_stack.push(current);
+        }
+
+        self.reset_text(cx, &state.text);
+        cx.text_context.select_range(self.content_entity, state.selection);
+        self.typing = false;
+        self.set_caret(cx);
+        self.edited(cx);
+    }
+
     pub fn move_cursor(&mut self, cx: &mut EventContext, movement: Movement, selection: bool) {
         cx.text_context.with_editor(self.content_entity, |buf| {
             if selection {
@@ -212,10 +542,19 @@ impl TextboxData {
     }
 
     pub fn clone_selected(&self, cx: &mut EventContext) -> Option<String> {
+        // The contents of a password textbox must never leave it.
+        if self.password {
+            return None;
+        }
+
         cx.text_context.with_editor(self.content_entity, |buf| buf.copy_selection())
     }
 
     pub fn clone_text(&self, cx: &mut EventContext) -> String {
+        if self.password {
+            return self.secret.clone();
+        }
+
         cx.text_context.with_buffer(self.content_entity, |buf| {
             buf.lines.iter().map(|line| line.text()).collect::<Vec<_>>().join("\n").to_string()
         })
@@ -230,6 +569,7 @@ pub enum TextEvent {
     SelectAll,
     SelectWord,
     SelectParagraph,
+    SelectRange(Selection),
     StartEdit,
     EndEdit,
     Submit(bool),
@@ -240,9 +580,30 @@ pub enum TextEvent {
     Paste,
     Cut,
 
+    // Search
+    Find(String, SearchOptions),
+    FindNext,
+    FindPrevious,
+    ClearFind,
+    Replace(String),
+    ReplaceAll(String),
+    OpenFind,
+
+    // History
+    Undo,
+    Redo,
+
     // Helpers
     SetOnEdit(Option<Arc<dyn Fn(&mut EventContext, String) + Send + Sync>>),
     SetOnSubmit(Option<Arc<dyn Fn(&mut EventContext, String, bool) + Send + Sync>>),
+    SetOnInvalid(Option<Arc<dyn Fn(&mut EventContext, String) + Send + Sync>>),
+    SetValidator(Option<Arc<dyn Fn(&str) -> Result<(), String> + Send + Sync>>),
+    SetAllowedChars(Option<Arc<dyn Fn(char) -> bool + Send + Sync>>),
+    SetMaxLength(Option<usize>),
+    SetPassword(bool),
+    SetPlaceholder(String),
+    SetOnFind(Option<Arc<dyn Fn(&mut EventContext, Vec<Selection>, Option<usize>) + Send + Sync>>),
+    SetOnOpenFind(Option<Arc<dyn Fn(&mut EventContext, String) + Send + Sync>>),
     InitContent(Entity, TextboxKind),
     GeometryChanged,
 }
@@ -252,39 +613,41 @@ impl Model for TextboxData {
         event.map(|text_event, _| match text_event {
             TextEvent::InsertText(text) => {
                 if self.edit {
+                    // Consecutive typed characters of a word are undone together
+                    let word = text.chars().count() == 1 && text.chars().all(char::is_alphanumeric);
+                    if !(self.typing && word) {
+                        self.push_undo(cx);
+                    }
+
                     self.insert_text(cx, text);
+                    self.typing = word;
                     self.set_caret(cx);
-
-                    if let Some(callback) = self.on_edit.take() {
-                        let text = self.clone_text(cx);
-                        (callback)(cx, text);
-
-                        self.on_edit = Some(callback);
-                    }
+                    self.edited(cx);
                 }
             }
 
             TextEvent::ResetText(text) => {
                 self.reset_text(cx, text);
                 self.scroll(cx, 0.0, 0.0); // ensure_visible
+                self.validate(cx);
+                self.update_placeholder(cx);
+                if !self.search_query.is_empty() {
+                    self.update_matches(cx);
+                }
             }
 
             TextEvent::DeleteText(movement) => {
                 if self.edit {
+                    self.push_undo(cx);
                     self.delete_text(cx, *movement);
                     self.set_caret(cx);
-
-                    if let Some(callback) = self.on_edit.take() {
-                        let text = self.clone_text(cx);
-                        (callback)(cx, text);
-
-                        self.on_edit = Some(callback);
-                    }
+                    self.edited(cx);
                 }
             }
 
             TextEvent::MoveCursor(movement, selection) => {
                 if self.edit {
+                    self.typing = false;
                     self.move_cursor(cx, *movement, *selection);
                     self.set_caret(cx);
                 }
@@ -297,6 +660,7 @@ impl Model for TextboxData {
                         cx.focus_with_visibility(false);
                         cx.capture();
                         cx.set_checked(true);
+                        self.update_placeholder(cx);
                     }
                 }
             }
@@ -306,14 +670,17 @@ impl Model for TextboxData {
                 self.edit = false;
                 cx.set_checked(false);
                 cx.release();
+                self.update_placeholder(cx);
             }
 
             TextEvent::Submit(reason) => {
-                if let Some(callback) = self.on_submit.take() {
-                    let text = self.clone_text(cx);
-                    (callback)(cx, text, *reason);
+                if self.validate(cx) {
+                    if let Some(callback) = self.on_submit.take() {
+                        let text = self.clone_text(cx);
+                        (callback)(cx, text, *reason);
 
-                    self.on_submit = Some(callback);
+                        self.on_submit = Some(callback);
+                    }
                 }
                 cx.emit(TextEvent::EndEdit);
             }
@@ -333,7 +700,14 @@ impl Model for TextboxData {
                 self.set_caret(cx);
             }
 
+            TextEvent::SelectRange(selection) => {
+                self.typing = false;
+                cx.text_context.select_range(self.content_entity, *selection);
+                self.set_caret(cx);
+            }
+
             TextEvent::Hit(posx, posy) => {
+                self.typing = false;
                 self.hit(cx, *posx, *posy);
                 self.set_caret(cx);
             }
@@ -378,18 +752,65 @@ impl Model for TextboxData {
                         if selected_text.len() > 0 {
                             cx.set_clipboard(selected_text)
                                 .expect("Failed to add text to clipboard");
+                            self.push_undo(cx);
                             self.delete_text(cx, Movement::Grapheme(Direction::Upstream));
-                            if let Some(callback) = self.on_edit.take() {
-                                let text = self.clone_text(cx);
-                                (callback)(cx, text);
-
-                                self.on_edit = Some(callback);
-                            }
+                            self.edited(cx);
                         }
                     }
                 }
             }
 
+            TextEvent::Find(query, options) => {
+                self.search_query = query.clone();
+                self.search_options = *options;
+                self.update_matches(cx);
+                self.select_match(cx, true);
+            }
+
+            TextEvent::FindNext => {
+                self.select_match(cx, true);
+            }
+
+            TextEvent::FindPrevious => {
+                self.select_match(cx, false);
+            }
+
+            TextEvent::ClearFind => {
+                self.search_query.clear();
+                self.update_matches(cx);
+            }
+
+            TextEvent::Replace(replacement) => {
+                if !cx.is_disabled() {
+                    self.replace(cx, replacement);
+                }
+            }
+
+            TextEvent::ReplaceAll(replacement) => {
+                if !cx.is_disabled() {
+                    self.replace_all(cx, replacement);
+                }
+            }
+
+            TextEvent::OpenFind => {
+                if let Some(callback) = self.on_open_find.clone() {
+                    let selected = self.clone_selected(cx).unwrap_or_default();
+                    (callback)(cx, selected);
+                }
+            }
+
+            TextEvent::Undo => {
+                if self.edit {
+                    self.undo(cx, false);
+                }
+            }
+
+            TextEvent::Redo => {
+                if self.edit {
+                    self.undo(cx, true);
+                }
+            }
+
             TextEvent::SetOnEdit(on_edit) => {
                 self.on_edit = on_edit.clone();
             }
@@ -406,6 +827,44 @@ impl Model for TextboxData {
             TextEvent::SetOnSubmit(on_submit) => {
                 self.on_submit = on_submit.clone();
             }
+
+            TextEvent::SetOnInvalid(on_invalid) => {
+                self.on_invalid = on_invalid.clone();
+            }
+
+            TextEvent::SetValidator(validator) => {
+                self.validator = validator.clone();
+                self.validate(cx);
+            }
+
+            TextEvent::SetAllowedChars(allowed_chars) => {
+                self.allowed_chars = allowed_chars.clone();
+            }
+
+            TextEvent::SetMaxLength(max_length) => {
+                self.max_length = *max_length;
+            }
+
+            TextEvent::SetPassword(password) => {
+                if self.password != *password {
+                    let text = self.clone_text(cx);
+                    self.password = *password;
+                    self.reset_text(cx, &text);
+                }
+            }
+
+            TextEvent::SetPlaceholder(placeholder) => {
+                self.placeholder = placeholder.clone();
+                self.update_placeholder(cx);
+            }
+
+            TextEvent::SetOnFind(on_find) => {
+                self.on_find = on_find.clone();
+            }
+
+            TextEvent::SetOnOpenFind(on_open_find) => {
+                self.on_open_find = on_open_find.clone();
+            }
         });
     }
 }
@@ -441,23 +900,83 @@ where
     fn new_core(cx: &mut Context, lens: L, kind: TextboxKind) -> Handle<Self> {
         // TODO can this be simplified now that text doesn't live in TextboxData?
         let result = Self { lens: lens.clone(), kind }.build(cx, move |cx| {
+            let entries = key_chords()
+                .into_iter()
+                .map(|(chord, action)| {
+                    let entry = match action {
+                        TextboxAction::OpenFind => {
+                            KeymapEntry::new(action, |cx| cx.emit(TextEvent::OpenFind))
+                        }
+                        TextboxAction::Undo => {
+                            KeymapEntry::new(action, |cx| cx.emit(TextEvent::Undo))
+                        }
+                        TextboxAction::Redo => {
+                            KeymapEntry::new(action, |cx| cx.emit(TextEvent::Redo))
+                        }
+                    };
+
+                    (chord, entry)
+                })
+                .collect::<Vec<_>>();
+            Keymap::from(entries).build(cx);
+
             Binding::new(cx, lens.clone(), |cx, text| {
                 let text_str = text.view(cx.data().unwrap(), |text| {
                     text.map(|x| x.to_string()).unwrap_or_else(|| "".to_owned())
                 });
                 if let Some(text_data) = cx.data::<TextboxData>() {
                     if !text_data.edit {
-                        let td = TextboxData {
+                        let mut td = TextboxData {
                             edit: text_data.edit,
                             transform: text_data.transform,
                             on_edit: text_data.on_edit.clone(),
                             content_entity: text_data.content_entity,
                             kind: text_data.kind,
                             on_submit: text_data.on_submit.clone(),
+                            on_invalid: text_data.on_invalid.clone(),
+                            validator: text_data.validator.clone(),
+                            allowed_chars: text_data.allowed_chars.clone(),
+                            max_length: text_data.max_length,
+                            password: text_data.password,
+                            secret: text_str.clone(),
+                            placeholder: text_data.placeholder.clone(),
+                            placeholder_shown: text_str.is_empty(),
+                            search_query: text_data.search_query.clone(),
+                            search_options: text_data.search_options,
+                            matches: if text_data.password {
+                                Vec::new()
+                            } else {
+                                find_matches(
+                                    &text_str,
+                                    &text_data.search_query,
+                                    text_data.search_options,
+                                )
+                            },
+                            on_find: text_data.on_find.clone(),
+                            on_open_find: text_data.on_open_find.clone(),
+                            undo_stack: text_data.undo_stack.clone(),
+                            redo_stack: text_data.redo_stack.clone(),
+                            typing: false,
                         };
-                        cx.text_context.with_buffer(text_data.content_entity, |buf| {
-                            buf.set_text(&text_str, Attrs::new());
+                        let shown = if td.password { mask(&text_str) } else { text_str };
+                        let changed = cx.text_context.with_buffer(td.content_entity, |buf| {
+                            // Keep the cursor and selection if the text itself is unchanged, e.g.
+                            // after a replacement made while the textbox wasn't being edited.
+                            let current =
+                                buf.lines.iter().map(|line| line.text()).collect::<Vec<_>>();
+                            let changed = current.join("\n") != shown;
+                            if changed {
+                                buf.set_text(&shown, Attrs::new());
+                            }
+                            changed
                         });
+
+                        // The history doesn't apply to text replaced from outside the textbox
+                        if changed {
+                            td.undo_stack.clear();
+                            td.redo_stack.clear();
+                        }
+                        cx.text_context.set_search_matches(td.content_entity, td.matches.clone());
                         let parent = cx.current().parent(&cx.tree).unwrap();
                         cx.with_current(parent, |cx| td.build(cx));
                         // push an event into the queue to force an update because the textbox data
@@ -490,6 +1009,13 @@ where
                     cx.text_context.with_buffer(lbl, |buf| {
                         buf.set_text(&text, Attrs::new());
                     });
+
+                    TextboxPlaceholder {}
+                        .build(cx, |_| {})
+                        .hoverable(false)
+                        .class("textbox_placeholder")
+                        .text(TextboxData::placeholder)
+                        .display(TextboxData::placeholder_shown);
                 })
                 .hoverable(false)
                 .class("textbox_container");
@@ -524,6 +1050,126 @@ impl<'a, L: Lens> Handle<'a, Textbox<L>> {
 
         self
     }
+
+    /// Sets a validator which is run over the text after every edit.
+    ///
+    /// While the validator returns an error the textbox has the `:invalid` pseudo-class, and the
+    /// `on_edit` and `on_submit` callbacks are not called. The error can be retrieved with
+    /// [`on_invalid`](Self::on_invalid).
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # #[derive(Lens)]
+    /// # struct AppData {
+    /// #     number: i32,
+    /// # }
+    /// # impl Model for AppData {}
+    /// # let cx = &mut Context::default();
+    /// # AppData { number: 5 }.build(cx);
+    /// Textbox::new(cx, AppData::number)
+    ///     .validate(|text| text.parse::<i32>())
+    ///     .on_invalid(|_, error| println!("Not a number: {}", error));
+    /// ```
+    pub fn validate<T, E, F>(self, validator: F) -> Self
+    where
+        E: ToString,
+        F: 'static + Fn(&str) -> Result<T, E> + Send + Sync,
+    {
+        self.cx.emit_to(
+            self.entity,
+            TextEvent::SetValidator(Some(Arc::new(move |text| {
+                (validator)(text).map(|_| ()).map_err(|error| error.to_string())
+            }))),
+        );
+
+        self
+    }
+
+    /// Sets a callback which is called with the validator error when the text becomes invalid.
+    pub fn on_invalid<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, String) + Send + Sync,
+    {
+        self.cx.emit_to(self.entity, TextEvent::SetOnInvalid(Some(Arc::new(callback))));
+
+        self
+    }
+
+    /// Sets a filter for the characters which can be typed or pasted into the textbox.
+    pub fn allowed_chars<F>(self, filter: F) -> Self
+    where
+        F: 'static + Fn(char) -> bool + Send + Sync,
+    {
+        self.cx.emit_to(self.entity, TextEvent::SetAllowedChars(Some(Arc::new(filter))));
+
+        self
+    }
+
+    /// Sets the maximum number of characters the textbox will accept.
+    pub fn max_length(self, max_length: usize) -> Self {
+        self.cx.emit_to(self.entity, TextEvent::SetMaxLength(Some(max_length)));
+
+        self
+    }
+
+    /// Sets whether the textbox is a password field, which shows a bullet for each character and
+    /// disables copying its contents.
+    pub fn password(self, password: bool) -> Self {
+        self.cx.emit_to(self.entity, TextEvent::SetPassword(password));
+
+        self
+    }
+
+    /// Sets the text shown when the textbox is empty and not being edited.
+    pub fn placeholder(self, placeholder: impl Into<String>) -> Self {
+        self.cx.emit_to(self.entity, TextEvent::SetPlaceholder(placeholder.into()));
+
+        self
+    }
+
+    /// Sets the callback triggered when the search matches or the selected match change, after a
+    /// [`TextEvent::Find`] or an edit. It receives the matches, as byte ranges of the text, and the
+    /// index of the selected match.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_core::state::StaticLens;
+    /// # let cx = &mut Context::default();
+    /// Textbox::new_multiline(cx, StaticLens::new(&"one two one"), false)
+    ///     .on_find(|_, matches, current| {
+    ///         println!("Match {:?} of {}", current.map(|index| index + 1), matches.len());
+    ///     });
+    /// ```
+    pub fn on_find<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, Vec<Selection>, Option<usize>) + Send + Sync,
+    {
+        self.cx.emit_to(self.entity, TextEvent::SetOnFind(Some(Arc::new(callback))));
+
+        self
+    }
+
+    /// Sets the callback triggered when `Ctrl+F` is pressed while the textbox is focused, which
+    /// receives the selected text so that it can be searched for.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_core::state::StaticLens;
+    /// # let cx = &mut Context::default();
+    /// Textbox::new_multiline(cx, StaticLens::new(&"one two one"), false)
+    ///     .on_open_find(|_, selected| println!("Find {}", selected));
+    /// ```
+    pub fn on_open_find<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, String) + Send + Sync,
+    {
+        self.cx.emit_to(self.entity, TextEvent::SetOnOpenFind(Some(Arc::new(callback))));
+
+        self
+    }
 }
 
 impl<L: Lens> View for Textbox<L>
@@ -535,7 +1181,7 @@ where
     }
 
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
-        event.map(|window_event, _| match window_event {
+        event.map(|window_event, meta| match window_event {
             WindowEvent::MouseDown(MouseButton::Left) => {
                 if cx.is_over() {
                     cx.focus_with_visibility(false);
@@ -607,6 +1253,10 @@ where
                 cx.emit(TextEvent::Scroll(*x, *y));
             }
 
+            WindowEvent::MouseScrollPixels(x, y) => {
+                cx.emit(TextEvent::Scroll(*x / SCROLL_SENSITIVITY, *y / SCROLL_SENSITIVITY));
+            }
+
             WindowEvent::CharInput(c) => {
                 if *c != '\u{1b}' && // Escape
                             *c != '\u{8}' && // Backspace
@@ -705,11 +1355,13 @@ where
                     cx.set_checked(false);
                 }
 
+                // Navigation keys are consumed so that a containing scroll view doesn't scroll too
                 Code::Home => {
                     cx.emit(TextEvent::MoveCursor(
                         Movement::LineStart,
                         cx.modifiers.contains(Modifiers::SHIFT),
                     ));
+                    meta.consume();
                 }
 
                 Code::End => {
@@ -717,6 +1369,7 @@ where
                         Movement::LineEnd,
                         cx.modifiers.contains(Modifiers::SHIFT),
                     ));
+                    meta.consume();
                 }
 
                 Code::PageUp | Code::PageDown => {
@@ -733,6 +1386,15 @@ where
                         },
                         cx.modifiers.contains(Modifiers::SHIFT),
                     ));
+                    meta.consume();
+                }
+
+                Code::F3 => {
+                    if cx.modifiers.contains(Modifiers::SHIFT) {
+                        cx.emit(TextEvent::FindPrevious);
+                    } else {
+                        cx.emit(TextEvent::FindNext);
+                    }
                 }
 
                 Code::KeyA => {
@@ -753,7 +1415,14 @@ where
                     cx.emit(TextEvent::Cut);
                 }
 
-                _ => {}
+                // The key chords of the keymap of this textbox have been handled, and are consumed
+                // so that keymaps of its ancestors don't also handle them
+                _ => {
+                    let chord = KeyChord::new(*cx.modifiers, *code);
+                    if key_chords().iter().any(|(key_chord, _)| *key_chord == chord) {
+                        meta.consume();
+                    }
+                }
             },
 
             _ => {}
@@ -776,3 +1445,109 @@ impl View for TextboxLabel {
         Some("textboxlabel")
     }
 }
+
+pub struct TextboxPlaceholder {}
+impl View for TextboxPlaceholder {
+    fn element(&self) -> Option<&'static str> {
+        Some("textboxplaceholder")
+    }
+}
+
+fn mask(text: &str) -> String {
+    text.chars().map(|_| PASSWORD_BULLET).collect()
+}
+
+fn char_to_byte_range(text: &str, range: Range<usize>) -> Range<usize> {
+    let byte_index =
+        |index: usize| text.char_indices().nth(index).map_or(text.len(), |(index, _)| index);
+    byte_index(range.start)..byte_index(range.end)
+}
+
+// Replaces a range of characters of the text.
+fn replace_chars(text: &mut String, range: Range<usize>, replacement: &str) {
+    let range = char_to_byte_range(text, range);
+    text.replace_range(range, replacement);
+}
+
+// Pushes the state onto an undo stack, dropping the oldest state once the stack holds
+// `UNDO_LIMIT` states.
+fn push_limited(stack: &mut Vec<UndoState>, state: UndoState) {
+    if stack.len() >= UNDO_LIMIT {
+        stack.drain(..=stack.len() - UNDO_LIMIT);
+    }
+    stack.push(state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edits_password_secret_by_characters() {
+        let mut secret = String::from("añb");
+
+        // Typing at the end and in the middle of multi-byte characters.
+        replace_chars(&mut secret, 3..3, "日");
+        assert_eq!(secret, "añb日");
+        replace_chars(&mut secret, 1..1, "é");
+        assert_eq!(secret, "aéñb日");
+
+        // Backspace removes a single character.
+        replace_chars(&mut secret, 4..5, "");
+        assert_eq!(secret, "aéñb");
+
+        // Replacing a selection.
+        replace_chars(&mut secret, 1..3, "本");
+        assert_eq!(secret, "a本b");
+
+        assert_eq!(mask(&secret), "\u{2022}\u{2022}\u{2022}");
+    }
+
+    #[test]
+    fn cuts_input_to_max_length() {
+        let mut data = TextboxData::new();
+        data.max_length = Some(5);
+
+        assert_eq!(data.accepted_input("ünïcode", 3), "ün");
+        assert_eq!(data.accepted_input("ünïcode", 5), "");
+        assert_eq!(data.accepted_input("ünïcode", 8), "");
+        assert_eq!(data.accepted_input("日本", 0), "日本");
+    }
+
+    #[test]
+    fn filters_disallowed_characters() {
+        let mut data = TextboxData::new();
+        data.allowed_chars = Some(Arc::new(|c: char| c.is_ascii_digit()));
+
+        assert_eq!(data.accepted_input("1a2ü3", 0), "123");
+
+        data.max_length = Some(4);
+        assert_eq!(data.accepted_input("1a2ü3", 2), "12");
+    }
+
+    #[test]
+    fn limits_undo_stack() {
+        let state =
+            |text: &str| UndoState { text: text.to_owned(), selection: Selection::caret(0) };
+        let mut stack = Vec::new();
+        for index in 0..UNDO_LIMIT + 2 {
+            push_limited(&mut stack, state(&index.to_string()));
+        }
+
+        assert_eq!(stack.len(), UNDO_LIMIT);
+        assert_eq!(stack[0].text, "2");
+        assert_eq!(stack[UNDO_LIMIT - 1].text, (UNDO_LIMIT + 1).to_string());
+    }
+
+    #[test]
+    fn checks_text_with_validator() {
+        let mut data = TextboxData::new();
+        assert_eq!(data.check("anything"), Ok(()));
+
+        data.validator = Some(Arc::new(|text: &str| {
+            text.parse::<i32>().map(|_| ()).map_err(|error| error.to_string())
+        }));
+        assert_eq!(data.check("42"), Ok(()));
+        assert_eq!(data.check("4x2"), Err(String::from("invalid digit found in string")));
+    }
+}
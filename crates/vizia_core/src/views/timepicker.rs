@@ -0,0 +1,273 @@
+use chrono::{Duration, NaiveTime, Timelike};
+
+use crate::fonts::material_names::CLOCK;
+use crate::prelude::*;
+
+/// The times which can be picked with a [`TimePicker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeConstraints {
+    min: NaiveTime,
+    max: NaiveTime,
+    // The interval between the times offered by the picker
+    step: Duration,
+}
+
+impl Default for TimeConstraints {
+    fn default() -> Self {
+        Self {
+            min: NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+            max: NaiveTime::from_hms_opt(23, 59, 59).unwrap(),
+            step: Duration::minutes(30),
+        }
+    }
+}
+
+impl Data for TimeConstraints {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl TimeConstraints {
+    // Returns the time if it can be picked, or why it can't.
+    fn check(&self, time: NaiveTime) -> Result<NaiveTime, String> {
+        if time < self.min {
+            Err(format!("{} is before {}", time, self.min))
+        } else if time > self.max {
+            Err(format!("{} is after {}", time, self.max))
+        } else {
+            Ok(time)
+        }
+    }
+
+    // Returns the times offered by the picker, from the minimum time to the maximum time.
+    fn times(&self) -> Vec<NaiveTime> {
+        if self.step <= Duration::zero() {
+            return vec![self.min];
+        }
+
+        let mut times = Vec::new();
+        let mut time = self.min;
+        while time <= self.max {
+            times.push(time);
+            let (next, wrapped) = time.overflowing_add_signed(self.step);
+            if wrapped != 0 {
+                break;
+            }
+
+            time = next;
+        }
+
+        times
+    }
+}
+
+// Parses a time typed in the given format, which must be a time that can be picked. Seconds may
+// be left out of formats showing them.
+fn parse_time(
+    text: &str,
+    format: &str,
+    constraints: &TimeConstraints,
+) -> Result<NaiveTime, String> {
+    let text = text.trim();
+    NaiveTime::parse_from_str(text, format)
+        .or_else(|error| {
+            NaiveTime::parse_from_str(text, &format.replace(":%S", "")).map_err(|_| error)
+        })
+        .map_err(|error| error.to_string())
+        .and_then(|time| constraints.check(time))
+}
+
+enum TimePickerEventInternal {
+    Select(NaiveTime),
+}
+
+/// A textbox for typing a time, with a button opening a list of times to pick from.
+///
+/// Typed times are parsed with the format of the picker, which is `%H:%M` by default, and must be
+/// between the minimum and maximum times. The list offers the times from the minimum time at
+/// intervals of the step, which is thirty minutes by default. The textbox has the `:invalid`
+/// pseudo-class while its text isn't a valid time.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # use vizia_core::chrono::{Duration, NaiveTime};
+/// # use vizia_derive::*;
+/// # let cx = &mut Context::default();
+/// # #[derive(Lens)]
+/// # pub struct AppData {
+/// #     time: NaiveTime,
+/// # }
+/// # impl Model for AppData {}
+/// # AppData { time: NaiveTime::from_hms_opt(9, 0, 0).unwrap() }.build(cx);
+/// TimePicker::new(cx, AppData::time)
+///     .min_time(NaiveTime::from_hms_opt(8, 0, 0).unwrap())
+///     .max_time(NaiveTime::from_hms_opt(18, 0, 0).unwrap())
+///     .step(Duration::minutes(15))
+///     .on_select(|_, time| println!("Picked {}", time));
+/// ```
+#[derive(Lens)]
+pub struct TimePicker<L: Lens> {
+    lens: L,
+    format: String,
+    constraints: TimeConstraints,
+    is_open: bool,
+    on_select: Option<Box<dyn Fn(&mut EventContext, NaiveTime)>>,
+}
+
+impl<L> TimePicker<L>
+where
+    L: Lens<Target = NaiveTime>,
+{
+    /// Creates a new time picker bound to the time targeted by the lens.
+    pub fn new(cx: &mut Context, lens: L) -> Handle<Self> {
+        Self {
+            lens: lens.clone(),
+            format: String::from("%H:%M"),
+            constraints: TimeConstraints::default(),
+            is_open: false,
+            on_select: None,
+        }
+        .build(cx, |cx| {
+            let picker = cx.current();
+
+            let text_lens = lens.clone();
+            Binding::new(cx, TimePicker::<L>::format, move |cx, format| {
+                let format = format.get(cx);
+                let lens = text_lens.clone();
+                Binding::new(cx, TimePicker::<L>::constraints, move |cx, constraints| {
+                    let constraints = constraints.get(cx);
+                    let (display, parse, submit) = (format.clone(), format.clone(), format.clone());
+                    Textbox::new(
+                        cx,
+                        lens.clone().map(move |time| time.format(&display).to_string()),
+                    )
+                    .validate(move |text| parse_time(text, &parse, &constraints))
+                    .on_submit(move |cx, text, _| {
+                        if let Ok(time) = parse_time(&text, &submit, &constraints) {
+                            cx.emit(TimePickerEventInternal::Select(time));
+                        }
+                    })
+                    .width(Stretch(1.0));
+                });
+            });
+
+            Button::new(
+                cx,
+                |cx| cx.emit(PopupEvent::Switch),
+                |cx| Label::new(cx, CLOCK).class("material-icon"),
+            )
+            .class("open");
+
+            Popup::new(cx, TimePicker::<L>::is_open, true, move |cx| {
+                let format = TimePicker::<L>::format.get(cx);
+                let constraints = TimePicker::<L>::constraints.get(cx);
+                let selected = lens.get(cx);
+                ScrollView::new(cx, 0.0, 0.0, false, true, move |cx| {
+                    for time in constraints.times() {
+                        Label::new(cx, &time.format(&format).to_string())
+                            .class("time")
+                            .checked(time == selected)
+                            .on_press(move |cx| cx.emit(TimePickerEventInternal::Select(time)));
+                    }
+                });
+            })
+            .on_blur(|cx| cx.emit(PopupEvent::Close))
+            .anchor(Anchor::new(picker, AnchorSide::Bottom).offset(2.0))
+            .width(Stretch(1.0));
+        })
+    }
+}
+
+impl<L: Lens> View for TimePicker<L> {
+    fn element(&self) -> Option<&'static str> {
+        Some("timepicker")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|time_picker_event, meta| match time_picker_event {
+            TimePickerEventInternal::Select(time) => {
+                self.is_open = false;
+                if let Some(callback) = &self.on_select {
+                    (callback)(cx, *time);
+                }
+
+                meta.consume();
+            }
+        });
+
+        event.map(|popup_event, meta| {
+            match popup_event {
+                PopupEvent::Open => self.is_open = true,
+                PopupEvent::Close => self.is_open = false,
+                PopupEvent::Switch => self.is_open ^= true,
+            }
+
+            meta.consume();
+        });
+    }
+}
+
+impl<L: Lens> Handle<'_, TimePicker<L>> {
+    /// Sets the [`chrono::format::strftime`] format in which times are shown and typed, such as
+    /// `%H:%M:%S` to include seconds or `%I:%M %p` for a twelve hour clock.
+    pub fn format(self, format: impl Into<String>) -> Self {
+        let format = format.into();
+        self.modify(|time_picker| time_picker.format = format)
+    }
+
+    /// Sets the earliest time which can be picked.
+    pub fn min_time(self, time: NaiveTime) -> Self {
+        self.modify(|time_picker| time_picker.constraints.min = time)
+    }
+
+    /// Sets the latest time which can be picked.
+    pub fn max_time(self, time: NaiveTime) -> Self {
+        self.modify(|time_picker| time_picker.constraints.max = time)
+    }
+
+    /// Sets the interval between the times offered by the list of the picker.
+    pub fn step(self, step: Duration) -> Self {
+        self.modify(|time_picker| time_picker.constraints.step = step)
+    }
+
+    /// Sets the callback triggered when a time is typed or picked from the list.
+    pub fn on_select<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, NaiveTime),
+    {
+        self.modify(|time_picker| time_picker.on_select = Some(Box::new(callback)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn offers_times_between_bounds() {
+        let constraints =
+            TimeConstraints { min: time(9, 0), max: time(10, 0), step: Duration::minutes(20) };
+        assert_eq!(constraints.times(), vec![time(9, 0), time(9, 20), time(9, 40), time(10, 0)]);
+
+        // The times stop at midnight rather than wrapping
+        let times = TimeConstraints::default().times();
+        assert_eq!(times.len(), 48);
+        assert_eq!(times.last().map(|time| time.hour()), Some(23));
+    }
+
+    #[test]
+    fn parses_allowed_times() {
+        let constraints = TimeConstraints { min: time(8, 0), ..Default::default() };
+        assert_eq!(parse_time("09:30", "%H:%M", &constraints), Ok(time(9, 30)));
+        assert_eq!(parse_time("09:30", "%H:%M:%S", &constraints), Ok(time(9, 30)));
+        assert_eq!(parse_time("9:30 PM", "%I:%M %p", &constraints), Ok(time(21, 30)));
+        assert!(parse_time("07:30", "%H:%M", &constraints).is_err());
+        assert!(parse_time("9.30", "%H:%M", &constraints).is_err());
+    }
+}
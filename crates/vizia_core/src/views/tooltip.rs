@@ -0,0 +1,77 @@
+use morphorm::PositionType;
+
+use crate::prelude::*;
+
+/// Where a tooltip is placed when it is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TooltipPlacement {
+    /// Next to the view with the tooltip, centered on the given side of it.
+    Anchored(AnchorSide),
+    /// Below and to the right of the cursor.
+    Cursor,
+}
+
+#[derive(Lens)]
+struct TooltipData {
+    open: bool,
+}
+
+pub(crate) enum TooltipEvent {
+    Show,
+    Hide,
+}
+
+impl Model for TooltipData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|tooltip_event, meta| {
+            match tooltip_event {
+                TooltipEvent::Show => self.open = true,
+                TooltipEvent::Hide => self.open = false,
+            }
+
+            meta.consume();
+        });
+    }
+}
+
+/// A small popup describing a view, shown while the view is hovered or focused with the keyboard.
+///
+/// Tooltips are added to a view with the [`tooltip`](crate::prelude::ActionModifiers::tooltip) and
+/// [`tooltip_text`](crate::prelude::ActionModifiers::tooltip_text) modifiers. The content of a
+/// tooltip is only built while it is shown, and can be styled with the `tooltip` element selector.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// Button::new(cx, |_| {}, |cx| Label::new(cx, "Save")).tooltip_text("Save the document");
+/// ```
+pub struct Tooltip {}
+
+impl Tooltip {
+    pub(crate) fn new<F>(cx: &mut Context, content: F) -> Handle<Self>
+    where
+        F: 'static + Fn(&mut Context),
+    {
+        Self {}
+            .build(cx, |cx| {
+                TooltipData { open: false }.build(cx);
+
+                Binding::new(cx, TooltipData::open, move |cx, open| {
+                    if open.get(cx) {
+                        (content)(cx);
+                    }
+                });
+            })
+            .checked(TooltipData::open)
+            .position_type(PositionType::SelfDirected)
+            .overlay(true)
+            .hoverable(false)
+    }
+}
+
+impl View for Tooltip {
+    fn element(&self) -> Option<&'static str> {
+        Some("tooltip")
+    }
+}
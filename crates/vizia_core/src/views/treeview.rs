@@ -0,0 +1,936 @@
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use morphorm::PositionType;
+
+use crate::fonts::icons_names::{DOWN, RIGHT};
+use crate::prelude::*;
+use crate::state::Then;
+use crate::views::scrollview::DRAG_THRESHOLD;
+
+/// The height of the rows of a tree view, in logical pixels.
+const ROW_HEIGHT: f32 = 24.0;
+/// The indentation of each level of the tree, in logical pixels.
+const INDENT: f32 = 16.0;
+/// The number of rows built either side of those in view.
+const OVERSCAN: usize = 2;
+/// The fraction of a row at its top and bottom in which dragged nodes are dropped beside the row.
+const DROP_EDGE_FRACTION: f32 = 0.25;
+
+/// Data which forms a tree, so that it can be shown by [`TreeView::new`].
+pub trait TreeNode: 'static + Sized {
+    /// Returns the children of the node.
+    fn children(&self) -> &[Self];
+}
+
+/// A lens to a node of a tree, from the roots of the tree, by the path of indices leading to it.
+pub struct TreeNodeLens<T> {
+    path: Vec<usize>,
+    children: Rc<dyn Fn(&T) -> &[T]>,
+}
+
+impl<T> Clone for TreeNodeLens<T> {
+    fn clone(&self) -> Self {
+        Self { path: self.path.clone(), children: self.children.clone() }
+    }
+}
+
+impl<T: 'static> Lens for TreeNodeLens<T> {
+    type Source = Vec<T>;
+    type Target = T;
+
+    fn view<O, F: FnOnce(Option<&Self::Target>) -> O>(&self, source: &Self::Source, map: F) -> O {
+        map(node_at(source, &self.path, &*self.children))
+    }
+}
+
+/// How the rows of a [`TreeView`] are selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionMode {
+    /// A single row is selected at a time.
+    Single,
+    /// Rows are added to and removed from the selection with `Ctrl`, and ranges of rows are
+    /// selected with `Shift`.
+    Multiple,
+}
+
+/// Where nodes dragged within a [`TreeView`] are dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeDrop {
+    /// The path of the node which the dropped nodes become children of, which is empty for the
+    /// roots of the tree.
+    pub parent: Vec<usize>,
+    /// The index among the children of the parent at which the dropped nodes are inserted,
+    /// counted before the dropped nodes are removed from the tree.
+    pub index: usize,
+}
+
+/// The expanded and selected nodes of a [`TreeView`], by path.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TreeState {
+    expanded: HashSet<Vec<usize>>,
+    selected: Vec<Vec<usize>>,
+    // The node moved by the keyboard, which is also the end of a range selection
+    cursor: Option<Vec<usize>>,
+    // The start of a range selection
+    anchor: Option<Vec<usize>>,
+}
+
+impl Data for TreeState {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl TreeState {
+    fn select(&mut self, path: &[usize]) {
+        self.selected = vec![path.to_vec()];
+        self.cursor = Some(path.to_vec());
+        self.anchor = Some(path.to_vec());
+    }
+
+    fn toggle_selected(&mut self, path: &[usize]) {
+        match self.selected.iter().position(|selected| selected == path) {
+            Some(index) => {
+                self.selected.remove(index);
+            }
+
+            None => self.selected.push(path.to_vec()),
+        }
+
+        self.cursor = Some(path.to_vec());
+        self.anchor = Some(path.to_vec());
+    }
+
+    // Selects the rows from the anchor to the given path, keeping the anchor.
+    fn select_range(&mut self, rows: &[TreeRow], path: &[usize]) {
+        let anchor = self.anchor.clone().unwrap_or_else(|| path.to_vec());
+        let position = |path: &[usize]| rows.iter().position(|row| row.path == path);
+        if let (Some(start), Some(end)) = (position(&anchor), position(path)) {
+            let (start, end) = (start.min(end), start.max(end));
+            self.selected = rows[start..=end].iter().map(|row| row.path.clone()).collect();
+        }
+
+        self.cursor = Some(path.to_vec());
+        self.anchor = Some(anchor);
+    }
+
+    // Returns the selected nodes in tree order, without the nodes within other selected nodes.
+    fn selected_roots(&self) -> Vec<Vec<usize>> {
+        let mut selected = self.selected.clone();
+        selected.sort();
+        let mut roots: Vec<Vec<usize>> = Vec::new();
+        for path in selected {
+            if !roots.iter().any(|root| path.starts_with(root)) {
+                roots.push(path);
+            }
+        }
+
+        roots
+    }
+}
+
+// A visible node of a tree, in the order the nodes are shown.
+#[derive(Debug, Clone, PartialEq)]
+struct TreeRow {
+    path: Vec<usize>,
+    expandable: bool,
+    expanded: bool,
+}
+
+/// The visible rows of a [`TreeView`], which are shared with the bindings building them.
+#[derive(Debug, Clone, Default)]
+pub struct TreeRows(Rc<Vec<TreeRow>>);
+
+impl Data for TreeRows {
+    fn same(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+// Returns the node at the given path of a tree.
+fn node_at<'a, T>(roots: &'a [T], path: &[usize], children: &dyn Fn(&T) -> &[T]) -> Option<&'a T> {
+    let (first, rest) = path.split_first()?;
+    let mut node = roots.get(*first)?;
+    for index in rest {
+        node = children(node).get(*index)?;
+    }
+
+    Some(node)
+}
+
+// Returns the visible nodes of a tree, which are the roots and the children of expanded nodes.
+// The children of collapsed nodes aren't visited.
+fn flatten<T>(
+    roots: &[T],
+    children: &dyn Fn(&T) -> &[T],
+    expanded: &HashSet<Vec<usize>>,
+) -> Vec<TreeRow> {
+    fn visit<T>(
+        nodes: &[T],
+        children: &dyn Fn(&T) -> &[T],
+        expanded: &HashSet<Vec<usize>>,
+        path: &mut Vec<usize>,
+        rows: &mut Vec<TreeRow>,
+    ) {
+        for (index, node) in nodes.iter().enumerate() {
+            path.push(index);
+            let nodes = children(node);
+            let is_expanded = !nodes.is_empty() && expanded.contains(path);
+            rows.push(TreeRow {
+                path: path.clone(),
+                expandable: !nodes.is_empty(),
+                expanded: is_expanded,
+            });
+
+            if is_expanded {
+                visit(nodes, children, expanded, path, rows);
+            }
+
+            path.pop();
+        }
+    }
+
+    let mut rows = Vec::new();
+    visit(roots, children, expanded, &mut Vec::new(), &mut rows);
+    rows
+}
+
+// Returns the range of rows to build for the given scroll offset and height of the view, and
+// height of the rows, in physical pixels.
+fn visible_rows(offset: f32, height: f32, row_height: f32) -> (usize, usize) {
+    if row_height <= 0.0 {
+        return (0, 0);
+    }
+
+    let start = (offset / row_height).floor().max(0.0) as usize;
+    let end = ((offset + height) / row_height).ceil().max(0.0) as usize;
+    (start.saturating_sub(OVERSCAN), end + OVERSCAN)
+}
+
+// Where the drop indicator is shown, in rows from the top of the tree.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DropIndicator {
+    // A line at the top of a row, indented to the given depth.
+    Line { row: usize, depth: usize },
+    // The whole of a row.
+    Row(usize),
+}
+
+// Returns where nodes are dropped with the pointer at the given position, in rows from the top of
+// the tree, along with where the drop indicator is shown. Dropping near the top or bottom of a
+// row drops beside it, or as the first child of an expanded row, and dropping in the middle of a
+// row drops as its last child. Nodes can't be dropped within themselves.
+fn drop_target(
+    rows: &[TreeRow],
+    position: f32,
+    roots: usize,
+    child_count: &dyn Fn(&[usize]) -> usize,
+    dragged: &[Vec<usize>],
+) -> Option<(TreeDrop, DropIndicator)> {
+    let (target, indicator) = if position < 0.0 {
+        (TreeDrop { parent: Vec::new(), index: 0 }, DropIndicator::Line { row: 0, depth: 0 })
+    } else if position as usize >= rows.len() {
+        (
+            TreeDrop { parent: Vec::new(), index: roots },
+            DropIndicator::Line { row: rows.len(), depth: 0 },
+        )
+    } else {
+        let index = position as usize;
+        let row = &rows[index];
+        let (last, parent) = row.path.split_last()?;
+        let fraction = position.fract();
+        if fraction < DROP_EDGE_FRACTION {
+            (
+                TreeDrop { parent: parent.to_vec(), index: *last },
+                DropIndicator::Line { row: index, depth: parent.len() },
+            )
+        } else if fraction > 1.0 - DROP_EDGE_FRACTION && row.expanded {
+            (
+                TreeDrop { parent: row.path.clone(), index: 0 },
+                DropIndicator::Line { row: index + 1, depth: row.path.len() },
+            )
+        } else if fraction > 1.0 - DROP_EDGE_FRACTION {
+            (
+                TreeDrop { parent: parent.to_vec(), index: last + 1 },
+                DropIndicator::Line { row: index + 1, depth: parent.len() },
+            )
+        } else {
+            (
+                TreeDrop { parent: row.path.clone(), index: child_count(&row.path) },
+                DropIndicator::Row(index),
+            )
+        }
+    };
+
+    if dragged.iter().any(|node| target.parent.starts_with(node)) {
+        None
+    } else {
+        Some((target, indicator))
+    }
+}
+
+enum TreeEventInternal {
+    // Flattens the tree again after its data has changed
+    Flatten,
+    Press(Vec<usize>),
+    Toggle(Vec<usize>),
+}
+
+// A press on a row, which drags the selected nodes once the pointer has moved far enough.
+struct TreeDrag {
+    // The nodes which are dragged.
+    nodes: Vec<Vec<usize>>,
+    // The node to select if the press doesn't become a drag, when pressing a selected node.
+    pending: Option<Vec<usize>>,
+    dragging: bool,
+    target: Option<TreeDrop>,
+}
+
+/// A view which shows hierarchical data as a tree of rows which can be expanded and collapsed.
+///
+/// The tree is read from the bound `Vec` of root nodes, and the children of each node are given
+/// by the [`TreeNode`] trait, or by a closure with [`TreeView::with_children`]. The row closure
+/// builds the content of a row from a lens to its node. Nodes are identified by their paths,
+/// which are the indices leading to them from the roots.
+///
+/// Rows are a fixed height, so that only the rows in view are built, and the children of a node
+/// aren't visited until it's expanded, which keeps large trees responsive. Rows are expanded and
+/// collapsed with their arrows or by double clicking them, and with the keyboard, where the up
+/// and down arrows move between rows, the right arrow expands a row or moves to its first child,
+/// and the left arrow collapses a row or moves to its parent.
+///
+/// Rows are selected according to the [`SelectionMode`], which calls the `on_select` callback.
+/// Dragging the selected rows and dropping them elsewhere in the tree calls the `on_drop`
+/// callback, which can move the nodes within the bound data.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # use vizia_derive::*;
+/// # let cx = &mut Context::default();
+/// #[derive(Clone, Data)]
+/// pub struct Item {
+///     name: String,
+///     children: Vec<Item>,
+/// }
+///
+/// impl TreeNode for Item {
+///     fn children(&self) -> &[Self] {
+///         &self.children
+///     }
+/// }
+/// # #[derive(Lens)]
+/// # pub struct AppData {
+/// #     items: Vec<Item>,
+/// # }
+/// # impl Model for AppData {}
+/// # AppData { items: Vec::new() }.build(cx);
+///
+/// TreeView::new(cx, AppData::items, |cx, item| {
+///     Label::new(cx, item.map(|item| item.name.clone()));
+/// })
+/// .on_select(|_, selected| println!("Selected {:?}", selected));
+/// ```
+#[derive(Lens)]
+pub struct TreeView<L: Lens, T: Data> {
+    lens: L,
+    children: Rc<dyn Fn(&T) -> &[T]>,
+    state: TreeState,
+    // The visible rows, which are flattened again only when the data or the expanded nodes change
+    rows: TreeRows,
+    selection_mode: SelectionMode,
+    // The element setting the height of the scrolled content, at the top of the rows.
+    spacer: Entity,
+    indicator: Entity,
+    #[lens(ignore)]
+    drag: Option<TreeDrag>,
+    on_select: Option<Box<dyn Fn(&mut EventContext, Vec<Vec<usize>>)>>,
+    on_drop: Option<Box<dyn Fn(&mut EventContext, Vec<Vec<usize>>, TreeDrop)>>,
+}
+
+impl<L, T> TreeView<L, T>
+where
+    L: Lens<Target = Vec<T>>,
+    T: TreeNode + Data,
+{
+    /// Creates a new tree view of the root nodes targeted by the lens, with the content of each
+    /// row built by the row closure.
+    pub fn new<F>(cx: &mut Context, lens: L, row: F) -> Handle<Self>
+    where
+        F: 'static + Fn(&mut Context, Then<L, TreeNodeLens<T>>),
+    {
+        Self::with_children(cx, lens, T::children, row)
+    }
+}
+
+impl<L, T> TreeView<L, T>
+where
+    L: Lens<Target = Vec<T>>,
+    T: Data,
+{
+    /// Creates a new tree view of the root nodes targeted by the lens, with the children of each
+    /// node given by the children closure, and with the content of each row built by the row
+    /// closure.
+    pub fn with_children<C, F>(cx: &mut Context, lens: L, children: C, row: F) -> Handle<Self>
+    where
+        C: 'static + Fn(&T) -> &[T],
+        F: 'static + Fn(&mut Context, Then<L, TreeNodeLens<T>>),
+    {
+        let children: Rc<dyn Fn(&T) -> &[T]> = Rc::new(children);
+        let row: Rc<dyn Fn(&mut Context, Then<L, TreeNodeLens<T>>)> = Rc::new(row);
+        let mut spacer = Entity::null();
+        let mut indicator = Entity::null();
+
+        Self {
+            lens: lens.clone(),
+            children: children.clone(),
+            state: TreeState::default(),
+            rows: TreeRows::default(),
+            selection_mode: SelectionMode::Single,
+            spacer: Entity::null(),
+            indicator: Entity::null(),
+            drag: None,
+            on_select: None,
+            on_drop: None,
+        }
+        .build(cx, |cx| {
+            ScrollData::default().build(cx);
+
+            ScrollView::custom(cx, false, true, ScrollData::root, |cx| {
+                spacer = Element::new(cx).class("tree-spacer").hoverable(false).entity();
+
+                Binding::new(cx, lens.clone(), |cx, _| cx.emit(TreeEventInternal::Flatten));
+
+                // The rows are rebuilt when the visible rows change, and when scrolling brings
+                // other rows into view, while the selection and cursor are bound to each row
+                Binding::new(cx, TreeView::<L, T>::rows, move |cx, rows| {
+                    let rows = rows.get(cx).0;
+                    cx.style.height.insert(spacer, Pixels(rows.len() as f32 * ROW_HEIGHT));
+                    cx.style.relayout(spacer);
+
+                    let lens = lens.clone();
+                    let children = children.clone();
+                    let row = row.clone();
+                    let row_height = ROW_HEIGHT * cx.style.dpi_factor as f32;
+                    let range = ScrollData::root.map(move |data| {
+                        let offset = data.scroll_y * (data.child_y - data.parent_y).max(0.0);
+                        visible_rows(offset, data.parent_y, row_height)
+                    });
+
+                    Binding::new(cx, range, move |cx, range| {
+                        let (start, end) = range.get(cx);
+                        let end = end.min(rows.len());
+                        for (index, tree_row) in rows.iter().enumerate().take(end).skip(start) {
+                            let node = lens.clone().then(TreeNodeLens {
+                                path: tree_row.path.clone(),
+                                children: children.clone(),
+                            });
+
+                            let path = tree_row.path.clone();
+                            let selected = TreeView::<L, T>::state
+                                .map(move |state| state.selected.contains(&path));
+                            let path = tree_row.path.clone();
+                            let cursor = TreeView::<L, T>::state
+                                .map(move |state| state.cursor.as_ref() == Some(&path));
+
+                            TreeRowView::new(cx, tree_row, |cx| (row)(cx, node))
+                                .checked(selected)
+                                .toggle_class("cursor", cursor)
+                                .top(Pixels(index as f32 * ROW_HEIGHT))
+                                .height(Pixels(ROW_HEIGHT));
+                        }
+                    });
+                });
+
+                indicator = Element::new(cx)
+                    .class("drop-indicator")
+                    .position_type(PositionType::SelfDirected)
+                    .display(Display::None)
+                    .hoverable(false)
+                    .entity();
+            })
+            .class("tree-scroll");
+        })
+        .navigable(true)
+        .modify(|tree_view| {
+            tree_view.spacer = spacer;
+            tree_view.indicator = indicator;
+        })
+    }
+}
+
+impl<L, T> TreeView<L, T>
+where
+    L: Lens<Target = Vec<T>>,
+    T: Data,
+{
+    // Returns the visible rows of the tree.
+    fn rows(&self) -> Rc<Vec<TreeRow>> {
+        self.rows.0.clone()
+    }
+
+    // Flattens the tree into its visible rows, which are empty if the data can't be found.
+    fn flatten(&mut self, cx: &EventContext) {
+        let children = &*self.children;
+        let expanded = &self.state.expanded;
+        let rows = cx.data::<L::Source>().map_or_else(Vec::new, |source| {
+            self.lens.view(source, |roots| {
+                roots.map_or_else(Vec::new, |roots| flatten(roots, children, expanded))
+            })
+        });
+
+        self.rows = TreeRows(Rc::new(rows));
+    }
+
+    fn set_expanded(&mut self, cx: &EventContext, path: &[usize], expanded: bool) {
+        if expanded {
+            self.state.expanded.insert(path.to_vec());
+        } else {
+            self.state.expanded.remove(path);
+        }
+
+        self.flatten(cx);
+    }
+
+    // Calls the selection callback if the selection has changed from the given selection.
+    fn selection_changed(&self, cx: &mut EventContext, previous: &[Vec<usize>]) {
+        if self.state.selected != previous {
+            if let Some(callback) = &self.on_select {
+                (callback)(cx, self.state.selected.clone());
+            }
+        }
+    }
+
+    fn press(&mut self, cx: &mut EventContext, path: &[usize]) {
+        cx.focus();
+        let previous = self.state.selected.clone();
+        let multiple = self.selection_mode == SelectionMode::Multiple;
+        let selected = self.state.selected.iter().any(|selected| selected == path);
+
+        let mut pending = None;
+        if multiple && cx.modifiers.contains(Modifiers::CTRL) {
+            self.state.toggle_selected(path);
+        } else if multiple && cx.modifiers.contains(Modifiers::SHIFT) {
+            let rows = self.rows();
+            self.state.select_range(&rows, path);
+        } else if selected {
+            // The selection is kept until release, so that the selected nodes can be dragged
+            pending = Some(path.to_vec());
+        } else {
+            self.state.select(path);
+        }
+
+        self.selection_changed(cx, &previous);
+
+        let nodes = if self.state.selected.iter().any(|selected| selected == path) {
+            self.state.selected_roots()
+        } else {
+            vec![path.to_vec()]
+        };
+        self.drag = Some(TreeDrag { nodes, pending, dragging: false, target: None });
+        cx.capture();
+    }
+
+    // Moves the cursor to the row at the given index, selecting it, and scrolls it into view.
+    fn move_cursor(&mut self, cx: &mut EventContext, rows: &[TreeRow], index: usize) {
+        let path = match rows.get(index) {
+            Some(row) => row.path.clone(),
+            None => return,
+        };
+
+        let previous = self.state.selected.clone();
+        if self.selection_mode == SelectionMode::Multiple && cx.modifiers.contains(Modifiers::SHIFT)
+        {
+            self.state.select_range(rows, &path);
+        } else {
+            self.state.select(&path);
+        }
+
+        self.selection_changed(cx, &previous);
+        self.scroll_to_row(cx, index);
+    }
+
+    // Scrolls the view the least distance needed to show the row at the given index.
+    fn scroll_to_row(&self, cx: &mut EventContext, index: usize) {
+        let data = ScrollData::root.get(cx);
+        let max = data.child_y - data.parent_y;
+        if max <= 0.0 {
+            return;
+        }
+
+        let row_height = ROW_HEIGHT * cx.style.dpi_factor as f32;
+        let offset = data.scroll_y * max;
+        let top = index as f32 * row_height;
+        let offset = if top < offset {
+            top
+        } else if top + row_height > offset + data.parent_y {
+            top + row_height - data.parent_y
+        } else {
+            return;
+        };
+
+        cx.emit(ScrollEvent::SetY((offset / max).clamp(0.0, 1.0)));
+    }
+
+    fn show_indicator(&self, cx: &mut EventContext, indicator: Option<DropIndicator>) {
+        let entity = self.indicator;
+        match indicator {
+            Some(DropIndicator::Line { row, depth }) => {
+                cx.style.top.insert(entity, Pixels(row as f32 * ROW_HEIGHT - 1.0));
+                cx.style.left.insert(entity, Pixels(depth as f32 * INDENT));
+                cx.style.height.insert(entity, Pixels(2.0));
+                cx.style.display.insert(entity, Display::Flex);
+            }
+
+            Some(DropIndicator::Row(row)) => {
+                cx.style.top.insert(entity, Pixels(row as f32 * ROW_HEIGHT));
+                cx.style.left.insert(entity, Pixels(0.0));
+                cx.style.height.insert(entity, Pixels(ROW_HEIGHT));
+                cx.style.display.insert(entity, Display::Flex);
+            }
+
+            None => {
+                cx.style.display.insert(entity, Display::None);
+            }
+        }
+
+        cx.needs_relayout();
+        cx.needs_redraw();
+    }
+}
+
+impl<L, T> View for TreeView<L, T>
+where
+    L: Lens<Target = Vec<T>>,
+    T: Data,
+{
+    fn element(&self) -> Option<&'static str> {
+        Some("treeview")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|tree_event, meta| {
+            match tree_event {
+                TreeEventInternal::Flatten => self.flatten(cx),
+                TreeEventInternal::Press(path) => self.press(cx, path),
+                TreeEventInternal::Toggle(path) => {
+                    let expanded = self.state.expanded.contains(path);
+                    self.set_expanded(cx, path, !expanded);
+                }
+            }
+
+            meta.consume();
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseMove(x, y) => {
+                let mut drag = match self.drag.take() {
+                    Some(drag) => drag,
+                    None => return,
+                };
+
+                if !drag.dragging {
+                    let (down_x, down_y) = cx.mouse.left.pos_down;
+                    if (x - down_x).hypot(y - down_y) < DRAG_THRESHOLD {
+                        self.drag = Some(drag);
+                        return;
+                    }
+
+                    drag.dragging = true;
+                }
+
+                let rows = self.rows();
+                let top = cx.cache.get_bounds(self.spacer).y;
+                let position = (y - top) / (ROW_HEIGHT * cx.style.dpi_factor as f32);
+                let children = &*self.children;
+                let target = cx.data::<L::Source>().and_then(|source| {
+                    self.lens.view(source, |roots| {
+                        let roots = roots.map_or(&[][..], |roots| &roots[..]);
+                        let child_count = |path: &[usize]| {
+                            node_at(roots, path, children).map_or(0, |node| children(node).len())
+                        };
+                        drop_target(&rows, position, roots.len(), &child_count, &drag.nodes)
+                    })
+                });
+
+                self.show_indicator(cx, target.as_ref().map(|(_, indicator)| *indicator));
+                drag.target = target.map(|(target, _)| target);
+                self.drag = Some(drag);
+                meta.consume();
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if let Some(drag) = self.drag.take() {
+                    cx.release();
+                    self.show_indicator(cx, None);
+
+                    if drag.dragging {
+                        if let (Some(target), Some(callback)) = (drag.target, &self.on_drop) {
+                            (callback)(cx, drag.nodes, target);
+                        }
+                    } else if let Some(path) = drag.pending {
+                        let previous = self.state.selected.clone();
+                        self.state.select(&path);
+                        self.selection_changed(cx, &previous);
+                    }
+                }
+            }
+
+            WindowEvent::KeyDown(code, _) if meta.target == cx.current() => {
+                let rows = self.rows();
+                if rows.is_empty() {
+                    return;
+                }
+
+                let cursor = self
+                    .state
+                    .cursor
+                    .as_ref()
+                    .and_then(|cursor| rows.iter().position(|row| &row.path == cursor));
+                let last = rows.len() - 1;
+
+                match (code, cursor) {
+                    (Code::ArrowDown, Some(index)) => {
+                        self.move_cursor(cx, &rows, (index + 1).min(last))
+                    }
+                    (Code::ArrowUp, Some(index)) => {
+                        self.move_cursor(cx, &rows, index.saturating_sub(1))
+                    }
+                    (Code::ArrowDown, None) | (Code::ArrowUp, None) | (Code::Home, _) => {
+                        self.move_cursor(cx, &rows, 0)
+                    }
+                    (Code::End, _) => self.move_cursor(cx, &rows, last),
+
+                    (Code::ArrowRight, Some(index)) => {
+                        let row = &rows[index];
+                        if row.expanded {
+                            self.move_cursor(cx, &rows, index + 1);
+                        } else if row.expandable {
+                            self.set_expanded(cx, &row.path, true);
+                        }
+                    }
+
+                    (Code::ArrowLeft, Some(index)) => {
+                        let row = &rows[index];
+                        if row.expanded {
+                            self.set_expanded(cx, &row.path, false);
+                        } else {
+                            let parent = &row.path[..row.path.len() - 1];
+                            if let Some(parent) = rows.iter().position(|row| row.path == parent) {
+                                self.move_cursor(cx, &rows, parent);
+                            }
+                        }
+                    }
+
+                    (Code::Enter, Some(index)) if rows[index].expandable => {
+                        self.set_expanded(cx, &rows[index].path, !rows[index].expanded);
+                    }
+
+                    _ => return,
+                }
+
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
+}
+
+impl<L: Lens, T: Data> Handle<'_, TreeView<L, T>> {
+    /// Sets how the rows of the tree are selected.
+    pub fn selection_mode(self, selection_mode: SelectionMode) -> Self {
+        self.modify(|tree_view| tree_view.selection_mode = selection_mode)
+    }
+
+    /// Sets the callback triggered when the selection changes, which is given the paths of the
+    /// selected nodes.
+    pub fn on_select<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, Vec<Vec<usize>>),
+    {
+        self.modify(|tree_view| tree_view.on_select = Some(Box::new(callback)))
+    }
+
+    /// Sets the callback triggered when nodes are dragged and dropped within the tree.
+    ///
+    /// The callback is given the paths of the dragged nodes in tree order, without any nodes
+    /// within other dragged nodes, and where they were dropped. The expanded and selected rows
+    /// are kept by path, so they don't follow nodes moved by the callback.
+    pub fn on_drop<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, Vec<Vec<usize>>, TreeDrop),
+    {
+        self.modify(|tree_view| tree_view.on_drop = Some(Box::new(callback)))
+    }
+}
+
+// A row of a tree view, with indentation guides and an arrow to expand or collapse the row.
+struct TreeRowView {
+    path: Vec<usize>,
+    expandable: bool,
+    arrow: Entity,
+}
+
+impl TreeRowView {
+    fn new<'a>(
+        cx: &'a mut Context,
+        row: &TreeRow,
+        content: impl FnOnce(&mut Context),
+    ) -> Handle<'a, Self> {
+        let mut arrow = Entity::null();
+        Self { path: row.path.clone(), expandable: row.expandable, arrow: Entity::null() }
+            .build(cx, |cx| {
+                for _ in 1..row.path.len() {
+                    Element::new(cx)
+                        .class("guide")
+                        .hoverable(false)
+                        .width(Pixels(1.0))
+                        .left(Pixels(INDENT / 2.0))
+                        .right(Pixels(INDENT / 2.0 - 1.0));
+                }
+
+                arrow = Label::new(cx, if row.expanded { DOWN } else { RIGHT })
+                    .class("arrow")
+                    .width(Pixels(INDENT))
+                    .visibility(if row.expandable {
+                        Visibility::Visible
+                    } else {
+                        Visibility::Invisible
+                    })
+                    .entity();
+
+                HStack::new(cx, content).class("tree-row-content");
+            })
+            .position_type(PositionType::SelfDirected)
+            .modify(|tree_row| tree_row.arrow = arrow)
+    }
+}
+
+impl View for TreeRowView {
+    fn element(&self) -> Option<&'static str> {
+        Some("treerow")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                if meta.target != self.arrow {
+                    cx.emit(TreeEventInternal::Press(self.path.clone()));
+                } else if self.expandable {
+                    cx.emit(TreeEventInternal::Toggle(self.path.clone()));
+                }
+
+                // Keeps the scroll view from dragging its content
+                meta.consume();
+            }
+
+            WindowEvent::MouseDoubleClick(MouseButton::Left) if meta.target != self.arrow => {
+                if self.expandable {
+                    cx.emit(TreeEventInternal::Toggle(self.path.clone()));
+                }
+
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Node(Vec<Node>);
+
+    fn children(node: &Node) -> &[Node] {
+        &node.0
+    }
+
+    fn tree() -> Vec<Node> {
+        vec![Node(vec![Node(vec![]), Node(vec![Node(vec![])])]), Node(vec![])]
+    }
+
+    fn paths(rows: &[TreeRow]) -> Vec<Vec<usize>> {
+        rows.iter().map(|row| row.path.clone()).collect()
+    }
+
+    #[test]
+    fn flattens_expanded_nodes() {
+        let roots = tree();
+        let mut expanded = HashSet::new();
+        assert_eq!(paths(&flatten(&roots, &children, &expanded)), vec![vec![0], vec![1]]);
+
+        // Expanded nodes within collapsed nodes stay hidden
+        expanded.insert(vec![0, 1]);
+        assert_eq!(paths(&flatten(&roots, &children, &expanded)), vec![vec![0], vec![1]]);
+
+        expanded.insert(vec![0]);
+        let rows = flatten(&roots, &children, &expanded);
+        assert_eq!(paths(&rows), vec![vec![0], vec![0, 0], vec![0, 1], vec![0, 1, 0], vec![1]]);
+        assert!(rows[0].expandable && rows[0].expanded);
+        assert!(!rows[1].expandable && !rows[4].expandable);
+    }
+
+    #[test]
+    fn finds_nodes_by_path() {
+        let roots = tree();
+        assert!(node_at(&roots, &[0, 1, 0], &children).is_some());
+        assert!(node_at(&roots, &[0, 2], &children).is_none());
+        assert!(node_at(&roots, &[], &children).is_none());
+    }
+
+    #[test]
+    fn builds_rows_in_view() {
+        assert_eq!(visible_rows(0.0, 100.0, 20.0), (0, 5 + OVERSCAN));
+        assert_eq!(visible_rows(210.0, 100.0, 20.0), (10 - OVERSCAN, 16 + OVERSCAN));
+    }
+
+    #[test]
+    fn selects_ranges_and_roots() {
+        let mut expanded = HashSet::new();
+        expanded.insert(vec![0]);
+        let rows = flatten(&tree(), &children, &expanded);
+
+        let mut state = TreeState::default();
+        state.select(&[0, 1]);
+        state.select_range(&rows, &[1]);
+        assert_eq!(state.selected, vec![vec![0, 1], vec![1]]);
+        state.select_range(&rows, &[0]);
+        assert_eq!(state.selected, vec![vec![0], vec![0, 0], vec![0, 1]]);
+        assert_eq!(state.selected_roots(), vec![vec![0]]);
+
+        state.toggle_selected(&[0]);
+        state.toggle_selected(&[1]);
+        assert_eq!(state.selected_roots(), vec![vec![0, 0], vec![0, 1], vec![1]]);
+    }
+
+    #[test]
+    fn drops_beside_and_within_rows() {
+        let mut expanded = HashSet::new();
+        expanded.insert(vec![0]);
+        let roots = tree();
+        let rows = flatten(&roots, &children, &expanded);
+        let child_count = |path: &[usize]| {
+            node_at(&roots, path, &children).map_or(0, |node| children(node).len())
+        };
+        let target = |position: f32, dragged: &[Vec<usize>]| {
+            drop_target(&rows, position, roots.len(), &child_count, dragged)
+                .map(|(target, _)| (target.parent, target.index))
+        };
+
+        let dragged = [vec![1]];
+        assert_eq!(target(2.1, &dragged), Some((vec![0], 1)));
+        assert_eq!(target(2.5, &dragged), Some((vec![0, 1], 1)));
+        assert_eq!(target(1.9, &dragged), Some((vec![0], 1)));
+        assert_eq!(target(0.9, &dragged), Some((vec![0], 0)));
+        assert_eq!(target(10.0, &dragged), Some((vec![], 2)));
+
+        // Nodes can't be dropped within themselves
+        assert_eq!(target(2.5, &[vec![0]]), None);
+        assert_eq!(target(0.5, &[vec![0]]), None);
+        assert_eq!(target(0.1, &[vec![0]]), Some((vec![], 0)));
+    }
+}
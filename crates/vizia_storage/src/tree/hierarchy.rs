@@ -36,3 +36,165 @@ where
         self.get_layout_parent(node)
     }
 }
+
+/// A part of a [`Tree`], made up of a root node and its descendants, which can be laid out without
+/// laying out the rest of the tree.
+///
+/// The root node keeps its current position and size, which are assumed to be up to date.
+pub struct Subtree<'t, I>
+where
+    I: GenerationalId,
+{
+    tree: &'t Tree<I>,
+    root: I,
+}
+
+impl<'t, I> Subtree<'t, I>
+where
+    I: GenerationalId,
+{
+    pub fn new(tree: &'t Tree<I>, root: I) -> Self {
+        Self { tree, root }
+    }
+
+    pub fn root(&self) -> I {
+        self.root
+    }
+}
+
+impl<'a, 't: 'a, I> Hierarchy<'a> for Subtree<'t, I>
+where
+    I: GenerationalId + for<'w> Node<'w> + 'a,
+{
+    type Item = I;
+    type DownIter = LayoutTreeIterator<'a, I>;
+    type UpIter = Rev<LayoutTreeIterator<'a, I>>;
+    type ChildIter = LayoutChildIterator<'a, I>;
+
+    fn down_iter(&'a self) -> Self::DownIter {
+        LayoutTreeIterator::subtree(self.tree, self.root)
+    }
+
+    fn up_iter(&'a self) -> Self::UpIter {
+        LayoutTreeIterator::subtree(self.tree, self.root).rev()
+    }
+
+    fn child_iter(&'a self, node: Self::Item) -> Self::ChildIter {
+        LayoutChildIterator::new(self.tree, node)
+    }
+
+    fn is_first_child(&self, node: Self::Item) -> bool {
+        self.tree.is_first_child(node)
+    }
+
+    fn is_last_child(&self, node: Self::Item) -> bool {
+        self.tree.is_last_child(node)
+    }
+
+    fn parent(&self, node: Self::Item) -> Option<Self::Item> {
+        if node == self.root {
+            None
+        } else {
+            self.tree.get_layout_parent(node)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use morphorm::{LayoutType, PositionType, Units};
+    use vizia_id::{
+        impl_generational_id, GenerationalId, IdManager, GENERATIONAL_ID_GENERATION_MASK,
+        GENERATIONAL_ID_INDEX_BITS, GENERATIONAL_ID_INDEX_MASK,
+    };
+
+    #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Entity(u32);
+
+    impl_generational_id!(Entity);
+
+    macro_rules! unset {
+        ($($name:ident: $ty:ty),*) => {
+            $(fn $name(&self, _store: &Self::Data) -> Option<$ty> {
+                None
+            })*
+        };
+    }
+
+    impl<'w> Node<'w> for Entity {
+        type Data = ();
+        type Sublayout = ();
+
+        unset!(layout_type: LayoutType, position_type: PositionType);
+        unset!(left: Units, min_left: Units, max_left: Units);
+        unset!(right: Units, min_right: Units, max_right: Units);
+        unset!(top: Units, min_top: Units, max_top: Units);
+        unset!(bottom: Units, min_bottom: Units, max_bottom: Units);
+        unset!(width: Units, min_width: Units, max_width: Units);
+        unset!(height: Units, min_height: Units, max_height: Units);
+        unset!(content_width: f32, content_height: f32);
+        unset!(child_left: Units, child_right: Units, child_top: Units, child_bottom: Units);
+        unset!(grid_cols: Vec<Units>, grid_rows: Vec<Units>);
+        unset!(row_between: Units, col_between: Units);
+        unset!(border_left: Units, border_right: Units, border_top: Units, border_bottom: Units);
+        unset!(row_index: usize, row_span: usize, col_index: usize, col_span: usize);
+
+        fn content_width_secondary(
+            &self,
+            _store: &Self::Data,
+            _sublayout: &mut Self::Sublayout,
+            _height: f32,
+        ) -> Option<f32> {
+            None
+        }
+
+        fn content_height_secondary(
+            &self,
+            _store: &Self::Data,
+            _sublayout: &mut Self::Sublayout,
+            _width: f32,
+        ) -> Option<f32> {
+            None
+        }
+    }
+
+    #[test]
+    fn subtree_hierarchy() {
+        let mut tree = Tree::new();
+        let mut mgr: IdManager<Entity> = IdManager::new();
+
+        let a = mgr.create();
+        let b = mgr.create();
+        let ba = mgr.create();
+        let baa = mgr.create();
+        let bb = mgr.create();
+        let c = mgr.create();
+
+        tree.add(a, Entity::root()).unwrap();
+        tree.add(b, Entity::root()).unwrap();
+        tree.add(ba, b).unwrap();
+        tree.add(baa, ba).unwrap();
+        tree.add(bb, b).unwrap();
+        tree.add(c, Entity::root()).unwrap();
+        tree.set_ignored(ba, true);
+
+        let subtree = Subtree::new(&tree, b);
+        let down: Vec<Entity> = subtree.down_iter().collect();
+        assert_eq!(down, vec![b, baa, bb]);
+        let up: Vec<Entity> = subtree.up_iter().collect();
+        assert_eq!(up, vec![bb, baa, b]);
+        let children: Vec<Entity> = subtree.child_iter(b).collect();
+        assert_eq!(children, vec![baa, bb]);
+
+        // The root of the subtree has no parent within it
+        assert_eq!(subtree.parent(b), None);
+        assert_eq!(subtree.parent(baa), Some(b));
+        assert_eq!(subtree.parent(bb), Some(b));
+
+        let leaf = Subtree::new(&tree, c);
+        let down: Vec<Entity> = leaf.down_iter().collect();
+        assert_eq!(down, vec![c]);
+        assert_eq!(leaf.child_iter(c).count(), 0);
+    }
+}
@@ -14,6 +14,7 @@ mod tree_ext;
 mod tree_tour;
 
 pub use self::{
+    hierarchy::Subtree,
     iter::*,
     tour_direction::TourDirection,
     tour_step::TourStep,
@@ -30,6 +30,9 @@ pub enum WindowEvent {
     MouseMove(f32, f32),
     /// Emitted when the mouse scroll wheel is scrolled.
     MouseScroll(f32, f32),
+    /// Emitted when a trackpad or other precise scrolling device is scrolled, with the distance in
+    /// physical pixels.
+    MouseScrollPixels(f32, f32),
     /// Emitted when the mouse cursor enters the bounding box of an entity.
     MouseOver,
     /// Emitted when the mouse cursor leaves the bounding box of an entity.
@@ -85,6 +88,8 @@ pub enum WindowEvent {
     GeometryChanged(GeometryChanged),
     /// Requests a redraw of the window contents.
     Redraw,
+    /// Emitted to a view which requested an animation frame, before the next frame is drawn.
+    AnimationFrame,
     /// Request a restyle.
     Restyle,
     /// Requests a relayout.
@@ -241,6 +241,13 @@ impl Application {
                         event_loop_proxy.send_event(Event::new(())).expect("Failed to send event");
                     }
 
+                    // Wake up to show a tooltip once its delay has passed
+                    if let Some(deadline) = cx.tooltip_deadline() {
+                        if *stored_control_flow.borrow() == ControlFlow::Wait {
+                            *stored_control_flow.borrow_mut() = ControlFlow::WaitUntil(deadline);
+                        }
+                    }
+
                     if let Some(window_event_handler) = cx.views().remove(&Entity::root()) {
                         if let Some(window) = window_event_handler.downcast_ref::<Window>() {
                             if window.should_close {
@@ -327,10 +334,7 @@ impl Application {
                                     WindowEvent::MouseScroll(x, y)
                                 }
                                 winit::event::MouseScrollDelta::PixelDelta(pos) => {
-                                    WindowEvent::MouseScroll(
-                                        pos.x as f32 / 20.0,
-                                        pos.y as f32 / 20.0, // this number calibrated for wayland
-                                    )
+                                    WindowEvent::MouseScrollPixels(pos.x as f32, pos.y as f32)
                                 }
                             };
 
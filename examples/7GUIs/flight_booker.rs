@@ -1,58 +1,20 @@
-use std::str::FromStr;
-
 use vizia::fonts::icons_names::DOWN;
 use vizia::prelude::*;
 
-use chrono::{NaiveDate, ParseError};
-
-const STYLE: &str = r#"
-    /*
-    * {
-        border-width: 1px;
-        border-color: red;
-    }
-    */
-
-
-    textbox.invalid {
-        background-color: #AA0000;
-    }
-"#;
-
-#[derive(Clone)]
-pub struct SimpleDate(NaiveDate);
-
-impl Data for SimpleDate {
-    fn same(&self, other: &Self) -> bool {
-        self.0 == other.0
-    }
-}
-
-impl std::fmt::Display for SimpleDate {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0.format("%Y:%m:%d"))
-    }
-}
-
-impl FromStr for SimpleDate {
-    type Err = ParseError;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        NaiveDate::parse_from_str(s, "%Y:%m:%d").map(|date| SimpleDate(date))
-    }
-}
+use chrono::NaiveDate;
 
 #[derive(Lens)]
 pub struct AppData {
     options: Vec<&'static str>,
     choice: String,
-    start_date: SimpleDate,
-    end_date: SimpleDate,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
 }
 
 pub enum AppEvent {
     SetChoice(String),
-    SetStartDate(SimpleDate),
-    SetEndDate(SimpleDate),
+    SetStartDate(NaiveDate),
+    SetEndDate(NaiveDate),
 }
 
 impl Model for AppData {
@@ -63,11 +25,11 @@ impl Model for AppData {
             }
 
             AppEvent::SetStartDate(date) => {
-                self.start_date = date.clone();
+                self.start_date = *date;
             }
 
             AppEvent::SetEndDate(date) => {
-                self.end_date = date.clone();
+                self.end_date = *date;
             }
         });
     }
@@ -78,15 +40,14 @@ impl AppData {
         Self {
             options: vec!["one-way flight", "return flight"],
             choice: "one-way flight".to_string(),
-            start_date: SimpleDate(NaiveDate::from_ymd_opt(2022, 02, 12).unwrap()),
-            end_date: SimpleDate(NaiveDate::from_ymd_opt(2022, 02, 26).unwrap()),
+            start_date: NaiveDate::from_ymd_opt(2022, 02, 12).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2022, 02, 26).unwrap(),
         }
     }
 }
 
 fn main() {
     Application::new(|cx| {
-        cx.add_theme(STYLE);
         AppData::new().build(cx);
         VStack::new(cx, |cx| {
             Dropdown::new(
@@ -123,26 +84,14 @@ fn main() {
             )
             .width(Pixels(150.0));
 
-            Textbox::new(cx, AppData::start_date)
-                .on_edit(|cx, text| {
-                    if let Ok(val) = text.parse::<SimpleDate>() {
-                        cx.emit(AppEvent::SetStartDate(val));
-                        cx.toggle_class("invalid", false);
-                    } else {
-                        cx.toggle_class("invalid", true);
-                    }
-                })
+            DatePicker::new(cx, AppData::start_date)
+                .format("%Y:%m:%d")
+                .on_select(|cx, date| cx.emit(AppEvent::SetStartDate(date)))
                 .width(Pixels(150.0));
 
-            Textbox::new(cx, AppData::end_date)
-                .on_edit(|cx, text| {
-                    if let Ok(val) = text.parse::<SimpleDate>() {
-                        cx.emit(AppEvent::SetEndDate(val));
-                        cx.toggle_class("invalid", false);
-                    } else {
-                        cx.toggle_class("invalid", true);
-                    }
-                })
+            DatePicker::new(cx, AppData::end_date)
+                .format("%Y:%m:%d")
+                .on_select(|cx, date| cx.emit(AppEvent::SetEndDate(date)))
                 .width(Pixels(150.0))
                 .disabled(AppData::choice.map(|choice| choice == "one-way flight"));
 
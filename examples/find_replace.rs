@@ -0,0 +1,184 @@
+//! Press CTRL+F to search the text, F3 and SHIFT+F3 step through the matches.
+
+use vizia::prelude::*;
+use vizia::text::SearchOptions;
+
+const TEXT: &str = "The quick brown fox jumps over the lazy dog.
+The dog wakes up, and the fox runs away.
+Foxes are quicker than dogs, but dogs are friendlier than foxes.";
+
+#[derive(Lens)]
+pub struct AppData {
+    text: String,
+    query: String,
+    replacement: String,
+    case_sensitive: bool,
+    whole_word: bool,
+    show_find: bool,
+    status: String,
+}
+
+pub enum AppEvent {
+    SetText(String),
+    SetQuery(String),
+    SetReplacement(String),
+    ToggleCaseSensitive,
+    ToggleWholeWord,
+    ShowFind(String),
+    HideFind,
+    SetStatus(String),
+}
+
+impl AppData {
+    fn options(&self) -> SearchOptions {
+        SearchOptions {
+            case_sensitive: self.case_sensitive,
+            whole_word: self.whole_word,
+            ..Default::default()
+        }
+    }
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::SetText(text) => self.text = text.clone(),
+            AppEvent::SetQuery(query) => self.query = query.clone(),
+            AppEvent::SetReplacement(replacement) => self.replacement = replacement.clone(),
+            AppEvent::ToggleCaseSensitive => self.case_sensitive ^= true,
+            AppEvent::ToggleWholeWord => self.whole_word ^= true,
+            AppEvent::ShowFind(selected) => {
+                self.show_find = true;
+                if !selected.is_empty() {
+                    self.query = selected.clone();
+                }
+            }
+            AppEvent::HideFind => self.show_find = false,
+            AppEvent::SetStatus(status) => self.status = status.clone(),
+        });
+    }
+}
+
+// Searches the text with the query and options of the model, after applying a change which the model
+// hasn't received yet.
+fn find(cx: &mut EventContext, text: Entity, change: impl FnOnce(&mut String, &mut SearchOptions)) {
+    let data = cx.data::<AppData>().unwrap();
+    let (mut query, mut options) = (data.query.clone(), data.options());
+    change(&mut query, &mut options);
+    cx.emit_to(text, TextEvent::Find(query, options));
+}
+
+fn main() {
+    Application::new(|cx| {
+        AppData {
+            text: TEXT.to_owned(),
+            query: String::new(),
+            replacement: String::new(),
+            case_sensitive: false,
+            whole_word: false,
+            show_find: false,
+            status: String::new(),
+        }
+        .build(cx);
+
+        VStack::new(cx, |cx| {
+            let text = Textbox::new_multiline(cx, AppData::text, true)
+                .on_edit(|cx, text| cx.emit(AppEvent::SetText(text)))
+                .on_open_find(|cx, selected| cx.emit(AppEvent::ShowFind(selected)))
+                .on_find(|cx, matches, current| {
+                    let status = match current {
+                        Some(index) => format!("{} of {}", index + 1, matches.len()),
+                        None => format!("{} matches", matches.len()),
+                    };
+                    cx.emit(AppEvent::SetStatus(status));
+                })
+                .width(Stretch(1.0))
+                .height(Stretch(1.0))
+                .entity;
+
+            Binding::new(cx, AppData::show_find, move |cx, show_find| {
+                if !show_find.get(cx) {
+                    return;
+                }
+
+                HStack::new(cx, |cx| {
+                    Textbox::new(cx, AppData::query)
+                        .placeholder("Find")
+                        .on_edit(move |cx, query| {
+                            cx.emit(AppEvent::SetQuery(query.clone()));
+                            find(cx, text, |current, _| *current = query);
+                        })
+                        .on_submit(move |cx, _, enter| {
+                            if enter {
+                                cx.emit_to(text, TextEvent::FindNext);
+                            }
+                        })
+                        .on_build(|cx| cx.emit(TextEvent::StartEdit))
+                        .width(Pixels(150.0));
+
+                    Checkbox::new(cx, AppData::case_sensitive).on_toggle(move |cx| {
+                        cx.emit(AppEvent::ToggleCaseSensitive);
+                        find(cx, text, |_, options| options.case_sensitive ^= true);
+                    });
+                    Label::new(cx, "Aa");
+
+                    Checkbox::new(cx, AppData::whole_word).on_toggle(move |cx| {
+                        cx.emit(AppEvent::ToggleWholeWord);
+                        find(cx, text, |_, options| options.whole_word ^= true);
+                    });
+                    Label::new(cx, "Word");
+
+                    Button::new(
+                        cx,
+                        move |cx| cx.emit_to(text, TextEvent::FindPrevious),
+                        |cx| Label::new(cx, "Previous"),
+                    );
+                    Button::new(
+                        cx,
+                        move |cx| cx.emit_to(text, TextEvent::FindNext),
+                        |cx| Label::new(cx, "Next"),
+                    );
+                    Label::new(cx, AppData::status).width(Pixels(80.0));
+
+                    Textbox::new(cx, AppData::replacement)
+                        .placeholder("Replace")
+                        .on_edit(|cx, replacement| cx.emit(AppEvent::SetReplacement(replacement)))
+                        .width(Pixels(150.0));
+
+                    Button::new(
+                        cx,
+                        move |cx| {
+                            let replacement = cx.data::<AppData>().unwrap().replacement.clone();
+                            cx.emit_to(text, TextEvent::Replace(replacement));
+                        },
+                        |cx| Label::new(cx, "Replace"),
+                    );
+                    Button::new(
+                        cx,
+                        move |cx| {
+                            let replacement = cx.data::<AppData>().unwrap().replacement.clone();
+                            cx.emit_to(text, TextEvent::ReplaceAll(replacement));
+                        },
+                        |cx| Label::new(cx, "Replace All"),
+                    );
+                    Button::new(
+                        cx,
+                        move |cx| {
+                            cx.emit_to(text, TextEvent::ClearFind);
+                            cx.emit(AppEvent::HideFind);
+                        },
+                        |cx| Label::new(cx, "Close"),
+                    );
+                })
+                .height(Auto)
+                .col_between(Pixels(5.0))
+                .child_top(Stretch(1.0))
+                .child_bottom(Stretch(1.0));
+            });
+        })
+        .row_between(Pixels(5.0))
+        .child_space(Pixels(10.0));
+    })
+    .title("Find and Replace")
+    .run();
+}
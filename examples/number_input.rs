@@ -1,75 +1,52 @@
 use vizia::prelude::*;
-
-const STYLE: &'static str = r#"
-textbox.validation_error {
-    background-color: #ffc0c0;
-}
-
-.validation_error_label {
-    display: none;
-    color: red;
-}
-
-.validation_error_label.validation_error {
-    display: flex;
-}
-"#;
+use vizia::views::normalized_map::DisplayDecimals;
 
 #[derive(Lens)]
 pub struct AppData {
-    number: i32,
-    invalid: bool,
+    count: i32,
+    gain: f32,
 }
 
 #[derive(Debug)]
 pub enum AppEvent {
-    SetNumber(i32),
-    SetInvalid,
+    SetCount(i32),
+    SetGain(f32),
 }
 
 impl Model for AppData {
     fn event(&mut self, _: &mut EventContext, event: &mut Event) {
         event.map(|app_event, _| match app_event {
-            AppEvent::SetNumber(num) => {
-                self.number = *num;
-                self.invalid = false;
-            }
-            AppEvent::SetInvalid => {
-                self.invalid = true;
-            }
+            AppEvent::SetCount(count) => self.count = *count,
+            AppEvent::SetGain(gain) => self.gain = *gain,
         });
     }
 }
 
 fn main() {
     Application::new(|cx| {
-        cx.add_theme(STYLE);
-        AppData { number: 5, invalid: false }.build(cx);
-
-        HStack::new(cx, |cx| {
-            Textbox::new(cx, AppData::number)
-                .on_edit(|cx, text| {
-                    if let Ok(valid_number) = text.parse::<i32>() {
-                        cx.emit(AppEvent::SetNumber(valid_number));
-                    } else {
-                        cx.emit(AppEvent::SetInvalid);
-                    }
-                })
-                .width(Pixels(200.0))
-                .child_left(Pixels(5.0))
-                .toggle_class("validation_error", AppData::invalid);
-
-            Label::new(cx, "Please enter a number")
-                .class("validation_error_label")
-                .toggle_class("validation_error", AppData::invalid);
-
-            Label::new(cx, AppData::number)
-                .width(Pixels(200.0))
-                .height(Pixels(30.0))
-                .child_left(Pixels(5.0));
+        AppData { count: 5, gain: -6.0 }.build(cx);
+
+        VStack::new(cx, |cx| {
+            NumberInput::new(cx, AppData::count)
+                .label("Count")
+                .range(0..=100)
+                .on_change(|cx, count| cx.emit(AppEvent::SetCount(count)));
+
+            // Shift steps by 5 dB and Ctrl by 0.05 dB
+            NumberInput::new(cx, AppData::gain)
+                .label("Gain")
+                .range(-60.0..=12.0)
+                .step(0.5)
+                .decimals(DisplayDecimals::Two)
+                .units(" dB")
+                .on_change(|cx, gain| cx.emit(AppEvent::SetGain(gain)));
+
+            Label::new(cx, AppData::count.map(|count| format!("Count: {}", count)));
+            Label::new(cx, AppData::gain.map(|gain| format!("Gain: {} dB", gain)));
         })
         .space(Stretch(1.0))
-        .col_between(Pixels(10.0));
+        .size(Auto)
+        .row_between(Pixels(10.0));
     })
     .title("Number Input")
     .run();
@@ -0,0 +1,90 @@
+use vizia::prelude::*;
+
+const STYLE: &str = r#"
+    .card {
+        width: 200px;
+        height: 140px;
+        border-radius: 16px;
+        overflow: hidden;
+        background-color: #e0e0e0;
+    }
+
+    .header {
+        height: 60px;
+        background-color: #4060c0;
+    }
+
+    .item {
+        height: 40px;
+        child-left: 10px;
+        child-top: 1s;
+        child-bottom: 1s;
+    }
+
+    .item:hover {
+        background-color: #f0c040;
+    }
+
+    .avatar {
+        width: 140px;
+        height: 140px;
+        clip-path: circle(50%);
+        background-color: #40a060;
+    }
+
+    .badge {
+        width: 200px;
+        height: 140px;
+        clip-path: inset(10px 20px round 24px);
+        background-color: #c04060;
+    }
+
+    .wide {
+        width: 200px;
+        height: 140px;
+        clip-path: ellipse(50% 35%);
+        background-color: #8040c0;
+    }
+"#;
+
+// A card whose content is clipped to its rounded corners, with hoverable items which only respond
+// within the clip.
+fn card(cx: &mut Context) -> Handle<VStack> {
+    VStack::new(cx, |cx| {
+        Element::new(cx).class("header");
+        for index in 0..3 {
+            Label::new(cx, &format!("Item {}", index + 1)).class("item");
+        }
+    })
+    .class("card")
+}
+
+fn main() {
+    Application::new(|cx| {
+        cx.add_theme(STYLE);
+
+        VStack::new(cx, |cx| {
+            HStack::new(cx, |cx| {
+                card(cx);
+                card(cx).rotate(15.0);
+            })
+            .height(Auto)
+            .col_between(Pixels(40.0));
+
+            HStack::new(cx, |cx| {
+                Element::new(cx).class("avatar").tooltip_text("clip-path: circle(50%)");
+                Element::new(cx)
+                    .class("badge")
+                    .tooltip_text("clip-path: inset(10px 20px round 24px)");
+                Element::new(cx).class("wide").tooltip_text("clip-path: ellipse(50% 35%)");
+            })
+            .height(Auto)
+            .col_between(Pixels(20.0));
+        })
+        .child_space(Pixels(20.0))
+        .row_between(Pixels(40.0));
+    })
+    .title("Clipping")
+    .inner_size((700, 450))
+    .run();
+}
@@ -0,0 +1,56 @@
+use vizia::prelude::*;
+
+const STYLE: &str = r#"
+    .form {
+        layout-type: grid;
+        grid-template-areas: "title title" "name-label name" "email-label email";
+        grid-cols: 100px minmax(150px, 1s);
+        grid-rows: repeat(3, 40px);
+        grid-auto-rows: 30px;
+        child-space: 20px;
+        row-between: 10px;
+        col-between: 10px;
+        background-color: #e8e8e8;
+    }
+
+    .title {
+        grid-area: title;
+        font-size: 20px;
+    }
+
+    .field {
+        width: 1s;
+        height: 30px;
+    }
+
+    .option {
+        height: 30px;
+        child-top: 1s;
+        child-bottom: 1s;
+    }
+"#;
+
+fn main() {
+    Application::new(|cx| {
+        cx.add_theme(STYLE);
+
+        VStack::new(cx, |cx| {
+            Label::new(cx, "Account").class("title");
+
+            Label::new(cx, "Name").grid_area("name-label");
+            Element::new(cx).class("field").background_color(Color::white()).grid_area("name");
+
+            Label::new(cx, "Email").grid_area("email-label");
+            Element::new(cx).class("field").background_color(Color::white()).grid_area("email");
+
+            // Children without an area fill the first free cells, in rows added as needed
+            for option in ["Newsletter", "Updates", "Offers", "Reminders"] {
+                Label::new(cx, option).class("option");
+            }
+        })
+        .class("form");
+    })
+    .title("Grid Areas")
+    .inner_size((500, 400))
+    .run();
+}
@@ -0,0 +1,105 @@
+use vizia::prelude::*;
+use vizia_core::resource::ImageRetentionPolicy;
+
+const STYLE: &str = r#"
+    image {
+        width: 150px;
+        height: 150px;
+        background-color: #d0d0d0;
+    }
+
+    .fill { object-fit: fill; }
+    .contain { object-fit: contain; }
+    .cover { object-fit: cover; }
+    .none { object-fit: none; object-position: left top; }
+    .scale-down { object-fit: scale-down; object-position: 0% 100%; }
+
+    .background {
+        width: 150px;
+        height: 150px;
+        background-color: #d0d0d0;
+        background-image: "sample.png";
+    }
+
+    .background.no-repeat {
+        background-size: 50%;
+        background-position: center;
+        background-repeat: no-repeat;
+    }
+
+    .background.repeat-x {
+        background-size: auto 50px;
+        background-position: left bottom;
+        background-repeat: repeat-x;
+    }
+
+    .frame {
+        width: 1s;
+        height: 1s;
+        border-image-source: "frame.png";
+        border-image-slice: 12 fill;
+        child-space: 20px;
+    }
+"#;
+
+// A frame with dark corners and lighter edges, so that stretching of the corners would be visible.
+fn frame_image() -> image::DynamicImage {
+    image::DynamicImage::ImageRgba8(image::RgbaImage::from_fn(36, 36, |x, y| {
+        let edge_x = x < 12 || x >= 24;
+        let edge_y = y < 12 || y >= 24;
+        match (edge_x, edge_y) {
+            (true, true) => image::Rgba([40, 40, 120, 255]),
+            (true, false) | (false, true) => image::Rgba([90, 90, 200, 255]),
+            (false, false) => image::Rgba([230, 230, 250, 255]),
+        }
+    }))
+}
+
+fn main() {
+    Application::new(|cx| {
+        cx.add_theme(STYLE);
+        cx.set_image_loader(|cx, path| match path {
+            "sample.png" => cx.load_image(
+                path.to_owned(),
+                image::load_from_memory_with_format(
+                    include_bytes!("../resources/sample-hut-400x300.png"),
+                    image::ImageFormat::Png,
+                )
+                .unwrap(),
+                ImageRetentionPolicy::Forever,
+            ),
+            "frame.png" => {
+                cx.load_image(path.to_owned(), frame_image(), ImageRetentionPolicy::Forever)
+            }
+            _ => {}
+        });
+
+        HStack::new(cx, |cx| {
+            for class in ["fill", "contain", "cover", "none", "scale-down"] {
+                VStack::new(cx, |cx| {
+                    Image::new(cx, "sample.png").class(class);
+                    Label::new(cx, class);
+                })
+                .size(Auto);
+            }
+        })
+        .height(Auto)
+        .col_between(Pixels(10.0));
+
+        HStack::new(cx, |cx| {
+            Element::new(cx).class("background");
+            Element::new(cx).class("background").class("no-repeat");
+            Element::new(cx).class("background").class("repeat-x");
+        })
+        .height(Auto)
+        .col_between(Pixels(10.0));
+
+        VStack::new(cx, |cx| {
+            Label::new(cx, "Resize the window: the corners of this frame keep their size.");
+        })
+        .class("frame");
+    })
+    .title("Image Fit")
+    .inner_size((850, 600))
+    .run();
+}
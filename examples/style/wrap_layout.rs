@@ -0,0 +1,80 @@
+use vizia::prelude::*;
+
+const STYLE: &str = r#"
+    .tags {
+        layout-type: wrap-row;
+        height: auto;
+        child-space: 10px;
+        col-between: 6px;
+        row-between: 6px;
+        background-color: #e8e8e8;
+    }
+
+    .tag {
+        width: auto;
+        height: auto;
+        child-space: 6px;
+        border-radius: 4px;
+        background-color: #4060c0;
+        color: white;
+    }
+
+    .presets {
+        layout-type: wrap-row;
+        height: auto;
+        col-between: 10px;
+        row-between: 10px;
+    }
+
+    .preset {
+        width: 1s;
+        min-width: 120px;
+        height: 60px;
+        child-space: 1s;
+        background-color: #c0c0c0;
+    }
+"#;
+
+const TAGS: [&str; 12] = [
+    "synth",
+    "bass",
+    "pad",
+    "lead",
+    "analog",
+    "digital",
+    "warm",
+    "bright",
+    "evolving",
+    "arp",
+    "ambient",
+    "percussive",
+];
+
+fn main() {
+    Application::new(|cx| {
+        cx.add_theme(STYLE);
+
+        VStack::new(cx, |cx| {
+            // Tags flow onto new lines when the window narrows
+            HStack::new(cx, |cx| {
+                for tag in TAGS {
+                    Label::new(cx, tag).class("tag");
+                }
+            })
+            .class("tags");
+
+            // Presets share the width of each line between them
+            HStack::new(cx, |cx| {
+                for index in 1..=7 {
+                    Label::new(cx, &format!("Preset {}", index)).class("preset");
+                }
+            })
+            .class("presets");
+        })
+        .child_space(Pixels(20.0))
+        .row_between(Pixels(20.0));
+    })
+    .title("Wrap Layout")
+    .inner_size((500, 400))
+    .run();
+}
@@ -0,0 +1,22 @@
+use vizia::prelude::*;
+use vizia::text::SyntectHighlighter;
+
+#[derive(Lens, Setter, Model)]
+pub struct AppData {
+    source: String,
+}
+
+fn main() {
+    Application::new(|cx| {
+        AppData { source: "fn main() {\n    println!(\"Hello, world!\");\n}\n".to_string() }
+            .build(cx);
+
+        CodeEditor::new(cx, AppData::source)
+            .on_edit(|cx, text| cx.emit(AppDataSetter::Source(text)))
+            .highlighter(SyntectHighlighter::new("rs"))
+            .width(Stretch(1.0))
+            .height(Stretch(1.0));
+    })
+    .title("Code Editor")
+    .run();
+}
@@ -0,0 +1,46 @@
+use vizia::prelude::*;
+
+fn main() {
+    Application::new(|cx| {
+        AppData {
+            color: Color::rgb(200, 80, 40),
+            swatches: vec![Color::rgb(0, 90, 158), Color::rgb(46, 160, 67), Color::black()],
+        }
+        .build(cx);
+
+        HStack::new(cx, |cx| {
+            ColorPicker::new(cx, AppData::color)
+                .swatches(AppData::swatches.get(cx))
+                .on_change(|cx, color| cx.emit(AppEvent::SetColor(color)))
+                .on_swatches_change(|cx, swatches| cx.emit(AppEvent::SetSwatches(swatches)));
+
+            // Shows the picked colour, which can also be picked back with the eyedropper
+            Element::new(cx).size(Pixels(120.0)).background_color(AppData::color);
+        })
+        .col_between(Pixels(20.0))
+        .child_space(Pixels(20.0));
+    })
+    .title("Color Picker")
+    .run();
+}
+
+#[derive(Debug, Lens)]
+pub struct AppData {
+    color: Color,
+    swatches: Vec<Color>,
+}
+
+#[derive(Debug)]
+pub enum AppEvent {
+    SetColor(Color),
+    SetSwatches(Vec<Color>),
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::SetColor(color) => self.color = *color,
+            AppEvent::SetSwatches(swatches) => self.swatches = swatches.clone(),
+        });
+    }
+}
@@ -0,0 +1,62 @@
+use vizia::chrono::{Datelike, NaiveDate, NaiveTime, Weekday};
+use vizia::prelude::*;
+
+fn main() {
+    Application::new(|cx| {
+        AppData {
+            date: NaiveDate::from_ymd_opt(2023, 3, 14).unwrap(),
+            time: NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+        }
+        .build(cx);
+
+        HStack::new(cx, |cx| {
+            Calendar::new(cx, AppData::date)
+                .week_numbers(true)
+                .on_select(|cx, date| cx.emit(AppEvent::SetDate(date)));
+
+            VStack::new(cx, |cx| {
+                // Weekends can't be picked
+                DatePicker::new(cx, AppData::date)
+                    .format("%d/%m/%Y")
+                    .min_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+                    .max_date(NaiveDate::from_ymd_opt(2030, 12, 31).unwrap())
+                    .disabled_dates(|date| matches!(date.weekday(), Weekday::Sat | Weekday::Sun))
+                    .on_select(|cx, date| cx.emit(AppEvent::SetDate(date)));
+
+                TimePicker::new(cx, AppData::time)
+                    .min_time(NaiveTime::from_hms_opt(8, 0, 0).unwrap())
+                    .max_time(NaiveTime::from_hms_opt(18, 0, 0).unwrap())
+                    .on_select(|cx, time| cx.emit(AppEvent::SetTime(time)));
+
+                Label::new(cx, AppData::date.map(|date| date.format("%A %-d %B %Y").to_string()));
+                Label::new(cx, AppData::time);
+            })
+            .row_between(Pixels(10.0));
+        })
+        .col_between(Pixels(20.0))
+        .child_space(Pixels(20.0));
+    })
+    .title("Date Picker")
+    .run();
+}
+
+#[derive(Debug, Lens)]
+pub struct AppData {
+    date: NaiveDate,
+    time: NaiveTime,
+}
+
+#[derive(Debug)]
+pub enum AppEvent {
+    SetDate(NaiveDate),
+    SetTime(NaiveTime),
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::SetDate(date) => self.date = *date,
+            AppEvent::SetTime(time) => self.time = *time,
+        });
+    }
+}
@@ -0,0 +1,69 @@
+use vizia::prelude::*;
+
+fn main() {
+    Application::new(|cx| {
+        AppData {
+            layout: DockLayout::new(DockNode::split(
+                Orientation::Horizontal,
+                vec![
+                    DockNode::tabs(&["Files", "Search"]),
+                    DockNode::split(
+                        Orientation::Vertical,
+                        vec![DockNode::tabs(&["Editor", "Preview"]), DockNode::tabs(&["Console"])],
+                    ),
+                ],
+            )),
+        }
+        .build(cx);
+
+        VStack::new(cx, |cx| {
+            // Buttons to restore the closed panels
+            HStack::new(cx, |cx| {
+                Binding::new(
+                    cx,
+                    AppData::layout.map(|layout| layout.closed.clone()),
+                    |cx, closed| {
+                        for panel in closed.get(cx) {
+                            let label = format!("Show {}", panel);
+                            Button::new(
+                                cx,
+                                move |cx| cx.emit(AppEvent::Restore(panel.clone())),
+                                move |cx| Label::new(cx, &label),
+                            );
+                        }
+                    },
+                );
+            })
+            .col_between(Pixels(5.0))
+            .height(Auto)
+            .child_space(Pixels(5.0));
+
+            DockArea::new(cx, AppData::layout, |cx, panel| {
+                Label::new(cx, &format!("The {} panel", panel)).child_space(Pixels(10.0));
+            })
+            .on_change(|cx, layout| cx.emit(AppEvent::SetLayout(layout)));
+        });
+    })
+    .title("Dock")
+    .run();
+}
+
+#[derive(Debug, Lens)]
+pub struct AppData {
+    layout: DockLayout,
+}
+
+#[derive(Debug)]
+pub enum AppEvent {
+    SetLayout(DockLayout),
+    Restore(String),
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::SetLayout(layout) => self.layout = layout.clone(),
+            AppEvent::Restore(panel) => self.layout.restore(panel),
+        });
+    }
+}
@@ -0,0 +1,19 @@
+use vizia::prelude::*;
+
+fn main() {
+    Application::new(|cx| {
+        HStack::new(cx, |cx| {
+            for name in ["home", "search", "settings", "delete", "favorite"] {
+                Icon::new(cx, name).font_size(24.0);
+            }
+
+            for name in ["note", "cog", "trash", "heart", "cancel-circled"] {
+                Icon::entypo(cx, name).font_size(24.0);
+            }
+        })
+        .col_between(Pixels(10.0))
+        .space(Stretch(1.0));
+    })
+    .title("Icon")
+    .run();
+}
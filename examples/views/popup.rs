@@ -4,11 +4,13 @@ fn main() {
     Application::new(|cx| {
         PopupData::default().build(cx);
 
-        Button::new(cx, |cx| cx.emit(PopupEvent::Switch), |cx| Label::new(cx, "Open"));
+        let button = Button::new(cx, |cx| cx.emit(PopupEvent::Switch), |cx| Label::new(cx, "Open"))
+            .space(Pixels(100.0))
+            .entity();
 
         Popup::new(cx, PopupData::is_open, true, |_| {})
             .on_blur(|cx| cx.emit(PopupEvent::Close))
-            .space(Pixels(100.0))
+            .anchor(Anchor::new(button, AnchorSide::Bottom).offset(4.0))
             .size(Pixels(200.0))
             .background_color(Color::red());
     })
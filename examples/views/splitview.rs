@@ -0,0 +1,49 @@
+use vizia::prelude::*;
+
+fn main() {
+    Application::new(|cx| {
+        AppData { columns: vec![200.0], rows: vec![] }.build(cx);
+
+        SplitView::new(cx, AppData::columns, Orientation::Horizontal, |cx| {
+            Label::new(cx, "Sidebar")
+                .min_width(Pixels(100.0))
+                .max_width(Pixels(400.0))
+                .child_space(Pixels(10.0))
+                .background_color(Color::rgb(230, 230, 230));
+
+            SplitView::new(cx, AppData::rows, Orientation::Vertical, |cx| {
+                Label::new(cx, "Editor").min_height(Pixels(50.0)).child_space(Pixels(10.0));
+                Label::new(cx, "Console")
+                    .min_height(Pixels(50.0))
+                    .child_space(Pixels(10.0))
+                    .background_color(Color::rgb(240, 240, 240));
+            })
+            .min_width(Pixels(200.0))
+            .on_resize(|cx, sizes| cx.emit(AppEvent::SetRows(sizes)));
+        })
+        .on_resize(|cx, sizes| cx.emit(AppEvent::SetColumns(sizes)));
+    })
+    .title("Split View")
+    .run();
+}
+
+#[derive(Debug, Lens)]
+pub struct AppData {
+    columns: Vec<f32>,
+    rows: Vec<f32>,
+}
+
+#[derive(Debug)]
+pub enum AppEvent {
+    SetColumns(Vec<f32>),
+    SetRows(Vec<f32>),
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::SetColumns(sizes) => self.columns = sizes.clone(),
+            AppEvent::SetRows(sizes) => self.rows = sizes.clone(),
+        });
+    }
+}
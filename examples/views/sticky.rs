@@ -0,0 +1,72 @@
+use vizia::prelude::*;
+
+const STYLE: &str = r#"
+    .toolbar {
+        child-left: 20px;
+        child-top: 1s;
+        child-bottom: 1s;
+        background-color: #4060c0;
+        font-size: 24.0;
+    }
+
+    .header {
+        height: 40px;
+        child-left: 10px;
+        child-top: 1s;
+        child-bottom: 1s;
+        background-color: #d0d0d0;
+        position: sticky;
+        sticky-top: 0px;
+        z-index: 1;
+    }
+
+    .item {
+        height: 32px;
+        child-left: 20px;
+        child-top: 1s;
+        child-bottom: 1s;
+    }
+"#;
+
+fn main() {
+    Application::new(|cx| {
+        cx.add_theme(STYLE);
+
+        // Built outside of the scroll view so that the toolbar can follow the scroll position
+        ScrollData {
+            scroll_x: 0.0,
+            scroll_y: 0.0,
+            child_x: 0.0,
+            child_y: 0.0,
+            parent_x: 0.0,
+            parent_y: 0.0,
+        }
+        .build(cx);
+
+        VStack::new(cx, |cx| {
+            // The toolbar shrinks and its title fades as the content is scrolled
+            HStack::new(cx, |cx| {
+                Label::new(cx, "Contacts")
+                    .opacity(ScrollData::scroll_y.map(|y| (1.0 - y * 4.0).max(0.0)));
+            })
+            .class("toolbar")
+            .height(ScrollData::scroll_y.map(|y| Pixels((80.0 - y * 160.0).max(40.0))));
+
+            ScrollView::custom(cx, false, true, ScrollData::root, |cx| {
+                for letter in ['A', 'B', 'C', 'D', 'E'] {
+                    // Each header sticks to the top while its section is in view
+                    VStack::new(cx, move |cx| {
+                        Label::new(cx, &letter.to_string()).class("header");
+                        for index in 1..=6 {
+                            Label::new(cx, &format!("{} contact {}", letter, index)).class("item");
+                        }
+                    })
+                    .height(Auto);
+                }
+            });
+        });
+    })
+    .title("Sticky")
+    .inner_size((400, 500))
+    .run();
+}
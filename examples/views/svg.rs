@@ -0,0 +1,31 @@
+use vizia::prelude::*;
+use vizia_core::resource::ImageRetentionPolicy;
+
+const LOGO: &str = r##"<svg xmlns="http://www.w3.org/2000/svg" width="64" height="64" viewBox="0 0 64 64">
+    <circle cx="32" cy="32" r="30" fill="#4c00ff"/>
+    <path d="M18 20 L32 46 L46 20" stroke="white" stroke-width="6" fill="none" stroke-linejoin="round"/>
+</svg>"##;
+
+fn main() {
+    Application::new(|cx| {
+        cx.set_image_loader(|cx, path| {
+            if path == "logo.svg" {
+                cx.load_svg(
+                    path.to_owned(),
+                    LOGO.as_bytes(),
+                    ImageRetentionPolicy::DropWhenNoObservers,
+                )
+                .expect("Failed to parse SVG");
+            }
+        });
+
+        VStack::new(cx, |cx| {
+            Image::new(cx, "logo.svg");
+            Label::new(cx, "SVG images stay sharp at any scale factor.");
+        })
+        .row_between(Pixels(10.0))
+        .child_space(Stretch(1.0));
+    })
+    .title("SVG")
+    .run();
+}
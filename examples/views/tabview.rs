@@ -0,0 +1,68 @@
+use vizia::prelude::*;
+
+fn main() {
+    Application::new(|cx| {
+        AppData { tabs: (1..=12).map(|index| format!("Document {}", index)).collect(), next: 13 }
+            .build(cx);
+
+        VStack::new(cx, |cx| {
+            Button::new(cx, |cx| cx.emit(AppEvent::Open), |cx| Label::new(cx, "New Tab"))
+                .space(Pixels(5.0));
+
+            TabView::new(
+                cx,
+                AppData::tabs,
+                |cx, tab| {
+                    Label::new(cx, &tab);
+                },
+                |cx, tab| {
+                    // The scroll position of each tab is kept while other tabs are selected
+                    ScrollView::new(cx, 0.0, 0.0, false, true, move |cx| {
+                        for line in 1..=50 {
+                            Label::new(cx, &format!("{}, line {}", tab, line));
+                        }
+                    })
+                    .child_space(Pixels(10.0));
+                },
+            )
+            .retention(TabRetention::Keep)
+            .on_close(|cx, index| cx.emit(AppEvent::Close(index)))
+            .on_reorder(|cx, from, to| cx.emit(AppEvent::Reorder(from, to)));
+        });
+    })
+    .title("Tab View")
+    .run();
+}
+
+#[derive(Debug, Lens)]
+pub struct AppData {
+    tabs: Vec<String>,
+    next: usize,
+}
+
+#[derive(Debug)]
+pub enum AppEvent {
+    Open,
+    Close(usize),
+    Reorder(usize, usize),
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::Open => {
+                self.tabs.push(format!("Document {}", self.next));
+                self.next += 1;
+            }
+
+            AppEvent::Close(index) => {
+                self.tabs.remove(*index);
+            }
+
+            AppEvent::Reorder(from, to) => {
+                let tab = self.tabs.remove(*from);
+                self.tabs.insert(*to, tab);
+            }
+        });
+    }
+}
@@ -17,6 +17,11 @@ fn main() {
                 cx.emit(TextEvent::StartEdit);
             });
 
+        Textbox::new(cx, StaticLens::new(&""))
+            .placeholder("Password")
+            .password(true)
+            .width(Pixels(200.0));
+
         Textbox::new_multiline(
             cx,
             StaticLens::new(
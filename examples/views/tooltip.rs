@@ -0,0 +1,25 @@
+use vizia::prelude::*;
+
+fn main() {
+    Application::new(|cx| {
+        cx.set_tooltip_delay(std::time::Duration::from_millis(300));
+
+        HStack::new(cx, |cx| {
+            // Tooltip with text, shown below the button
+            Button::new(cx, |_| {}, |cx| Label::new(cx, "Save")).tooltip_text("Save the document");
+            // Tooltip with custom content
+            Button::new(cx, |_| {}, |cx| Label::new(cx, "Export")).tooltip(|cx| {
+                VStack::new(cx, |cx| {
+                    Label::new(cx, "Export");
+                    Label::new(cx, "Writes the document to a PNG file");
+                })
+                .size(Auto);
+            });
+        })
+        .size(Auto)
+        .space(Stretch(1.0))
+        .col_between(Pixels(10.0));
+    })
+    .title("Tooltip")
+    .run();
+}
@@ -0,0 +1,109 @@
+use vizia::prelude::*;
+
+fn main() {
+    Application::new(|cx| {
+        // Over fifty thousand nodes, of which only the rows in view are built
+        let items = (1..=100)
+            .map(|folder| Item {
+                name: format!("Folder {}", folder),
+                children: (1..=20)
+                    .map(|subfolder| Item {
+                        name: format!("Folder {}.{}", folder, subfolder),
+                        children: (1..=25)
+                            .map(|file| Item {
+                                name: format!("File {}.{}.{}", folder, subfolder, file),
+                                children: Vec::new(),
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        AppData { items, selected: Vec::new() }.build(cx);
+
+        VStack::new(cx, |cx| {
+            Label::new(
+                cx,
+                AppData::selected.map(|selected| format!("{} selected", selected.len())),
+            )
+            .space(Pixels(5.0));
+
+            TreeView::new(cx, AppData::items, |cx, item| {
+                Label::new(cx, item.map(|item| item.name.clone()));
+            })
+            .selection_mode(SelectionMode::Multiple)
+            .on_select(|cx, selected| cx.emit(AppEvent::Select(selected)))
+            .on_drop(|cx, nodes, target| cx.emit(AppEvent::Move(nodes, target)));
+        });
+    })
+    .title("Tree View")
+    .run();
+}
+
+#[derive(Debug, Clone, Data)]
+pub struct Item {
+    name: String,
+    children: Vec<Item>,
+}
+
+impl TreeNode for Item {
+    fn children(&self) -> &[Self] {
+        &self.children
+    }
+}
+
+#[derive(Debug, Lens)]
+pub struct AppData {
+    items: Vec<Item>,
+    selected: Vec<Vec<usize>>,
+}
+
+#[derive(Debug)]
+pub enum AppEvent {
+    Select(Vec<Vec<usize>>),
+    Move(Vec<Vec<usize>>, TreeDrop),
+}
+
+// Returns the children of the node at the given path, or the roots for an empty path.
+fn children_mut<'a>(items: &'a mut Vec<Item>, path: &[usize]) -> &'a mut Vec<Item> {
+    path.iter().fold(items, |items, index| &mut items[*index].children)
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::Select(selected) => self.selected = selected.clone(),
+
+            AppEvent::Move(nodes, target) => {
+                let mut target = target.clone();
+                let mut moved = Vec::new();
+
+                // Removing the last nodes first keeps the paths of the others valid, while the
+                // target is shifted past each removed node before it
+                for path in nodes.iter().rev() {
+                    let (index, parent) = path.split_last().unwrap();
+                    moved.push(children_mut(&mut self.items, parent).remove(*index));
+
+                    if target.parent == parent {
+                        if *index < target.index {
+                            target.index -= 1;
+                        }
+                    } else if target.parent.starts_with(parent)
+                        && target.parent[parent.len()] > *index
+                    {
+                        target.parent[parent.len()] -= 1;
+                    }
+                }
+
+                let children = children_mut(&mut self.items, &target.parent);
+                for item in moved {
+                    children.insert(target.index, item);
+                }
+
+                // The moved nodes have new paths
+                self.selected.clear();
+            }
+        });
+    }
+}